@@ -712,6 +712,61 @@ mod bench_selfadjoint_evd {
     }
 }
 
+mod bench_transposed_matmul {
+    use super::*;
+    use faer::quad::Double;
+
+    // `A^T * B` for a tall `A` with `64 * n` rows and 16 columns. `Double<f64>` isn't handled by
+    // `gemm`, so the product goes through the transposed lhs path of the generic kernel.
+    fn tall_pair(n: usize) -> (Mat<Double<f64>>, Mat<Double<f64>>) {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let mut random = |_, _| Double(rng.gen::<f64>(), 0.0);
+        (
+            Mat::from_fn(64 * n, 16, &mut random),
+            Mat::from_fn(64 * n, 16, &mut random),
+        )
+    }
+
+    fn transposed_view(bencher: Bencher, n: usize, parallelism: faer::Parallelism) {
+        let (A, B) = tall_pair(n);
+        let mut acc = Mat::<Double<f64>>::zeros(16, 16);
+        bencher.bench(|| {
+            faer::linalg::matmul::matmul(
+                acc.as_mut(),
+                A.transpose(),
+                B.as_ref(),
+                None,
+                Double(1.0, 0.0),
+                parallelism,
+            )
+        })
+    }
+
+    pub fn faer_seq_transposed_view(bencher: Bencher, PlotArg(n): PlotArg) {
+        transposed_view(bencher, n, faer::Parallelism::None)
+    }
+
+    pub fn faer_par_transposed_view(bencher: Bencher, PlotArg(n): PlotArg) {
+        transposed_view(bencher, n, faer::Parallelism::Rayon(0))
+    }
+
+    pub fn faer_explicit_transpose(bencher: Bencher, PlotArg(n): PlotArg) {
+        let (A, B) = tall_pair(n);
+        let mut acc = Mat::<Double<f64>>::zeros(16, 16);
+        bencher.bench(|| {
+            let At = A.transpose().to_owned();
+            faer::linalg::matmul::matmul(
+                acc.as_mut(),
+                At.as_ref(),
+                B.as_ref(),
+                None,
+                Double(1.0, 0.0),
+                faer::Parallelism::None,
+            )
+        })
+    }
+}
+
 fn register_for<E: TypeDispatch>(bench: &mut Bench)
 where
     Standard: Distribution<E> + Distribution<E::Type>,
@@ -830,6 +885,15 @@ fn main() -> std::io::Result<()> {
         ..config.clone()
     });
 
+    bench.register_many(
+        list![
+            bench_transposed_matmul::faer_seq_transposed_view,
+            bench_transposed_matmul::faer_par_transposed_view,
+            bench_transposed_matmul::faer_explicit_transpose,
+        ],
+        [4, 8, 16, 32, 64, 128, 256, 512, 1024].map(PlotArg),
+    );
+
     register_for::<f32>(bench);
     register_for::<f64>(bench);
     register_for::<c32>(bench);
@@ -71,6 +71,40 @@ where
     })
 }
 
+fn faer_large(bencher: Bencher, n: usize, par: faer::Parallelism) {
+    let rng = &mut StdRng::seed_from_u64(0);
+    let H = random_mat::<f64>(rng, n, n);
+    let H = &H * H.adjoint() + Mat::<f64>::identity(n, n);
+    let mut llt = H.clone();
+    let mut mem = GlobalPodBuffer::new(
+        faer::linalg::cholesky::llt::compute::cholesky_in_place_req::<f64>(
+            n,
+            par,
+            Default::default(),
+        )
+        .unwrap(),
+    );
+    bencher.bench(|| {
+        llt.copy_from_triangular_lower(&H);
+        faer::linalg::cholesky::llt::compute::cholesky_in_place(
+            llt.as_mut(),
+            Default::default(),
+            par,
+            PodStack::new(&mut mem),
+            Default::default(),
+        )
+        .unwrap();
+    })
+}
+
+fn faer_seq_large(bencher: Bencher, PlotArg(n): PlotArg) {
+    faer_large(bencher, n, faer::Parallelism::None)
+}
+
+fn faer_par_large(bencher: Bencher, PlotArg(n): PlotArg) {
+    faer_large(bencher, n, faer::Parallelism::Rayon(0))
+}
+
 fn nalgebra<E: TypeDispatch>(bencher: Bencher, PlotArg(n): PlotArg)
 where
     Standard: Distribution<E::Type>,
@@ -82,6 +116,103 @@ where
     bencher.bench(|| H.clone().cholesky().unwrap())
 }
 
+fn laplacian_2d(m: usize) -> faer::sparse::SparseColMat<usize, f64> {
+    // 2d laplacian on an m×m grid
+    let n = m * m;
+    let mut triplets = Vec::new();
+    for x in 0..m {
+        for y in 0..m {
+            let i = x * m + y;
+            triplets.push((i, i, 4.0));
+            if x + 1 < m {
+                triplets.push((i, i + m, -1.0));
+            }
+            if y + 1 < m {
+                triplets.push((i, i + 1, -1.0));
+            }
+        }
+    }
+    faer::sparse::SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets).unwrap()
+}
+
+fn faer_sparse_factorize(bencher: Bencher, m: usize, par: faer::Parallelism) {
+    use faer::sparse::linalg::{cholesky, FactorizationVariant};
+
+    let A = laplacian_2d(m);
+    let symbolic = cholesky::factorize_symbolic_cholesky_with_variant(
+        A.symbolic(),
+        faer::Side::Upper,
+        cholesky::SymmetricOrdering::Amd,
+        Default::default(),
+        FactorizationVariant::Supernodal,
+    )
+    .unwrap();
+    let mut L_values = vec![0.0; symbolic.len_values()];
+    let mut mem = GlobalPodBuffer::new(symbolic.factorize_numeric_llt_req::<f64>(par).unwrap());
+    bencher.bench(|| {
+        symbolic
+            .factorize_numeric_llt::<f64>(
+                &mut L_values,
+                A.as_ref(),
+                faer::Side::Upper,
+                Default::default(),
+                par,
+                PodStack::new(&mut mem),
+            )
+            .unwrap();
+    })
+}
+
+fn faer_seq_sparse_factorize(bencher: Bencher, PlotArg(m): PlotArg) {
+    faer_sparse_factorize(bencher, m, faer::Parallelism::None)
+}
+
+fn faer_par_sparse_factorize(bencher: Bencher, PlotArg(m): PlotArg) {
+    faer_sparse_factorize(bencher, m, faer::Parallelism::Rayon(0))
+}
+
+fn faer_sparse_solve(bencher: Bencher, PlotArg(k): PlotArg) {
+    use faer::sparse::linalg::{cholesky, FactorizationVariant};
+
+    let par = faer::Parallelism::None;
+
+    let m = 64;
+    let n = m * m;
+    let A = laplacian_2d(m);
+
+    let symbolic = cholesky::factorize_symbolic_cholesky_with_variant(
+        A.symbolic(),
+        faer::Side::Upper,
+        cholesky::SymmetricOrdering::Amd,
+        Default::default(),
+        FactorizationVariant::Supernodal,
+    )
+    .unwrap();
+    let mut L_values = vec![0.0; symbolic.len_values()];
+    symbolic
+        .factorize_numeric_llt::<f64>(
+            &mut L_values,
+            A.as_ref(),
+            faer::Side::Upper,
+            Default::default(),
+            par,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                symbolic.factorize_numeric_llt_req::<f64>(par).unwrap(),
+            )),
+        )
+        .unwrap();
+    let llt = cholesky::LltRef::<'_, usize, f64>::new(&symbolic, &L_values);
+
+    let rng = &mut StdRng::seed_from_u64(0);
+    let rhs = random_mat::<f64>(rng, n, k);
+    let mut x = rhs.clone();
+    let mut mem = GlobalPodBuffer::new(symbolic.solve_in_place_req::<f64>(k).unwrap());
+    bencher.bench(|| {
+        x.copy_from(&rhs);
+        llt.solve_in_place_with_conj(faer::Conj::No, x.as_mut(), par, PodStack::new(&mut mem));
+    })
+}
+
 fn main() -> std::io::Result<()> {
     let mut bench = Bench::new(BenchConfig::from_args()?);
     bench.register_many(
@@ -92,6 +223,15 @@ fn main() -> std::io::Result<()> {
         list![faer::<f64>, nalgebra::<f64>],
         [1, 2, 4, 8, 16, 32, 64, 128, 256].map(PlotArg),
     );
+    bench.register_many(
+        list![faer_seq_large, faer_par_large],
+        [4096, 8192].map(PlotArg),
+    );
+    bench.register(faer_sparse_solve, [1, 8, 64].map(PlotArg));
+    bench.register_many(
+        list![faer_seq_sparse_factorize, faer_par_sparse_factorize],
+        [64, 128, 256].map(PlotArg),
+    );
     bench.run()?;
 
     Ok(())
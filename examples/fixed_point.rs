@@ -0,0 +1,187 @@
+//! Using a custom real number type as the scalar type of faer matrices.
+//!
+//! The type implemented here is a signed fixed-point number with 32 fractional bits. Once its
+//! arithmetic operators and [`SimpleRealField`] are implemented, [`impl_simple_real_field!`]
+//! provides the `faer_entity` traits, and the type can be used with the decompositions and solvers
+//! of the library.
+
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use faer::{
+    linalg::entity::{impl_simple_real_field, SimpleRealField},
+    prelude::*,
+};
+
+const FRAC_BITS: u32 = 32;
+
+/// Fixed-point number, whose value is `bits / 2^32`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+struct Fixed(i64);
+
+// SAFETY: `Fixed` is a transparent wrapper around `i64`
+unsafe impl bytemuck::Zeroable for Fixed {}
+unsafe impl bytemuck::Pod for Fixed {}
+
+impl Fixed {
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << FRAC_BITS) as f64
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Fixed(self.0.saturating_add(rhs.0))
+    }
+}
+impl Sub for Fixed {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Fixed(self.0.saturating_sub(rhs.0))
+    }
+}
+impl Mul for Fixed {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let wide = (self.0 as i128 * rhs.0 as i128) >> FRAC_BITS;
+        Fixed(wide.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+}
+impl Div for Fixed {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        // saturate instead of panicking on division by zero, so that `1 / 0` acts as infinity
+        if rhs.0 == 0 {
+            return Fixed(if self.0 < 0 { i64::MIN } else { i64::MAX });
+        }
+        let wide = ((self.0 as i128) << FRAC_BITS) / rhs.0 as i128;
+        Fixed(wide.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+}
+impl Rem for Fixed {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        Fixed(self.0 % rhs.0)
+    }
+}
+impl Neg for Fixed {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl MulAssign for Fixed {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl DivAssign for Fixed {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+impl RemAssign for Fixed {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl num_traits::Zero for Fixed {
+    fn zero() -> Self {
+        Fixed(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+impl num_traits::One for Fixed {
+    fn one() -> Self {
+        Fixed(1 << FRAC_BITS)
+    }
+}
+impl num_traits::Num for Fixed {
+    type FromStrRadixErr = num_traits::ParseFloatError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Fixed::from_f64)
+    }
+}
+
+impl SimpleRealField for Fixed {
+    fn from_f64(value: f64) -> Self {
+        // `as` saturates on overflow
+        Fixed((value * (1u64 << FRAC_BITS) as f64).round() as i64)
+    }
+
+    fn sqrt(self) -> Self {
+        // newton iterations on the raw bits, `sqrt(x / 2^32) * 2^32 = sqrt(x * 2^32)`
+        if self.0 <= 0 {
+            return Fixed(0);
+        }
+        let n = (self.0 as u128) << FRAC_BITS;
+        let mut x = n;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + n / x) / 2;
+        }
+        Fixed(x as i64)
+    }
+
+    fn epsilon() -> Self {
+        Fixed(1)
+    }
+
+    fn min_positive() -> Self {
+        // the inverse of `2^-30` is `2^30`, which is representable
+        Fixed(4)
+    }
+
+    fn nan() -> Self {
+        // fixed-point numbers have no NaN
+        Fixed(0)
+    }
+}
+
+impl_simple_real_field!(Fixed);
+
+fn main() {
+    let a = Mat::from_fn(4, 4, |i, j| {
+        Fixed::from_f64(if i == j {
+            4.0
+        } else {
+            1.0 / (1 + i + j) as f64
+        })
+    });
+    let b = Mat::from_fn(4, 1, |i, _| Fixed::from_f64(i as f64 + 1.0));
+
+    let x = a.partial_piv_lu().solve(&b);
+    let residual = &a * &x - &b;
+
+    for i in 0..4 {
+        println!("x[{i}] = {:.9}", x.read(i, 0).to_f64());
+    }
+    println!("residual = {:.3e}", residual.norm_max().to_f64());
+    assert!(residual.norm_max().to_f64() < 1e-7);
+
+    // the result agrees with the same computation in floating point
+    let a = Mat::from_fn(4, 4, |i, j| a.read(i, j).to_f64());
+    let b = Mat::from_fn(4, 1, |i, j| b.read(i, j).to_f64());
+    let x_f64 = a.partial_piv_lu().solve(&b);
+    for i in 0..4 {
+        assert!((x.read(i, 0).to_f64() - x_f64.read(i, 0)).abs() < 1e-7);
+    }
+}
@@ -0,0 +1,49 @@
+/* C interface to a subset of faer, following the BLAS and LAPACK conventions.
+ *
+ * All matrices are stored in column-major order, with a leading dimension that must be at least
+ * max(1, number of rows). Pivot indices are one-based.
+ *
+ * Every routine returns an info code: 0 on success, -i if the i-th argument had an illegal
+ * value, FAER_OUT_OF_MEMORY if the workspace couldn't be allocated, and a positive
+ * routine-specific value otherwise.
+ */
+
+#ifndef FAER_H
+#define FAER_H
+
+#include <stdint.h>
+
+/* Info code returned when the workspace of a routine can't be allocated. */
+#define FAER_OUT_OF_MEMORY INT32_MIN
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* C := alpha * op(A) * op(B) + beta * C, with op(X) = X ('N') or X^T ('T', 'C').
+ * C is m x n, op(A) is m x k and op(B) is k x n. */
+int32_t faer_dgemm(char transa, char transb, int32_t m, int32_t n, int32_t k, double alpha,
+                   const double *a, int32_t lda, const double *b, int32_t ldb, double beta,
+                   double *c, int32_t ldc);
+
+/* LU factorization with partial pivoting A = P * L * U of the m x n matrix A.
+ * ipiv must have room for min(m, n) entries.
+ * Returns i > 0 if U(i, i) is exactly zero. */
+int32_t faer_dgetrf(int32_t m, int32_t n, double *a, int32_t lda, int32_t *ipiv);
+
+/* Cholesky factorization A = L * L^T ('L') or A = U^T * U ('U') of the n x n symmetric positive
+ * definite matrix A. The other triangle is preserved.
+ * Returns i > 0 if the leading minor of order i is not positive definite, in which case the
+ * factorization is not completed. */
+int32_t faer_dpotrf(char uplo, int32_t n, double *a, int32_t lda);
+
+/* Solves op(A) * X = alpha * B ('L') or X * op(A) = alpha * B ('R') with A triangular, and
+ * overwrites the m x n matrix B with X. */
+int32_t faer_dtrsm(char side, char uplo, char transa, char diag, int32_t m, int32_t n,
+                   double alpha, const double *a, int32_t lda, double *b, int32_t ldb);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FAER_H */
@@ -0,0 +1,713 @@
+//! C interface to a subset of `faer`, with signatures following the BLAS and LAPACK conventions.
+//!
+//! All the matrices are stored in column-major order, with a leading dimension (the distance
+//! between two consecutive columns) that must be at least the number of rows, and at least `1`.
+//! Integer arguments and pivot indices are 32-bit signed integers, and the pivot indices are
+//! one-based, as in the reference LAPACK implementation.
+//!
+//! Every routine returns an `info` code:
+//! - `0` on success,
+//! - `-i` if the `i`-th argument had an illegal value, in which case nothing is modified,
+//! - [`FAER_OUT_OF_MEMORY`] if the workspace of the routine couldn't be allocated, in which case
+//!   nothing is modified,
+//! - a positive value with a routine-specific meaning, see the documentation of each routine.
+//!
+//! The C declarations are provided in `include/faer.h`.
+
+#![allow(clippy::missing_safety_doc)]
+#![allow(clippy::too_many_arguments)]
+
+use core::{ffi::c_char, ptr::NonNull};
+use faer::{
+    dyn_stack::{GlobalPodBuffer, PodStack, SizeOverflow, StackReq},
+    get_global_parallelism,
+    linalg::{cholesky::llt, lu::partial_pivoting, matmul, triangular_solve},
+    MatMut, MatRef,
+};
+
+/// Info code returned when the workspace of a routine can't be allocated. It can't be mistaken for
+/// the index of an illegal argument.
+pub const FAER_OUT_OF_MEMORY: i32 = i32::MIN;
+
+/// Allocates the workspace described by `req`, or returns `None` if its size overflows or it can't
+/// be allocated.
+fn workspace(req: Result<StackReq, SizeOverflow>) -> Option<GlobalPodBuffer> {
+    GlobalPodBuffer::try_new(req.ok()?).ok()
+}
+
+/// Returns an empty vector with room for `len` elements, or `None` if it can't be allocated.
+fn try_with_capacity<T>(len: usize) -> Option<Vec<T>> {
+    let mut v = Vec::new();
+    v.try_reserve_exact(len).ok()?;
+    Some(v)
+}
+
+/// Returns the vector `[0, 1, ..., len - 1]`, or `None` if it can't be allocated.
+fn try_iota(len: usize) -> Option<Vec<usize>> {
+    let mut v = try_with_capacity(len)?;
+    v.extend(0..len);
+    Some(v)
+}
+
+fn is(c: c_char, expected: u8) -> bool {
+    (c as u8).eq_ignore_ascii_case(&expected)
+}
+
+fn is_trans(c: c_char) -> bool {
+    is(c, b'T') || is(c, b'C')
+}
+
+fn is_valid_trans(c: c_char) -> bool {
+    is(c, b'N') || is_trans(c)
+}
+
+fn is_valid_uplo(c: c_char) -> bool {
+    is(c, b'L') || is(c, b'U')
+}
+
+/// Returns whether the leading dimension `ld` is valid for a matrix with `nrows` rows.
+fn is_valid_ld(ld: i32, nrows: i32) -> bool {
+    ld >= Ord::max(1, nrows)
+}
+
+unsafe fn mat_ref<'a>(ptr: *const f64, nrows: usize, ncols: usize, ld: i32) -> MatRef<'a, f64> {
+    // the pointer may be null when the matrix is empty
+    let ptr = if nrows == 0 || ncols == 0 {
+        NonNull::dangling().as_ptr()
+    } else {
+        ptr
+    };
+    faer::mat::from_raw_parts(ptr, nrows, ncols, 1, ld as isize)
+}
+
+unsafe fn mat_mut<'a>(ptr: *mut f64, nrows: usize, ncols: usize, ld: i32) -> MatMut<'a, f64> {
+    let ptr = if nrows == 0 || ncols == 0 {
+        NonNull::dangling().as_ptr()
+    } else {
+        ptr
+    };
+    faer::mat::from_raw_parts_mut(ptr, nrows, ncols, 1, ld as isize)
+}
+
+/// Multiplies `dst` by `beta`, ignoring its previous values if `beta` is zero.
+fn scale(dst: MatMut<'_, f64>, beta: f64) {
+    let mut dst = dst;
+    if beta == 0.0 {
+        dst.fill_zero();
+    } else if beta != 1.0 {
+        for j in 0..dst.ncols() {
+            for i in 0..dst.nrows() {
+                dst.write(i, j, beta * dst.read(i, j));
+            }
+        }
+    }
+}
+
+/// Computes $C := \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C$, where
+/// $\operatorname{op}(X)$ is either $X$ or $X^\top$ depending on `transa` and `transb`, which may
+/// be `'N'`, `'T'` or `'C'`.
+///
+/// $C$ is $m \times n$, $\operatorname{op}(A)$ is $m \times k$ and $\operatorname{op}(B)$ is
+/// $k \times n$. If `beta` is zero, `c` doesn't need to be initialized on entry.
+#[no_mangle]
+pub unsafe extern "C" fn faer_dgemm(
+    transa: c_char,
+    transb: c_char,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: f64,
+    a: *const f64,
+    lda: i32,
+    b: *const f64,
+    ldb: i32,
+    beta: f64,
+    c: *mut f64,
+    ldc: i32,
+) -> i32 {
+    let (nrowa, ncola) = if is_trans(transa) { (k, m) } else { (m, k) };
+    let (nrowb, ncolb) = if is_trans(transb) { (n, k) } else { (k, n) };
+
+    if !is_valid_trans(transa) {
+        return -1;
+    }
+    if !is_valid_trans(transb) {
+        return -2;
+    }
+    if m < 0 {
+        return -3;
+    }
+    if n < 0 {
+        return -4;
+    }
+    if k < 0 {
+        return -5;
+    }
+    if !is_valid_ld(lda, nrowa) {
+        return -8;
+    }
+    if !is_valid_ld(ldb, nrowb) {
+        return -10;
+    }
+    if !is_valid_ld(ldc, m) {
+        return -13;
+    }
+
+    let c = mat_mut(c, m as usize, n as usize, ldc);
+    if m == 0 || n == 0 {
+        return 0;
+    }
+    if k == 0 || alpha == 0.0 {
+        scale(c, beta);
+        return 0;
+    }
+
+    let a = mat_ref(a, nrowa as usize, ncola as usize, lda);
+    let b = mat_ref(b, nrowb as usize, ncolb as usize, ldb);
+    let a = if is_trans(transa) { a.transpose() } else { a };
+    let b = if is_trans(transb) { b.transpose() } else { b };
+
+    // faer computes `dst := alpha * dst + beta * lhs * rhs`
+    matmul::matmul(
+        c,
+        a,
+        b,
+        if beta == 0.0 { None } else { Some(beta) },
+        alpha,
+        get_global_parallelism(),
+    );
+    0
+}
+
+/// Computes the LU factorization with partial pivoting $A = PLU$ of the $m \times n$ matrix $A$,
+/// where $P$ is a permutation matrix, $L$ is unit lower triangular (lower trapezoidal if
+/// $m > n$), and $U$ is upper triangular (upper trapezoidal if $m < n$).
+///
+/// $L$ and $U$ overwrite `a`, with the unit diagonal of $L$ not being stored. `ipiv` must have
+/// room for $\min(m, n)$ pivot indices: row `i` was interchanged with row `ipiv[i]`, in one-based
+/// indexing.
+///
+/// Returns `i > 0` if $U_{i,i}$ (in one-based indexing) is exactly zero. The factorization is
+/// still completed in that case, but $U$ is singular.
+#[no_mangle]
+pub unsafe extern "C" fn faer_dgetrf(m: i32, n: i32, a: *mut f64, lda: i32, ipiv: *mut i32) -> i32 {
+    if m < 0 {
+        return -1;
+    }
+    if n < 0 {
+        return -2;
+    }
+    if !is_valid_ld(lda, m) {
+        return -4;
+    }
+
+    let (m, n) = (m as usize, n as usize);
+    let size = Ord::min(m, n);
+    if size == 0 {
+        return 0;
+    }
+
+    let mut a = mat_mut(a, m, n, lda);
+    let ipiv = core::slice::from_raw_parts_mut(ipiv, size);
+
+    let parallelism = get_global_parallelism();
+    let (Some(mut perm), Some(mut perm_inv), Some(mut row_at), Some(mut pos), Some(mut mem)) = (
+        try_iota(m),
+        try_iota(m),
+        try_iota(m),
+        try_iota(m),
+        workspace(partial_pivoting::compute::lu_in_place_req::<usize, f64>(
+            m,
+            n,
+            parallelism,
+            Default::default(),
+        )),
+    ) else {
+        return FAER_OUT_OF_MEMORY;
+    };
+
+    partial_pivoting::compute::lu_in_place(
+        a.as_mut(),
+        &mut perm,
+        &mut perm_inv,
+        parallelism,
+        PodStack::new(&mut mem),
+        Default::default(),
+    );
+
+    // convert the row permutation to a sequence of interchanges. `row_at[p]` is the original row
+    // currently at position `p`, and `pos` is its inverse
+    for i in 0..size {
+        let row = perm[i];
+        let p = pos[row];
+        ipiv[i] = (p + 1) as i32;
+
+        let displaced = row_at[i];
+        row_at.swap(i, p);
+        pos[row] = i;
+        pos[displaced] = p;
+    }
+
+    match (0..size).find(|&i| a.read(i, i) == 0.0) {
+        Some(i) => (i + 1) as i32,
+        None => 0,
+    }
+}
+
+/// Computes the Cholesky factorization of the $n \times n$ symmetric positive definite matrix
+/// $A$, either $A = LL^\top$ if `uplo` is `'L'`, or $A = U^\top U$ if `uplo` is `'U'`.
+///
+/// Only the triangle selected by `uplo` is read, and it is overwritten by the corresponding
+/// factor. The other triangle is preserved.
+///
+/// Returns `i > 0` if the leading minor of order `i` is not positive definite. As in LAPACK, the
+/// factorization is not completed in that case, and the selected triangle is partially
+/// overwritten.
+#[no_mangle]
+pub unsafe extern "C" fn faer_dpotrf(uplo: c_char, n: i32, a: *mut f64, lda: i32) -> i32 {
+    if !is_valid_uplo(uplo) {
+        return -1;
+    }
+    if n < 0 {
+        return -2;
+    }
+    if !is_valid_ld(lda, n) {
+        return -4;
+    }
+
+    let n = n as usize;
+    if n == 0 {
+        return 0;
+    }
+
+    let a = mat_mut(a, n, n, lda);
+    // the upper triangle of a real matrix is the lower triangle of its transpose
+    let mut a = if is(uplo, b'U') { a.transpose_mut() } else { a };
+
+    // the factorization is computed in place, but may clobber the strictly upper part of its
+    // input, which holds the other triangle. only that part is saved, and restored afterwards
+    let parallelism = get_global_parallelism();
+    let (Some(mut upper), Some(mut mem)) = (
+        n.checked_mul(n - 1).and_then(|len| try_with_capacity(len / 2)),
+        workspace(llt::compute::cholesky_in_place_req::<f64>(
+            n,
+            parallelism,
+            Default::default(),
+        )),
+    ) else {
+        return FAER_OUT_OF_MEMORY;
+    };
+    for j in 1..n {
+        for i in 0..j {
+            upper.push(a.read(i, j));
+        }
+    }
+
+    let result = llt::compute::cholesky_in_place(
+        a.as_mut(),
+        Default::default(),
+        parallelism,
+        PodStack::new(&mut mem),
+        Default::default(),
+    );
+
+    let mut upper = upper.into_iter();
+    for j in 1..n {
+        for (i, value) in (0..j).zip(upper.by_ref()) {
+            a.write(i, j, value);
+        }
+    }
+
+    match result {
+        Ok(_) => 0,
+        // the dense factorization reports the zero-based index of the failing pivot
+        Err(err) => (err.non_positive_definite_minor + 1) as i32,
+    }
+}
+
+/// Solves $\operatorname{op}(A) X = \alpha B$ if `side` is `'L'`, or
+/// $X \operatorname{op}(A) = \alpha B$ if `side` is `'R'`, where $A$ is triangular and
+/// $\operatorname{op}(A)$ is either $A$ or $A^\top$ depending on `transa`, which may be `'N'`,
+/// `'T'` or `'C'`.
+///
+/// `uplo` specifies whether $A$ is lower (`'L'`) or upper (`'U'`) triangular, and only that
+/// triangle is read. `diag` specifies whether $A$ has a unit diagonal (`'U'`), in which case the
+/// diagonal is not read, or not (`'N'`).
+///
+/// $B$ is $m \times n$, and is overwritten by $X$.
+#[no_mangle]
+pub unsafe extern "C" fn faer_dtrsm(
+    side: c_char,
+    uplo: c_char,
+    transa: c_char,
+    diag: c_char,
+    m: i32,
+    n: i32,
+    alpha: f64,
+    a: *const f64,
+    lda: i32,
+    b: *mut f64,
+    ldb: i32,
+) -> i32 {
+    let left = is(side, b'L');
+    let k = if left { m } else { n };
+
+    if !left && !is(side, b'R') {
+        return -1;
+    }
+    if !is_valid_uplo(uplo) {
+        return -2;
+    }
+    if !is_valid_trans(transa) {
+        return -3;
+    }
+    if !is(diag, b'U') && !is(diag, b'N') {
+        return -4;
+    }
+    if m < 0 {
+        return -5;
+    }
+    if n < 0 {
+        return -6;
+    }
+    if !is_valid_ld(lda, k) {
+        return -9;
+    }
+    if !is_valid_ld(ldb, m) {
+        return -11;
+    }
+
+    let mut b = mat_mut(b, m as usize, n as usize, ldb);
+    if m == 0 || n == 0 {
+        return 0;
+    }
+    scale(b.as_mut(), alpha);
+    if alpha == 0.0 {
+        return 0;
+    }
+
+    let a = mat_ref(a, k as usize, k as usize, lda);
+    let mut lower = is(uplo, b'L');
+    let mut tri = if is_trans(transa) {
+        lower = !lower;
+        a.transpose()
+    } else {
+        a
+    };
+    // X op(A) = B is equivalent to op(A)^T X^T = B^T
+    let rhs = if left {
+        b
+    } else {
+        lower = !lower;
+        tri = tri.transpose();
+        b.transpose_mut()
+    };
+
+    let parallelism = get_global_parallelism();
+    match (lower, is(diag, b'U')) {
+        (true, false) => triangular_solve::solve_lower_triangular_in_place(tri, rhs, parallelism),
+        (true, true) => {
+            triangular_solve::solve_unit_lower_triangular_in_place(tri, rhs, parallelism)
+        }
+        (false, false) => triangular_solve::solve_upper_triangular_in_place(tri, rhs, parallelism),
+        (false, true) => {
+            triangular_solve::solve_unit_upper_triangular_in_place(tri, rhs, parallelism)
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::Mat;
+
+    fn mat(m: usize, n: usize, seed: u64) -> Mat<f64> {
+        // deterministic pseudo-random values in [-1, 1)
+        Mat::from_fn(m, n, |i, j| {
+            let mut x = (i as u64) << 32 ^ (j as u64) << 8 ^ seed ^ 0x9e3779b97f4a7c15;
+            x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+            x ^= x >> 31;
+            (x >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        })
+    }
+
+    fn dist(a: MatRef<'_, f64>, b: MatRef<'_, f64>) -> f64 {
+        (a - b).norm_max()
+    }
+
+    #[test]
+    fn test_dgemm() {
+        let (m, n, k) = (5, 4, 3);
+        for (ta, tb) in [(b'N', b'N'), (b'T', b'N'), (b'N', b'T'), (b'C', b'T')] {
+            let a = if ta == b'N' {
+                mat(m, k, 0)
+            } else {
+                mat(k, m, 0)
+            };
+            let b = if tb == b'N' {
+                mat(k, n, 1)
+            } else {
+                mat(n, k, 1)
+            };
+            let op_a = if ta == b'N' {
+                a.as_ref()
+            } else {
+                a.transpose()
+            };
+            let op_b = if tb == b'N' {
+                b.as_ref()
+            } else {
+                b.transpose()
+            };
+
+            for beta in [0.0, 1.0, -0.5] {
+                let c0 = mat(m, n, 2);
+                let mut c = c0.clone();
+                if beta == 0.0 {
+                    c.fill(f64::NAN);
+                }
+                let info = unsafe {
+                    faer_dgemm(
+                        ta as c_char,
+                        tb as c_char,
+                        m as i32,
+                        n as i32,
+                        k as i32,
+                        2.0,
+                        a.as_ptr(),
+                        a.col_stride() as i32,
+                        b.as_ptr(),
+                        b.col_stride() as i32,
+                        beta,
+                        c.as_ptr_mut(),
+                        c.col_stride() as i32,
+                    )
+                };
+                assert_eq!(info, 0);
+                let target = faer::scale(2.0) * (op_a * op_b) + faer::scale(beta) * &c0;
+                assert!(dist(c.as_ref(), target.as_ref()) < 1e-13);
+            }
+        }
+
+        let mut c = mat(2, 2, 0);
+        let info = unsafe {
+            let ptr = c.as_ptr_mut();
+            faer_dgemm(
+                b'X' as c_char,
+                b'N' as c_char,
+                2,
+                2,
+                2,
+                1.0,
+                ptr,
+                2,
+                ptr,
+                2,
+                0.0,
+                ptr,
+                2,
+            )
+        };
+        assert_eq!(info, -1);
+        let info = unsafe {
+            let ptr = c.as_ptr_mut();
+            faer_dgemm(
+                b'N' as c_char,
+                b'N' as c_char,
+                2,
+                2,
+                2,
+                1.0,
+                ptr,
+                1,
+                ptr,
+                2,
+                0.0,
+                ptr,
+                2,
+            )
+        };
+        assert_eq!(info, -8);
+    }
+
+    #[test]
+    fn test_dgetrf() {
+        for (m, n) in [(6, 6), (7, 4), (3, 5)] {
+            let a0 = mat(m, n, 0);
+            let mut a = a0.clone();
+            let mut ipiv = vec![0i32; Ord::min(m, n)];
+            let info = unsafe {
+                faer_dgetrf(
+                    m as i32,
+                    n as i32,
+                    a.as_ptr_mut(),
+                    a.col_stride() as i32,
+                    ipiv.as_mut_ptr(),
+                )
+            };
+            assert_eq!(info, 0);
+
+            // apply the interchanges to the input, and compare it with the product of the factors
+            let mut pa = a0.clone();
+            for (i, &p) in ipiv.iter().enumerate() {
+                let p = p as usize - 1;
+                assert!(p >= i && p < m);
+                for j in 0..n {
+                    let tmp = pa.read(i, j);
+                    pa.write(i, j, pa.read(p, j));
+                    pa.write(p, j, tmp);
+                }
+            }
+            let size = Ord::min(m, n);
+            let l = Mat::from_fn(m, size, |i, j| match i.cmp(&j) {
+                core::cmp::Ordering::Greater => a.read(i, j),
+                core::cmp::Ordering::Equal => 1.0,
+                core::cmp::Ordering::Less => 0.0,
+            });
+            let u = Mat::from_fn(size, n, |i, j| if i <= j { a.read(i, j) } else { 0.0 });
+            assert!(dist(pa.as_ref(), (&l * &u).as_ref()) < 1e-13);
+        }
+
+        let mut singular = faer::mat![[1.0, 2.0], [2.0, 4.0f64]];
+        let mut ipiv = [0i32; 2];
+        let info = unsafe {
+            faer_dgetrf(
+                2,
+                2,
+                singular.as_ptr_mut(),
+                singular.col_stride() as i32,
+                ipiv.as_mut_ptr(),
+            )
+        };
+        assert_eq!(info, 2);
+    }
+
+    #[test]
+    fn test_dpotrf() {
+        let n = 80;
+        let x = mat(n, n, 0);
+        let h = &x * x.transpose() + Mat::<f64>::identity(n, n);
+
+        for uplo in [b'L', b'U'] {
+            let mut a = h.clone();
+            let info = unsafe {
+                faer_dpotrf(
+                    uplo as c_char,
+                    n as i32,
+                    a.as_ptr_mut(),
+                    a.col_stride() as i32,
+                )
+            };
+            assert_eq!(info, 0);
+
+            let lower = uplo == b'L';
+            let factor = Mat::from_fn(n, n, |i, j| {
+                let in_triangle = if lower { i >= j } else { i <= j };
+                if in_triangle {
+                    a.read(i, j)
+                } else {
+                    0.0
+                }
+            });
+            let product = if lower {
+                &factor * factor.transpose()
+            } else {
+                factor.transpose() * &factor
+            };
+            assert!(dist(product.as_ref(), h.as_ref()) < 1e-10);
+
+            // the other triangle is untouched
+            for j in 0..n {
+                for i in 0..n {
+                    if (lower && i < j) || (!lower && i > j) {
+                        assert_eq!(a.read(i, j), h.read(i, j));
+                    }
+                }
+            }
+        }
+
+        let mut indefinite = faer::mat![[1.0, 2.0], [2.0, 1.0f64]];
+        let info = unsafe {
+            faer_dpotrf(
+                b'L' as c_char,
+                2,
+                indefinite.as_ptr_mut(),
+                indefinite.col_stride() as i32,
+            )
+        };
+        assert_eq!(info, 2);
+        // the other triangle is still preserved
+        assert_eq!(indefinite.read(0, 1), 2.0);
+        let info = unsafe { faer_dpotrf(b'X' as c_char, 2, indefinite.as_ptr_mut(), 2) };
+        assert_eq!(info, -1);
+    }
+
+    #[test]
+    fn test_dtrsm() {
+        let (m, n) = (6, 4);
+        for side in [b'L', b'R'] {
+            for uplo in [b'L', b'U'] {
+                for trans in [b'N', b'T'] {
+                    for diag in [b'N', b'U'] {
+                        let k = if side == b'L' { m } else { n };
+                        let a = mat(k, k, 0);
+                        // well conditioned triangular matrix, with garbage in the other triangle
+                        let tri = Mat::from_fn(k, k, |i, j| {
+                            let in_triangle = if uplo == b'L' { i >= j } else { i <= j };
+                            if i == j {
+                                if diag == b'U' {
+                                    1.0
+                                } else {
+                                    4.0 + a.read(i, j)
+                                }
+                            } else if in_triangle {
+                                a.read(i, j)
+                            } else {
+                                0.0
+                            }
+                        });
+                        let mut a = tri.clone();
+                        for j in 0..k {
+                            for i in 0..k {
+                                let in_triangle = if uplo == b'L' { i > j } else { i < j };
+                                if !in_triangle && (i != j || diag == b'U') {
+                                    a.write(i, j, f64::NAN);
+                                }
+                            }
+                        }
+
+                        let b = mat(m, n, 1);
+                        let mut x = b.clone();
+                        let info = unsafe {
+                            faer_dtrsm(
+                                side as c_char,
+                                uplo as c_char,
+                                trans as c_char,
+                                diag as c_char,
+                                m as i32,
+                                n as i32,
+                                0.5,
+                                a.as_ptr(),
+                                a.col_stride() as i32,
+                                x.as_ptr_mut(),
+                                x.col_stride() as i32,
+                            )
+                        };
+                        assert_eq!(info, 0);
+
+                        let op = if trans == b'N' {
+                            tri.as_ref()
+                        } else {
+                            tri.transpose()
+                        };
+                        let lhs = if side == b'L' { op * &x } else { &x * op };
+                        let target = (faer::scale(0.5) * &b).to_owned();
+                        assert!(dist(lhs.as_ref(), target.as_ref()) < 1e-12);
+                    }
+                }
+            }
+        }
+    }
+}
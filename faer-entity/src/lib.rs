@@ -1,6 +1,7 @@
 #![allow(clippy::type_complexity)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub use num_traits;
 pub use pulp;
 
 use bytemuck::Pod;
@@ -2910,6 +2911,89 @@ unsafe impl Entity for Symbolic {
     }
 }
 
+// indices can be stored in matrices, but support no arithmetic
+unsafe impl Entity for usize {
+    type Unit = usize;
+    type Index = usize;
+    type SimdUnit<S: pulp::Simd> = usize;
+    type SimdMask<S: pulp::Simd> = bool;
+    type SimdIndex<S: pulp::Simd> = usize;
+    type Group = IdentityGroup;
+    type Iter<I: Iterator> = I;
+    const N_COMPONENTS: usize = 1;
+    const UNIT: GroupCopyFor<Self, ()> = ();
+
+    type PrefixUnit<'a, S: Simd> = &'a [Self];
+    type SuffixUnit<'a, S: Simd> = &'a [Self];
+    type PrefixMutUnit<'a, S: Simd> = &'a mut [Self];
+    type SuffixMutUnit<'a, S: Simd> = &'a mut [Self];
+
+    #[inline(always)]
+    fn faer_first<T>(group: GroupFor<Self, T>) -> T {
+        group
+    }
+
+    #[inline(always)]
+    fn faer_from_units(group: GroupFor<Self, Self::Unit>) -> Self {
+        group
+    }
+
+    #[inline(always)]
+    fn faer_into_units(self) -> GroupFor<Self, Self::Unit> {
+        self
+    }
+
+    #[inline(always)]
+    fn faer_as_ref<T>(group: &GroupFor<Self, T>) -> GroupFor<Self, &T> {
+        group
+    }
+
+    #[inline(always)]
+    fn faer_as_mut<T>(group: &mut GroupFor<Self, T>) -> GroupFor<Self, &mut T> {
+        group
+    }
+
+    #[inline(always)]
+    fn faer_as_ptr<T>(group: *mut GroupFor<Self, T>) -> GroupFor<Self, *mut T> {
+        group
+    }
+
+    #[inline(always)]
+    fn faer_map_impl<T, U>(
+        group: GroupFor<Self, T>,
+        f: &mut impl FnMut(T) -> U,
+    ) -> GroupFor<Self, U> {
+        (*f)(group)
+    }
+
+    #[inline(always)]
+    fn faer_zip<T, U>(
+        first: GroupFor<Self, T>,
+        second: GroupFor<Self, U>,
+    ) -> GroupFor<Self, (T, U)> {
+        (first, second)
+    }
+
+    #[inline(always)]
+    fn faer_unzip<T, U>(zipped: GroupFor<Self, (T, U)>) -> (GroupFor<Self, T>, GroupFor<Self, U>) {
+        zipped
+    }
+
+    #[inline(always)]
+    fn faer_map_with_context<Ctx, T, U>(
+        ctx: Ctx,
+        group: GroupFor<Self, T>,
+        f: &mut impl FnMut(Ctx, T) -> (Ctx, U),
+    ) -> (Ctx, GroupFor<Self, U>) {
+        (*f)(ctx, group)
+    }
+
+    #[inline(always)]
+    fn faer_into_iter<I: IntoIterator>(iter: GroupFor<Self, I>) -> Self::Iter<I::IntoIter> {
+        iter.into_iter()
+    }
+}
+
 unsafe impl Conjugate for Symbolic {
     type Conj = Symbolic;
     type Canonical = Symbolic;
@@ -3390,6 +3474,745 @@ impl ComplexField for Symbolic {
     }
 }
 
+/// Scalar operations of a custom real number type, from which [`impl_simple_real_field!`]
+/// implements [`Entity`], [`Conjugate`], [`ComplexField`] and [`RealField`].
+///
+/// The arithmetic operators, along with `zero` and `one`, are taken from the [`num_traits::Num`]
+/// and [`num_traits::NumAssignOps`] implementations, so only the operations that have no standard
+/// trait are listed here. Each value is stored as a single unit, and the generated
+/// implementations process one value at a time instead of using SIMD instructions.
+pub trait SimpleRealField:
+    Copy
+    + Pod
+    + PartialOrd
+    + Send
+    + Sync
+    + Debug
+    + 'static
+    + num_traits::Num
+    + num_traits::NumAssignOps
+    + core::ops::Neg<Output = Self>
+{
+    /// Converts `value` from `f64` to `Self`, possibly with a loss of precision.
+    fn from_f64(value: f64) -> Self;
+    /// Returns the square root of `self`.
+    fn sqrt(self) -> Self;
+    /// Returns the difference between `1` and the next larger representable value.
+    fn epsilon() -> Self;
+    /// Returns the smallest positive value whose inverse is representable.
+    fn min_positive() -> Self;
+    /// Returns a NaN value. Types that can't represent NaN may return any value.
+    fn nan() -> Self;
+}
+
+/// Implements [`Entity`], [`Conjugate`], [`ComplexField`] and [`RealField`] for a type that
+/// implements [`SimpleRealField`], so that it can be used as the scalar type of matrices.
+///
+/// The type is stored as a single unit, like `f32` and `f64`, and all the SIMD hooks of these
+/// traits are implemented with scalar fallbacks operating on one value at a time.
+///
+/// # Example
+/// ```
+/// use faer_entity::{impl_simple_real_field, SimpleRealField};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+/// #[repr(transparent)]
+/// struct Real(f64);
+/// # unsafe impl bytemuck::Zeroable for Real {}
+/// # unsafe impl bytemuck::Pod for Real {}
+/// # macro_rules! ops {
+/// #     ($($trait: ident, $f: ident, $assign: ident, $f_assign: ident, $op: tt;)*) => {$(
+/// #         impl core::ops::$trait for Real {
+/// #             type Output = Self;
+/// #             fn $f(self, rhs: Self) -> Self { Real(self.0 $op rhs.0) }
+/// #         }
+/// #         impl core::ops::$assign for Real {
+/// #             fn $f_assign(&mut self, rhs: Self) { self.0 = self.0 $op rhs.0 }
+/// #         }
+/// #     )*};
+/// # }
+/// # ops! {
+/// #     Add, add, AddAssign, add_assign, +;
+/// #     Sub, sub, SubAssign, sub_assign, -;
+/// #     Mul, mul, MulAssign, mul_assign, *;
+/// #     Div, div, DivAssign, div_assign, /;
+/// #     Rem, rem, RemAssign, rem_assign, %;
+/// # }
+/// # impl core::ops::Neg for Real {
+/// #     type Output = Self;
+/// #     fn neg(self) -> Self { Real(-self.0) }
+/// # }
+/// # impl num_traits::Zero for Real {
+/// #     fn zero() -> Self { Real(0.0) }
+/// #     fn is_zero(&self) -> bool { self.0 == 0.0 }
+/// # }
+/// # impl num_traits::One for Real {
+/// #     fn one() -> Self { Real(1.0) }
+/// # }
+/// # impl num_traits::Num for Real {
+/// #     type FromStrRadixErr = num_traits::ParseFloatError;
+/// #     fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+/// #         f64::from_str_radix(s, radix).map(Real)
+/// #     }
+/// # }
+///
+/// // the arithmetic operators, as well as the `num_traits` traits, are implemented as usual
+///
+/// impl SimpleRealField for Real {
+///     fn from_f64(value: f64) -> Self {
+///         Real(value)
+///     }
+///     fn sqrt(self) -> Self {
+///         Real(self.0.sqrt())
+///     }
+///     fn epsilon() -> Self {
+///         Real(f64::EPSILON)
+///     }
+///     fn min_positive() -> Self {
+///         Real(f64::MIN_POSITIVE)
+///     }
+///     fn nan() -> Self {
+///         Real(f64::NAN)
+///     }
+/// }
+///
+/// impl_simple_real_field!(Real);
+///
+/// use faer_entity::ComplexField;
+/// assert!(Real(4.0).faer_sqrt().faer_inv() == Real(0.5));
+/// ```
+#[macro_export]
+macro_rules! impl_simple_real_field {
+    ($ty: ty) => {
+        unsafe impl $crate::Entity for $ty {
+            type Unit = Self;
+            type Index = usize;
+            type SimdUnit<S: $crate::pulp::Simd> = Self;
+            type SimdMask<S: $crate::pulp::Simd> = bool;
+            type SimdIndex<S: $crate::pulp::Simd> = usize;
+            type Group = $crate::IdentityGroup;
+            type Iter<I: ::core::iter::Iterator> = I;
+
+            type PrefixUnit<'a, S: $crate::pulp::Simd> = &'a [Self];
+            type SuffixUnit<'a, S: $crate::pulp::Simd> = &'a [Self];
+            type PrefixMutUnit<'a, S: $crate::pulp::Simd> = &'a mut [Self];
+            type SuffixMutUnit<'a, S: $crate::pulp::Simd> = &'a mut [Self];
+
+            const N_COMPONENTS: usize = 1;
+            const UNIT: $crate::GroupCopyFor<Self, ()> = ();
+
+            #[inline(always)]
+            fn faer_first<T>(group: $crate::GroupFor<Self, T>) -> T {
+                group
+            }
+
+            #[inline(always)]
+            fn faer_from_units(group: $crate::GroupFor<Self, Self::Unit>) -> Self {
+                group
+            }
+
+            #[inline(always)]
+            fn faer_into_units(self) -> $crate::GroupFor<Self, Self::Unit> {
+                self
+            }
+
+            #[inline(always)]
+            fn faer_as_ref<T>(group: &$crate::GroupFor<Self, T>) -> $crate::GroupFor<Self, &T> {
+                group
+            }
+
+            #[inline(always)]
+            fn faer_as_mut<T>(
+                group: &mut $crate::GroupFor<Self, T>,
+            ) -> $crate::GroupFor<Self, &mut T> {
+                group
+            }
+
+            #[inline(always)]
+            fn faer_as_ptr<T>(
+                group: *mut $crate::GroupFor<Self, T>,
+            ) -> $crate::GroupFor<Self, *mut T> {
+                group
+            }
+
+            #[inline(always)]
+            fn faer_map_impl<T, U>(
+                group: $crate::GroupFor<Self, T>,
+                f: &mut impl FnMut(T) -> U,
+            ) -> $crate::GroupFor<Self, U> {
+                (*f)(group)
+            }
+
+            #[inline(always)]
+            fn faer_zip<T, U>(
+                first: $crate::GroupFor<Self, T>,
+                second: $crate::GroupFor<Self, U>,
+            ) -> $crate::GroupFor<Self, (T, U)> {
+                (first, second)
+            }
+
+            #[inline(always)]
+            fn faer_unzip<T, U>(
+                zipped: $crate::GroupFor<Self, (T, U)>,
+            ) -> ($crate::GroupFor<Self, T>, $crate::GroupFor<Self, U>) {
+                zipped
+            }
+
+            #[inline(always)]
+            fn faer_map_with_context<Ctx, T, U>(
+                ctx: Ctx,
+                group: $crate::GroupFor<Self, T>,
+                f: &mut impl FnMut(Ctx, T) -> (Ctx, U),
+            ) -> (Ctx, $crate::GroupFor<Self, U>) {
+                (*f)(ctx, group)
+            }
+
+            #[inline(always)]
+            fn faer_into_iter<I: ::core::iter::IntoIterator>(
+                iter: $crate::GroupFor<Self, I>,
+            ) -> Self::Iter<I::IntoIter> {
+                iter.into_iter()
+            }
+        }
+
+        unsafe impl $crate::Conjugate for $ty {
+            type Conj = Self;
+            type Canonical = Self;
+
+            #[inline(always)]
+            fn canonicalize(self) -> Self::Canonical {
+                self
+            }
+        }
+
+        impl $crate::RealField for $ty {
+            #[inline(always)]
+            fn faer_epsilon() -> Self {
+                <Self as $crate::SimpleRealField>::epsilon()
+            }
+
+            #[inline(always)]
+            fn faer_zero_threshold() -> Self {
+                <Self as $crate::SimpleRealField>::min_positive()
+            }
+
+            #[inline(always)]
+            fn faer_min_positive() -> Self {
+                <Self as $crate::SimpleRealField>::min_positive()
+            }
+
+            #[inline(always)]
+            fn faer_min_positive_inv() -> Self {
+                <Self as $crate::ComplexField>::faer_inv(
+                    <Self as $crate::SimpleRealField>::min_positive(),
+                )
+            }
+
+            #[inline(always)]
+            fn faer_min_positive_sqrt() -> Self {
+                <Self as $crate::SimpleRealField>::sqrt(
+                    <Self as $crate::SimpleRealField>::min_positive(),
+                )
+            }
+
+            #[inline(always)]
+            fn faer_min_positive_sqrt_inv() -> Self {
+                <Self as $crate::ComplexField>::faer_inv(
+                    <Self as $crate::RealField>::faer_min_positive_sqrt(),
+                )
+            }
+
+            #[inline(always)]
+            fn faer_div(self, rhs: Self) -> Self {
+                self / rhs
+            }
+
+            #[inline(always)]
+            fn faer_usize_to_index(a: usize) -> $crate::IndexFor<Self> {
+                a
+            }
+
+            #[inline(always)]
+            fn faer_index_to_usize(a: $crate::IndexFor<Self>) -> usize {
+                a
+            }
+
+            #[inline(always)]
+            fn faer_max_index() -> $crate::IndexFor<Self> {
+                usize::MAX
+            }
+
+            #[inline(always)]
+            fn faer_simd_less_than<S: $crate::pulp::Simd>(
+                _simd: S,
+                a: $crate::SimdGroupFor<Self, S>,
+                b: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdMaskFor<Self, S> {
+                a < b
+            }
+
+            #[inline(always)]
+            fn faer_simd_less_than_or_equal<S: $crate::pulp::Simd>(
+                _simd: S,
+                a: $crate::SimdGroupFor<Self, S>,
+                b: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdMaskFor<Self, S> {
+                a <= b
+            }
+
+            #[inline(always)]
+            fn faer_simd_greater_than<S: $crate::pulp::Simd>(
+                _simd: S,
+                a: $crate::SimdGroupFor<Self, S>,
+                b: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdMaskFor<Self, S> {
+                a > b
+            }
+
+            #[inline(always)]
+            fn faer_simd_greater_than_or_equal<S: $crate::pulp::Simd>(
+                _simd: S,
+                a: $crate::SimdGroupFor<Self, S>,
+                b: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdMaskFor<Self, S> {
+                a >= b
+            }
+
+            #[inline(always)]
+            fn faer_simd_select<S: $crate::pulp::Simd>(
+                _simd: S,
+                mask: $crate::SimdMaskFor<Self, S>,
+                if_true: $crate::SimdGroupFor<Self, S>,
+                if_false: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                if mask {
+                    if_true
+                } else {
+                    if_false
+                }
+            }
+
+            #[inline(always)]
+            fn faer_simd_index_select<S: $crate::pulp::Simd>(
+                _simd: S,
+                mask: $crate::SimdMaskFor<Self, S>,
+                if_true: $crate::SimdIndexFor<Self, S>,
+                if_false: $crate::SimdIndexFor<Self, S>,
+            ) -> $crate::SimdIndexFor<Self, S> {
+                if mask {
+                    if_true
+                } else {
+                    if_false
+                }
+            }
+
+            #[inline(always)]
+            fn faer_simd_index_seq<S: $crate::pulp::Simd>(
+                _simd: S,
+            ) -> $crate::SimdIndexFor<Self, S> {
+                0
+            }
+
+            #[inline(always)]
+            fn faer_simd_index_splat<S: $crate::pulp::Simd>(
+                _simd: S,
+                value: $crate::IndexFor<Self>,
+            ) -> $crate::SimdIndexFor<Self, S> {
+                value
+            }
+
+            #[inline(always)]
+            fn faer_simd_index_add<S: $crate::pulp::Simd>(
+                _simd: S,
+                a: $crate::SimdIndexFor<Self, S>,
+                b: $crate::SimdIndexFor<Self, S>,
+            ) -> $crate::SimdIndexFor<Self, S> {
+                a.wrapping_add(b)
+            }
+
+            #[inline(always)]
+            fn faer_simd_index_rotate_left<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdIndexFor<Self, S>,
+                _amount: usize,
+            ) -> $crate::SimdIndexFor<Self, S> {
+                values
+            }
+
+            #[inline(always)]
+            fn faer_simd_abs<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                <Self as $crate::ComplexField>::faer_abs(values)
+            }
+        }
+
+        impl $crate::ComplexField for $ty {
+            type Real = Self;
+            type Simd = $crate::NoSimd;
+            type ScalarSimd = $crate::NoSimd;
+            type PortableSimd = $crate::NoSimd;
+
+            #[inline(always)]
+            fn faer_from_f64(value: f64) -> Self {
+                <Self as $crate::SimpleRealField>::from_f64(value)
+            }
+
+            #[inline(always)]
+            fn faer_add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+
+            #[inline(always)]
+            fn faer_sub(self, rhs: Self) -> Self {
+                self - rhs
+            }
+
+            #[inline(always)]
+            fn faer_mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+
+            #[inline(always)]
+            fn faer_neg(self) -> Self {
+                -self
+            }
+
+            #[inline(always)]
+            fn faer_inv(self) -> Self {
+                <Self as $crate::ComplexField>::faer_one() / self
+            }
+
+            #[inline(always)]
+            fn faer_conj(self) -> Self {
+                self
+            }
+
+            #[inline(always)]
+            fn faer_sqrt(self) -> Self {
+                <Self as $crate::SimpleRealField>::sqrt(self)
+            }
+
+            #[inline(always)]
+            fn faer_scale_real(self, rhs: Self::Real) -> Self {
+                self * rhs
+            }
+
+            #[inline(always)]
+            fn faer_scale_power_of_two(self, rhs: Self::Real) -> Self {
+                self * rhs
+            }
+
+            #[inline(always)]
+            fn faer_score(self) -> Self::Real {
+                <Self as $crate::ComplexField>::faer_abs(self)
+            }
+
+            #[inline(always)]
+            fn faer_abs(self) -> Self::Real {
+                if self < <Self as $crate::ComplexField>::faer_zero() {
+                    -self
+                } else {
+                    self
+                }
+            }
+
+            #[inline(always)]
+            fn faer_abs2(self) -> Self::Real {
+                self * self
+            }
+
+            #[inline(always)]
+            fn faer_nan() -> Self {
+                <Self as $crate::SimpleRealField>::nan()
+            }
+
+            #[inline(always)]
+            fn faer_from_real(real: Self::Real) -> Self {
+                real
+            }
+
+            #[inline(always)]
+            fn faer_real(self) -> Self::Real {
+                self
+            }
+
+            #[inline(always)]
+            fn faer_imag(self) -> Self::Real {
+                <Self as $crate::ComplexField>::faer_zero()
+            }
+
+            #[inline(always)]
+            fn faer_zero() -> Self {
+                <Self as $crate::num_traits::Zero>::zero()
+            }
+
+            #[inline(always)]
+            fn faer_one() -> Self {
+                <Self as $crate::num_traits::One>::one()
+            }
+
+            #[inline(always)]
+            fn faer_align_offset<S: $crate::pulp::Simd>(
+                _simd: S,
+                _ptr: *const $crate::UnitFor<Self>,
+                len: usize,
+            ) -> $crate::pulp::Offset<$crate::SimdMaskFor<Self, S>> {
+                <$crate::pulp::Scalar as $crate::pulp::Simd>::i32s_align_offset(
+                    $crate::pulp::Scalar::new(),
+                    ::core::ptr::null(),
+                    len,
+                )
+            }
+
+            #[inline(always)]
+            fn faer_slice_as_aligned_simd<S: $crate::pulp::Simd>(
+                _simd: S,
+                slice: &[$crate::UnitFor<Self>],
+                _offset: $crate::pulp::Offset<$crate::SimdMaskFor<Self, S>>,
+            ) -> (
+                Self::PrefixUnit<'_, S>,
+                &[$crate::SimdUnitFor<Self, S>],
+                Self::SuffixUnit<'_, S>,
+            ) {
+                (&[], slice, &[])
+            }
+
+            #[inline(always)]
+            fn faer_slice_as_aligned_simd_mut<S: $crate::pulp::Simd>(
+                _simd: S,
+                slice: &mut [$crate::UnitFor<Self>],
+                _offset: $crate::pulp::Offset<$crate::SimdMaskFor<Self, S>>,
+            ) -> (
+                Self::PrefixMutUnit<'_, S>,
+                &mut [$crate::SimdUnitFor<Self, S>],
+                Self::SuffixMutUnit<'_, S>,
+            ) {
+                (&mut [], slice, &mut [])
+            }
+
+            #[inline(always)]
+            fn faer_slice_as_simd<S: $crate::pulp::Simd>(
+                slice: &[$crate::UnitFor<Self>],
+            ) -> (&[$crate::SimdUnitFor<Self, S>], &[$crate::UnitFor<Self>]) {
+                (slice, &[])
+            }
+
+            #[inline(always)]
+            fn faer_slice_as_simd_mut<S: $crate::pulp::Simd>(
+                slice: &mut [$crate::UnitFor<Self>],
+            ) -> (
+                &mut [$crate::SimdUnitFor<Self, S>],
+                &mut [$crate::UnitFor<Self>],
+            ) {
+                (slice, &mut [])
+            }
+
+            #[inline(always)]
+            fn faer_partial_load_unit<S: $crate::pulp::Simd>(
+                _simd: S,
+                slice: &[$crate::UnitFor<Self>],
+            ) -> $crate::SimdUnitFor<Self, S> {
+                match slice.first() {
+                    Some(&value) => value,
+                    None => <Self as $crate::ComplexField>::faer_zero(),
+                }
+            }
+
+            #[inline(always)]
+            fn faer_partial_store_unit<S: $crate::pulp::Simd>(
+                _simd: S,
+                slice: &mut [$crate::UnitFor<Self>],
+                values: $crate::SimdUnitFor<Self, S>,
+            ) {
+                if let Some(first) = slice.first_mut() {
+                    *first = values;
+                }
+            }
+
+            #[inline(always)]
+            fn faer_partial_load_last_unit<S: $crate::pulp::Simd>(
+                _simd: S,
+                slice: &[$crate::UnitFor<Self>],
+            ) -> $crate::SimdUnitFor<Self, S> {
+                match slice.last() {
+                    Some(&value) => value,
+                    None => <Self as $crate::ComplexField>::faer_zero(),
+                }
+            }
+
+            #[inline(always)]
+            fn faer_partial_store_last_unit<S: $crate::pulp::Simd>(
+                _simd: S,
+                slice: &mut [$crate::UnitFor<Self>],
+                values: $crate::SimdUnitFor<Self, S>,
+            ) {
+                if let Some(last) = slice.last_mut() {
+                    *last = values;
+                }
+            }
+
+            #[inline(always)]
+            fn faer_simd_splat_unit<S: $crate::pulp::Simd>(
+                _simd: S,
+                unit: $crate::UnitFor<Self>,
+            ) -> $crate::SimdUnitFor<Self, S> {
+                unit
+            }
+
+            #[inline(always)]
+            fn faer_simd_scalar_mul<S: $crate::pulp::Simd>(_simd: S, lhs: Self, rhs: Self) -> Self {
+                lhs * rhs
+            }
+
+            #[inline(always)]
+            fn faer_simd_scalar_conj_mul<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: Self,
+                rhs: Self,
+            ) -> Self {
+                lhs * rhs
+            }
+
+            #[inline(always)]
+            fn faer_simd_scalar_mul_adde<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: Self,
+                rhs: Self,
+                acc: Self,
+            ) -> Self {
+                lhs * rhs + acc
+            }
+
+            #[inline(always)]
+            fn faer_simd_scalar_conj_mul_adde<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: Self,
+                rhs: Self,
+                acc: Self,
+            ) -> Self {
+                lhs * rhs + acc
+            }
+
+            #[inline(always)]
+            fn faer_simd_neg<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                -values
+            }
+
+            #[inline(always)]
+            fn faer_simd_conj<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                values
+            }
+
+            #[inline(always)]
+            fn faer_simd_rotate_left<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdGroupFor<Self, S>,
+                _amount: usize,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                values
+            }
+
+            #[inline(always)]
+            fn faer_simd_add<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: $crate::SimdGroupFor<Self, S>,
+                rhs: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                lhs + rhs
+            }
+
+            #[inline(always)]
+            fn faer_simd_sub<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: $crate::SimdGroupFor<Self, S>,
+                rhs: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                lhs - rhs
+            }
+
+            #[inline(always)]
+            fn faer_simd_mul<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: $crate::SimdGroupFor<Self, S>,
+                rhs: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                lhs * rhs
+            }
+
+            #[inline(always)]
+            fn faer_simd_scale_real<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: $crate::SimdGroupFor<Self::Real, S>,
+                rhs: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                lhs * rhs
+            }
+
+            #[inline(always)]
+            fn faer_simd_conj_mul<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: $crate::SimdGroupFor<Self, S>,
+                rhs: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                lhs * rhs
+            }
+
+            #[inline(always)]
+            fn faer_simd_mul_adde<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: $crate::SimdGroupFor<Self, S>,
+                rhs: $crate::SimdGroupFor<Self, S>,
+                acc: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                lhs * rhs + acc
+            }
+
+            #[inline(always)]
+            fn faer_simd_conj_mul_adde<S: $crate::pulp::Simd>(
+                _simd: S,
+                lhs: $crate::SimdGroupFor<Self, S>,
+                rhs: $crate::SimdGroupFor<Self, S>,
+                acc: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self, S> {
+                lhs * rhs + acc
+            }
+
+            #[inline(always)]
+            fn faer_simd_abs2_adde<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdGroupFor<Self, S>,
+                acc: $crate::SimdGroupFor<Self::Real, S>,
+            ) -> $crate::SimdGroupFor<Self::Real, S> {
+                values * values + acc
+            }
+
+            #[inline(always)]
+            fn faer_simd_abs2<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self::Real, S> {
+                values * values
+            }
+
+            #[inline(always)]
+            fn faer_simd_score<S: $crate::pulp::Simd>(
+                _simd: S,
+                values: $crate::SimdGroupFor<Self, S>,
+            ) -> $crate::SimdGroupFor<Self::Real, S> {
+                <Self as $crate::ComplexField>::faer_abs(values)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,6 +71,8 @@ impl<'a, E: Entity, R: Shape> IntoConst for ColMut<'a, E, R> {
 impl<'a, E: Entity, R: Shape> ColMut<'a, E, R> {
     #[inline]
     pub(crate) unsafe fn __from_raw_parts(ptr: PtrMut<E>, nrows: R, row_stride: isize) -> Self {
+        #[cfg(debug_assertions)]
+        crate::utils::assert_strided_offsets_fit(nrows.unbound(), 1, row_stride, 0);
         Self {
             inner: VecImpl {
                 ptr: into_copy::<E, _>(E::faer_map(
@@ -761,6 +763,21 @@ impl<'a, E: Entity, R: Shape> ColMut<'a, E, R> {
         (*self).rb().as_2d().is_all_finite()
     }
 
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`](crate::mat::MatRef::approx_eq).
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsColRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        let other = other.as_col_ref();
+        self.as_ref()
+            .as_2d()
+            .approx_eq(other.as_2d(), rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -847,6 +864,27 @@ impl<'a, E: Entity, R: Shape> ColMut<'a, E, R> {
         }
     }
 
+    /// Returns the column as a contiguous slice.
+    ///
+    /// # Panics
+    /// The function panics if the row stride of `self` is not equal to `1`.
+    #[track_caller]
+    #[inline]
+    pub fn as_slice(self) -> Slice<'a, E> {
+        self.into_const().as_slice()
+    }
+
+    /// Returns the column as a contiguous mutable slice.
+    ///
+    /// # Panics
+    /// The function panics if the row stride of `self` is not equal to `1`.
+    #[track_caller]
+    #[inline]
+    pub fn as_slice_mut(self) -> SliceMut<'a, E> {
+        assert!(self.row_stride() == 1);
+        self.try_as_slice_mut().unwrap()
+    }
+
     /// Returns the column as a contiguous potentially uninitialized slice if its row stride is
     /// equal to `1`.
     ///
@@ -1061,12 +1099,16 @@ impl<'a, E: Entity, R: Shape> ColMut<'a, E, R> {
 /// # Safety:
 /// This function has the same safety requirements as
 /// [`mat::from_raw_parts_mut(ptr, nrows, 1, row_stride, 0)`]
+///
+/// # Panics
+/// The function panics if `(nrows - 1) * |row_stride| > isize::MAX`.
 #[inline(always)]
 pub unsafe fn from_raw_parts_mut<'a, E: Entity, R: Shape>(
     ptr: PtrMut<E>,
     nrows: R,
     row_stride: isize,
 ) -> ColMut<'a, E, R> {
+    crate::utils::assert_strided_offsets_fit(nrows.unbound(), 1, row_stride, 0);
     ColMut::__from_raw_parts(ptr, nrows, row_stride)
 }
 
@@ -1096,6 +1138,27 @@ pub fn from_slice_mut<E: SimpleEntity>(slice: &mut [E]) -> ColMut<'_, E> {
     from_slice_mut_generic(slice)
 }
 
+impl<'a, E: Entity> ColMut<'a, E> {
+    /// Returns a mutable view over the elements of `slice`, with a row stride of `1`. The data is
+    /// not copied.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::col::ColMut;
+    ///
+    /// let mut data = [1.0, 2.0, 3.0];
+    /// ColMut::from_slice_mut(&mut data).fill(0.0);
+    /// assert_eq!(data, [0.0; 3]);
+    /// ```
+    #[inline]
+    pub fn from_slice_mut(slice: &'a mut [E]) -> Self
+    where
+        E: SimpleEntity,
+    {
+        from_slice_mut(slice)
+    }
+}
+
 impl<E: Entity, R: Shape> As2D<E> for ColMut<'_, E, R> {
     #[inline]
     fn as_2d_ref(&self) -> MatRef<'_, E> {
@@ -30,6 +30,7 @@ impl<E: Entity, R: Shape> Drop for Col<E, R> {
             ptr: self.inner.ptr,
             row_capacity: self.row_capacity,
             col_capacity: 1,
+            align: align_for::<E::Unit>(),
         });
     }
 }
@@ -66,6 +67,50 @@ impl<E: Entity> Col<E> {
             __marker: PhantomData,
         }
     }
+
+    /// Returns a new column holding a copy of the elements of `slice`.
+    ///
+    /// See [`ColRef::from_slice`] for a view that does not copy the data.
+    ///
+    /// # Panics
+    /// The function panics if the total capacity in bytes exceeds `isize::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, Col};
+    ///
+    /// let col = Col::from_slice(&[1.0, 2.0, 3.0]);
+    /// assert_eq!(col, col![1.0, 2.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn from_slice(slice: &[E]) -> Self
+    where
+        E: SimpleEntity,
+    {
+        Self::from_fn(slice.len(), |i| slice[i])
+    }
+
+    /// Returns a new column made up of `n` copies of `self`, stacked vertically.
+    ///
+    /// See [`ColRef::repeat`].
+    #[track_caller]
+    pub fn repeat(&self, n: usize) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().repeat(n)
+    }
+
+    /// Returns a new matrix with `ncols` columns, each of which is a copy of `self`.
+    ///
+    /// See [`ColRef::tile_into_mat`].
+    #[track_caller]
+    pub fn tile_into_mat(&self, ncols: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().tile_into_mat(ncols)
+    }
 }
 
 impl<E: Entity, R: Shape> Col<E, R> {
@@ -364,10 +409,28 @@ impl<E: Entity, R: Shape> Col<E, R> {
     /// Reserves the minimum capacity for `row_capacity` rows without reallocating. Does nothing if
     /// the capacity is already sufficient.
     ///
+    /// See [`Self::reserve_exact_reporting`] for the growth strategy.
+    ///
     /// # Panics
     /// The function panics if the new total capacity in bytes exceeds `isize::MAX`.
     #[inline]
     pub fn reserve_exact(&mut self, row_capacity: usize) {
+        self.reserve_exact_reporting(row_capacity);
+    }
+
+    /// Reserves the minimum capacity for `row_capacity` rows without reallocating. Does nothing if
+    /// the capacity is already sufficient.
+    ///
+    /// Returns `true` if the vector was reallocated, in which case its elements were moved to the
+    /// new allocation, or `false` if the existing capacity was reused.
+    ///
+    /// No amortized over-allocation is performed: the capacity grows to `row_capacity`, rounded up
+    /// to a multiple of the SIMD alignment of the element type.
+    ///
+    /// # Panics
+    /// The function panics if the new total capacity in bytes exceeds `isize::MAX`.
+    #[inline]
+    pub fn reserve_exact_reporting(&mut self, row_capacity: usize) -> bool {
         #[cold]
         fn do_reserve_exact<E: Entity>(self_: &mut Col<E>, mut new_row_capacity: usize) {
             if is_vectorizable::<E::Unit>() {
@@ -388,6 +451,7 @@ impl<E: Entity, R: Shape> Col<E, R> {
                             ptr,
                             row_capacity: old_row_capacity,
                             col_capacity: 1,
+                            align: align_for::<E::Unit>(),
                         },
                         nrows,
                         ncols: 1,
@@ -406,9 +470,10 @@ impl<E: Entity, R: Shape> Col<E, R> {
         }
 
         if self.row_capacity() >= row_capacity {
-            // do nothing
+            false
         } else if core::mem::size_of::<E::Unit>() == 0 {
             self.row_capacity = self.row_capacity().max(row_capacity);
+            false
         } else {
             let mut tmp = core::mem::ManuallyDrop::new(Col::<E> {
                 inner: VecOwnImpl {
@@ -430,6 +495,7 @@ impl<E: Entity, R: Shape> Col<E, R> {
             core::mem::forget(guard);
             self.row_capacity = tmp.row_capacity;
             self.inner.ptr = tmp.inner.ptr;
+            true
         }
     }
 
@@ -920,6 +986,21 @@ impl<E: Entity, R: Shape> Col<E, R> {
         self.as_ref().is_all_finite()
     }
 
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`](crate::mat::MatRef::approx_eq).
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsColRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        let other = other.as_col_ref();
+        self.as_ref()
+            .as_2d()
+            .approx_eq(other.as_2d(), rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -68,6 +68,8 @@ impl<E: Entity, R: Shape> IntoConst for ColRef<'_, E, R> {
 impl<'a, E: Entity, R: Shape> ColRef<'a, E, R> {
     #[inline]
     pub(crate) unsafe fn __from_raw_parts(ptr: PtrConst<E>, nrows: R, row_stride: isize) -> Self {
+        #[cfg(debug_assertions)]
+        crate::utils::assert_strided_offsets_fit(nrows.unbound(), 1, row_stride, 0);
         Self {
             inner: VecImpl {
                 ptr: into_copy::<E, _>(E::faer_map(
@@ -497,6 +499,21 @@ impl<'a, E: Entity, R: Shape> ColRef<'a, E, R> {
         )
     }
 
+    /// Returns a new column whose elements are computed by applying the vectorized kernel `f` to
+    /// the elements of `self`.
+    ///
+    /// See [`SimdMap`](crate::utils::simd::SimdMap) for the requirements on the kernel.
+    #[inline]
+    pub fn map_simd(&self, f: impl crate::utils::simd::SimdMap<E>) -> Col<E, R>
+    where
+        E: ComplexField,
+    {
+        let mut f = f;
+        let mut out = Col::<E, R>::zeros(self.nrows());
+        crate::utils::simd::map_simd_col(out.as_dyn_mut(), self.as_dyn(), &mut f);
+        out
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -515,6 +532,21 @@ impl<'a, E: Entity, R: Shape> ColRef<'a, E, R> {
         (*self).rb().as_2d().is_all_finite()
     }
 
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`](crate::mat::MatRef::approx_eq).
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsColRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        let other = other.as_col_ref();
+        self.as_ref()
+            .as_2d()
+            .approx_eq(other.as_2d(), rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -591,6 +623,26 @@ impl<'a, E: Entity, R: Shape> ColRef<'a, E, R> {
         }
     }
 
+    /// Returns the column as a contiguous slice.
+    ///
+    /// # Panics
+    /// The function panics if the row stride of `self` is not equal to `1`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::col::ColRef;
+    ///
+    /// let data = [1.0, 2.0, 3.0];
+    /// let col = ColRef::from_slice(&data);
+    /// assert_eq!(col.as_slice(), &data);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn as_slice(self) -> Slice<'a, E> {
+        assert!(self.row_stride() == 1);
+        self.try_as_slice().unwrap()
+    }
+
     /// Returns a view over the matrix.
     #[inline]
     pub fn as_ref(&self) -> ColRef<'_, E, R> {
@@ -708,12 +760,16 @@ impl<'a, E: Entity, R: Shape> ColRef<'a, E, R> {
 /// # Safety:
 /// This function has the same safety requirements as
 /// [`mat::from_raw_parts(ptr, nrows, 1, row_stride, 0)`]
+///
+/// # Panics
+/// The function panics if `(nrows - 1) * |row_stride| > isize::MAX`.
 #[inline(always)]
 pub unsafe fn from_raw_parts<'a, E: Entity, R: Shape>(
     ptr: PtrConst<E>,
     nrows: R,
     row_stride: isize,
 ) -> ColRef<'a, E, R> {
+    crate::utils::assert_strided_offsets_fit(nrows.unbound(), 1, row_stride, 0);
     ColRef::__from_raw_parts(ptr, nrows, row_stride)
 }
 
@@ -743,6 +799,111 @@ pub fn from_slice<E: SimpleEntity>(slice: &[E]) -> ColRef<'_, E> {
     from_slice_generic(slice)
 }
 
+impl<'a, E: Entity> ColRef<'a, E> {
+    /// Returns a view over the elements of `slice`, with a row stride of `1`. The data is not
+    /// copied.
+    ///
+    /// See [`Col::from_slice`] for an owned copy, and [`ColRef::as_slice`] for the inverse
+    /// operation.
+    #[inline]
+    pub fn from_slice(slice: &'a [E]) -> Self
+    where
+        E: SimpleEntity,
+    {
+        from_slice(slice)
+    }
+
+    /// Returns a new column made up of the elements of `pieces`, stacked vertically in order.
+    ///
+    /// # Panics
+    /// The function panics if the total number of rows overflows `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, col::ColRef};
+    ///
+    /// let a = col![1.0, 2.0];
+    /// let b = col![3.0];
+    /// let c = ColRef::concat(&[a.as_ref(), b.as_ref(), a.as_ref()]);
+    ///
+    /// assert_eq!(c, col![1.0, 2.0, 3.0, 1.0, 2.0]);
+    /// ```
+    #[track_caller]
+    pub fn concat(pieces: &[ColRef<'_, E>]) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let mut nrows = 0usize;
+        for piece in pieces {
+            nrows = nrows
+                .checked_add(piece.nrows())
+                .expect("the total number of rows must fit in a usize");
+        }
+
+        let mut out = Col::<E::Canonical>::zeros(nrows);
+        let mut start = 0;
+        for piece in pieces {
+            out.as_mut()
+                .subrows_mut(start, piece.nrows())
+                .copy_from(piece);
+            start += piece.nrows();
+        }
+        out
+    }
+
+    /// Returns a new column made up of `n` copies of `self`, stacked vertically.
+    ///
+    /// # Panics
+    /// The function panics if the total number of rows overflows `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::col;
+    ///
+    /// let a = col![1.0, 2.0];
+    ///
+    /// assert_eq!(a.as_ref().repeat(3), col![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+    /// ```
+    #[track_caller]
+    pub fn repeat(self, n: usize) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let len = self.nrows();
+        let nrows = len
+            .checked_mul(n)
+            .expect("the total number of rows must fit in a usize");
+
+        let mut out = Col::<E::Canonical>::zeros(nrows);
+        for k in 0..n {
+            out.as_mut().subrows_mut(k * len, len).copy_from(self);
+        }
+        out
+    }
+
+    /// Returns a new matrix with `ncols` columns, each of which is a copy of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat};
+    ///
+    /// let a = col![1.0, 2.0];
+    ///
+    /// assert_eq!(a.as_ref().tile_into_mat(3), mat![[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]]);
+    /// ```
+    #[track_caller]
+    pub fn tile_into_mat(self, ncols: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let mut out = Mat::<E::Canonical>::zeros(self.nrows(), ncols);
+        for j in 0..ncols {
+            out.as_mut().col_mut(j).copy_from(self);
+        }
+        out
+    }
+}
+
 impl<E: Entity, R: Shape> As2D<E> for ColRef<'_, E, R> {
     #[inline]
     fn as_2d_ref(&self) -> MatRef<'_, E> {
@@ -169,3 +169,71 @@ impl<'a, FromE: Entity, ToE: Entity> Coerce<ColMut<'a, ToE>> for ColMut<'a, From
         unsafe { transmute_unchecked::<ColMut<'a, FromE>, ColMut<'a, ToE>>(self) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{col, complex_native::c64, mat, row, row::RowRef};
+    use equator::assert;
+
+    #[test]
+    fn test_concat_repeat() {
+        let a = col![1.0, 2.0];
+        let b = Col::<f64>::new();
+        let c = col![3.0, 4.0, 5.0];
+        let v = ColRef::concat(&[a.as_ref(), b.as_ref(), c.as_ref().reverse_rows()]);
+        assert!(v == col![1.0, 2.0, 5.0, 4.0, 3.0]);
+        assert!(ColRef::<f64>::concat(&[]).nrows() == 0);
+
+        // strided pieces and conjugated views are copied into canonical form
+        let m = mat![[1.0, 2.0], [3.0, 4.0]];
+        let v = ColRef::concat(&[m.row(0).transpose(), m.row(1).transpose()]);
+        assert!(v == col![1.0, 2.0, 3.0, 4.0]);
+        let z = Col::from_fn(2, |i| c64::new(i as f64, 1.0));
+        let v = ColRef::concat(&[z.as_ref().conjugate(), z.as_ref().conjugate()]);
+        assert!(v.read(0) == c64::new(0.0, -1.0));
+        assert!(v.read(3) == c64::new(1.0, -1.0));
+
+        assert!(a.repeat(0).nrows() == 0);
+        assert!(a.repeat(2) == col![1.0, 2.0, 1.0, 2.0]);
+        assert!(a.tile_into_mat(0).shape() == (2, 0));
+        assert!(b.tile_into_mat(3).shape() == (0, 3));
+
+        let r = row![1.0, 2.0];
+        let h = RowRef::concat(&[r.as_ref(), m.col(1).transpose()]);
+        assert!(h == row![1.0, 2.0, 2.0, 4.0]);
+        assert!(r.repeat(2) == row![1.0, 2.0, 1.0, 2.0]);
+        assert!(r.tile_into_mat(3) == mat![[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_from_slice_as_slice() {
+        let mut data = [1.0, 2.0, 3.0, 4.0];
+
+        let view = ColRef::from_slice(&data);
+        assert!(view.row_stride() == 1);
+        assert!(view == col![1.0, 2.0, 3.0, 4.0]);
+        let slice: &[f64] = view.as_slice();
+        assert!(slice.as_ptr() == data.as_ptr());
+
+        let owned = Col::from_slice(&data);
+        assert!(owned == view);
+        assert!(owned.as_ptr() != data.as_ptr());
+
+        ColMut::from_slice_mut(&mut data)
+            .subrows_mut(1, 2)
+            .as_slice_mut()
+            .copy_from_slice(&[-2.0, -3.0]);
+        assert!(data == [1.0, -2.0, -3.0, 4.0]);
+
+        let empty = ColRef::<f64>::from_slice(&[]);
+        assert!(empty.as_slice().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_as_slice_strided() {
+        let m = mat![[1.0, 2.0], [3.0, 4.0]];
+        let _ = m.row(0).transpose().as_slice();
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
 };
 
 use self::chunks::ChunkPolicy;
+use reborrow::*;
 
 /// Fixed-size chunked column iterator over the elements.
 #[derive(Debug, Clone)]
@@ -111,7 +112,9 @@ macro_rules! impl_chunk_iter {
             #[inline]
             fn next(&mut self) -> Option<Self::Item> {
                 let dim = self.inner.$dim();
-                if dim == 0 {
+                // partitions may contain empty chunks, so the number of remaining chunks is
+                // tracked by the policy rather than by the remaining dimension
+                if self.policy.len(dim) == 0 {
                     None
                 } else {
                     let size = self.policy.advance(dim);
@@ -131,7 +134,7 @@ macro_rules! impl_chunk_iter {
             #[inline]
             fn next_back(&mut self) -> Option<Self::Item> {
                 let dim = self.inner.$dim();
-                if dim == 0 {
+                if self.policy.len(dim) == 0 {
                     None
                 } else {
                     let size = self.policy.advance_back(dim);
@@ -194,6 +197,23 @@ pub struct RowIterMut<'a, E: Entity, C: Shape = usize> {
     pub(crate) inner: MatMut<'a, E, usize, C>,
 }
 
+/// Iterator over the elements of a matrix along with their `(row, col)` indices, in column-major
+/// order.
+#[derive(Debug, Clone)]
+pub struct EnumerateElemIter<'a, E: Entity> {
+    pub(crate) inner: MatRef<'a, E>,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+/// Iterator over the elements of a matrix along with their `(row, col)` indices, in column-major
+/// order.
+#[derive(Debug)]
+pub struct EnumerateElemIterMut<'a, E: Entity> {
+    pub(crate) inner: MatMut<'a, E>,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
 impl<'a, E: Entity> Iterator for ElemIter<'a, E> {
     type Item = GroupFor<E, &'a E::Unit>;
 
@@ -208,6 +228,14 @@ impl<'a, E: Entity> Iterator for ElemIter<'a, E> {
         }
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (_, tail) = self.inner.split_at(Ord::min(n, nrows));
+        self.inner = tail;
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.inner.nrows(), Some(self.inner.nrows()))
@@ -225,6 +253,14 @@ impl<'a, E: Entity> DoubleEndedIterator for ElemIter<'a, E> {
             None => None,
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (head, _) = self.inner.split_at(nrows - Ord::min(n, nrows));
+        self.inner = head;
+        self.next_back()
+    }
 }
 
 impl<'a, E: Entity> ExactSizeIterator for ElemIter<'a, E> {
@@ -248,6 +284,14 @@ impl<'a, E: Entity> Iterator for ElemIterMut<'a, E> {
         }
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (_, tail) = core::mem::take(&mut self.inner).split_at_mut(Ord::min(n, nrows));
+        self.inner = tail;
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.inner.nrows(), Some(self.inner.nrows()))
@@ -265,6 +309,14 @@ impl<'a, E: Entity> DoubleEndedIterator for ElemIterMut<'a, E> {
             None => None,
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (head, _) = core::mem::take(&mut self.inner).split_at_mut(nrows - Ord::min(n, nrows));
+        self.inner = head;
+        self.next_back()
+    }
 }
 
 impl<'a, E: Entity> ExactSizeIterator for ElemIterMut<'a, E> {
@@ -274,6 +326,145 @@ impl<'a, E: Entity> ExactSizeIterator for ElemIterMut<'a, E> {
     }
 }
 
+impl<'a, E: Entity> EnumerateElemIter<'a, E> {
+    #[inline]
+    pub(crate) fn new(inner: MatRef<'a, E>) -> Self {
+        Self {
+            inner,
+            start: 0,
+            end: inner.nrows() * inner.ncols(),
+        }
+    }
+
+    #[inline(always)]
+    fn item(&self, k: usize) -> ((usize, usize), GroupFor<E, &'a E::Unit>) {
+        let m = self.inner.nrows();
+        let (i, j) = (k % m, k / m);
+        ((i, j), unsafe { self.inner.at_unchecked(i, j) })
+    }
+}
+
+impl<'a, E: Entity> EnumerateElemIterMut<'a, E> {
+    #[inline]
+    pub(crate) fn new(inner: MatMut<'a, E>) -> Self {
+        let end = inner.nrows() * inner.ncols();
+        Self {
+            inner,
+            start: 0,
+            end,
+        }
+    }
+
+    #[inline(always)]
+    fn item(&mut self, k: usize) -> ((usize, usize), GroupFor<E, &'a mut E::Unit>) {
+        let m = self.inner.nrows();
+        let (i, j) = (k % m, k / m);
+        // SAFETY: each linear index is yielded at most once, so the references don't alias
+        let ptr = unsafe { self.inner.rb_mut().ptr_inbounds_at_mut(i, j) };
+        ((i, j), E::faer_map(ptr, |ptr| unsafe { &mut *ptr }))
+    }
+}
+
+impl<'a, E: Entity> Iterator for EnumerateElemIter<'a, E> {
+    type Item = ((usize, usize), GroupFor<E, &'a E::Unit>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.start += 1;
+            Some(self.item(self.start - 1))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.start += Ord::min(n, self.end - self.start);
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.start, Some(self.end - self.start))
+    }
+}
+
+impl<'a, E: Entity> DoubleEndedIterator for EnumerateElemIter<'a, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(self.item(self.end))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.end -= Ord::min(n, self.end - self.start);
+        self.next_back()
+    }
+}
+
+impl<'a, E: Entity> ExactSizeIterator for EnumerateElemIter<'a, E> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl<'a, E: Entity> Iterator for EnumerateElemIterMut<'a, E> {
+    type Item = ((usize, usize), GroupFor<E, &'a mut E::Unit>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.start += 1;
+            Some(self.item(self.start - 1))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.start += Ord::min(n, self.end - self.start);
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.start, Some(self.end - self.start))
+    }
+}
+
+impl<'a, E: Entity> DoubleEndedIterator for EnumerateElemIterMut<'a, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(self.item(self.end))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.end -= Ord::min(n, self.end - self.start);
+        self.next_back()
+    }
+}
+
+impl<'a, E: Entity> ExactSizeIterator for EnumerateElemIterMut<'a, E> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
 impl<'a, E: Entity, R: Shape> Iterator for ColIter<'a, E, R> {
     type Item = ColRef<'a, E, R>;
 
@@ -298,6 +489,14 @@ impl<'a, E: Entity, R: Shape> Iterator for ColIter<'a, E, R> {
         }
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let ncols = self.inner.ncols();
+        let (_, tail) = self.inner.split_at_col(Ord::min(n, ncols));
+        self.inner = tail;
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.inner.ncols(), Some(self.inner.ncols()))
@@ -324,6 +523,14 @@ impl<'a, E: Entity, R: Shape> DoubleEndedIterator for ColIter<'a, E, R> {
             None => None,
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let ncols = self.inner.ncols();
+        let (head, _) = self.inner.split_at_col(ncols - Ord::min(n, ncols));
+        self.inner = head;
+        self.next_back()
+    }
 }
 impl<'a, E: Entity, R: Shape> ExactSizeIterator for ColIter<'a, E, R> {
     #[inline]
@@ -356,6 +563,23 @@ impl<'a, E: Entity, R: Shape> Iterator for ColIterMut<'a, E, R> {
         }
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let ncols = self.inner.ncols();
+        let (_, tail) = core::mem::replace(
+            &mut self.inner,
+            mat::from_column_major_slice_mut_generic(
+                E::faer_map(E::UNIT, |()| &mut [] as &mut [E::Unit]),
+                nrows,
+                0,
+            ),
+        )
+        .split_at_col_mut(Ord::min(n, ncols));
+        self.inner = tail;
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.inner.ncols(), Some(self.inner.ncols()))
@@ -382,6 +606,23 @@ impl<'a, E: Entity, R: Shape> DoubleEndedIterator for ColIterMut<'a, E, R> {
             None => None,
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let ncols = self.inner.ncols();
+        let (head, _) = core::mem::replace(
+            &mut self.inner,
+            mat::from_column_major_slice_mut_generic(
+                E::faer_map(E::UNIT, |()| &mut [] as &mut [E::Unit]),
+                nrows,
+                0,
+            ),
+        )
+        .split_at_col_mut(ncols - Ord::min(n, ncols));
+        self.inner = head;
+        self.next_back()
+    }
 }
 impl<'a, E: Entity, R: Shape> ExactSizeIterator for ColIterMut<'a, E, R> {
     #[inline]
@@ -404,6 +645,14 @@ impl<'a, E: Entity> Iterator for RowIter<'a, E> {
         }
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (_, tail) = self.inner.split_at_row(Ord::min(n, nrows));
+        self.inner = tail;
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.inner.nrows(), Some(self.inner.nrows()))
@@ -420,6 +669,14 @@ impl<'a, E: Entity> DoubleEndedIterator for RowIter<'a, E> {
             None => None,
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (head, _) = self.inner.split_at_row(nrows - Ord::min(n, nrows));
+        self.inner = head;
+        self.next_back()
+    }
 }
 impl<'a, E: Entity> ExactSizeIterator for RowIter<'a, E> {
     #[inline]
@@ -442,6 +699,14 @@ impl<'a, E: Entity> Iterator for RowIterMut<'a, E> {
         }
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (_, tail) = core::mem::take(&mut self.inner).split_at_row_mut(Ord::min(n, nrows));
+        self.inner = tail;
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.inner.nrows(), Some(self.inner.nrows()))
@@ -458,6 +723,15 @@ impl<'a, E: Entity> DoubleEndedIterator for RowIterMut<'a, E> {
             None => None,
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let nrows = self.inner.nrows();
+        let (head, _) =
+            core::mem::take(&mut self.inner).split_at_row_mut(nrows - Ord::min(n, nrows));
+        self.inner = head;
+        self.next_back()
+    }
 }
 impl<'a, E: Entity> ExactSizeIterator for RowIterMut<'a, E> {
     #[inline]
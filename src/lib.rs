@@ -31,6 +31,16 @@
 //! matrix addition, `-` for subtraction, `*` for either scalar or matrix multiplication depending
 //! on the types of the operands.
 //!
+//! The binary operators check that the dimensions of their operands are compatible, and panic
+//! otherwise. They allocate a new matrix for their result, except for the scaling of a borrowed
+//! matrix by a [`Scale`], which is deferred until it's used (see its documentation): a
+//! matrix-matrix or matrix-vector product with `*` allocates its output, then calls
+//! [`linalg::matmul::matmul`] on it. In performance sensitive code, calling
+//! [`linalg::matmul::matmul`] directly with a preallocated destination avoids the allocation, and
+//! can also accumulate into existing data.
+//! The compound assignment operators `+=`, `-=`, `*=` and `/=` update their left hand side in
+//! place, and don't allocate.
+//!
 //! ## Example
 //! ```
 //! use faer::{mat, scale, Mat};
@@ -55,6 +65,18 @@
 //! # Matrix decompositions
 //! `faer` provides a variety of matrix factorizations, each with its own advantages and drawbacks:
 //!
+//! The methods on [`Mat`]/[`MatRef`]/[`MatMut`] (e.g. [`Mat::partial_piv_lu`]) copy their input,
+//! which is left unmodified. When the input matrix is no longer needed, the decompositions in
+//! [`linalg::solvers`] can instead be computed in the storage of an owned [`Mat`] with
+//! `new_in_place` (e.g. [`PartialPivLu::new_in_place`](linalg::solvers::PartialPivLu::new_in_place)),
+//! which avoids an allocation. The lower level functions in [`linalg`] (e.g.
+//! [`linalg::lu::partial_pivoting::compute::lu_in_place`]) always overwrite their input.
+//!
+//! A decomposition of $A$ can also be used to solve systems involving $A^\top$ or $A^H$ without
+//! refactorizing, with
+//! [`SpSolver::solve_transpose_in_place`](linalg::solvers::SpSolver::solve_transpose_in_place) and
+//! [`SpSolver::solve_conj_transpose_in_place`](linalg::solvers::SpSolver::solve_conj_transpose_in_place).
+//!
 //! ## Cholesky decomposition
 //! [`Mat::cholesky`] decomposes a self-adjoint positive definite matrix $A$ such that
 //! $$A = LL^H,$$
@@ -126,7 +148,7 @@
 //!
 //! ## Eigendecomposition
 //! **Note**: The order of the eigenvalues is currently unspecified and may be changed in a future
-//! release.
+//! release. [`Mat::eigendecomposition_sorted`] can be used to obtain them in a well-defined order.
 //!
 //! The eigendecomposition of a square matrix $M$ of shape $(n, n)$ is a decomposition into
 //! two components $U$, $S$:
@@ -142,7 +164,9 @@
 //! Depending on the domain of the input matrix and whether it is self-adjoint, multiple methods
 //! are provided to compute the eigendecomposition:
 //! * [`Mat::selfadjoint_eigendecomposition`] can be used with either real or complex matrices,
-//! producing an eigendecomposition of the same type.
+//! producing an eigendecomposition of the same type. Only one triangular half of the matrix is
+//! accessed, and the eigenvalues are guaranteed to be real (see
+//! [`SelfAdjointEigendecomposition::real_eigenvalues`](linalg::solvers::SelfAdjointEigendecomposition::real_eigenvalues)).
 //! * [`Mat::eigendecomposition`] can be used with either real or complex matrices, but the output
 //! complex type has to be specified.
 //! * [`Mat::complex_eigendecomposition`] can only be used with complex matrices, with the output
@@ -311,6 +335,8 @@ pub mod linop;
 pub mod mat;
 /// Permutation matrices.
 pub mod perm;
+/// Double-double floating point arithmetic.
+pub mod quad;
 /// Row vector type.
 pub mod row;
 /// Sparse data structures and algorithms.
@@ -327,6 +353,10 @@ mod sort;
 pub use faer_entity::{ComplexField, Conjugate, Entity, RealField, SimpleEntity};
 
 /// Specifies whether the triangular lower or upper part of a matrix should be accessed.
+///
+/// Routines taking a Hermitian matrix along with a `Side` read the diagonal and the selected
+/// triangle only. The values in the opposite strictly triangular part are never read, and may be
+/// arbitrary (e.g. NaN), so that only half of the matrix needs to hold meaningful values.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Side {
     /// Lower half should be accessed.
@@ -687,6 +717,82 @@ macro_rules! row {
     }};
 }
 
+/// Asserts that two matrices are approximately equal, given a relative tolerance and an absolute
+/// tolerance, in the sense of [`MatRef::approx_eq`](crate::mat::MatRef::approx_eq).
+///
+/// On failure, the panic message shows the dimensions of both matrices if they differ. Otherwise,
+/// it shows the indices and values of the first mismatching element in column-major order, along
+/// with the number of mismatching elements.
+///
+/// Both arguments may be any type implementing [`AsMatRef`](crate::mat::AsMatRef). Columns and
+/// rows can be compared after converting them with `as_2d`.
+///
+/// # Example
+/// ```
+/// use faer::{assert_matrix_approx_eq, mat};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+/// let b = mat![[1.0, 2.0], [3.0, 4.0 + 1e-12]];
+///
+/// assert_matrix_approx_eq!(a, b, 1e-10, 0.0);
+/// assert_matrix_approx_eq!(a.col(0).as_2d(), b.col(0).as_2d(), 0.0, 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($lhs: expr, $rhs: expr, $rel_tol: expr, $abs_tol: expr $(,)?) => {
+        $crate::assert_matrix_approx_eq_impl(
+            &$lhs,
+            &$rhs,
+            $rel_tol,
+            $abs_tol,
+            ::core::stringify!($lhs),
+            ::core::stringify!($rhs),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_matrix_approx_eq_impl<E: ComplexField>(
+    lhs: impl mat::AsMatRef<E>,
+    rhs: impl mat::AsMatRef<E>,
+    rel_tol: E::Real,
+    abs_tol: E::Real,
+    lhs_source: &str,
+    rhs_source: &str,
+) {
+    let lhs = lhs.as_mat_ref();
+    let rhs = rhs.as_mat_ref();
+    let (lhs, rhs) = (lhs.as_dyn(), rhs.as_dyn());
+
+    if lhs.shape() != rhs.shape() {
+        panic!(
+            "Assertion failed: {lhs_source} ~ {rhs_source}\n\
+            - {lhs_source} has dimensions {:?}\n\
+            - {rhs_source} has dimensions {:?}",
+            lhs.shape(),
+            rhs.shape(),
+        );
+    }
+
+    let mut mismatches = mat::approx_eq_mismatches(lhs, rhs, rel_tol, abs_tol);
+    if let Some((i, j)) = mismatches.next() {
+        let count = 1 + mismatches.count();
+        let (a, b) = (lhs.read(i, j), rhs.read(i, j));
+        panic!(
+            "Assertion failed: {lhs_source} ~ {rhs_source}\n\
+            with relative tolerance = {rel_tol:?}\n\
+            with absolute tolerance = {abs_tol:?}\n\
+            {count} out of {} elements differ, the first one at ({i}, {j}):\n\
+            - {lhs_source}[({i}, {j})] = {a:?}\n\
+            - {rhs_source}[({i}, {j})] = {b:?}\n\
+            - distance = {:?}",
+            lhs.nrows() * lhs.ncols(),
+            a.faer_sub(b).faer_abs(),
+        );
+    }
+}
+
 /// Trait for unsigned integers that can be indexed with.
 ///
 /// Always smaller than or equal to `usize`.
@@ -886,6 +992,41 @@ impl SignedIndex for isize {
 }
 
 /// Factor for matrix-scalar multiplication.
+///
+/// # Allocation
+/// Multiplying a borrowed matrix ([`MatRef`], [`MatMut`], `&Mat`) by a `Scale` in either order,
+/// or dividing it by one, doesn't compute anything and returns a lazy [`mat::ScaledMat`]. The
+/// factor is applied when that value is used:
+/// * multiplying it by a matrix, a column, a row or another `ScaledMat` lowers to a single call
+///   to [`linalg::matmul::matmul`] with the factor as its scaling factor, so
+///   `&a * Scale(k) * &b` only allocates the product,
+/// * adding it to or subtracting it from a matrix only allocates the result,
+/// * multiplying or dividing it by another `Scale`, or negating it, combines the factors without
+///   allocating,
+/// * [`mat::ScaledMat::to_owned`] allocates and returns the scaled matrix.
+///
+/// Owned matrices, as well as columns, rows and diagonals, are scaled eagerly, and the result is
+/// allocated. The in-place operators, `*=` and `/=`, don't allocate.
+///
+/// Each product in a sum is still allocated separately. To compute
+/// `dst = alpha * dst + beta * lhs * rhs` in a single pass without any temporaries, call
+/// [`linalg::matmul::matmul`] directly, passing `Some(alpha)` and `beta` as its scaling factors.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::matmul::matmul, mat, scale, Parallelism};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+/// let b = mat![[0.5, 0.0], [0.0, 0.5f64]];
+/// let mut c = mat![[1.0, 1.0], [1.0, 1.0f64]];
+///
+/// // allocates the product, scaled by `2.0` during the multiplication, then the sum
+/// let expected = scale(2.0) * &a * &b + &c;
+///
+/// // same result, computed in place in `c`
+/// matmul(c.as_mut(), a.as_ref(), b.as_ref(), Some(1.0), 2.0, Parallelism::None);
+/// assert_eq!(c, expected);
+/// ```
 #[derive(Copy, Clone, Debug)]
 pub struct Scale<E>(pub E);
 
@@ -903,6 +1044,27 @@ pub fn scale<E>(val: E) -> Scale<E> {
     Scale(val)
 }
 
+/// Real factor for matrix-scalar multiplication.
+///
+/// Scaling a complex matrix by a `ScaleReal` multiplies the real and imaginary parts of each
+/// element by the factor, instead of performing a full complex multiplication.
+#[derive(Copy, Clone, Debug)]
+pub struct ScaleReal<E>(pub E);
+
+impl<E> ScaleReal<E> {
+    /// Returns the inner value.
+    #[inline]
+    pub fn value(self) -> E {
+        self.0
+    }
+}
+
+/// Returns a real factor for matrix-scalar multiplication.
+#[inline]
+pub fn scale_real<E>(val: E) -> ScaleReal<E> {
+    ScaleReal(val)
+}
+
 /// Parallelism strategy that can be passed to most of the routines in the library.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -978,6 +1140,114 @@ pub fn get_global_parallelism() -> Parallelism<'static> {
     }
 }
 
+/// Block sizes used by the dense factorizations of the high-level solvers, overriding the
+/// built-in heuristics.
+///
+/// Each value is `None` by default, in which case the block size is chosen automatically from the
+/// matrix dimensions and the scalar type. The heuristics were tuned on common desktop processors,
+/// and may be far from optimal on hardware with unusually large or small caches.
+///
+/// - `lu_block` is the number of columns at or below which the recursive LU decomposition with
+///   partial pivoting switches to its unblocked kernel. It is forwarded to
+///   [`PartialPivLuComputeParams::blocksize`](crate::linalg::lu::partial_pivoting::compute::PartialPivLuComputeParams::blocksize).
+/// - `qr_block` is the block size of the Householder sequences of the QR and LQ decompositions,
+///   i.e. the number of rows of the Householder factor. It is clamped to the range
+///   `1..=min(nrows, ncols)`.
+/// - `chol_block` is the dimension at or below which the recursive Cholesky decomposition switches
+///   to its unblocked kernel. It is forwarded to
+///   [`LltParams::blocksize`](crate::linalg::cholesky::llt::compute::LltParams::blocksize).
+///   Since that kernel works in registers, only values below the size that fits in them have an
+///   effect.
+///
+/// The low-level functions in [`linalg`] ignore these settings, and take the block size from
+/// their own parameters instead.
+///
+/// # Choosing the block sizes
+///
+/// The optimal values depend on the cache sizes, the SIMD width, the scalar type and the number
+/// of threads, so they are best measured on the target machine, with matrices of the sizes that
+/// are used in practice. A simple approach is to factorize the same matrix a few times with each
+/// candidate value, keep the fastest time for each, and pick the value that minimizes it. Powers
+/// of two between 8 and 256 are reasonable candidates, and the result should be compared against
+/// the default (`None`) to make sure the override is an improvement.
+///
+/// ```
+/// use faer::{get_global_blocking_params, set_global_blocking_params, BlockingParams, Mat};
+/// use std::time::{Duration, Instant};
+///
+/// let n = 256;
+/// let a = Mat::<f64>::from_fn(n, n, |i, j| if i == j { n as f64 } else { 1.0 });
+///
+/// let mut best = (None, Duration::MAX);
+/// for qr_block in [None, Some(8), Some(16), Some(32), Some(64)] {
+///     set_global_blocking_params(BlockingParams {
+///         qr_block,
+///         ..Default::default()
+///     });
+///     let time = (0..3)
+///         .map(|_| {
+///             let start = Instant::now();
+///             let _ = a.qr();
+///             start.elapsed()
+///         })
+///         .min()
+///         .unwrap();
+///     if time < best.1 {
+///         best = (qr_block, time);
+///     }
+/// }
+///
+/// set_global_blocking_params(BlockingParams {
+///     qr_block: best.0,
+///     ..Default::default()
+/// });
+/// assert_eq!(get_global_blocking_params().qr_block, best.0);
+/// # set_global_blocking_params(BlockingParams::default());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockingParams {
+    /// Block size of the LU decomposition with partial pivoting. `None` to automatically determine
+    /// it.
+    pub lu_block: Option<usize>,
+    /// Block size of the QR and LQ decompositions. `None` to automatically determine it.
+    pub qr_block: Option<usize>,
+    /// Block size of the Cholesky decomposition. `None` to automatically determine it.
+    pub chol_block: Option<usize>,
+}
+
+/// 0: automatic
+/// n >= 1: Some(n)
+static GLOBAL_LU_BLOCK: AtomicUsize = AtomicUsize::new(0);
+static GLOBAL_QR_BLOCK: AtomicUsize = AtomicUsize::new(0);
+static GLOBAL_CHOL_BLOCK: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the global block size settings. See [`BlockingParams`].
+pub fn set_global_blocking_params(params: BlockingParams) {
+    let store = |global: &AtomicUsize, value: Option<usize>| {
+        // a block size of zero is meaningless, so it is treated as one
+        global.store(
+            value.map_or(0, |value| Ord::max(value, 1)),
+            core::sync::atomic::Ordering::Relaxed,
+        )
+    };
+    store(&GLOBAL_LU_BLOCK, params.lu_block);
+    store(&GLOBAL_QR_BLOCK, params.qr_block);
+    store(&GLOBAL_CHOL_BLOCK, params.chol_block);
+}
+
+/// Gets the global block size settings. See [`BlockingParams`].
+pub fn get_global_blocking_params() -> BlockingParams {
+    let load = |global: &AtomicUsize| match global.load(core::sync::atomic::Ordering::Relaxed) {
+        0 => None,
+        n => Some(n),
+    };
+    BlockingParams {
+        lu_block: load(&GLOBAL_LU_BLOCK),
+        qr_block: load(&GLOBAL_QR_BLOCK),
+        chol_block: load(&GLOBAL_CHOL_BLOCK),
+    }
+}
+
 /// De-serialization from common matrix file formats.
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -1028,6 +1298,99 @@ mod tests {
         assert!(slice.get(1, 2) == &6.0);
     }
 
+    #[test]
+    fn try_get() {
+        let mut m = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f64]];
+
+        assert!(m.try_get(1, 2) == Some(&6.0));
+        assert!(m.try_get(2, 0) == None);
+        assert!(m.try_get(0, 3) == None);
+        assert!(m.as_ref().transpose().try_get(2, 1) == Some(&6.0));
+        assert!(m.as_ref().transpose().try_get(1, 2) == None);
+
+        *m.try_get_mut(0, 1).unwrap() = -2.0;
+        assert!(m.try_get_mut(usize::MAX, 0).is_none());
+        assert!(m.read(0, 1) == -2.0);
+
+        let empty = Mat::<f64>::new();
+        assert!(empty.try_get(0, 0).is_none());
+    }
+
+    #[test]
+    fn elementwise_unary() {
+        let a = mat![[1.0, -4.0], [0.25, 9.0f64]];
+
+        assert!(a.abs() == mat![[1.0, 4.0], [0.25, 9.0]]);
+        assert!(a.clamp(0.0, 2.0) == mat![[1.0, 0.0], [0.25, 2.0]]);
+        assert!(a.abs().sqrt() == mat![[1.0, 2.0], [0.5, 3.0]]);
+        assert!(a.recip() == mat![[1.0, -0.25], [4.0, 1.0 / 9.0]]);
+        assert!((a.exp().ln() - &a).norm_max() < 1e-14);
+        assert!(
+            a.as_ref().exp() == Mat::from_fn(2, 2, |i, j| crate::utils::math::exp(a.read(i, j)))
+        );
+
+        let mut b = a.clone();
+        b.as_mut().abs_in_place();
+        b.as_mut().ln_in_place();
+        b.as_mut().exp_in_place();
+        assert!((&b - a.abs()).norm_max() < 1e-14);
+
+        let mut b = a.transpose().to_owned();
+        b.as_mut().transpose_mut().clamp_in_place(-1.0, 1.0);
+        assert!(b == mat![[1.0, 0.25], [-1.0, 1.0]]);
+        b.recip_in_place();
+        b.sqrt_in_place();
+        assert!(b.read(0, 1) == 2.0);
+        assert!(b.read(1, 0).is_nan());
+    }
+
+    #[test]
+    fn from_diagonal() {
+        let d = col![1.0, -2.0, 3.0f64];
+        let m = Mat::from_diagonal(d.as_ref());
+        assert!(m == mat![[1.0, 0.0, 0.0], [0.0, -2.0, 0.0], [0.0, 0.0, 3.0]]);
+        assert!(m.diagonal().column_vector() == d);
+        assert!(
+            Mat::from_diagonal(Col::<f64>::full(3, 1.0).as_ref()) == Mat::<f64>::identity(3, 3)
+        );
+
+        let d = col![
+            complex_native::c64::new(1.0, 2.0),
+            complex_native::c64::new(3.0, -1.0)
+        ];
+        let m = Mat::from_diagonal(d.as_ref().conjugate());
+        assert!(m.read(0, 0) == complex_native::c64::new(1.0, -2.0));
+        assert!(m.read(1, 0) == complex_native::c64::new(0.0, 0.0));
+        assert!(Mat::<f64>::from_diagonal(Col::<f64>::new().as_ref()).nrows() == 0);
+    }
+
+    #[test]
+    fn off_diagonals() {
+        let mut m = Mat::from_fn(3, 4, |i, j| (10 * i + j) as f64);
+
+        assert!(m.superdiagonal(0) == col![0.0, 11.0, 22.0]);
+        assert!(m.superdiagonal(1) == col![1.0, 12.0, 23.0]);
+        assert!(m.superdiagonal(3) == col![3.0]);
+        assert!(m.superdiagonal(4).nrows() == 0);
+        assert!(m.subdiagonal(1) == col![10.0, 21.0]);
+        assert!(m.subdiagonal(2) == col![20.0]);
+        assert!(m.as_ref().transpose().subdiagonal(1) == m.superdiagonal(1));
+
+        m.subdiagonal_mut(1).fill(-1.0);
+        assert!(m.read(1, 0) == -1.0);
+        assert!(m.read(2, 1) == -1.0);
+
+        let band = m.band(1, 2);
+        assert!(
+            band == mat![
+                [0.0, 1.0, 2.0, 0.0],
+                [-1.0, 11.0, 12.0, 13.0],
+                [0.0, -1.0, 22.0, 23.0],
+            ]
+        );
+        assert!(m.band(usize::MAX, usize::MAX) == m);
+    }
+
     #[test]
     fn empty() {
         {
@@ -1116,6 +1479,32 @@ mod tests {
         assert!(m.read(0, 1) == -1.0);
     }
 
+    #[test]
+    fn reserve_exact_reporting() {
+        let mut m = Mat::<f64>::new();
+        assert!(m.reserve_exact_reporting(3, 4));
+        let row_capacity = m.row_capacity();
+        assert!(all(row_capacity >= 3, m.col_capacity() == 4));
+        let ptr = m.as_ptr();
+        assert!(!m.reserve_exact_reporting(3, 4));
+        assert!(!m.reserve_exact_reporting(0, 2));
+        m.resize_with(3, 4, |i, j| (i + j) as f64);
+        assert!(m.as_ptr() == ptr);
+        assert!(m.reserve_exact_reporting(3, 5));
+        assert!(all(m.row_capacity() == row_capacity, m.col_capacity() == 5));
+        assert!(m == Mat::from_fn(3, 4, |i, j| (i + j) as f64));
+
+        let mut c = Col::<f64>::new();
+        assert!(c.reserve_exact_reporting(5));
+        assert!(!c.reserve_exact_reporting(5));
+        let mut r = Row::<complex_native::c64>::new();
+        assert!(r.reserve_exact_reporting(5));
+        assert!(!r.reserve_exact_reporting(1));
+
+        let mut m = Mat::<faer_entity::Symbolic>::new();
+        assert!(!m.reserve_exact_reporting(5, 5));
+    }
+
     #[test]
     fn resize_zst() {
         // miri test
@@ -1523,6 +1912,149 @@ mod tests {
         assert!(none == None);
     }
 
+    #[test]
+    fn test_iter_len() {
+        let mut mat = Mat::from_fn(5, 7, |i, j| (i + 10 * j) as f64);
+
+        let mut iter = mat.row_iter();
+        assert!(iter.len() == 5);
+        assert!(iter.next_back().unwrap()[0] == 4.0);
+        assert!(iter.next_back().unwrap()[0] == 3.0);
+        assert!(iter.len() == 3);
+        assert!(iter.next().unwrap()[0] == 0.0);
+        assert!(iter.size_hint() == (2, Some(2)));
+        assert!(iter.next_back().unwrap()[0] == 2.0);
+        assert!(iter.next().unwrap()[0] == 1.0);
+        assert!(all(
+            iter.len() == 0,
+            iter.next().is_none(),
+            iter.next_back().is_none()
+        ));
+
+        let mut iter = mat.col_iter_mut();
+        assert!(iter.len() == 7);
+        assert!(iter.next().unwrap()[0] == 0.0);
+        assert!(iter.next_back().unwrap()[0] == 60.0);
+        assert!(iter.next_back().unwrap()[0] == 50.0);
+        assert!(iter.len() == 4);
+        assert!(iter.collect::<Vec<_>>().len() == 4);
+
+        let col = mat.col(1);
+        let mut iter = col.iter();
+        assert!(iter.next_back() == Some(&14.0));
+        assert!(iter.next() == Some(&10.0));
+        assert!(iter.len() == 3);
+
+        // 7 columns in chunks of 3: [0, 3), [3, 6), [6, 7)
+        let mut iter = mat.col_chunks(3);
+        assert!(iter.len() == 3);
+        assert!(iter.next_back().unwrap().ncols() == 1);
+        assert!(iter.len() == 2);
+        assert!(iter.next().unwrap().ncols() == 3);
+        assert!(iter.next_back().unwrap().read(0, 0) == 30.0);
+        assert!(all(iter.len() == 0, iter.next().is_none()));
+
+        // 2 rows in 4 partitions: the last two partitions are empty
+        let mat = Mat::from_fn(2, 3, |i, j| (i + j) as f64);
+        for mut k in 0..16u32 {
+            let mut iter = mat.row_partition(4);
+            let mut sizes = [usize::MAX; 4];
+            let (mut front, mut back) = (0, 4);
+            while front < back {
+                assert!(iter.len() == back - front);
+                if k % 2 == 0 {
+                    sizes[front] = iter.next().unwrap().nrows();
+                    front += 1;
+                } else {
+                    back -= 1;
+                    sizes[back] = iter.next_back().unwrap().nrows();
+                }
+                k /= 2;
+            }
+            assert!(all(
+                iter.len() == 0,
+                iter.next().is_none(),
+                iter.next_back().is_none()
+            ));
+            assert!(sizes == [1, 1, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_iter_nth() {
+        let mut mat = Mat::from_fn(6, 5, |i, j| (i + 10 * j) as f64);
+
+        let mut iter = mat.as_ref().transpose().row_iter();
+        assert!(iter.nth(1).unwrap()[0] == 10.0);
+        assert!(iter.nth_back(1).unwrap()[0] == 30.0);
+        assert!(iter.len() == 1);
+        assert!(iter.nth(1).is_none());
+        assert!(iter.len() == 0);
+
+        let mut iter = mat.col_iter();
+        assert!(iter.nth(0).unwrap()[0] == 0.0);
+        assert!(iter.nth_back(0).unwrap()[0] == 40.0);
+        assert!(iter.nth_back(2).unwrap()[0] == 10.0);
+        assert!(iter.len() == 0);
+
+        let mut iter = mat.col_iter_mut();
+        assert!(iter.nth_back(10).is_none());
+        assert!(iter.len() == 0);
+
+        let col = mat.col(2);
+        let mut iter = col.iter();
+        assert!(iter.nth(2) == Some(&22.0));
+        assert!(iter.nth_back(1) == Some(&24.0));
+        assert!(iter.collect::<Vec<_>>() == [&23.0]);
+
+        let mut iter = mat.row_iter_mut();
+        let mut row = iter.nth(3).unwrap();
+        row[1] = -1.0;
+        assert!(iter.len() == 2);
+        let mut elems = mat.col_mut(1).iter_mut();
+        assert!(*elems.nth(3).unwrap() == -1.0);
+        *elems.nth_back(0).unwrap() = -2.0;
+        assert!(elems.len() == 1);
+        assert!(mat.read(5, 1) == -2.0);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_par_iter() {
+        use rayon::prelude::*;
+
+        let mut mat = Mat::from_fn(37, 23, |i, j| (i + 100 * j) as f64);
+
+        let row_sums: Vec<f64> = mat.par_row_iter().map(|row| row.sum()).collect();
+        assert!(row_sums == mat.row_iter().map(|row| row.sum()).collect::<Vec<_>>());
+        let col_sums: Vec<f64> = mat
+            .as_ref()
+            .transpose()
+            .par_col_iter()
+            .map(|col| col.sum())
+            .collect();
+        assert!(col_sums == row_sums);
+        assert!(mat.par_col_iter().len() == 23);
+        assert!(
+            mat.par_col_iter()
+                .rev()
+                .map(|col| col[0])
+                .collect::<Vec<_>>()[0]
+                == 2200.0
+        );
+
+        mat.par_row_iter_mut()
+            .enumerate()
+            .for_each(|(i, mut row)| row.fill(i as f64));
+        assert!(mat == Mat::from_fn(37, 23, |i, _| i as f64));
+
+        mat.as_mut()
+            .par_col_iter_mut()
+            .zip(0..23)
+            .for_each(|(mut col, j)| col[j] = -1.0);
+        assert!(mat == Mat::from_fn(37, 23, |i, j| if i == j { -1.0 } else { i as f64 }));
+    }
+
     #[test]
     fn test_col_index() {
         let mut col_32: Col<f32> = Col::from_fn(3, |i| i as f32);
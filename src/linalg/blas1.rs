@@ -0,0 +1,252 @@
+//! Level 1 BLAS operations on column vectors.
+//!
+//! These are the building blocks of hand-written iterative methods, such as the conjugate
+//! gradient method or GMRES. Contiguous columns are processed with vectorized kernels, while
+//! strided columns fall back to a scalar loop.
+
+use crate::{
+    assert,
+    col::{ColMut, ColRef},
+    linalg::matmul::inner_prod::inner_prod_with_conj,
+    unzipped,
+    utils::{simd::*, slice::*},
+    zipped_rw, ComplexField, Conj,
+};
+use faer_entity::*;
+use reborrow::*;
+
+/// Computes `y = alpha * x + y` if `x` is provided, and `y = alpha * y` otherwise.
+/// `y` and `x` must be contiguous.
+fn axpy_or_scal_contiguous<E: ComplexField>(
+    alpha: E,
+    x: Option<SliceGroup<'_, E>>,
+    y: SliceGroupMut<'_, E>,
+) {
+    struct Impl<'a, E: ComplexField> {
+        alpha: E,
+        x: Option<SliceGroup<'a, E>>,
+        y: SliceGroupMut<'a, E>,
+    }
+
+    impl<E: ComplexField> pulp::WithSimd for Impl<'_, E> {
+        type Output = ();
+
+        #[inline(always)]
+        fn with_simd<S: pulp::Simd>(self, simd: S) -> Self::Output {
+            let Self { alpha, x, mut y } = self;
+            let simd = SimdFor::<E, S>::new(simd);
+            let zero = simd.splat(E::faer_zero());
+            let alpha = simd.splat(alpha);
+
+            let offset = simd.align_offset(y.rb());
+            let (y_head, y_body, y_tail) = simd.as_aligned_simd_mut(y.rb_mut(), offset);
+
+            #[inline(always)]
+            fn axpy<E: ComplexField, S: pulp::Simd>(
+                simd: SimdFor<E, S>,
+                alpha: SimdGroupFor<E, S>,
+                x: impl Read<Output = SimdGroupFor<E, S>>,
+                mut y: impl Write<Output = SimdGroupFor<E, S>>,
+                zero: SimdGroupFor<E, S>,
+            ) {
+                let y_value = y.read_or(zero);
+                y.write(simd.mul_add_e(alpha, x.read_or(zero), y_value));
+            }
+
+            #[inline(always)]
+            fn scal<E: ComplexField, S: pulp::Simd>(
+                simd: SimdFor<E, S>,
+                alpha: SimdGroupFor<E, S>,
+                mut y: impl Write<Output = SimdGroupFor<E, S>>,
+                zero: SimdGroupFor<E, S>,
+            ) {
+                let y_value = y.read_or(zero);
+                y.write(simd.mul(alpha, y_value));
+            }
+
+            match x {
+                Some(x) => {
+                    let (x_head, x_body, x_tail) = simd.as_aligned_simd(x, offset);
+                    axpy(simd, alpha, x_head, y_head, zero);
+                    for (x, y) in x_body.into_ref_iter().zip(y_body.into_mut_iter()) {
+                        axpy(simd, alpha, x, y, zero);
+                    }
+                    axpy(simd, alpha, x_tail, y_tail, zero);
+                }
+                None => {
+                    scal(simd, alpha, y_head, zero);
+                    for y in y_body.into_mut_iter() {
+                        scal(simd, alpha, y, zero);
+                    }
+                    scal(simd, alpha, y_tail, zero);
+                }
+            }
+        }
+    }
+
+    E::Simd::default().dispatch(Impl { alpha, x, y });
+}
+
+/// Computes `y = alpha * x + y`.
+///
+/// # Panics
+/// Panics if `x.nrows() != y.nrows()`.
+///
+/// # Example
+/// ```
+/// use faer::{col, linalg::blas1::axpy};
+///
+/// let x = col![1.0, 2.0, 3.0];
+/// let mut y = col![1.0, 1.0, 1.0];
+/// axpy(2.0, x.as_ref(), y.as_mut());
+/// assert_eq!(y, col![3.0, 5.0, 7.0]);
+/// ```
+#[track_caller]
+pub fn axpy<E: ComplexField>(alpha: E, x: ColRef<'_, E>, y: ColMut<'_, E>) {
+    assert!(x.nrows() == y.nrows());
+    if x.row_stride() == 1 && y.row_stride() == 1 {
+        axpy_or_scal_contiguous(
+            alpha,
+            Some(SliceGroup::new(x.try_as_slice().unwrap())),
+            SliceGroupMut::new(y.try_as_slice_mut().unwrap()),
+        );
+    } else {
+        zipped_rw!(y, x)
+            .for_each(|unzipped!(mut y, x)| y.write(alpha.faer_mul(x.read()).faer_add(y.read())));
+    }
+}
+
+/// Computes `x = alpha * x`.
+///
+/// # Example
+/// ```
+/// use faer::{col, linalg::blas1::scal};
+///
+/// let mut x = col![1.0, 2.0, 3.0];
+/// scal(-2.0, x.as_mut());
+/// assert_eq!(x, col![-2.0, -4.0, -6.0]);
+/// ```
+pub fn scal<E: ComplexField>(alpha: E, x: ColMut<'_, E>) {
+    if x.row_stride() == 1 {
+        axpy_or_scal_contiguous(
+            alpha,
+            None,
+            SliceGroupMut::new(x.try_as_slice_mut().unwrap()),
+        );
+    } else {
+        zipped_rw!(x).for_each(|unzipped!(mut x)| x.write(alpha.faer_mul(x.read())));
+    }
+}
+
+/// Copies the elements of `x` to `y`.
+///
+/// # Panics
+/// Panics if `x.nrows() != y.nrows()`.
+#[track_caller]
+pub fn copy<E: ComplexField>(x: ColRef<'_, E>, y: ColMut<'_, E>) {
+    let mut y = y;
+    y.copy_from(x);
+}
+
+/// Returns the inner product $x^H y$, i.e., the sum of the products of the conjugated elements of
+/// `x` with the elements of `y`. For real vectors, this is the usual dot product.
+///
+/// # Panics
+/// Panics if `x.nrows() != y.nrows()`.
+///
+/// # Example
+/// ```
+/// use faer::{col, complex_native::c64, linalg::blas1::dot};
+///
+/// let x = col![c64::new(0.0, 1.0), c64::new(2.0, 0.0)];
+/// let y = col![c64::new(0.0, 1.0), c64::new(1.0, 1.0)];
+/// assert_eq!(dot(x.as_ref(), y.as_ref()), c64::new(3.0, 2.0));
+/// ```
+#[track_caller]
+pub fn dot<E: ComplexField>(x: ColRef<'_, E>, y: ColRef<'_, E>) -> E {
+    assert!(x.nrows() == y.nrows());
+    inner_prod_with_conj(x, Conj::Yes, y, Conj::No)
+}
+
+/// Returns the euclidean norm of `x`.
+///
+/// The computation is scaled to avoid overflow and underflow of the intermediate sum of squares.
+pub fn nrm2<E: ComplexField>(x: ColRef<'_, E>) -> E::Real {
+    x.norm_l2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Col, Mat};
+
+    fn close<E: ComplexField<Real = f64>>(a: E, b: E) -> bool {
+        a.faer_sub(b).faer_abs() < 1e-12
+    }
+
+    fn check<E: ComplexField<Real = f64>>(alpha: E, random: impl Fn() -> E) {
+        for n in [0, 1, 3, 4, 7, 16, 33] {
+            let x = Col::from_fn(n, |_| random());
+            let y = Col::from_fn(n, |_| random());
+            // the rows of a column-major matrix are strided
+            let strided = Mat::from_fn(2, n, |_, j| y.read(j));
+
+            let expected = Col::from_fn(n, |i| alpha.faer_mul(x.read(i)).faer_add(y.read(i)));
+            let mut z = y.clone();
+            axpy(alpha, x.as_ref(), z.as_mut());
+            let mut w = strided.clone();
+            axpy(alpha, x.as_ref(), w.as_mut().row_mut(1).transpose_mut());
+            for i in 0..n {
+                assert!(close(z.read(i), expected.read(i)));
+                assert!(close(w.read(1, i), expected.read(i)));
+                assert!(w.read(0, i) == y.read(i));
+            }
+
+            let expected = Col::from_fn(n, |i| alpha.faer_mul(y.read(i)));
+            let mut z = y.clone();
+            scal(alpha, z.as_mut());
+            let mut w = strided.clone();
+            scal(alpha, w.as_mut().row_mut(0).transpose_mut());
+            for i in 0..n {
+                assert!(close(z.read(i), expected.read(i)));
+                assert!(close(w.read(0, i), expected.read(i)));
+            }
+
+            let mut z = Col::<E>::zeros(n);
+            copy(x.as_ref(), z.as_mut());
+            assert!(z == x);
+
+            let mut expected = E::faer_zero();
+            for i in 0..n {
+                expected = expected.faer_add(x.read(i).faer_conj().faer_mul(y.read(i)));
+            }
+            assert!(close(dot(x.as_ref(), y.as_ref()), expected));
+            assert!(close(dot(x.as_ref(), strided.row(1).transpose()), expected));
+
+            let nrm = dot(x.as_ref(), x.as_ref()).faer_real().sqrt();
+            assert!((nrm2(x.as_ref()) - nrm).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_blas1() {
+        check(2.5, rand::random::<f64>);
+        check(c64::new(0.5, -1.5), || {
+            c64::new(rand::random(), rand::random())
+        });
+    }
+
+    #[test]
+    fn test_nrm2_no_overflow() {
+        let x = Col::<f64>::from_fn(4, |_| 1e300);
+        assert!((nrm2(x.as_ref()) - 2e300).abs() < 1e286);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axpy_mismatched_dimensions() {
+        let x = Col::<f64>::zeros(3);
+        let mut y = Col::<f64>::zeros(4);
+        axpy(1.0, x.as_ref(), y.as_mut());
+    }
+}
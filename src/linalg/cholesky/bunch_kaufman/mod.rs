@@ -755,6 +755,10 @@ pub mod compute {
     /// The diagonal of the block diagonal matrix is stored on the diagonal
     /// of `matrix`, while the subdiagonal elements of the blocks are stored in `subdiag`.
     ///
+    /// The input matrix is interpreted as Hermitian with the values being extracted from the lower
+    /// part. The values in the strictly upper triangular part are never read, and may be
+    /// arbitrary (e.g. NaN). They are clobbered and may be filled with garbage values.
+    ///
     /// # Panics
     ///
     /// Panics if the input matrix is not square.
@@ -794,6 +798,7 @@ pub mod compute {
 
         let _ = parallelism;
         let mut matrix = matrix;
+        crate::linalg::debug_poison_strictly_upper(matrix.rb_mut());
 
         let alpha = E::Real::faer_one()
             .faer_add(E::Real::faer_from_f64(17.0).faer_sqrt())
@@ -1502,7 +1502,8 @@ impl<E: ComplexField> Default for LdltRegularization<'_, E> {
 /// The result is stored back in the same matrix.
 ///
 /// The input matrix is interpreted as Hermitian with the values being extracted from the lower
-/// part, but the entire matrix is required to be initialized.
+/// part, but the entire matrix is required to be initialized. The values in the strictly upper
+/// triangular part are never read, and may be arbitrary (e.g. NaN).
 ///
 /// The matrix $L$ is stored in the strictly lower triangular part of the input matrix, and the
 /// diagonal elements of $D$ are stored on the diagonal.
@@ -1542,6 +1543,9 @@ pub fn raw_cholesky_in_place<E: ComplexField>(
         }
     }
 
+    let mut matrix = matrix;
+    crate::linalg::debug_poison_strictly_upper(matrix.rb_mut());
+
     let mut count = 0;
     cholesky_in_place_impl(
         &mut count,
@@ -1,4 +1,4 @@
-use super::CholeskyError;
+use super::{CholeskyError, CholeskyStats};
 use crate::{
     assert, debug_assert,
     linalg::{
@@ -8,7 +8,7 @@ use crate::{
         triangular_solve,
     },
     utils::DivCeil,
-    ComplexField, Entity, MatMut, Parallelism,
+    ComplexField, Entity, MatMut, MatRef, Parallelism,
 };
 use core::marker::PhantomData;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
@@ -35,9 +35,18 @@ fn cholesky_in_place_left_looking_impl<E: ComplexField>(
 }
 
 /// LLT factorization tuning parameters.
-#[derive(Default, Copy, Clone)]
+#[derive(Default, Copy, Clone, Debug)]
 #[non_exhaustive]
-pub struct LltParams {}
+pub struct LltParams {
+    /// Dimension at or below which the recursion stops and the unblocked kernel is used. `None`
+    /// to automatically determine this threshold.
+    ///
+    /// The unblocked kernel keeps the matrix in registers, so this value is capped by the size
+    /// that fits, e.g. 16 for `f64` with AVX2. Only smaller values have an effect in that case.
+    ///
+    /// See [`BlockingParams`](crate::BlockingParams) for how to choose this value.
+    pub blocksize: Option<usize>,
+}
 
 /// Dynamic LLT regularization.
 /// Values below `epsilon` in absolute value, or with a negative sign are set to `delta` with
@@ -83,7 +92,9 @@ fn cholesky_in_place_impl<E: ComplexField>(
     stack: &mut PodStack,
     params: LltParams,
 ) -> Result<(), CholeskyError> {
-    // right looking cholesky
+    // recursive right looking cholesky: factorize the leading half, update the trailing half with
+    // a triangular solve and a hermitian rank-k update, then recurse on the trailing half. the
+    // recursion bottoms out in the unblocked kernel once the matrix fits in a few registers
 
     debug_assert!(matrix.nrows() == matrix.ncols());
     let mut matrix = matrix;
@@ -105,7 +116,10 @@ fn cholesky_in_place_impl<E: ComplexField>(
     let stride = matrix.nrows().msrv_div_ceil(lanes);
 
     let n = matrix.nrows();
-    if stride <= 4 && n <= 64 {
+    let leaf_dim = params
+        .blocksize
+        .map_or(64, |blocksize| Ord::max(blocksize, 1));
+    if stride <= 4 && n <= leaf_dim {
         *count += cholesky_in_place_left_looking_impl(
             offset,
             matrix,
@@ -176,7 +190,8 @@ pub struct LltInfo {
 /// matrix is not positive definite.
 ///
 /// The input matrix is interpreted as Hermitian with the values being extracted from the lower
-/// part, but the entire matrix is required to be initialized.
+/// part, but the entire matrix is required to be initialized. The values in the strictly upper
+/// triangular part are never read, and may be arbitrary (e.g. NaN).
 ///
 /// The strictly upper triangular part of the matrix is clobbered and may be filled with garbage
 /// values.
@@ -207,6 +222,9 @@ pub fn cholesky_in_place<E: ComplexField>(
         }
     }
 
+    let mut matrix = matrix;
+    crate::linalg::debug_poison_strictly_upper(matrix.rb_mut());
+
     let mut count = 0;
     cholesky_in_place_impl(
         0,
@@ -221,3 +239,41 @@ pub fn cholesky_in_place<E: ComplexField>(
         dynamic_regularization_count: count,
     })
 }
+
+/// Computes the pivot statistics of the LLT factorization, given the Cholesky factor $L$ (as
+/// computed by [`cholesky_in_place`]).
+///
+/// Only the diagonal of `cholesky_factor` is accessed. If the matrix is empty, both the minimum
+/// and the maximum pivot are set to zero.
+///
+/// # Panics
+///
+/// Panics if `cholesky_factor` is not square.
+#[track_caller]
+pub fn cholesky_stats<E: ComplexField>(cholesky_factor: MatRef<'_, E>) -> CholeskyStats<E::Real> {
+    assert!(cholesky_factor.nrows() == cholesky_factor.ncols());
+    let n = cholesky_factor.nrows();
+    if n == 0 {
+        return CholeskyStats {
+            min_pivot: E::Real::faer_zero(),
+            max_pivot: E::Real::faer_zero(),
+        };
+    }
+
+    let pivot = |i: usize| cholesky_factor.read(i, i).faer_abs2();
+    let mut min_pivot = pivot(0);
+    let mut max_pivot = min_pivot;
+    for i in 1..n {
+        let p = pivot(i);
+        if p < min_pivot {
+            min_pivot = p;
+        }
+        if p > max_pivot {
+            max_pivot = p;
+        }
+    }
+    CholeskyStats {
+        min_pivot,
+        max_pivot,
+    }
+}
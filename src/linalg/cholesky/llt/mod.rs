@@ -31,6 +31,29 @@ impl core::fmt::Display for CholeskyError {
 
 impl core::error::Error for CholeskyError {}
 
+/// Pivot statistics of the LLT decomposition, which can be used to detect matrices that are
+/// numerically positive definite but nearly singular.
+///
+/// The pivots are the squared diagonal entries of the Cholesky factor $L$. For a positive definite
+/// matrix, each pivot lies between the smallest and the largest eigenvalue, so the ratio
+/// `max_pivot / min_pivot` is a lower bound on the condition number of the matrix.
+#[derive(Debug, Clone, Copy)]
+pub struct CholeskyStats<R> {
+    /// The smallest pivot encountered during the factorization.
+    pub min_pivot: R,
+    /// The largest pivot encountered during the factorization.
+    pub max_pivot: R,
+}
+
+impl<R: crate::RealField> CholeskyStats<R> {
+    /// Returns the ratio `max_pivot / min_pivot`, which is a lower bound on the condition number
+    /// of the factorized matrix.
+    #[inline]
+    pub fn pivot_ratio(&self) -> R {
+        self.max_pivot.faer_div(self.min_pivot)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{compute::*, inverse::*, reconstruct::*, solve::*, update::*};
@@ -6,10 +6,7 @@ use core::cmp::Ordering;
 pub mod bunch_kaufman;
 pub mod ldlt_diagonal;
 pub mod llt;
-
-#[cfg(feature = "unstable")]
-#[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
-pub(crate) mod piv_llt;
+pub mod piv_llt;
 
 /// Computes a permutation that reduces the chance of numerical errors during the $LDL^H$
 /// factorization with diagonal $D$, then stores the result in `perm_indices` and
@@ -1,3 +1,12 @@
+//! The pivoted Cholesky decomposition of a Hermitian positive semidefinite matrix $A$ is such that:
+//! $$PAP^\top = LL^H,$$
+//! where $P$ is a permutation matrix, and $L$ is a lower triangular matrix.
+//!
+//! At each step, the largest remaining diagonal element is chosen as the pivot, and the
+//! factorization stops once it drops below a tolerance. The number of completed steps is the
+//! numerical rank $r$ of $A$, and the first $r$ columns of $L$ form a low-rank approximation
+//! $PAP^\top \approx L_{:, :r} L_{:, :r}^H$.
+
 use crate::{
     linalg::{
         cholesky::llt::CholeskyError, matmul::triangular::BlockStructure, temp_mat_req,
@@ -10,13 +19,16 @@ use crate::{
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use reborrow::*;
 
+/// Computing the decomposition.
 pub mod compute {
     use super::*;
     use equator::assert;
 
+    /// Tuning parameters for the decomposition.
     #[derive(Copy, Clone, Debug)]
     #[non_exhaustive]
     pub struct PivLltParams {
+        /// Block size of the algorithm.
         pub blocksize: usize,
     }
 
@@ -27,12 +39,16 @@ pub mod compute {
         }
     }
 
+    /// Information about the resulting decomposition.
     #[derive(Copy, Clone, Debug)]
     #[non_exhaustive]
     pub struct PivLltInfo {
+        /// Numerical rank of the matrix, i.e. the number of completed steps of the decomposition.
         pub rank: usize,
     }
 
+    /// Computes the size and alignment of required workspace for performing a pivoted Cholesky
+    /// decomposition.
     #[inline]
     pub fn cholesky_in_place_req<I: Index, E: ComplexField>(
         dim: usize,
@@ -42,22 +58,46 @@ pub mod compute {
         temp_mat_req::<E::Real>(dim, 2)
     }
 
+    /// Computes the pivoted Cholesky factor $L$ of the positive semidefinite matrix `a`, as well
+    /// as the permutation $P$, and stores $L$ in the lower triangular half of `a`. Only the lower
+    /// triangular half of `a` is accessed.
+    ///
+    /// The decomposition stops as soon as the largest remaining pivot is smaller than or equal to
+    /// `tolerance`, or to $n \varepsilon \max_i a_{ii}$ if `tolerance` is `None`. The number of
+    /// completed steps is returned as the numerical rank $r$, and only the first $r$ columns of the
+    /// lower triangular half of `a` contain the factor $L$. The remaining columns contain
+    /// unspecified values.
+    ///
+    /// # Errors
+    /// Returns an error if the diagonal of `a` contains a negative value or NaN, in which case the
+    /// matrix cannot be positive semidefinite.
+    ///
+    /// # Panics
+    /// - Panics if `a` is not a square matrix.
+    /// - Panics if `perm` and `perm_inv` don't have the same length as the dimension of `a`.
+    /// - Panics if the provided memory in `stack` is insufficient (see [`cholesky_in_place_req`]).
     #[track_caller]
     pub fn cholesky_in_place<'out, I: Index, E: ComplexField>(
         a: MatMut<'_, E>,
         perm: &'out mut [I],
         perm_inv: &'out mut [I],
+        tolerance: Option<E::Real>,
         parallelism: Parallelism,
         stack: &mut PodStack,
         params: PivLltParams,
     ) -> Result<(PivLltInfo, PermRef<'out, I>), CholeskyError> {
         assert!(a.nrows() == a.ncols());
         let n = a.nrows();
-        assert!(n <= I::Signed::MAX.zx());
+        assert!(all(
+            n <= I::Signed::MAX.zx(),
+            perm.len() == n,
+            perm_inv.len() == n,
+        ));
         let mut rank = n;
         'exit: {
             if n > 0 {
                 let mut a = a;
+                crate::linalg::debug_poison_strictly_upper(a.rb_mut());
                 for (i, p) in perm.iter_mut().enumerate() {
                     *p = I::truncate(i);
                 }
@@ -83,9 +123,11 @@ pub mod compute {
                     }
                 }
 
-                let tol = E::Real::faer_epsilon()
-                    .faer_mul(E::Real::faer_from_f64(n as f64))
-                    .faer_mul(ajj);
+                let tol = tolerance.unwrap_or_else(|| {
+                    E::Real::faer_epsilon()
+                        .faer_mul(E::Real::faer_from_f64(n as f64))
+                        .faer_mul(ajj)
+                });
 
                 let mut k = 0usize;
                 while k < n {
@@ -128,11 +170,11 @@ pub mod compute {
                                     ajj = aii;
                                 }
                             }
-                            if ajj < tol {
-                                rank = j;
-                                a.write(j, j, E::faer_from_real(ajj));
-                                break 'exit;
-                            }
+                        }
+                        if ajj <= tol {
+                            rank = j;
+                            a.write(j, j, E::faer_from_real(ajj));
+                            break 'exit;
                         }
 
                         if pvt != j {
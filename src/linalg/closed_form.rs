@@ -0,0 +1,175 @@
+//! Closed form expressions for the determinant, adjugate, and inverse of small matrices, computed
+//! with cofactor expansions.
+
+use crate::{assert, ComplexField, Conj, Conjugate, Mat, MatMut, MatRef};
+use reborrow::*;
+
+/// Largest dimension handled by the closed form expressions.
+pub(crate) const MAX_DIM: usize = 4;
+
+// laplace expansion along the first selected row
+fn minor<E: ComplexField>(mat: MatRef<'_, E>, rows: &[usize], cols: &[usize]) -> E {
+    let n = rows.len();
+    if n == 0 {
+        return E::faer_one();
+    }
+    if n == 1 {
+        return mat.read(rows[0], cols[0]);
+    }
+    if n == 2 {
+        return mat
+            .read(rows[0], cols[0])
+            .faer_mul(mat.read(rows[1], cols[1]))
+            .faer_sub(
+                mat.read(rows[0], cols[1])
+                    .faer_mul(mat.read(rows[1], cols[0])),
+            );
+    }
+
+    let mut sub_cols = [0usize; MAX_DIM];
+    let mut det = E::faer_zero();
+    for t in 0..n {
+        let mut k = 0;
+        for (s, &col) in cols.iter().enumerate() {
+            if s != t {
+                sub_cols[k] = col;
+                k += 1;
+            }
+        }
+        let term = mat
+            .read(rows[0], cols[t])
+            .faer_mul(minor(mat, &rows[1..], &sub_cols[..n - 1]));
+        det = if t % 2 == 0 {
+            det.faer_add(term)
+        } else {
+            det.faer_sub(term)
+        };
+    }
+    det
+}
+
+/// Computes the determinant of `mat`.
+#[track_caller]
+pub(crate) fn determinant<E: ComplexField>(mat: MatRef<'_, E>) -> E {
+    let n = mat.nrows();
+    assert!(all(mat.ncols() == n, n <= MAX_DIM));
+    let idx = [0, 1, 2, 3];
+    minor(mat, &idx[..n], &idx[..n])
+}
+
+/// Computes the adjugate of `mat`, and stores it in `dst`.
+#[track_caller]
+pub(crate) fn adjugate<E: ComplexField>(dst: MatMut<'_, E>, mat: MatRef<'_, E>) {
+    let mut dst = dst;
+    let n = mat.nrows();
+    assert!(all(
+        mat.ncols() == n,
+        n <= MAX_DIM,
+        dst.nrows() == n,
+        dst.ncols() == n,
+    ));
+
+    if n == 1 {
+        dst.write(0, 0, E::faer_one());
+        return;
+    }
+
+    let mut rows = [0usize; MAX_DIM];
+    let mut cols = [0usize; MAX_DIM];
+    for i in 0..n {
+        let mut k = 0;
+        for r in 0..n {
+            if r != i {
+                rows[k] = r;
+                k += 1;
+            }
+        }
+        for j in 0..n {
+            let mut k = 0;
+            for c in 0..n {
+                if c != j {
+                    cols[k] = c;
+                    k += 1;
+                }
+            }
+            let cofactor = minor(mat, &rows[..n - 1], &cols[..n - 1]);
+            dst.write(
+                j,
+                i,
+                if (i + j) % 2 == 0 {
+                    cofactor
+                } else {
+                    cofactor.faer_neg()
+                },
+            );
+        }
+    }
+}
+
+/// Computes the inverse of `mat` from its adjugate, and stores it in `dst`. Returns the
+/// determinant of `mat`.
+///
+/// If the determinant is zero, the result contains non finite values.
+#[track_caller]
+pub(crate) fn inverse<E: ComplexField>(dst: MatMut<'_, E>, mat: MatRef<'_, E>) -> E {
+    let mut dst = dst;
+    adjugate(dst.rb_mut(), mat);
+
+    let n = mat.nrows();
+    let mut det = E::faer_zero();
+    for j in 0..n {
+        det = det.faer_add(mat.read(0, j).faer_mul(dst.read(j, 0)));
+    }
+    if n == 0 {
+        det = E::faer_one();
+    }
+
+    let det_inv = det.faer_inv();
+    for j in 0..n {
+        for i in 0..n {
+            dst.write(i, j, dst.read(i, j).faer_mul(det_inv));
+        }
+    }
+    det
+}
+
+/// Computes the determinant of `mat`, which may be conjugated.
+#[track_caller]
+pub(crate) fn determinant_with_conj<E: Conjugate>(mat: MatRef<'_, E>) -> E::Canonical
+where
+    E::Canonical: ComplexField,
+{
+    let (mat, conj) = mat.canonicalize();
+    let det = determinant(mat);
+    if conj == Conj::Yes {
+        det.faer_conj()
+    } else {
+        det
+    }
+}
+
+/// Computes the adjugate of `mat`, which may be conjugated.
+#[track_caller]
+pub(crate) fn adjugate_with_conj<E: Conjugate>(mat: MatRef<'_, E>) -> Mat<E::Canonical>
+where
+    E::Canonical: ComplexField,
+{
+    let mat = mat.to_owned();
+    let mut adj = Mat::zeros(mat.nrows(), mat.ncols());
+    adjugate(adj.as_mut(), mat.as_ref());
+    adj
+}
+
+/// Computes the inverse and the determinant of `mat`, which may be conjugated.
+#[track_caller]
+pub(crate) fn inverse_with_conj<E: Conjugate>(
+    mat: MatRef<'_, E>,
+) -> (Mat<E::Canonical>, E::Canonical)
+where
+    E::Canonical: ComplexField,
+{
+    let mat = mat.to_owned();
+    let mut inv = Mat::zeros(mat.nrows(), mat.ncols());
+    let det = inverse(inv.as_mut(), mat.as_ref());
+    (inv, det)
+}
@@ -0,0 +1,294 @@
+//! The Cosine-Sine (CS) decomposition of a matrix with orthonormal columns, partitioned into two
+//! row blocks.
+//!
+//! If $Q = \begin{bmatrix} Q_1 \\ Q_2 \end{bmatrix}$ has orthonormal columns, with $Q_1$ of size
+//! $m_1 \times p$ and $Q_2$ of size $m_2 \times p$, then there exist unitary matrices $U_1$, $U_2$
+//! and $V$ such that
+//! $$Q_1 = U_1 C V^H, \quad Q_2 = U_2 S V^H,$$
+//! where $C$ and $S$ are rectangular diagonal matrices holding respectively the cosines and sines
+//! of the principal angles between the column space of $Q$ and the subspace spanned by its first
+//! $m_1$ coordinates. In other words, the same right factor $V$ simultaneously diagonalizes both
+//! blocks, and their singular values satisfy $c_i^2 + s_i^2 = 1$.
+
+use crate::{
+    assert,
+    linalg::solvers::{Qr, Svd},
+    Col, ColRef, ComplexField, Mat, MatMut, MatRef,
+};
+use reborrow::*;
+
+/// Cosine-Sine decomposition of a matrix with orthonormal columns, partitioned into two row
+/// blocks.
+///
+/// The cosines are in nonincreasing order, and the sines in nondecreasing order, up to rounding
+/// errors. For an input with $p$ columns and blocks with $m_1$ and $m_2$ rows:
+/// - $C$ is the $m_1 \times p$ matrix whose only nonzero entries are $C_{i,i} = c_i$, for
+///   $i < \min(m_1, p)$, and $c_i = 0$ for $i \geq m_1$,
+/// - $S$ is the $m_2 \times p$ matrix whose only nonzero entries are $S_{i - d, i} = s_i$, for
+///   $i \geq d$, where $d = p - \min(m_2, p)$, and $s_i = 0$ for $i < d$.
+#[derive(Debug)]
+pub struct Csd<E: ComplexField> {
+    u1: Mat<E>,
+    u2: Mat<E>,
+    v: Mat<E>,
+    cos: Col<E::Real>,
+    sin: Col<E::Real>,
+}
+
+/// Multiplies the columns of `u` by the phases of the diagonal elements of `r`, so that
+/// $U^H Q V$ has a real nonnegative diagonal, and returns their absolute values.
+fn absorb_phases<E: ComplexField>(
+    u: MatMut<'_, E>,
+    r: MatRef<'_, E>,
+    columns: impl Iterator<Item = usize>,
+) -> alloc::vec::Vec<E::Real> {
+    let mut u = u;
+    let mut abs = alloc::vec::Vec::new();
+    for (j, col) in columns.enumerate() {
+        let rjj = r.read(j, j);
+        let rjj_abs = rjj.faer_abs();
+        if rjj_abs != E::Real::faer_zero() {
+            let phase = rjj.faer_scale_real(rjj_abs.faer_inv());
+            let mut u = u.rb_mut().col_mut(col);
+            for i in 0..u.nrows() {
+                u.write(i, u.read(i).faer_mul(phase));
+            }
+        }
+        abs.push(rjj_abs);
+    }
+    abs
+}
+
+impl<E: ComplexField> Csd<E> {
+    /// Computes the CS decomposition of `q`, whose first `m1` rows form $Q_1$ and remaining rows
+    /// form $Q_2$.
+    ///
+    /// The right factor is first taken from the SVD of $Q_1$, which determines accurately the
+    /// directions whose sines are large. The directions whose sines are small are then refined
+    /// with the SVD of the corresponding columns of $Q_2 V$. Finally, $U_1$ and $U_2$ are obtained
+    /// from the QR decompositions of $Q_1 V$ and $Q_2 V$, whose columns are orthogonal, and the
+    /// cosines and sines are the norms of these columns.
+    ///
+    /// The columns of `q` are assumed to be orthonormal, this is not checked.
+    ///
+    /// # Panics
+    /// Panics if `m1 > q.nrows()`, or if `q.ncols() > q.nrows()`.
+    #[track_caller]
+    pub fn new(q: MatRef<'_, E>, m1: usize) -> Self {
+        let (m, p) = q.shape();
+        assert!(all(m1 <= m, p <= m));
+        let m2 = m - m1;
+        let q1 = q.subrows(0, m1);
+        let q2 = q.subrows(m1, m2);
+
+        // columns whose cosine is larger than 1/sqrt(2) have a small sine, which is poorly
+        // determined by the SVD of the first block
+        let half = E::Real::faer_from_f64(0.5);
+        let (mut v, k) = if m1 == 0 || p == 0 {
+            (Mat::<E>::identity(p, p), 0)
+        } else {
+            let svd = Svd::new(q1);
+            let s = svd.s_diagonal();
+            let k = (0..s.nrows())
+                .take_while(|&j| s.read(j).faer_real().faer_abs2() > half)
+                .count();
+            (svd.v().to_owned(), k)
+        };
+
+        if k > 0 && m2 > 0 {
+            let w = q2 * v.as_ref().subcols(0, k);
+            let svd = Svd::new(w.as_ref());
+            // reverse the order so that the sines are nondecreasing
+            let rot = Mat::<E>::from_fn(k, k, |i, j| svd.v().read(i, k - 1 - j));
+            let vk = v.as_ref().subcols(0, k) * &rot;
+            v.subcols_mut(0, k).copy_from(&vk);
+        }
+
+        let mut cos = Col::<E::Real>::zeros(p);
+        let mut sin = Col::<E::Real>::zeros(p);
+
+        let r1 = Ord::min(m1, p);
+        let u1 = if r1 == 0 {
+            Mat::<E>::identity(m1, m1)
+        } else {
+            let p1 = q1 * v.as_ref().subcols(0, r1);
+            let qr = Qr::new(p1.as_ref());
+            let mut u1 = qr.compute_q();
+            let r = qr.compute_thin_r();
+            for (j, c) in absorb_phases(u1.as_mut(), r.as_ref(), 0..r1)
+                .into_iter()
+                .enumerate()
+            {
+                cos.write(j, c);
+            }
+            u1
+        };
+
+        // the largest sines are last, so the columns are processed in reverse order to keep the
+        // directions with small sines from polluting the others
+        let r2 = Ord::min(m2, p);
+        let d = p - r2;
+        let u2 = if r2 == 0 {
+            Mat::<E>::identity(m2, m2)
+        } else {
+            let p2 = q2 * v.as_ref().subcols(d, r2);
+            let p2_rev = Mat::<E>::from_fn(m2, r2, |i, j| p2.read(i, r2 - 1 - j));
+            let qr = Qr::new(p2_rev.as_ref());
+            let u2_rev = qr.compute_q();
+            let r = qr.compute_thin_r();
+            let mut u2 = Mat::<E>::from_fn(m2, m2, |i, j| {
+                if j < r2 {
+                    u2_rev.read(i, r2 - 1 - j)
+                } else {
+                    u2_rev.read(i, j)
+                }
+            });
+            for (j, s) in absorb_phases(u2.as_mut(), r.as_ref(), (0..r2).rev())
+                .into_iter()
+                .enumerate()
+            {
+                sin.write(p - 1 - j, s);
+            }
+            u2
+        };
+
+        Self {
+            u1,
+            u2,
+            v,
+            cos,
+            sin,
+        }
+    }
+
+    /// Returns the unitary factor $U_1$ of the first block.
+    pub fn u1(&self) -> MatRef<'_, E> {
+        self.u1.as_ref()
+    }
+    /// Returns the unitary factor $U_2$ of the second block.
+    pub fn u2(&self) -> MatRef<'_, E> {
+        self.u2.as_ref()
+    }
+    /// Returns the unitary factor $V$ shared by both blocks.
+    pub fn v(&self) -> MatRef<'_, E> {
+        self.v.as_ref()
+    }
+    /// Returns the cosines, i.e. the singular values of the first block, in nonincreasing order.
+    pub fn cos(&self) -> ColRef<'_, E::Real> {
+        self.cos.as_ref()
+    }
+    /// Returns the sines, i.e. the singular values of the second block, in nondecreasing order.
+    pub fn sin(&self) -> ColRef<'_, E::Real> {
+        self.sin.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+
+    fn check<E: ComplexField<Real = f64>>(q: MatRef<'_, E>, m1: usize) {
+        let (m, p) = q.shape();
+        let m2 = m - m1;
+        let csd = Csd::new(q, m1);
+        let d = p - Ord::min(m2, p);
+
+        let c = Mat::<E>::from_fn(m1, p, |i, j| {
+            if i == j {
+                E::faer_from_real(csd.cos().read(j))
+            } else {
+                E::faer_zero()
+            }
+        });
+        let s = Mat::<E>::from_fn(m2, p, |i, j| {
+            if j >= d && i == j - d {
+                E::faer_from_real(csd.sin().read(j))
+            } else {
+                E::faer_zero()
+            }
+        });
+
+        let tol = 1e-13;
+        let q1 = csd.u1() * &c * csd.v().adjoint();
+        let q2 = csd.u2() * &s * csd.v().adjoint();
+        assert!((&q1 - q.subrows(0, m1)).norm_max() < tol);
+        assert!((&q2 - q.subrows(m1, m2)).norm_max() < tol);
+
+        for u in [csd.u1(), csd.u2(), csd.v()] {
+            let n = u.ncols();
+            assert!((u.adjoint() * u - Mat::<E>::identity(n, n)).norm_max() < tol);
+        }
+        for j in 0..p {
+            let c = csd.cos().read(j);
+            let s = csd.sin().read(j);
+            assert!((c * c + s * s - 1.0).abs() < tol);
+            if j >= m1 {
+                assert!(c == 0.0);
+            }
+            if j < d {
+                assert!(s == 0.0);
+            }
+            if j > 0 {
+                assert!(c <= csd.cos().read(j - 1) + tol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_csd() {
+        for (m1, m2, p) in [
+            (4, 4, 3),
+            (6, 3, 2),
+            (3, 6, 3),
+            (5, 5, 10),
+            (7, 3, 8),
+            (2, 8, 6),
+            (0, 4, 2),
+            (4, 0, 2),
+            (3, 3, 0),
+            (20, 15, 12),
+        ] {
+            let a = Mat::<f64>::from_fn(m1 + m2, p, |_, _| rand::random());
+            let q = a.qr().compute_thin_q();
+            check(q.as_ref(), m1);
+
+            let a =
+                Mat::<c64>::from_fn(m1 + m2, p, |_, _| c64::new(rand::random(), rand::random()));
+            let q = a.qr().compute_thin_q();
+            check(q.as_ref(), m1);
+        }
+    }
+
+    #[test]
+    fn test_csd_small_angles() {
+        let (m1, m2, p) = (6, 5, 4);
+        let angles = [0.0f64, 1e-12, 1e-6, 0.3];
+
+        let u1 = Mat::<f64>::from_fn(m1, m1, |_, _| rand::random())
+            .qr()
+            .compute_q();
+        let u2 = Mat::<f64>::from_fn(m2, m2, |_, _| rand::random())
+            .qr()
+            .compute_q();
+        let v = Mat::<f64>::from_fn(p, p, |_, _| rand::random())
+            .qr()
+            .compute_q();
+
+        let c = Mat::<f64>::from_fn(m1, p, |i, j| if i == j { angles[j].cos() } else { 0.0 });
+        let s = Mat::<f64>::from_fn(m2, p, |i, j| if i == j { angles[j].sin() } else { 0.0 });
+        let mut q = Mat::<f64>::zeros(m1 + m2, p);
+        q.as_mut()
+            .subrows_mut(0, m1)
+            .copy_from(&u1 * &c * v.transpose());
+        q.as_mut()
+            .subrows_mut(m1, m2)
+            .copy_from(&u2 * &s * v.transpose());
+
+        check(q.as_ref(), m1);
+        let csd = Csd::new(q.as_ref(), m1);
+        for j in 0..p {
+            assert!((csd.sin().read(j) - angles[j].sin()).abs() < 1e-14);
+        }
+    }
+}
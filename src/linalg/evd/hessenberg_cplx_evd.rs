@@ -368,6 +368,32 @@ pub fn lahqr<E: ComplexField>(
     ihi: usize,
     epsilon: E::Real,
     zero_threshold: E::Real,
+) -> isize {
+    lahqr_impl(
+        want_t,
+        a,
+        z,
+        w,
+        ilo,
+        ihi,
+        epsilon,
+        zero_threshold,
+        epsilon,
+        default_max_sweeps_per_eigenvalue(),
+    )
+}
+
+fn lahqr_impl<E: ComplexField>(
+    want_t: bool,
+    a: MatMut<'_, E>,
+    z: Option<MatMut<'_, E>>,
+    w: ColMut<'_, E>,
+    ilo: usize,
+    ihi: usize,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+    deflation_tol: E::Real,
+    max_sweeps_per_eigenvalue: usize,
 ) -> isize {
     assert!(a.nrows() == a.ncols());
     assert!(ilo <= ihi);
@@ -389,6 +415,7 @@ pub fn lahqr<E: ComplexField>(
     let zero = E::Real::faer_zero();
     let eps = epsilon;
     let small_num = zero_threshold.faer_div(eps);
+    let tol = deflation_tol;
     let non_convergence_limit = 10;
     let dat1 = E::Real::faer_from_f64(0.75);
     let dat2 = E::Real::faer_from_f64(-0.4375);
@@ -403,8 +430,8 @@ pub fn lahqr<E: ComplexField>(
 
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
-    // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    // by default we set itmax to 30 times nh as a safe limit.
+    let itmax = max_sweeps_per_eigenvalue * Ord::max(10, nh);
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0usize;
@@ -463,7 +490,7 @@ pub fn lahqr<E: ComplexField>(
                 }
             }
 
-            if abs1(a.read(i, i - 1)) <= eps.faer_mul(tst) {
+            if abs1(a.read(i, i - 1)) <= tol.faer_mul(tst) {
                 //
                 // The elementwise deflation test has passed
                 // The following performs second deflation test due
@@ -488,7 +515,7 @@ pub fn lahqr<E: ComplexField>(
                 );
                 let s = aa.faer_add(ab);
                 if ba.faer_mul(ab.faer_div(s))
-                    <= max(small_num, eps.faer_mul(bb.faer_mul(aa.faer_div(s))))
+                    <= max(small_num, tol.faer_mul(bb.faer_mul(aa.faer_div(s))))
                 {
                     // A(i,i-1) is negligible, take i as new istart.
                     a.write(i, i - 1, E::faer_zero());
@@ -650,6 +677,20 @@ pub struct EvdParams {
     /// Threshold of percent of aggressive-early-deflation window that must converge to skip a
     /// sweep
     pub nibble_threshold: Option<usize>,
+    /// Number of QR sweeps allowed per eigenvalue before the iteration gives up
+    pub max_sweeps_per_eigenvalue: Option<usize>,
+    /// Relative tolerance used in the deflation tests, clamped below by the working epsilon
+    pub deflation_tol: Option<f64>,
+}
+
+impl EvdParams {
+    #[inline]
+    pub(crate) fn deflation_tol_or<E: RealField>(&self, epsilon: E) -> E {
+        match self.deflation_tol {
+            Some(tol) => max(epsilon, E::faer_from_f64(tol)),
+            None => epsilon,
+        }
+    }
 }
 
 pub fn default_recommended_shift_count(dim: usize, _active_block_dim: usize) -> usize {
@@ -705,6 +746,10 @@ pub fn default_nibble_threshold() -> usize {
     50
 }
 
+pub fn default_max_sweeps_per_eigenvalue() -> usize {
+    30
+}
+
 fn aggressive_early_deflation<E: ComplexField>(
     want_t: bool,
     mut a: MatMut<'_, E>,
@@ -724,10 +769,10 @@ fn aggressive_early_deflation<E: ComplexField>(
     // Because we will use the lower triangular part of A as workspace,
     // We have a maximum window size
     let nw_max = (n - 3) / 3;
-    let eps = epsilon;
     let small_num = zero_threshold
-        .faer_div(eps)
+        .faer_div(epsilon)
         .faer_mul(E::Real::faer_from_f64(n as f64));
+    let eps = params.deflation_tol_or(epsilon);
 
     // Size of the deflation window
     let jw = Ord::min(Ord::min(nw, ihi - ilo), nw_max);
@@ -796,7 +841,7 @@ fn aggressive_early_deflation<E: ComplexField>(
             .blocking_threshold
             .unwrap_or(default_blocking_threshold())
     {
-        lahqr(
+        lahqr_impl(
             true,
             tw.rb_mut(),
             Some(v.rb_mut()),
@@ -805,6 +850,10 @@ fn aggressive_early_deflation<E: ComplexField>(
             jw,
             epsilon,
             zero_threshold,
+            eps,
+            params
+                .max_sweeps_per_eigenvalue
+                .unwrap_or(default_max_sweeps_per_eigenvalue()),
         )
     } else {
         let infqr = multishift_qr(
@@ -1276,6 +1325,10 @@ pub fn multishift_qr<E: ComplexField>(
     let nibble = params
         .nibble_threshold
         .unwrap_or(default_nibble_threshold());
+    let max_sweeps = params
+        .max_sweeps_per_eigenvalue
+        .unwrap_or(default_max_sweeps_per_eigenvalue());
+    let deflation_tol = params.deflation_tol_or(epsilon);
 
     // Recommended number of shifts
     let nsr = (params
@@ -1293,7 +1346,18 @@ pub fn multishift_qr<E: ComplexField>(
 
     // Tiny matrices must use lahqr
     if n < nmin {
-        let err = lahqr(want_t, a, z, w, ilo, ihi, epsilon, zero_threshold);
+        let err = lahqr_impl(
+            want_t,
+            a,
+            z,
+            w,
+            ilo,
+            ihi,
+            epsilon,
+            zero_threshold,
+            deflation_tol,
+            max_sweeps,
+        );
         return (err, 0, 0);
     }
     if nh == 0 {
@@ -1304,8 +1368,8 @@ pub fn multishift_qr<E: ComplexField>(
 
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
-    // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    // by default we set itmax to 30 times nh as a safe limit.
+    let itmax = max_sweeps * Ord::max(10, nh);
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0;
@@ -1434,7 +1498,7 @@ pub fn multishift_qr<E: ComplexField>(
                 // get more
                 let mut temp = a.rb_mut().submatrix_mut(n - ns, 0, ns, ns);
                 let mut shifts = w.rb_mut().subrows_mut(istop - ns, ns);
-                let ierr = lahqr(
+                let ierr = lahqr_impl(
                     false,
                     temp.rb_mut(),
                     None,
@@ -1443,6 +1507,8 @@ pub fn multishift_qr<E: ComplexField>(
                     ns,
                     epsilon,
                     zero_threshold,
+                    deflation_tol,
+                    max_sweeps,
                 ) as usize;
 
                 ns = ns - ierr;
@@ -1511,7 +1577,7 @@ pub fn multishift_qr<E: ComplexField>(
             shifts.rb_mut(),
             istart,
             istop,
-            epsilon,
+            deflation_tol,
             zero_threshold,
             parallelism,
             stack.rb_mut(),
@@ -2880,6 +2946,8 @@ mod tests {
                     recommended_deflation_window: None,
                     blocking_threshold: Some(15),
                     nibble_threshold: Some(14),
+                    max_sweeps_per_eigenvalue: None,
+                    deflation_tol: None,
                 };
                 multishift_qr(
                     true,
@@ -13141,6 +13209,8 @@ mod tests {
             recommended_deflation_window: None,
             blocking_threshold: Some(15),
             nibble_threshold: Some(14),
+            max_sweeps_per_eigenvalue: None,
+            deflation_tol: None,
         };
         let (_, n_aed, n_sweep) = multishift_qr(
             true,
@@ -13181,6 +13251,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sweep_budget() {
+        let n = 64;
+        let mut h = Mat::<c64>::zeros(n, n);
+        for j in 0..n {
+            for i in 0..n {
+                if i <= j + 1 {
+                    h.write(i, j, c64::new(rand::random(), rand::random()));
+                }
+            }
+        }
+
+        let run = |params: EvdParams| {
+            let mut q = Mat::<c64>::identity(n, n);
+            let mut w = Col::zeros(n);
+            let mut t = h.clone();
+            let (info, _, _) = multishift_qr(
+                true,
+                t.as_mut(),
+                Some(q.as_mut()),
+                w.as_mut(),
+                0,
+                n,
+                f64::EPSILON,
+                f64::MIN_POSITIVE,
+                Parallelism::None,
+                make_stack!(multishift_qr_req::<c64>(
+                    n,
+                    n,
+                    true,
+                    true,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+            for j in 0..n {
+                for i in j + 1..n {
+                    t.write(i, j, c64::faer_zero());
+                }
+            }
+            (info, &q * &t * q.adjoint())
+        };
+
+        // no sweeps allowed: the iteration reports the unconverged block instead of panicking
+        let (info, _) = run(EvdParams {
+            blocking_threshold: Some(15),
+            max_sweeps_per_eigenvalue: Some(0),
+            ..Default::default()
+        });
+        assert!(info > 0);
+
+        let (info, h_reconstructed) = run(EvdParams {
+            blocking_threshold: Some(15),
+            max_sweeps_per_eigenvalue: Some(100),
+            deflation_tol: Some(1e-10),
+            ..Default::default()
+        });
+        assert!(info == 0);
+        assert!((&h_reconstructed - &h).norm_max() < 1e-8);
+    }
+
     #[test]
     fn test_gh_84_cplx_20x20() {
         let a = mat![
@@ -9,8 +9,8 @@ use crate::{
         evd::{
             hessenberg::make_hessenberg_in_place,
             hessenberg_cplx_evd::{
-                default_blocking_threshold, default_nibble_threshold,
-                default_recommended_deflation_window,
+                default_blocking_threshold, default_max_sweeps_per_eigenvalue,
+                default_nibble_threshold, default_recommended_deflation_window,
             },
         },
         householder::{
@@ -1412,10 +1412,10 @@ fn aggressive_early_deflation<E: RealField>(
     // Because we will use the lower triangular part of A as workspace,
     // We have a maximum window size
     let nw_max = (n - 3) / 3;
-    let eps = epsilon;
     let small_num = zero_threshold
-        .faer_div(eps)
+        .faer_div(epsilon)
         .faer_mul(E::Real::faer_from_f64(n as f64));
+    let eps = params.deflation_tol_or(epsilon);
 
     // Size of the deflation window
     let jw = Ord::min(Ord::min(nw, ihi - ilo), nw_max);
@@ -1486,7 +1486,7 @@ fn aggressive_early_deflation<E: RealField>(
             .blocking_threshold
             .unwrap_or(default_blocking_threshold())
     {
-        lahqr(
+        lahqr_impl(
             true,
             tw.rb_mut(),
             Some(v.rb_mut()),
@@ -1496,6 +1496,10 @@ fn aggressive_early_deflation<E: RealField>(
             jw,
             epsilon,
             zero_threshold,
+            eps,
+            params
+                .max_sweeps_per_eigenvalue
+                .unwrap_or(default_max_sweeps_per_eigenvalue()),
         )
     } else {
         let infqr = multishift_qr(
@@ -3208,6 +3212,10 @@ pub fn multishift_qr<E: RealField>(
     let nibble = params
         .nibble_threshold
         .unwrap_or(default_nibble_threshold());
+    let max_sweeps = params
+        .max_sweeps_per_eigenvalue
+        .unwrap_or(default_max_sweeps_per_eigenvalue());
+    let deflation_tol = params.deflation_tol_or(epsilon);
 
     // Recommended number of shifts
     let nsr = (params
@@ -3225,7 +3233,19 @@ pub fn multishift_qr<E: RealField>(
 
     // Tiny matrices must use lahqr
     if n < nmin {
-        let err = lahqr(want_t, a, z, w_re, w_im, ilo, ihi, epsilon, zero_threshold);
+        let err = lahqr_impl(
+            want_t,
+            a,
+            z,
+            w_re,
+            w_im,
+            ilo,
+            ihi,
+            epsilon,
+            zero_threshold,
+            deflation_tol,
+            max_sweeps,
+        );
         return (err, 0, 0);
     }
     if nh == 0 {
@@ -3236,8 +3256,8 @@ pub fn multishift_qr<E: RealField>(
 
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
-    // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    // by default we set itmax to 30 times nh as a safe limit.
+    let itmax = max_sweeps * Ord::max(10, nh);
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0;
@@ -3374,7 +3394,7 @@ pub fn multishift_qr<E: RealField>(
                 let mut temp = a.rb_mut().submatrix_mut(n - ns, 0, ns, ns);
                 let mut shifts_re = w_re.rb_mut().subrows_mut(istop - ns, ns);
                 let mut shifts_im = w_im.rb_mut().subrows_mut(istop - ns, ns);
-                let ierr = lahqr(
+                let ierr = lahqr_impl(
                     false,
                     temp.rb_mut(),
                     None,
@@ -3384,6 +3404,8 @@ pub fn multishift_qr<E: RealField>(
                     ns,
                     epsilon,
                     zero_threshold,
+                    deflation_tol,
+                    max_sweeps,
                 ) as usize;
 
                 ns = ns - ierr;
@@ -3484,7 +3506,7 @@ pub fn multishift_qr<E: RealField>(
             shifts_im.rb_mut(),
             istart,
             istop,
-            epsilon,
+            deflation_tol,
             zero_threshold,
             parallelism,
             stack.rb_mut(),
@@ -3505,6 +3527,34 @@ pub fn lahqr<E: RealField>(
     ihi: usize,
     epsilon: E,
     zero_threshold: E,
+) -> isize {
+    lahqr_impl(
+        want_t,
+        a,
+        z,
+        w_re,
+        w_im,
+        ilo,
+        ihi,
+        epsilon,
+        zero_threshold,
+        epsilon,
+        default_max_sweeps_per_eigenvalue(),
+    )
+}
+
+fn lahqr_impl<E: RealField>(
+    want_t: bool,
+    a: MatMut<'_, E>,
+    z: Option<MatMut<'_, E>>,
+    w_re: ColMut<'_, E>,
+    w_im: ColMut<'_, E>,
+    ilo: usize,
+    ihi: usize,
+    epsilon: E,
+    zero_threshold: E,
+    deflation_tol: E,
+    max_sweeps_per_eigenvalue: usize,
 ) -> isize {
     assert!(a.nrows() == a.ncols());
     assert!(ilo <= ihi);
@@ -3531,6 +3581,7 @@ pub fn lahqr<E: RealField>(
     let one = E::faer_one();
     let eps = epsilon;
     let small_num = zero_threshold.faer_div(eps);
+    let tol = deflation_tol;
     let non_convergence_limit = 10;
     let dat1 = E::faer_from_f64(0.75);
     let dat2 = E::faer_from_f64(-0.4375);
@@ -3546,8 +3597,8 @@ pub fn lahqr<E: RealField>(
 
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
-    // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    // by default we set itmax to 30 times nh as a safe limit.
+    let itmax = max_sweeps_per_eigenvalue * Ord::max(10, nh);
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0usize;
@@ -3614,7 +3665,7 @@ pub fn lahqr<E: RealField>(
                 }
             }
 
-            if a.read(i, i - 1).faer_abs() <= eps.faer_mul(tst) {
+            if a.read(i, i - 1).faer_abs() <= tol.faer_mul(tst) {
                 //
                 // The elementwise deflation test has passed
                 // The following performs second deflation test due
@@ -3639,7 +3690,7 @@ pub fn lahqr<E: RealField>(
                 );
                 let s = aa.faer_add(ab);
                 if ba.faer_mul(ab.faer_div(s))
-                    <= max(small_num, eps.faer_mul(bb.faer_mul(aa.faer_div(s))))
+                    <= max(small_num, tol.faer_mul(bb.faer_mul(aa.faer_div(s))))
                 {
                     // A(i,i-1) is negligible, take i as new istart.
                     a.write(i, i - 1, zero);
@@ -4083,6 +4134,8 @@ mod tests {
                     recommended_deflation_window: None,
                     blocking_threshold: Some(15),
                     nibble_threshold: Some(14),
+                    max_sweeps_per_eigenvalue: None,
+                    deflation_tol: None,
                 };
                 dbgf::dbgf!("6.?", &h);
                 multishift_qr(
@@ -5538,4 +5591,67 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_sweep_budget() {
+        let n = 64;
+        let mut h = Mat::<f64>::zeros(n, n);
+        for j in 0..n {
+            for i in 0..n {
+                if i <= j + 1 {
+                    h.write(i, j, rand::random());
+                }
+            }
+        }
+
+        let run = |params: EvdParams| {
+            let mut q = Mat::<f64>::identity(n, n);
+            let mut w_re = Col::zeros(n);
+            let mut w_im = Col::zeros(n);
+            let mut t = h.clone();
+            let (info, _, _) = multishift_qr(
+                true,
+                t.as_mut(),
+                Some(q.as_mut()),
+                w_re.as_mut(),
+                w_im.as_mut(),
+                0,
+                n,
+                f64::EPSILON,
+                f64::MIN_POSITIVE,
+                Parallelism::None,
+                make_stack!(multishift_qr_req::<f64>(
+                    n,
+                    n,
+                    true,
+                    true,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+            for j in 0..n {
+                for i in j + 2..n {
+                    t.write(i, j, 0.0);
+                }
+            }
+            (info, &q * &t * q.transpose())
+        };
+
+        let (info, _) = run(EvdParams {
+            blocking_threshold: Some(15),
+            max_sweeps_per_eigenvalue: Some(0),
+            ..Default::default()
+        });
+        assert!(info > 0);
+
+        let (info, h_reconstructed) = run(EvdParams {
+            blocking_threshold: Some(15),
+            max_sweeps_per_eigenvalue: Some(100),
+            deflation_tol: Some(1e-10),
+            ..Default::default()
+        });
+        assert!(info == 0);
+        assert!((&h_reconstructed - &h).norm_max() < 1e-8);
+    }
 }
@@ -79,6 +79,9 @@ pub fn compute_hermitian_evd_req<E: ComplexField>(
 ) -> Result<StackReq, SizeOverflow> {
     let _ = params;
     let _ = compute_eigenvectors;
+    if n == 0 {
+        return Ok(StackReq::empty());
+    }
     let householder_blocksize =
         crate::linalg::qr::no_pivoting::compute::recommended_blocksize::<E>(n, n);
 
@@ -110,7 +113,8 @@ pub fn compute_hermitian_evd_req<E: ComplexField>(
 }
 
 /// Computes the eigenvalue decomposition of a square Hermitian `matrix`. Only the lower triangular
-/// half of the matrix is accessed.
+/// half of the matrix is accessed, so the strictly upper triangular half may hold arbitrary values
+/// (e.g. NaN).
 ///
 /// `s` represents the diagonal of the matrix $S$, and must have size equal to the dimension of the
 /// matrix.
@@ -118,6 +122,9 @@ pub fn compute_hermitian_evd_req<E: ComplexField>(
 /// If `u` is `None`, then only the eigenvalues are computed. Otherwise, the eigenvectors are
 /// computed and stored in `u`.
 ///
+/// If the lower triangular half of `matrix` contains any non-finite value, the iterative part of
+/// the algorithm is skipped, and `s` and `u` are filled with NaN.
+///
 /// # Panics
 /// Panics if any of the conditions described above is violated, or if the type `E` does not have a
 /// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
@@ -214,6 +221,7 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
         crate::linalg::zip::Diag::Include,
         |unzipped!(mut dst, src)| dst.write(src.read()),
     );
+    crate::linalg::debug_poison_strictly_upper(trid.rb_mut());
 
     tridiag::tridiagonalize_in_place(
         trid.rb_mut(),
@@ -460,6 +468,9 @@ pub fn compute_hermitian_pseudoinverse_custom_epsilon<E: ComplexField>(
 /// corresponding to the eigenvalue $a + ib$ are stored at indices `k` and `k+1`. The eigenvector
 /// corresponding to $a - ib$ can be computed as the conjugate of that vector.
 ///
+/// If `matrix` contains any non-finite value, the iterative part of the algorithm is skipped, and
+/// `s_re`, `s_im` and `u` are filled with NaN.
+///
 /// # Panics
 /// Panics if any of the conditions described above is violated, or if the type `E` does not have a
 /// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
@@ -1312,6 +1323,9 @@ pub fn compute_evd_req<E: ComplexField>(
 /// If `u` is `None`, then only the eigenvalues are computed. Otherwise, the eigenvectors are
 /// computed and stored in `u`.
 ///
+/// If `matrix` contains any non-finite value, the iterative part of the algorithm is skipped, and
+/// `s` and `u` are filled with NaN.
+///
 /// # Panics
 /// Panics if any of the conditions described above is violated, or if the type `E` does not have a
 /// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
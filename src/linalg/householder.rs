@@ -52,6 +52,49 @@ use reborrow::*;
 ///
 /// $x$ is determined by $x_0$, contained in `head`, and $|x_{1\dots}|$, contained in `tail_norm`.
 /// The vector $v$ is such that $v_0 = 1$ and $v_{1\dots}$ is stored in `essential` (when provided).
+/// `essential` must initially contain $x_{1\dots}$, and is overwritten with $v_{1\dots}$.
+///
+/// If `tail_norm` is zero, then no reflection is needed, and $\tau$ is set to infinity, so that
+/// the transformation reduces to the identity.
+///
+/// The resulting reflection can be applied to a matrix with
+/// [`apply_block_householder_on_the_left_in_place_with_conj`], by passing the vector $v$ as the
+/// Householder basis (its first element is implicitly taken to be one) and the $1\times 1$
+/// matrix $[\tau]$ as the Householder factor.
+///
+/// # Example
+///
+/// ```
+/// use dyn_stack::{GlobalPodBuffer, PodStack};
+/// use faer::{col, linalg::householder, mat, Conj, Mat, Parallelism};
+///
+/// let x = col![3.0, 1.0, 2.0f64];
+///
+/// // `v` initially holds `x`. its head is implicitly one once the reflection is computed.
+/// let mut v = x.clone();
+/// let (tau, beta) = householder::make_householder_in_place(
+///     Some(v.subrows_mut(1, 2)),
+///     x.read(0),
+///     x.subrows(1, 2).norm_l2(),
+/// );
+///
+/// let mut y = x.as_2d().to_owned();
+/// householder::apply_block_householder_on_the_left_in_place_with_conj(
+///     v.as_2d(),
+///     mat![[tau]].as_ref(),
+///     Conj::No,
+///     y.as_mut(),
+///     Parallelism::None,
+///     PodStack::new(&mut GlobalPodBuffer::new(
+///         householder::apply_block_householder_on_the_left_in_place_req::<f64>(3, 1, 1).unwrap(),
+///     )),
+/// );
+///
+/// assert!((beta.abs() - 14.0f64.sqrt()).abs() < 1e-12);
+/// assert!((y.read(0, 0) - beta).abs() < 1e-12);
+/// assert!(y.read(1, 0).abs() < 1e-12);
+/// assert!(y.read(2, 0).abs() < 1e-12);
+/// ```
 #[inline]
 pub fn make_householder_in_place<E: ComplexField>(
     essential: Option<ColMut<'_, E>>,
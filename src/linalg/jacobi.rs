@@ -1,3 +1,12 @@
+//! Givens (Jacobi) plane rotations.
+//!
+//! A plane rotation is described by two real values $c$ and $s$ such that $c^2 + s^2 = 1$, and
+//! acts on a pair of rows (or columns) through the orthogonal matrix
+//! $$J = \begin{bmatrix} c & s \\ -s & c \end{bmatrix}.$$
+//!
+//! Rotations are the building block of algorithms that introduce zeros one at a time, such as the
+//! QR algorithm for tridiagonal matrices, or the Jacobi SVD.
+
 use crate::{
     assert, unzipped,
     utils::{simd::*, slice::*},
@@ -6,10 +15,13 @@ use crate::{
 use faer_entity::{pulp, SimdCtx, SimdGroupFor};
 use reborrow::*;
 
+/// Plane rotation $J = \begin{bmatrix} c & s \\ -s & c \end{bmatrix}$.
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct JacobiRotation<T> {
+    /// Cosine of the rotation angle.
     pub c: T,
+    /// Sine of the rotation angle.
     pub s: T,
 }
 
@@ -17,6 +29,22 @@ unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for JacobiRotation<T> {}
 unsafe impl<T: bytemuck::Pod> bytemuck::Pod for JacobiRotation<T> {}
 
 impl<E: RealField> JacobiRotation<E> {
+    /// Returns the Givens rotation $J$ such that $J^\top \begin{bmatrix} p \\ q \end{bmatrix} =
+    /// \begin{bmatrix} r \\ 0 \end{bmatrix}$, where $|r| = \sqrt{p^2 + q^2}$.
+    ///
+    /// The computation avoids overflow and underflow in the intermediate values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use faer::linalg::jacobi::JacobiRotation;
+    ///
+    /// let rot = JacobiRotation::make_givens(3.0f64, 4.0);
+    /// let (r, zero, _, _) = rot.transpose().apply_on_the_left_2x2(3.0, 0.0, 4.0, 0.0);
+    ///
+    /// assert!((r.abs() - 5.0).abs() < 1e-12);
+    /// assert!(zero.abs() < 1e-12);
+    /// ```
     #[inline]
     pub fn make_givens(p: E, q: E) -> Self {
         if q == E::faer_zero() {
@@ -60,6 +88,8 @@ impl<E: RealField> JacobiRotation<E> {
         }
     }
 
+    /// Returns the rotation $J$ that diagonalizes the symmetric matrix
+    /// $M = \begin{bmatrix} x & y \\ y & z \end{bmatrix}$, such that $J^\top M J$ is diagonal.
     #[inline]
     pub fn from_triplet(x: E, y: E, z: E) -> Self {
         let abs_y = y.faer_abs();
@@ -94,6 +124,8 @@ impl<E: RealField> JacobiRotation<E> {
         }
     }
 
+    /// Returns the product $J M$, where
+    /// $M = \begin{bmatrix} m_{00} & m_{01} \\ m_{10} & m_{11} \end{bmatrix}$.
     #[inline]
     pub fn apply_on_the_left_2x2(&self, m00: E, m01: E, m10: E, m11: E) -> (E, E, E, E) {
         let Self { c, s } = *self;
@@ -105,13 +137,21 @@ impl<E: RealField> JacobiRotation<E> {
         )
     }
 
+    /// Returns the product $M J$, where
+    /// $M = \begin{bmatrix} m_{00} & m_{01} \\ m_{10} & m_{11} \end{bmatrix}$.
     #[inline]
     pub fn apply_on_the_right_2x2(&self, m00: E, m01: E, m10: E, m11: E) -> (E, E, E, E) {
         let (r00, r01, r10, r11) = self.transpose().apply_on_the_left_2x2(m00, m10, m01, m11);
         (r00, r10, r01, r11)
     }
 
+    /// Replaces the rows $x$ and $y$ with $cx + sy$ and $-sx + cy$, which is equivalent to
+    /// multiplying the matrix $\begin{bmatrix} x \\ y \end{bmatrix}$ by $J$ on the left.
+    ///
+    /// # Panics
+    /// Panics if `x` and `y` don't have the same number of columns.
     #[inline]
+    #[track_caller]
     pub fn apply_on_the_left_in_place(&self, x: RowMut<'_, E>, y: RowMut<'_, E>) {
         self.apply_on_the_left_in_place_arch(E::Simd::default(), x, y);
     }
@@ -127,6 +167,7 @@ impl<E: RealField> JacobiRotation<E> {
         });
     }
 
+    #[doc(hidden)]
     #[inline(always)]
     pub fn apply_on_the_right_in_place_with_simd_and_offset<S: pulp::Simd>(
         &self,
@@ -144,6 +185,7 @@ impl<E: RealField> JacobiRotation<E> {
             );
     }
 
+    #[doc(hidden)]
     #[inline(always)]
     pub fn apply_on_the_left_in_place_with_simd_and_offset<S: pulp::Simd>(
         &self,
@@ -197,6 +239,7 @@ impl<E: RealField> JacobiRotation<E> {
         process(simd, x_tail, y_tail, c, s);
     }
 
+    #[doc(hidden)]
     #[inline]
     pub fn apply_on_the_left_in_place_arch(
         &self,
@@ -276,12 +319,19 @@ impl<E: RealField> JacobiRotation<E> {
         }
     }
 
+    /// Replaces the columns $x$ and $y$ with $cx - sy$ and $sx + cy$, which is equivalent to
+    /// multiplying the matrix $\begin{bmatrix} x & y \end{bmatrix}$ by $J$ on the right.
+    ///
+    /// # Panics
+    /// Panics if `x` and `y` don't have the same number of rows.
     #[inline]
+    #[track_caller]
     pub fn apply_on_the_right_in_place(&self, x: ColMut<'_, E>, y: ColMut<'_, E>) {
         self.transpose()
             .apply_on_the_left_in_place(x.transpose_mut(), y.transpose_mut());
     }
 
+    #[doc(hidden)]
     #[inline]
     pub fn apply_on_the_right_in_place_arch(
         &self,
@@ -296,6 +346,7 @@ impl<E: RealField> JacobiRotation<E> {
         );
     }
 
+    /// Returns the transposed rotation $J^\top$, which is also its inverse.
     #[inline]
     pub fn transpose(&self) -> Self {
         Self {
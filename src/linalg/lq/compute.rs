@@ -0,0 +1,58 @@
+use crate::{
+    linalg::qr::no_pivoting::compute::{self as qr, QrComputeParams},
+    ComplexField, Entity, MatMut, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// The recommended block size to use for an LQ decomposition of a matrix with the given shape.
+#[inline]
+pub fn recommended_blocksize<E: Entity>(nrows: usize, ncols: usize) -> usize {
+    qr::recommended_blocksize::<E>(ncols, nrows)
+}
+
+/// Computes the LQ decomposition of a rectangular matrix $A$, into a lower trapezoidal matrix $L$
+/// and a unitary matrix $Q$, represented as the transpose of a block Householder sequence, such
+/// that $$A = LQ.$$
+///
+/// This is the transpose of the QR decomposition of $A^\top = Q^\top L^\top$. The Householder
+/// bases of $Q^\top$ are stored as rows in the strictly upper trapezoidal part of `matrix` with an
+/// implicit unit diagonal, and its upper triangular Householder factors are stored in
+/// `householder_factor`, blockwise in chunks of `blocksize×blocksize`. The factor $L$ is stored in
+/// the lower trapezoidal part of `matrix`.
+///
+/// The block size is chosen as the number of rows of `householder_factor`.
+///
+/// # Panics
+///
+/// - Panics if the number of columns of the householder factor is not equal to the minimum of the
+///   number of rows and the number of columns of the input matrix.
+/// - Panics if the block size is zero.
+/// - Panics if the provided memory in `stack` is insufficient (see [`lq_in_place_req`]).
+#[track_caller]
+pub fn lq_in_place<E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    householder_factor: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: &mut PodStack,
+    params: QrComputeParams,
+) {
+    qr::qr_in_place(
+        matrix.transpose_mut(),
+        householder_factor,
+        parallelism,
+        stack,
+        params,
+    )
+}
+
+/// Computes the size and alignment of required workspace for performing an LQ decomposition.
+#[inline]
+pub fn lq_in_place_req<E: Entity>(
+    nrows: usize,
+    ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+    params: QrComputeParams,
+) -> Result<StackReq, SizeOverflow> {
+    qr::qr_in_place_req::<E>(ncols, nrows, blocksize, parallelism, params)
+}
@@ -0,0 +1,174 @@
+//! The LQ decomposition of a matrix $A$ is such that $A = LQ$, where $L$ is a lower trapezoidal
+//! matrix, and $Q$ is a unitary matrix, represented using the transpose of a block Householder
+//! sequence. It is the transpose of the QR decomposition of $A^\top$, and is computed as such, with
+//! the factors stored in the storage of $A$.
+//!
+//! # Example
+//!
+//! Assume we have an underdetermined system $AX = B$ where $A$ has full row rank, and that we wish
+//! to find the solution with the smallest norm.
+//!
+//! If we compute the LQ decomposition of $A$, such that $A = LQ = L_{\text{rect}}
+//! Q_{\text{thin}}$, then the solution is given by $$X = Q_{\text{thin}}^H L_{\text{rect}}^{-1}
+//! B.$$
+//!
+//! ```
+//! use dyn_stack::{GlobalPodBuffer, PodStack, StackReq};
+//! use faer::{linalg::lq, mat, Conj, Mat, Parallelism};
+//! use reborrow::*;
+//!
+//! let a = mat![[1.0, 0.0, 1.0], [0.0, 1.0, 1.0_f64]];
+//! let b = mat![[3.0], [3.0_f64]];
+//!
+//! let (m, n) = a.shape();
+//! let blocksize = lq::compute::recommended_blocksize::<f64>(m, n);
+//!
+//! let mut mem = GlobalPodBuffer::new(StackReq::any_of([
+//!     lq::compute::lq_in_place_req::<f64>(m, n, blocksize, Parallelism::None, Default::default())
+//!         .unwrap(),
+//!     lq::solve::solve_in_place_req::<f64>(n, blocksize, b.ncols()).unwrap(),
+//! ]));
+//! let mut stack = PodStack::new(&mut mem);
+//!
+//! let mut lq = a.clone();
+//! let mut h_factor = Mat::zeros(blocksize, Ord::min(m, n));
+//! lq::compute::lq_in_place(
+//!     lq.as_mut(),
+//!     h_factor.as_mut(),
+//!     Parallelism::None,
+//!     stack.rb_mut(),
+//!     Default::default(),
+//! );
+//!
+//! // the solution has as many rows as `a` has columns
+//! let mut x = Mat::zeros(n, b.ncols());
+//! x.as_mut().subrows_mut(0, m).copy_from(&b);
+//! lq::solve::solve_in_place(
+//!     lq.as_ref(),
+//!     h_factor.as_ref(),
+//!     Conj::No,
+//!     x.as_mut(),
+//!     Parallelism::None,
+//!     stack.rb_mut(),
+//! );
+//!
+//! // `x` solves the system, and is in the row space of `a`
+//! assert!((&a * &x - &b).norm_max() < 1e-12);
+//! let y = mat![[1.0], [1.0_f64]];
+//! assert!((&x - a.transpose() * &y).norm_max() < 1e-12);
+//! ```
+
+/// Computing the decomposition.
+pub mod compute;
+/// Solving a linear system using the decomposition.
+pub mod solve;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert, complex_native::c64, linalg::householder, linalg::zip::Diag, unzipped, zipped_rw,
+        Conj, Mat, Parallelism,
+    };
+    use dyn_stack::{GlobalPodBuffer, PodStack};
+
+    macro_rules! make_stack {
+        ($req: expr $(,)?) => {
+            PodStack::new(&mut GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    type E = c64;
+
+    fn random(_: usize, _: usize) -> E {
+        E::new(rand::random(), rand::random())
+    }
+
+    #[test]
+    #[cfg(feature = "svd")]
+    fn test_lq() {
+        for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+            for (m, n) in [
+                (2, 2),
+                (4, 2),
+                (2, 4),
+                (20, 20),
+                (20, 75),
+                (75, 20),
+                (130, 300),
+            ] {
+                let a = Mat::from_fn(m, n, random);
+                let size = Ord::min(m, n);
+
+                for blocksize in [1, 2, 16, compute::recommended_blocksize::<E>(m, n)] {
+                    let blocksize = Ord::min(blocksize, Ord::max(size, 1));
+                    let mut lq = a.clone();
+                    let mut h = Mat::zeros(blocksize, size);
+                    compute::lq_in_place(
+                        lq.as_mut(),
+                        h.as_mut(),
+                        parallelism,
+                        make_stack!(compute::lq_in_place_req::<E>(
+                            m,
+                            n,
+                            blocksize,
+                            parallelism,
+                            Default::default(),
+                        )),
+                        Default::default(),
+                    );
+
+                    let mut l = Mat::<E>::zeros(m, n);
+                    zipped_rw!(l.as_mut(), lq.as_ref())
+                        .for_each_triangular_lower(Diag::Include, |unzipped!(mut dst, src)| {
+                            dst.write(src.read())
+                        });
+
+                    // Q = Q_qr^T, where Q_qr is the Householder sequence of the QR of A^T
+                    let mut q = Mat::<E>::identity(n, n);
+                    householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                        lq.as_ref().transpose(),
+                        h.as_ref(),
+                        Conj::No,
+                        q.as_mut(),
+                        parallelism,
+                        make_stack!(
+                            householder::apply_block_householder_sequence_on_the_left_in_place_req::<
+                                E,
+                            >(n, blocksize, n,)
+                        ),
+                    );
+                    let q = q.transpose().to_owned();
+
+                    assert!((&l * &q - &a).norm_max() < 1e-10);
+                    assert!((&q * q.adjoint() - Mat::<E>::identity(n, n)).norm_max() < 1e-10);
+
+                    if m <= n {
+                        for conj in [Conj::No, Conj::Yes] {
+                            let b = Mat::from_fn(m, 3, random);
+                            let mut x = Mat::<E>::from_fn(n, 3, random);
+                            x.as_mut().subrows_mut(0, m).copy_from(&b);
+                            solve::solve_in_place(
+                                lq.as_ref(),
+                                h.as_ref(),
+                                conj,
+                                x.as_mut(),
+                                parallelism,
+                                make_stack!(solve::solve_in_place_req::<E>(n, blocksize, 3)),
+                            );
+
+                            let a = if conj == Conj::Yes {
+                                a.conjugate().to_owned()
+                            } else {
+                                a.clone()
+                            };
+                            let expected =
+                                crate::linalg::solvers::Svd::new(a.as_ref()).pseudoinverse() * &b;
+                            assert!((&x - &expected).norm_max() < 1e-8);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
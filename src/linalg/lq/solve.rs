@@ -0,0 +1,89 @@
+use crate::{
+    assert,
+    linalg::{
+        householder::{
+            apply_block_householder_sequence_on_the_left_in_place_req,
+            apply_block_householder_sequence_on_the_left_in_place_with_conj,
+        },
+        triangular_solve as solve,
+    },
+    ComplexField, Conj, Entity, MatMut, MatRef, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Computes the size and alignment of required workspace for computing the minimum-norm solution
+/// of a linear system defined by a wide matrix in place, given its LQ decomposition.
+#[inline]
+pub fn solve_in_place_req<E: Entity>(
+    lq_ncols: usize,
+    lq_blocksize: usize,
+    rhs_ncols: usize,
+) -> Result<StackReq, SizeOverflow> {
+    apply_block_householder_sequence_on_the_left_in_place_req::<E>(
+        lq_ncols,
+        lq_blocksize,
+        rhs_ncols,
+    )
+}
+
+/// Given the LQ factors of a wide matrix $A$ with full row rank, and a matrix $B$ stored in the top
+/// rows of `rhs`, this function computes the minimum-norm solution of the linear system:
+/// $$\text{Op}_A(A)X = B.$$
+///
+/// $\text{Op}_A$ is either the identity or the conjugation depending on the value of `conj_lhs`.
+///
+/// `rhs` must have as many rows as the number of columns of $A$. On entry, its top
+/// `lq_factors.nrows()` rows contain $B$, and its remaining rows are ignored. On exit, it contains
+/// the solution $X$.
+///
+/// # Panics
+///
+/// - Panics if `lq_factors` is not a wide matrix.
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+///   number of rows and the number of columns of `lq_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if `rhs` doesn't have the same number of rows as the number of columns of
+///   `lq_factors`.
+/// - Panics if the provided memory in `stack` is insufficient (see [`solve_in_place_req`]).
+#[track_caller]
+pub fn solve_in_place<E: ComplexField>(
+    lq_factors: MatRef<'_, E>,
+    householder_factor: MatRef<'_, E>,
+    conj_lhs: Conj,
+    rhs: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: &mut PodStack,
+) {
+    // conjᵃ(L × Hₖ₋₁ᵀ × ... × H₀ᵀ) X = B
+    // X = conj(conjᵃ(H₀)) × ... × conj(conjᵃ(Hₖ₋₁)) × [conjᵃ(L₁)⁻¹ × B; 0]
+    let m = lq_factors.nrows();
+    let n = lq_factors.ncols();
+    let blocksize = householder_factor.nrows();
+    assert!(all(
+        m <= n,
+        householder_factor.nrows() == blocksize,
+        householder_factor.ncols() == m,
+        rhs.nrows() == n,
+    ));
+
+    let mut rhs = rhs;
+    let (top, mut bot) = rhs.rb_mut().split_at_row_mut(m);
+
+    solve::solve_lower_triangular_in_place_with_conj(
+        lq_factors.submatrix(0, 0, m, m),
+        conj_lhs,
+        top,
+        parallelism,
+    );
+    bot.fill_zero();
+
+    apply_block_householder_sequence_on_the_left_in_place_with_conj(
+        lq_factors.transpose(),
+        householder_factor,
+        conj_lhs.compose(Conj::Yes),
+        rhs,
+        parallelism,
+        stack,
+    );
+}
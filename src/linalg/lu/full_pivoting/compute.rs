@@ -224,12 +224,22 @@ fn best_in_col<E: ComplexField, S: Simd>(
     let simd_real = SimdFor::<E::Real, S>::new(simd.simd);
 
     let (head, body, tail) = simd.as_aligned_simd(SliceGroup::<'_, E>::new(data), offset);
-    let (body4, body1) = body.as_arrays::<4>();
+
+    // each accumulator scans a contiguous quarter of the body, so that on every lane, the
+    // candidates seen by an accumulator precede those seen by the next one. merging them in order
+    // with a strict comparison then keeps the smallest index among tied candidates, regardless of
+    // the simd width
+    let quarter = body.len() / 4;
+    let (body0, body) = body.split_at(quarter);
+    let (body1, body) = body.split_at(quarter);
+    let (body2, body) = body.split_at(quarter);
+    let (body3, body_rem) = body.split_at(quarter);
 
     let iota = simd_real.index_seq();
     let lane_count = core::mem::size_of::<SimdUnitFor<E, S>>() / core::mem::size_of::<UnitFor<E>>();
     let increment1 = simd_real.index_splat(E::Real::faer_usize_to_index(lane_count));
-    let increment4 = simd_real.index_splat(E::Real::faer_usize_to_index(4 * lane_count));
+    let increment_quarter =
+        simd_real.index_splat(E::Real::faer_usize_to_index(quarter * lane_count));
 
     let mut best_value0 = simd_real.splat(E::Real::faer_one().faer_neg());
     let mut best_value1 = simd_real.splat(E::Real::faer_one().faer_neg());
@@ -267,25 +277,25 @@ fn best_in_col<E: ComplexField, S: Simd>(
     (best_value0, best_indices0) = process(simd, head, indices0, best_value0, best_indices0);
 
     indices0 = simd_real.index_add(indices0, increment1);
-    let mut indices1 = simd_real.index_add(indices0, increment1);
-    let mut indices2 = simd_real.index_add(indices1, increment1);
-    let mut indices3 = simd_real.index_add(indices2, increment1);
-    for [data0, data1, data2, data3] in body4.into_ref_iter().map(RefGroup::unzip) {
+    let mut indices1 = simd_real.index_add(indices0, increment_quarter);
+    let mut indices2 = simd_real.index_add(indices1, increment_quarter);
+    let mut indices3 = simd_real.index_add(indices2, increment_quarter);
+    for (((data0, data1), data2), data3) in body0
+        .into_ref_iter()
+        .zip(body1.into_ref_iter())
+        .zip(body2.into_ref_iter())
+        .zip(body3.into_ref_iter())
+    {
         (best_value0, best_indices0) = process(simd, data0, indices0, best_value0, best_indices0);
         (best_value1, best_indices1) = process(simd, data1, indices1, best_value1, best_indices1);
         (best_value2, best_indices2) = process(simd, data2, indices2, best_value2, best_indices2);
         (best_value3, best_indices3) = process(simd, data3, indices3, best_value3, best_indices3);
 
-        indices0 = simd_real.index_add(indices0, increment4);
-        indices1 = simd_real.index_add(indices1, increment4);
-        indices2 = simd_real.index_add(indices2, increment4);
-        indices3 = simd_real.index_add(indices3, increment4);
-    }
-    for data0 in body1.into_ref_iter() {
-        (best_value0, best_indices0) = process(simd, data0, indices0, best_value0, best_indices0);
         indices0 = simd_real.index_add(indices0, increment1);
+        indices1 = simd_real.index_add(indices1, increment1);
+        indices2 = simd_real.index_add(indices2, increment1);
+        indices3 = simd_real.index_add(indices3, increment1);
     }
-    (best_value0, best_indices0) = process(simd, tail, indices0, best_value0, best_indices0);
 
     (best_value0, best_indices0) = best_score::<E::Real, S>(
         simd_real,
@@ -301,14 +311,21 @@ fn best_in_col<E: ComplexField, S: Simd>(
         best_value3,
         best_indices3,
     );
-
-    best_score::<E::Real, S>(
+    (best_value0, best_indices0) = best_score::<E::Real, S>(
         simd_real,
         best_value0,
         best_indices0,
         best_value2,
         best_indices2,
-    )
+    );
+
+    // the remaining elements come after all the ones that were already seen
+    let mut indices = indices3;
+    for data in body_rem.into_ref_iter() {
+        (best_value0, best_indices0) = process(simd, data, indices, best_value0, best_indices0);
+        indices = simd_real.index_add(indices, increment1);
+    }
+    process(simd, tail, indices, best_value0, best_indices0)
 }
 
 #[inline(always)]
@@ -327,15 +344,21 @@ fn update_and_best_in_col<E: ComplexField, S: Simd>(
     let lane_count = core::mem::size_of::<SimdUnitFor<E, S>>() / core::mem::size_of::<UnitFor<E>>();
 
     let (dst_head, dst_body, dst_tail) = simd.as_aligned_simd_mut(data, offset);
-
-    let (dst_body3, dst_body1) = dst_body.as_arrays_mut::<3>();
     let (lhs_head, lhs_body, lhs_tail) =
         simd.as_aligned_simd(SliceGroup::<'_, E>::new(lhs), offset);
-    let (lhs_body3, lhs_body1) = lhs_body.as_arrays::<3>();
+
+    // see `best_in_col`
+    let third = dst_body.len() / 3;
+    let (dst_body0, dst_body) = dst_body.split_at(third);
+    let (dst_body1, dst_body) = dst_body.split_at(third);
+    let (dst_body2, dst_body_rem) = dst_body.split_at(third);
+    let (lhs_body0, lhs_body) = lhs_body.split_at(third);
+    let (lhs_body1, lhs_body) = lhs_body.split_at(third);
+    let (lhs_body2, lhs_body_rem) = lhs_body.split_at(third);
 
     let iota = simd_real.index_seq();
     let increment1 = simd_real.index_splat(E::Real::faer_usize_to_index(lane_count));
-    let increment3 = simd_real.index_splat(E::Real::faer_usize_to_index(3 * lane_count));
+    let increment_third = simd_real.index_splat(E::Real::faer_usize_to_index(third * lane_count));
 
     let mut best_value0 = simd_real.splat(E::Real::faer_zero());
     let mut best_value1 = simd_real.splat(E::Real::faer_zero());
@@ -384,12 +407,13 @@ fn update_and_best_in_col<E: ComplexField, S: Simd>(
     }
 
     indices0 = simd_real.index_add(indices0, increment1);
-    let mut indices1 = simd_real.index_add(indices0, increment1);
-    let mut indices2 = simd_real.index_add(indices1, increment1);
-    for ([dst0, dst1, dst2], [lhs0, lhs1, lhs2]) in dst_body3
+    let mut indices1 = simd_real.index_add(indices0, increment_third);
+    let mut indices2 = simd_real.index_add(indices1, increment_third);
+    for (((dst0, lhs0), (dst1, lhs1)), (dst2, lhs2)) in dst_body0
         .into_mut_iter()
-        .map(RefGroupMut::unzip)
-        .zip(lhs_body3.into_ref_iter().map(RefGroup::unzip))
+        .zip(lhs_body0.into_ref_iter())
+        .zip(dst_body1.into_mut_iter().zip(lhs_body1.into_ref_iter()))
+        .zip(dst_body2.into_mut_iter().zip(lhs_body2.into_ref_iter()))
     {
         (best_value0, best_indices0) =
             process(simd, dst0, lhs0, rhs, indices0, best_value0, best_indices0);
@@ -397,24 +421,10 @@ fn update_and_best_in_col<E: ComplexField, S: Simd>(
             process(simd, dst1, lhs1, rhs, indices1, best_value1, best_indices1);
         (best_value2, best_indices2) =
             process(simd, dst2, lhs2, rhs, indices2, best_value2, best_indices2);
-        indices0 = simd_real.index_add(indices0, increment3);
-        indices1 = simd_real.index_add(indices1, increment3);
-        indices2 = simd_real.index_add(indices2, increment3);
-    }
-    for (dst0, lhs0) in dst_body1.into_mut_iter().zip(lhs_body1.into_ref_iter()) {
-        (best_value0, best_indices0) =
-            process(simd, dst0, lhs0, rhs, indices0, best_value0, best_indices0);
         indices0 = simd_real.index_add(indices0, increment1);
+        indices1 = simd_real.index_add(indices1, increment1);
+        indices2 = simd_real.index_add(indices2, increment1);
     }
-    (best_value0, best_indices0) = process(
-        simd,
-        dst_tail,
-        lhs_tail,
-        rhs,
-        indices0,
-        best_value0,
-        best_indices0,
-    );
 
     (best_value0, best_indices0) = best_score::<E::Real, S>(
         simd_real,
@@ -423,12 +433,31 @@ fn update_and_best_in_col<E: ComplexField, S: Simd>(
         best_value1,
         best_indices1,
     );
-    best_score::<E::Real, S>(
+    (best_value0, best_indices0) = best_score::<E::Real, S>(
         simd_real,
         best_value0,
         best_indices0,
         best_value2,
         best_indices2,
+    );
+
+    let mut indices = indices2;
+    for (dst, lhs) in dst_body_rem
+        .into_mut_iter()
+        .zip(lhs_body_rem.into_ref_iter())
+    {
+        (best_value0, best_indices0) =
+            process(simd, dst, lhs, rhs, indices, best_value0, best_indices0);
+        indices = simd_real.index_add(indices, increment1);
+    }
+    process(
+        simd,
+        dst_tail,
+        lhs_tail,
+        rhs,
+        indices,
+        best_value0,
+        best_indices0,
     )
 }
 
@@ -438,10 +467,17 @@ fn best_in_col_c64_generic<S: pulp::Simd>(
     iota: S::u64s,
     data: &[c64],
 ) -> (S::f64s, S::u64s) {
-    let (head, tail) = S::c64s_as_simd(bytemuck::cast_slice(data));
+    let (body, tail) = S::c64s_as_simd(bytemuck::cast_slice(data));
     let lane_count = core::mem::size_of::<S::c64s>() / core::mem::size_of::<c64>();
+
+    // see `best_in_col`
+    let third = body.len() / 3;
+    let (body0, body) = body.split_at(third);
+    let (body1, body) = body.split_at(third);
+    let (body2, body_rem) = body.split_at(third);
+
     let increment1 = simd.u64s_splat(lane_count as u64);
-    let increment3 = simd.u64s_splat(3 * lane_count as u64);
+    let increment_third = simd.u64s_splat((third * lane_count) as u64);
     let mut best_value0 = simd.f64s_splat(0.0);
     let mut best_value1 = simd.f64s_splat(0.0);
     let mut best_value2 = simd.f64s_splat(0.0);
@@ -449,34 +485,31 @@ fn best_in_col_c64_generic<S: pulp::Simd>(
     let mut best_indices1 = simd.u64s_splat(0);
     let mut best_indices2 = simd.u64s_splat(0);
     let mut indices0 = iota;
-    let mut indices1 = simd.u64s_add(indices0, increment1);
-    let mut indices2 = simd.u64s_add(indices1, increment1);
-    let (head3, tail3) = pulp::as_arrays::<3, _>(head);
-    for &data in head3 {
-        (best_value0, best_indices0) =
-            best_c64(simd, best_value0, best_indices0, data[0], indices0);
-        (best_value1, best_indices1) =
-            best_c64(simd, best_value1, best_indices1, data[1], indices1);
-        (best_value2, best_indices2) =
-            best_c64(simd, best_value2, best_indices2, data[2], indices2);
-        indices0 = simd.u64s_add(indices0, increment3);
-        indices1 = simd.u64s_add(indices1, increment3);
-        indices2 = simd.u64s_add(indices2, increment3);
+    let mut indices1 = simd.u64s_add(indices0, increment_third);
+    let mut indices2 = simd.u64s_add(indices1, increment_third);
+    for ((&data0, &data1), &data2) in body0.iter().zip(body1).zip(body2) {
+        (best_value0, best_indices0) = best_c64(simd, best_value0, best_indices0, data0, indices0);
+        (best_value1, best_indices1) = best_c64(simd, best_value1, best_indices1, data1, indices1);
+        (best_value2, best_indices2) = best_c64(simd, best_value2, best_indices2, data2, indices2);
+        indices0 = simd.u64s_add(indices0, increment1);
+        indices1 = simd.u64s_add(indices1, increment1);
+        indices2 = simd.u64s_add(indices2, increment1);
     }
     (best_value0, best_indices0) =
         best_f64(simd, best_value0, best_indices0, best_value1, best_indices1);
     (best_value0, best_indices0) =
         best_f64(simd, best_value0, best_indices0, best_value2, best_indices2);
-    for &data in tail3 {
-        (best_value0, best_indices0) = best_c64(simd, best_value0, best_indices0, data, indices0);
-        indices0 = simd.u64s_add(indices0, increment1);
+    let mut indices = indices2;
+    for &data in body_rem {
+        (best_value0, best_indices0) = best_c64(simd, best_value0, best_indices0, data, indices);
+        indices = simd.u64s_add(indices, increment1);
     }
     (best_value0, best_indices0) = best_c64(
         simd,
         best_value0,
         best_indices0,
         simd.c64s_partial_load(tail),
-        indices0,
+        indices,
     );
     (best_value0, best_indices0)
 }
@@ -489,20 +522,30 @@ fn update_and_best_in_col_c64_generic<S: pulp::Simd>(
     rhs: c64,
 ) -> (S::f64s, S::u64s) {
     let lane_count = core::mem::size_of::<S::c64s>() / core::mem::size_of::<c64>();
-    let (dst_head, dst_tail) = S::c64s_as_mut_simd(bytemuck::cast_slice_mut(dst));
-    let (lhs_head, lhs_tail) = S::c64s_as_simd(bytemuck::cast_slice(lhs));
+    let (dst_body, dst_tail) = S::c64s_as_mut_simd(bytemuck::cast_slice_mut(dst));
+    let (lhs_body, lhs_tail) = S::c64s_as_simd(bytemuck::cast_slice(lhs));
+
+    // see `best_in_col`
+    let half = dst_body.len() / 2;
+    let (dst_body0, dst_body) = dst_body.split_at_mut(half);
+    let (dst_body1, dst_body_rem) = dst_body.split_at_mut(half);
+    let (lhs_body0, lhs_body) = lhs_body.split_at(half);
+    let (lhs_body1, lhs_body_rem) = lhs_body.split_at(half);
+
     let increment1 = simd.u64s_splat(lane_count as u64);
-    let increment2 = simd.u64s_splat(2 * lane_count as u64);
+    let increment_half = simd.u64s_splat((half * lane_count) as u64);
     let mut best_value0 = simd.f64s_splat(0.0);
     let mut best_value1 = simd.f64s_splat(0.0);
     let mut best_indices0 = simd.u64s_splat(0);
     let mut best_indices1 = simd.u64s_splat(0);
-    let mut indices0 = simd.u64s_add(iota, simd.u64s_splat(0));
-    let mut indices1 = simd.u64s_add(indices0, increment1);
-    let (dst_head2, dst_tail2) = pulp::as_arrays_mut::<2, _>(dst_head);
-    let (lhs_head2, lhs_tail2) = pulp::as_arrays::<2, _>(lhs_head);
+    let mut indices0 = iota;
+    let mut indices1 = simd.u64s_add(indices0, increment_half);
     let rhs_v = simd.c64s_splat(pulp::cast(rhs));
-    for ([dst0, dst1], [lhs0, lhs1]) in dst_head2.iter_mut().zip(lhs_head2) {
+    for ((dst0, lhs0), (dst1, lhs1)) in dst_body0
+        .iter_mut()
+        .zip(lhs_body0)
+        .zip(dst_body1.iter_mut().zip(lhs_body1))
+    {
         let new_dst0 = simd.c64s_mul_add_e(*lhs0, rhs_v, *dst0);
         let new_dst1 = simd.c64s_mul_add_e(*lhs1, rhs_v, *dst1);
         *dst0 = new_dst0;
@@ -511,17 +554,17 @@ fn update_and_best_in_col_c64_generic<S: pulp::Simd>(
             best_c64(simd, best_value0, best_indices0, new_dst0, indices0);
         (best_value1, best_indices1) =
             best_c64(simd, best_value1, best_indices1, new_dst1, indices1);
-        indices0 = simd.u64s_add(indices0, increment2);
-        indices1 = simd.u64s_add(indices1, increment2);
+        indices0 = simd.u64s_add(indices0, increment1);
+        indices1 = simd.u64s_add(indices1, increment1);
     }
     (best_value0, best_indices0) =
         best_f64(simd, best_value0, best_indices0, best_value1, best_indices1);
-    for (dst, lhs) in dst_tail2.iter_mut().zip(lhs_tail2) {
+    let mut indices = indices1;
+    for (dst, lhs) in dst_body_rem.iter_mut().zip(lhs_body_rem) {
         let new_dst = simd.c64s_mul_add_e(*lhs, rhs_v, *dst);
         *dst = new_dst;
-        (best_value0, best_indices0) =
-            best_c64(simd, best_value0, best_indices0, new_dst, indices0);
-        indices0 = simd.u64s_add(indices0, increment1);
+        (best_value0, best_indices0) = best_c64(simd, best_value0, best_indices0, new_dst, indices);
+        indices = simd.u64s_add(indices, increment1);
     }
     {
         let new_dst = simd.c64s_mul_add_e(
@@ -530,8 +573,7 @@ fn update_and_best_in_col_c64_generic<S: pulp::Simd>(
             simd.c64s_partial_load(dst_tail),
         );
         simd.c64s_partial_store(dst_tail, new_dst);
-        (best_value0, best_indices0) =
-            best_c64(simd, best_value0, best_indices0, new_dst, indices0);
+        (best_value0, best_indices0) = best_c64(simd, best_value0, best_indices0, new_dst, indices);
     }
     (best_value0, best_indices0)
 }
@@ -541,10 +583,17 @@ fn best_in_col_c32_generic<S: pulp::Simd>(
     iota: S::u32s,
     data: &[c32],
 ) -> (S::f32s, S::u32s) {
-    let (head, tail) = S::c32s_as_simd(bytemuck::cast_slice(data));
+    let (body, tail) = S::c32s_as_simd(bytemuck::cast_slice(data));
     let lane_count = core::mem::size_of::<S::c32s>() / core::mem::size_of::<c32>();
+
+    // see `best_in_col`
+    let third = body.len() / 3;
+    let (body0, body) = body.split_at(third);
+    let (body1, body) = body.split_at(third);
+    let (body2, body_rem) = body.split_at(third);
+
     let increment1 = simd.u32s_splat(lane_count as u32);
-    let increment3 = simd.u32s_splat(3 * lane_count as u32);
+    let increment_third = simd.u32s_splat((third * lane_count) as u32);
     let mut best_value0 = simd.f32s_splat(0.0);
     let mut best_value1 = simd.f32s_splat(0.0);
     let mut best_value2 = simd.f32s_splat(0.0);
@@ -552,34 +601,31 @@ fn best_in_col_c32_generic<S: pulp::Simd>(
     let mut best_indices1 = simd.u32s_splat(0);
     let mut best_indices2 = simd.u32s_splat(0);
     let mut indices0 = iota;
-    let mut indices1 = simd.u32s_add(indices0, increment1);
-    let mut indices2 = simd.u32s_add(indices1, increment1);
-    let (head3, tail3) = pulp::as_arrays::<3, _>(head);
-    for &data in head3 {
-        (best_value0, best_indices0) =
-            best_c32(simd, best_value0, best_indices0, data[0], indices0);
-        (best_value1, best_indices1) =
-            best_c32(simd, best_value1, best_indices1, data[1], indices1);
-        (best_value2, best_indices2) =
-            best_c32(simd, best_value2, best_indices2, data[2], indices2);
-        indices0 = simd.u32s_add(indices0, increment3);
-        indices1 = simd.u32s_add(indices1, increment3);
-        indices2 = simd.u32s_add(indices2, increment3);
+    let mut indices1 = simd.u32s_add(indices0, increment_third);
+    let mut indices2 = simd.u32s_add(indices1, increment_third);
+    for ((&data0, &data1), &data2) in body0.iter().zip(body1).zip(body2) {
+        (best_value0, best_indices0) = best_c32(simd, best_value0, best_indices0, data0, indices0);
+        (best_value1, best_indices1) = best_c32(simd, best_value1, best_indices1, data1, indices1);
+        (best_value2, best_indices2) = best_c32(simd, best_value2, best_indices2, data2, indices2);
+        indices0 = simd.u32s_add(indices0, increment1);
+        indices1 = simd.u32s_add(indices1, increment1);
+        indices2 = simd.u32s_add(indices2, increment1);
     }
     (best_value0, best_indices0) =
         best_f32(simd, best_value0, best_indices0, best_value1, best_indices1);
     (best_value0, best_indices0) =
         best_f32(simd, best_value0, best_indices0, best_value2, best_indices2);
-    for &data in tail3 {
-        (best_value0, best_indices0) = best_c32(simd, best_value0, best_indices0, data, indices0);
-        indices0 = simd.u32s_add(indices0, increment1);
+    let mut indices = indices2;
+    for &data in body_rem {
+        (best_value0, best_indices0) = best_c32(simd, best_value0, best_indices0, data, indices);
+        indices = simd.u32s_add(indices, increment1);
     }
     (best_value0, best_indices0) = best_c32(
         simd,
         best_value0,
         best_indices0,
         simd.c32s_partial_load(tail),
-        indices0,
+        indices,
     );
     (best_value0, best_indices0)
 }
@@ -592,20 +638,30 @@ fn update_and_best_in_col_c32_generic<S: pulp::Simd>(
     rhs: c32,
 ) -> (S::f32s, S::u32s) {
     let lane_count = core::mem::size_of::<S::c32s>() / core::mem::size_of::<c32>();
-    let (dst_head, dst_tail) = S::c32s_as_mut_simd(bytemuck::cast_slice_mut(dst));
-    let (lhs_head, lhs_tail) = S::c32s_as_simd(bytemuck::cast_slice(lhs));
+    let (dst_body, dst_tail) = S::c32s_as_mut_simd(bytemuck::cast_slice_mut(dst));
+    let (lhs_body, lhs_tail) = S::c32s_as_simd(bytemuck::cast_slice(lhs));
+
+    // see `best_in_col`
+    let half = dst_body.len() / 2;
+    let (dst_body0, dst_body) = dst_body.split_at_mut(half);
+    let (dst_body1, dst_body_rem) = dst_body.split_at_mut(half);
+    let (lhs_body0, lhs_body) = lhs_body.split_at(half);
+    let (lhs_body1, lhs_body_rem) = lhs_body.split_at(half);
+
     let increment1 = simd.u32s_splat(lane_count as u32);
-    let increment2 = simd.u32s_splat(2 * lane_count as u32);
+    let increment_half = simd.u32s_splat((half * lane_count) as u32);
     let mut best_value0 = simd.f32s_splat(0.0);
     let mut best_value1 = simd.f32s_splat(0.0);
     let mut best_indices0 = simd.u32s_splat(0);
     let mut best_indices1 = simd.u32s_splat(0);
-    let mut indices0 = simd.u32s_add(iota, simd.u32s_splat(0));
-    let mut indices1 = simd.u32s_add(indices0, increment1);
-    let (dst_head2, dst_tail2) = pulp::as_arrays_mut::<2, _>(dst_head);
-    let (lhs_head2, lhs_tail2) = pulp::as_arrays::<2, _>(lhs_head);
+    let mut indices0 = iota;
+    let mut indices1 = simd.u32s_add(indices0, increment_half);
     let rhs_v = simd.c32s_splat(pulp::cast(rhs));
-    for ([dst0, dst1], [lhs0, lhs1]) in dst_head2.iter_mut().zip(lhs_head2) {
+    for ((dst0, lhs0), (dst1, lhs1)) in dst_body0
+        .iter_mut()
+        .zip(lhs_body0)
+        .zip(dst_body1.iter_mut().zip(lhs_body1))
+    {
         let new_dst0 = simd.c32s_mul_add_e(*lhs0, rhs_v, *dst0);
         let new_dst1 = simd.c32s_mul_add_e(*lhs1, rhs_v, *dst1);
         *dst0 = new_dst0;
@@ -614,17 +670,17 @@ fn update_and_best_in_col_c32_generic<S: pulp::Simd>(
             best_c32(simd, best_value0, best_indices0, new_dst0, indices0);
         (best_value1, best_indices1) =
             best_c32(simd, best_value1, best_indices1, new_dst1, indices1);
-        indices0 = simd.u32s_add(indices0, increment2);
-        indices1 = simd.u32s_add(indices1, increment2);
+        indices0 = simd.u32s_add(indices0, increment1);
+        indices1 = simd.u32s_add(indices1, increment1);
     }
     (best_value0, best_indices0) =
         best_f32(simd, best_value0, best_indices0, best_value1, best_indices1);
-    for (dst, lhs) in dst_tail2.iter_mut().zip(lhs_tail2) {
+    let mut indices = indices1;
+    for (dst, lhs) in dst_body_rem.iter_mut().zip(lhs_body_rem) {
         let new_dst = simd.c32s_mul_add_e(*lhs, rhs_v, *dst);
         *dst = new_dst;
-        (best_value0, best_indices0) =
-            best_c32(simd, best_value0, best_indices0, new_dst, indices0);
-        indices0 = simd.u32s_add(indices0, increment1);
+        (best_value0, best_indices0) = best_c32(simd, best_value0, best_indices0, new_dst, indices);
+        indices = simd.u32s_add(indices, increment1);
     }
     {
         let new_dst = simd.c32s_mul_add_e(
@@ -633,8 +689,7 @@ fn update_and_best_in_col_c32_generic<S: pulp::Simd>(
             simd.c32s_partial_load(dst_tail),
         );
         simd.c32s_partial_store(dst_tail, new_dst);
-        (best_value0, best_indices0) =
-            best_c32(simd, best_value0, best_indices0, new_dst, indices0);
+        (best_value0, best_indices0) = best_c32(simd, best_value0, best_indices0, new_dst, indices);
     }
     (best_value0, best_indices0)
 }
@@ -657,30 +712,39 @@ fn reduce2d<E: RealField>(
         .take(len)
     {
         let value = E::faer_from_units(E::faer_deref(value));
-        (best_value_scalar, best_row_scalar, best_col_scalar) = {
-            if value > best_value_scalar {
-                (value, row, col)
-            } else {
-                (best_value_scalar, best_row_scalar, best_col_scalar)
-            }
-        };
+        let is_better = value > best_value_scalar
+            || (value == best_value_scalar
+                && is_first_in_col_major(
+                    (E::faer_index_to_usize(row), E::faer_index_to_usize(col)),
+                    (
+                        E::faer_index_to_usize(best_row_scalar),
+                        E::faer_index_to_usize(best_col_scalar),
+                    ),
+                ));
+        if is_better {
+            (best_value_scalar, best_row_scalar, best_col_scalar) = (value, row, col);
+        }
     }
     (best_value_scalar, best_row_scalar, best_col_scalar)
 }
 
+// lanes don't visit the elements in index order, so ties between lanes are broken by picking the
+// element that comes first in column-major order, which matches the scalar search
+#[inline(always)]
+fn is_first_in_col_major<I: Ord>((row, col): (I, I), (best_row, best_col): (I, I)) -> bool {
+    (col, row) < (best_col, best_row)
+}
+
 #[inline(always)]
 fn reduce2d_f64(best_value: &[f64], best_row: &[u64], best_col: &[u64]) -> (f64, u64, u64) {
     let (mut best_value_scalar, mut best_row_scalar, mut best_col_scalar) = (0.0, 0, 0);
     for ((data, &row), &col) in best_value.iter().copied().zip(best_row).zip(best_col) {
-        (best_value_scalar, best_row_scalar, best_col_scalar) = best2d_f64(
-            pulp::Scalar::new(),
-            best_value_scalar,
-            best_row_scalar,
-            best_col_scalar,
-            data,
-            row,
-            col,
-        );
+        if data > best_value_scalar
+            || (data == best_value_scalar
+                && is_first_in_col_major((row, col), (best_row_scalar, best_col_scalar)))
+        {
+            (best_value_scalar, best_row_scalar, best_col_scalar) = (data, row, col);
+        }
     }
     (best_value_scalar, best_row_scalar, best_col_scalar)
 }
@@ -689,15 +753,12 @@ fn reduce2d_f64(best_value: &[f64], best_row: &[u64], best_col: &[u64]) -> (f64,
 fn reduce2d_f32(best_value: &[f32], best_row: &[u32], best_col: &[u32]) -> (f32, u32, u32) {
     let (mut best_value_scalar, mut best_row_scalar, mut best_col_scalar) = (0.0, 0, 0);
     for ((data, &row), &col) in best_value.iter().copied().zip(best_row).zip(best_col) {
-        (best_value_scalar, best_row_scalar, best_col_scalar) = best2d_f32(
-            pulp::Scalar::new(),
-            best_value_scalar,
-            best_row_scalar,
-            best_col_scalar,
-            data,
-            row,
-            col,
-        );
+        if data > best_value_scalar
+            || (data == best_value_scalar
+                && is_first_in_col_major((row, col), (best_row_scalar, best_col_scalar)))
+        {
+            (best_value_scalar, best_row_scalar, best_col_scalar) = (data, row, col);
+        }
     }
     (best_value_scalar, best_row_scalar, best_col_scalar)
 }
@@ -727,7 +788,7 @@ fn update_and_best_in_col_c64<S: Simd>(
 fn best_in_col_c32<S: Simd>(simd: S, data: &[c32]) -> (S::f32s, S::u32s) {
     best_in_col_c32_generic(
         simd,
-        cast_lossy([0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 6, 7_u32]),
+        cast_lossy([0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7_u32]),
         data,
     )
 }
@@ -741,7 +802,7 @@ fn update_and_best_in_col_c32<S: Simd>(
 ) -> (S::f32s, S::u32s) {
     update_and_best_in_col_c32_generic(
         simd,
-        cast_lossy([0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 6, 7_u32]),
+        cast_lossy([0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7_u32]),
         dst,
         lhs,
         rhs,
@@ -1499,6 +1560,11 @@ pub struct FullPivLuInfo {
 /// decomposition of the matrix `matrix[row_perm, col_perm]`. `row_perm_inv` (resp. `col_perm_inv`)
 /// contains its inverse permutation.
 ///
+/// At each step, the pivot is the entry of largest magnitude in the remaining submatrix. If several
+/// entries have the same magnitude, the one that comes first in the storage order of the matrix is
+/// chosen (column-major order if `matrix` is column-major, and row-major order otherwise), so that
+/// the permutations don't depend on the simd instruction set or the number of threads.
+///
 /// # Output
 ///
 /// - The number of transpositions that constitute the permutation,
@@ -1606,7 +1672,7 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
 mod tests {
     use super::*;
     use crate::{assert, linalg::lu::full_pivoting::reconstruct, Mat};
-    use rand::random;
+    use rand::{random, rngs::StdRng, Rng, SeedableRng};
 
     macro_rules! make_stack {
         ($req: expr) => {
@@ -1764,6 +1830,134 @@ mod tests {
         compute_lu_row_major_generic::<c32>(random_c32, 1e-2);
     }
 
+    #[test]
+    fn test_best_in_matrix_ties() {
+        fn check<E: ComplexField>(from_f64: fn(f64) -> E) {
+            let rng = &mut StdRng::seed_from_u64(0);
+            for m in 1..70 {
+                for n in 1..4 {
+                    for row_offset in 0..3 {
+                        let mut mat =
+                            Mat::<E>::from_fn(m + row_offset, n, |_, _| from_f64(rng.gen::<f64>()));
+                        let mut ties = alloc::vec::Vec::new();
+                        for _ in 0..3 {
+                            let (i, j) = (rng.gen_range(0..m), rng.gen_range(0..n));
+                            mat.write(row_offset + i, j, from_f64(-2.0));
+                            ties.push((j, i));
+                        }
+                        let (first_col, first_row) = *ties.iter().min().unwrap();
+
+                        let mut mat = mat.as_mut().subrows_mut(row_offset, m);
+                        let (row, col, _) = best_in_matrix(mat.rb());
+                        assert!((row, col) == (first_row, first_col));
+
+                        let lhs = Mat::<E>::zeros(m, 1);
+                        let mut rhs = Mat::<E>::zeros(1, n);
+                        let (row, col, _) = rank_one_update_and_best_in_matrix(
+                            mat.rb_mut(),
+                            lhs.col(0),
+                            rhs.row_mut(0),
+                            0,
+                        );
+                        assert!((row, col) == (first_row, first_col));
+                    }
+                }
+            }
+        }
+
+        check::<f64>(|x| x);
+        check::<f32>(|x| x as f32);
+        check::<c64>(|x| c64::new(x, 0.0));
+        check::<c32>(|x| c32::new(x as f32, 0.0));
+    }
+
+    #[test]
+    fn test_lu_pivot_ties() {
+        // a scaled permutation matrix with equal nonzero entries: every step is a tie between the
+        // remaining columns, and the first one in column-major order wins
+        let n = 37;
+        let rows = (0..n)
+            .map(|j| (7 * j + 3) % n)
+            .collect::<alloc::vec::Vec<_>>();
+        let mut mat = Mat::<f64>::zeros(n, n);
+        for j in 0..n {
+            mat.write(rows[j], j, if j % 2 == 0 { 3.0 } else { -3.0 });
+        }
+
+        for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+            let mut lu = mat.clone();
+            let mut row_perm = vec![0usize; n];
+            let mut row_perm_inv = vec![0; n];
+            let mut col_perm = vec![0; n];
+            let mut col_perm_inv = vec![0; n];
+            let (_, row_perm, col_perm) = lu_in_place(
+                lu.as_mut(),
+                &mut row_perm,
+                &mut row_perm_inv,
+                &mut col_perm,
+                &mut col_perm_inv,
+                parallelism,
+                make_stack!(lu_in_place_req::<usize, f64>(
+                    n,
+                    n,
+                    parallelism,
+                    Default::default()
+                )),
+                Default::default(),
+            );
+            assert!(row_perm.arrays().0 == &*rows);
+            assert!(col_perm.arrays().0 == &*(0..n).collect::<alloc::vec::Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_lu_pivot_ties_same_column() {
+        // every nonzero entry has magnitude 5, with different phases. column 2 contains two
+        // candidates, and eliminating with the pivot from row 3 creates a new entry of magnitude 5
+        // at (1, 3), which ties with the one at (0, 3)
+        let n = 5;
+        let mut mat = Mat::<c64>::zeros(n, n);
+        for (i, j, v) in [
+            (2, 0, c64::new(0.0, -5.0)),
+            (4, 1, c64::new(5.0, 0.0)),
+            (1, 2, c64::new(5.0, 0.0)),
+            (3, 2, c64::new(-5.0, 0.0)),
+            (0, 3, c64::new(-5.0, 0.0)),
+            (3, 3, c64::new(0.0, 5.0)),
+            (0, 4, c64::new(0.0, 5.0)),
+        ] {
+            mat.write(i, j, v);
+        }
+
+        for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+            let mut lu = mat.clone();
+            let mut row_perm = vec![0usize; n];
+            let mut row_perm_inv = vec![0; n];
+            let mut col_perm = vec![0; n];
+            let mut col_perm_inv = vec![0; n];
+            let (_, row_perm, col_perm) = lu_in_place(
+                lu.as_mut(),
+                &mut row_perm,
+                &mut row_perm_inv,
+                &mut col_perm,
+                &mut col_perm_inv,
+                parallelism,
+                make_stack!(lu_in_place_req::<usize, c64>(
+                    n,
+                    n,
+                    parallelism,
+                    Default::default()
+                )),
+                Default::default(),
+            );
+            // within a column, the candidate in the smallest current row position wins. the second
+            // swap moves row 1 below row 3, so row 3 is picked before row 1 in column 2, then row 0
+            // before row 1 in column 3
+            assert!(row_perm.arrays().0 == [2, 4, 3, 0, 1]);
+            assert!(col_perm.arrays().0 == [0, 1, 2, 3, 4]);
+        }
+    }
+
     #[test]
     fn test_lu_c32_zeros() {
         for (m, n) in [
@@ -0,0 +1,153 @@
+use crate::{
+    assert,
+    linalg::{temp_mat_req, temp_mat_uninit},
+    perm::swap_rows_idx,
+    unzipped, zipped_rw, ComplexField, Conj, Entity, MatMut, MatRef,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Computes the size and alignment of required workspace for solving a batch of linear systems
+/// of dimension `dim` with [`solve_in_place`].
+pub fn solve_in_place_req<E: Entity>(dim: usize) -> Result<StackReq, SizeOverflow> {
+    temp_mat_req::<E>(dim, dim)
+}
+
+/// Solves each of the linear systems $A_i X_i = B_i$ (or $\bar{A_i} X_i = B_i$, depending on
+/// `conj_lhs`), where $A_i$ is `matrices[i]` and $B_i$ is `rhs[i]`, and stores the result in
+/// `rhs[i]`.
+///
+/// Each system is solved with an LU decomposition with partial pivoting, which is computed in the
+/// same workspace, and discarded afterwards. This is intended for solving a large number of small
+/// systems of the same size, for which setting up a
+/// [`PartialPivLu`](crate::linalg::solvers::PartialPivLu) per system would be dominated by
+/// overhead. For large systems, the blocked routines in [`compute`](super::compute) and
+/// [`solve`](super::solve) should be preferred instead.
+///
+/// The matrices are assumed to be invertible, in which case the behavior is the same as the
+/// non batched solver. Otherwise, the solution may contain non finite values.
+///
+/// # Panics
+/// Panics if any of the following conditions are violated:
+/// - `matrices` and `rhs` have the same length.
+/// - every matrix in `matrices` has dimensions `(dim, dim)`, where `dim` is the dimension of the
+///   first matrix.
+/// - every matrix in `rhs` has `dim` rows.
+/// - `stack` does not have enough capacity, as given by [`solve_in_place_req`].
+#[track_caller]
+pub fn solve_in_place<E: ComplexField>(
+    matrices: &[MatRef<'_, E>],
+    conj_lhs: Conj,
+    rhs: &mut [MatMut<'_, E>],
+    stack: &mut PodStack,
+) {
+    assert!(matrices.len() == rhs.len());
+    let Some(first) = matrices.first() else {
+        return;
+    };
+    let n = first.nrows();
+    for (a, b) in matrices.iter().zip(rhs.iter()) {
+        assert!(all(a.nrows() == n, a.ncols() == n, b.nrows() == n));
+    }
+
+    let (mut lu, _) = temp_mat_uninit::<E>(n, n, stack);
+    let mut lu = lu.as_mut();
+
+    for (a, b) in matrices.iter().zip(rhs.iter_mut()) {
+        let mut b = b.rb_mut();
+        let k = b.ncols();
+
+        match conj_lhs {
+            Conj::No => zipped_rw!(lu.rb_mut(), *a)
+                .for_each(|unzipped!(mut dst, src)| dst.write(src.read())),
+            Conj::Yes => zipped_rw!(lu.rb_mut(), *a)
+                .for_each(|unzipped!(mut dst, src)| dst.write(src.read().faer_conj())),
+        }
+
+        // elimination, with the row operations applied to the right-hand side as they are
+        // computed, so that neither the factor L nor the permutation need to be stored
+        for j in 0..n {
+            let mut pivot = j;
+            let mut pivot_score = lu.read(j, j).faer_score();
+            for i in j + 1..n {
+                let score = lu.read(i, j).faer_score();
+                if score > pivot_score {
+                    pivot = i;
+                    pivot_score = score;
+                }
+            }
+            swap_rows_idx(lu.rb_mut(), j, pivot);
+            swap_rows_idx(b.rb_mut(), j, pivot);
+
+            let inv = lu.read(j, j).faer_inv();
+            for i in j + 1..n {
+                let l = lu.read(i, j).faer_mul(inv);
+                for c in j + 1..n {
+                    lu.write(i, c, lu.read(i, c).faer_sub(l.faer_mul(lu.read(j, c))));
+                }
+                for c in 0..k {
+                    b.write(i, c, b.read(i, c).faer_sub(l.faer_mul(b.read(j, c))));
+                }
+            }
+        }
+
+        // back substitution with the upper triangular factor
+        for j in (0..n).rev() {
+            let inv = lu.read(j, j).faer_inv();
+            for c in 0..k {
+                let x = b.read(j, c).faer_mul(inv);
+                b.write(j, c, x);
+                for i in 0..j {
+                    b.write(i, c, b.read(i, c).faer_sub(lu.read(i, j).faer_mul(x)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Mat};
+    use dyn_stack::GlobalPodBuffer;
+
+    #[test]
+    fn test_batch_solve() {
+        for n in [0, 1, 2, 3, 4, 7] {
+            let count = 20;
+            let k = 2;
+            let random = |_, _| c64::new(rand::random(), rand::random());
+
+            let a = (0..count)
+                .map(|_| Mat::from_fn(n, n, random))
+                .collect::<alloc::vec::Vec<_>>();
+            let b = (0..count)
+                .map(|_| Mat::from_fn(n, k, random))
+                .collect::<alloc::vec::Vec<_>>();
+
+            for conj_lhs in [Conj::No, Conj::Yes] {
+                let mut x = b.clone();
+                solve_in_place(
+                    &a.iter().map(|a| a.as_ref()).collect::<alloc::vec::Vec<_>>(),
+                    conj_lhs,
+                    &mut x
+                        .iter_mut()
+                        .map(|x| x.as_mut())
+                        .collect::<alloc::vec::Vec<_>>(),
+                    PodStack::new(&mut GlobalPodBuffer::new(
+                        solve_in_place_req::<c64>(n).unwrap(),
+                    )),
+                );
+
+                for ((a, b), x) in a.iter().zip(&b).zip(&x) {
+                    let err = if conj_lhs == Conj::Yes {
+                        a.conjugate() * x - b
+                    } else {
+                        a * x - b
+                    };
+                    assert!(err.norm_max() < 1e-10);
+                }
+            }
+        }
+    }
+}
@@ -223,13 +223,18 @@ pub fn lu_in_place_impl<I: Index, E: ComplexField>(
     n: usize,
     transpositions: &mut [I],
     parallelism: Parallelism,
+    params: PartialPivLuComputeParams,
 ) -> usize {
     let m = matrix.nrows();
     let full_n = matrix.ncols();
 
     debug_assert!(m >= n);
 
-    if n <= recursion_threshold::<E>(m) {
+    let threshold = match params.blocksize {
+        Some(blocksize) => Ord::max(blocksize, 1),
+        None => recursion_threshold::<E>(m),
+    };
+    if n <= threshold {
         return lu_in_place_unblocked(matrix, col_start, n, transpositions);
     }
 
@@ -244,6 +249,7 @@ pub fn lu_in_place_impl<I: Index, E: ComplexField>(
         bs,
         &mut transpositions[..bs],
         parallelism,
+        params,
     );
 
     let (mat_top_left, mut mat_top_right, mat_bot_left, mut mat_bot_right) = matrix
@@ -267,6 +273,7 @@ pub fn lu_in_place_impl<I: Index, E: ComplexField>(
         n - bs,
         &mut transpositions[bs..],
         parallelism,
+        params,
     );
 
     let parallelism = if m * (full_n - n) > 128 * 128 {
@@ -320,10 +327,16 @@ pub fn lu_in_place_impl<I: Index, E: ComplexField>(
     n_transpositions
 }
 
-/// LUfactorization tuning parameters.
-#[derive(Default, Copy, Clone)]
+/// LU factorization tuning parameters.
+#[derive(Default, Copy, Clone, Debug)]
 #[non_exhaustive]
-pub struct PartialPivLuComputeParams {}
+pub struct PartialPivLuComputeParams {
+    /// Number of columns at or below which the recursion stops and the unblocked kernel is used.
+    /// `None` to automatically determine this threshold.
+    ///
+    /// See [`BlockingParams`](crate::BlockingParams) for how to choose this value.
+    pub blocksize: Option<usize>,
+}
 
 /// Information about the resulting LU factorization.
 #[derive(Copy, Clone, Debug)]
@@ -364,6 +377,10 @@ pub fn lu_in_place_req<I: Index, E: Entity>(
 /// result is the same as computing the non-pivoted LU decomposition of the matrix `matrix[perm,
 /// :]`. `perm_inv` contains its inverse permutation.
 ///
+/// At each step, the pivot is the entry of largest magnitude in the current column. If several
+/// entries have the same magnitude, the one with the smallest row index is chosen, so that the
+/// permutation only depends on the values of the matrix.
+///
 /// # Output
 ///
 /// - The number of transpositions that constitute the permutation,
@@ -382,7 +399,6 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
     stack: &mut PodStack,
     params: PartialPivLuComputeParams,
 ) -> (PartialPivLuInfo, PermRef<'out, I>) {
-    let _ = &params;
     let truncate = <I::Signed as SignedIndex>::truncate;
 
     assert!(perm.len() == matrix.nrows());
@@ -408,7 +424,14 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
     let (transpositions, _) = stack
         .rb_mut()
         .make_with(size, |_| I::from_signed(truncate(0)));
-    let n_transpositions = lu_in_place_impl(matrix.rb_mut(), 0, size, transpositions, parallelism);
+    let n_transpositions = lu_in_place_impl(
+        matrix.rb_mut(),
+        0,
+        size,
+        transpositions,
+        parallelism,
+        params,
+    );
 
     for (idx, t) in transpositions.iter().enumerate() {
         perm.swap(idx, idx + t.to_signed().zx());
@@ -515,6 +538,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compute_lu_pivot_ties() {
+        // |2| == |-2| in the first column, then 1.5 == 1.5 in the second one
+        let mat = crate::mat![[1.0, 2.0, 0.0], [2.0, 1.0, 1.0], [-2.0, 0.5, 3.0f64]];
+        let mut perm = vec![0usize; 3];
+        let mut perm_inv = vec![0; 3];
+
+        for parallelism in [Parallelism::None, Parallelism::Rayon(8)] {
+            let mut mat = mat.clone();
+            let (_, row_perm) = lu_in_place(
+                mat.as_mut(),
+                &mut perm,
+                &mut perm_inv,
+                parallelism,
+                make_stack!(lu_in_place_req::<usize, f64>(
+                    3,
+                    3,
+                    parallelism,
+                    Default::default()
+                )),
+                Default::default(),
+            );
+            assert!(row_perm.arrays().0 == [1, 0, 2]);
+        }
+    }
+
     #[test]
     fn compute_lu_non_contiguous() {
         for (m, n) in [
@@ -3,6 +3,8 @@
 //! where $P$ is a permutation matrix, $L$ is a unit lower triangular matrix, and $U$ is
 //! an upper triangular matrix.
 
+/// Solving a large number of small linear systems.
+pub mod batch;
 /// Computing the decomposition.
 pub mod compute;
 /// Reconstructing the inverse of the original matrix from the decomposition.
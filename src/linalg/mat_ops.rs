@@ -1,6 +1,7 @@
 use crate::{assert, col::*, diag::*, mat::*, perm::*, row::*, *};
 
 use faer_entity::*;
+use reborrow::IntoConst;
 
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
@@ -156,20 +157,132 @@ macro_rules! impl_div_scalar {
     };
 }
 
+macro_rules! impl_mul_scalar_lazy {
+    ($lhs: ty, $view: ident) => {
+        impl<
+                'a,
+                E: ComplexField,
+                LhsE: Conjugate<Canonical = E>,
+                RhsE: Conjugate<Canonical = E>,
+            > Mul<Scale<RhsE>> for $lhs
+        {
+            type Output = ScaledMat<'a, LhsE>;
+            #[track_caller]
+            fn mul(self, other: Scale<RhsE>) -> Self::Output {
+                self.$view().mul(other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_div_scalar_lazy {
+    ($lhs: ty, $view: ident) => {
+        impl<
+                'a,
+                E: ComplexField,
+                LhsE: Conjugate<Canonical = E>,
+                RhsE: Conjugate<Canonical = E>,
+            > Div<Scale<RhsE>> for $lhs
+        {
+            type Output = ScaledMat<'a, LhsE>;
+            #[track_caller]
+            fn div(self, other: Scale<RhsE>) -> Self::Output {
+                self.$view().div(other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_mul_lazy {
+    ($rhs: ty, $view: ident) => {
+        impl<
+                'a,
+                E: ComplexField,
+                LhsE: Conjugate<Canonical = E>,
+                RhsE: Conjugate<Canonical = E>,
+            > Mul<$rhs> for Scale<LhsE>
+        {
+            type Output = ScaledMat<'a, RhsE>;
+            #[track_caller]
+            fn mul(self, other: $rhs) -> Self::Output {
+                self.mul(other.$view())
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_real_mul {
+    ($lhs: ty, $rhs: ty, $out: ty) => {
+        impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<$rhs> for $lhs {
+            type Output = $out;
+            #[track_caller]
+            fn mul(self, other: $rhs) -> Self::Output {
+                self.mul(other.as_ref())
+            }
+        }
+    };
+}
+
+macro_rules! impl_mul_scalar_real {
+    ($lhs: ty, $rhs: ty, $out: ty) => {
+        impl<E: ComplexField, LhsE: Conjugate<Canonical = E>> Mul<$rhs> for $lhs {
+            type Output = $out;
+            #[track_caller]
+            fn mul(self, other: $rhs) -> Self::Output {
+                self.as_ref().mul(other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_div_scalar_real {
+    ($lhs: ty, $rhs: ty, $out: ty) => {
+        impl<E: ComplexField, LhsE: Conjugate<Canonical = E>> Div<$rhs> for $lhs {
+            type Output = $out;
+            #[track_caller]
+            fn div(self, other: $rhs) -> Self::Output {
+                self.as_ref().mul(ScaleReal(other.0.faer_inv()))
+            }
+        }
+    };
+}
+
+macro_rules! impl_mul_assign_scalar_real {
+    ($lhs: ty, $rhs: ty) => {
+        impl<LhsE: ComplexField> MulAssign<$rhs> for $lhs {
+            #[track_caller]
+            fn mul_assign(&mut self, other: $rhs) {
+                self.as_mut().mul_assign(other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_div_assign_scalar_real {
+    ($lhs: ty, $rhs: ty) => {
+        impl<LhsE: ComplexField> DivAssign<$rhs> for $lhs {
+            #[track_caller]
+            fn div_assign(&mut self, other: $rhs) {
+                self.as_mut().mul_assign(ScaleReal(other.0.faer_inv()))
+            }
+        }
+    };
+}
+
 macro_rules! impl_mul_primitive {
     ($rhs: ty, $out: ty) => {
         impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<$rhs> for f64 {
             type Output = $out;
             #[track_caller]
             fn mul(self, other: $rhs) -> Self::Output {
-                Scale(E::faer_from_f64(self)).mul(other)
+                ScaleReal(E::Real::faer_from_f64(self)).mul(other)
             }
         }
         impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<$rhs> for f32 {
             type Output = $out;
             #[track_caller]
             fn mul(self, other: $rhs) -> Self::Output {
-                Scale(E::faer_from_f64(self as f64)).mul(other)
+                ScaleReal(E::Real::faer_from_f64(self as f64)).mul(other)
             }
         }
 
@@ -177,28 +290,28 @@ macro_rules! impl_mul_primitive {
             type Output = $out;
             #[track_caller]
             fn mul(self, other: f64) -> Self::Output {
-                self.mul(Scale(E::faer_from_f64(other)))
+                self.mul(ScaleReal(E::Real::faer_from_f64(other)))
             }
         }
         impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<f32> for $rhs {
             type Output = $out;
             #[track_caller]
             fn mul(self, other: f32) -> Self::Output {
-                self.mul(Scale(E::faer_from_f64(other as f64)))
+                self.mul(ScaleReal(E::Real::faer_from_f64(other as f64)))
             }
         }
         impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Div<f64> for $rhs {
             type Output = $out;
             #[track_caller]
             fn div(self, other: f64) -> Self::Output {
-                self.mul(Scale(E::faer_from_f64(other.recip())))
+                self.mul(ScaleReal(E::Real::faer_from_f64(other.recip())))
             }
         }
         impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Div<f32> for $rhs {
             type Output = $out;
             #[track_caller]
             fn div(self, other: f32) -> Self::Output {
-                self.mul(Scale(E::faer_from_f64(other.recip() as f64)))
+                self.mul(ScaleReal(E::Real::faer_from_f64(other.recip() as f64)))
             }
         }
     };
@@ -209,25 +322,25 @@ macro_rules! impl_mul_assign_primitive {
         impl<LhsE: ComplexField> MulAssign<f64> for $lhs {
             #[track_caller]
             fn mul_assign(&mut self, other: f64) {
-                self.mul_assign(Scale(LhsE::faer_from_f64(other)))
+                self.mul_assign(ScaleReal(LhsE::Real::faer_from_f64(other)))
             }
         }
         impl<LhsE: ComplexField> MulAssign<f32> for $lhs {
             #[track_caller]
             fn mul_assign(&mut self, other: f32) {
-                self.mul_assign(Scale(LhsE::faer_from_f64(other as f64)))
+                self.mul_assign(ScaleReal(LhsE::Real::faer_from_f64(other as f64)))
             }
         }
         impl<LhsE: ComplexField> DivAssign<f64> for $lhs {
             #[track_caller]
             fn div_assign(&mut self, other: f64) {
-                self.mul_assign(Scale(LhsE::faer_from_f64(other.recip())))
+                self.mul_assign(ScaleReal(LhsE::Real::faer_from_f64(other.recip())))
             }
         }
         impl<LhsE: ComplexField> DivAssign<f32> for $lhs {
             #[track_caller]
             fn div_assign(&mut self, other: f32) {
-                self.mul_assign(Scale(LhsE::faer_from_f64(other.recip() as f64)))
+                self.mul_assign(ScaleReal(LhsE::Real::faer_from_f64(other.recip() as f64)))
             }
         }
     };
@@ -1773,24 +1886,33 @@ impl_perm!(&Row<E>, Perm<I>, Row<E::Canonical>);
 impl_perm!(&Row<E>, &PermRef<'_, I>, Row<E::Canonical>);
 impl_perm!(&Row<E>, &Perm<I>, Row<E::Canonical>);
 
-impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
-    Mul<Scale<RhsE>> for MatRef<'_, LhsE>
+impl<'a, E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+    Mul<Scale<RhsE>> for MatRef<'a, LhsE>
 {
-    type Output = Mat<E>;
+    type Output = ScaledMat<'a, LhsE>;
 
     fn mul(self, rhs: Scale<RhsE>) -> Self::Output {
-        let rhs = rhs.0.canonicalize();
-        zipped_rw!(self).map(|unzipped!(x)| x.read().canonicalize().faer_mul(rhs))
+        ScaledMat::new(self, rhs.0.canonicalize())
     }
 }
 
-impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
-    Mul<MatRef<'_, RhsE>> for Scale<LhsE>
+impl<'a, E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+    Mul<MatRef<'a, RhsE>> for Scale<LhsE>
 {
-    type Output = Mat<E>;
+    type Output = ScaledMat<'a, RhsE>;
 
-    fn mul(self, rhs: MatRef<'_, RhsE>) -> Self::Output {
-        zipped_rw!(rhs).map(|unzipped!(x)| x.read().canonicalize().faer_mul(self.0.canonicalize()))
+    fn mul(self, rhs: MatRef<'a, RhsE>) -> Self::Output {
+        ScaledMat::new(rhs, self.0.canonicalize())
+    }
+}
+
+impl<'a, E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+    Div<Scale<RhsE>> for MatRef<'a, LhsE>
+{
+    type Output = ScaledMat<'a, LhsE>;
+
+    fn div(self, rhs: Scale<RhsE>) -> Self::Output {
+        ScaledMat::new(self, rhs.0.canonicalize().faer_inv())
     }
 }
 
@@ -1855,26 +1977,450 @@ impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical
     }
 }
 
-// impl_mul_scalar!(MatRef<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_mul_scalar!(MatMut<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_mul_scalar!(Mat<LhsE>, Scale<RhsE>, Mat<E>);
-impl_mul_scalar!(&MatRef<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_mul_scalar!(&MatMut<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_mul_scalar!(&Mat<LhsE>, Scale<RhsE>, Mat<E>);
+// impl_mul_scalar_lazy!(MatRef<'a, LhsE>, into_const);
+impl_mul_scalar_lazy!(MatMut<'a, LhsE>, into_const);
+impl_mul_scalar_lazy!(&'a MatRef<'_, LhsE>, as_ref);
+impl_mul_scalar_lazy!(&'a MatMut<'_, LhsE>, as_ref);
+impl_mul_scalar_lazy!(&'a Mat<LhsE>, as_ref);
+
+// impl_div_scalar_lazy!(MatRef<'a, LhsE>, into_const);
+impl_div_scalar_lazy!(MatMut<'a, LhsE>, into_const);
+impl_div_scalar_lazy!(&'a MatRef<'_, LhsE>, as_ref);
+impl_div_scalar_lazy!(&'a MatMut<'_, LhsE>, as_ref);
+impl_div_scalar_lazy!(&'a Mat<LhsE>, as_ref);
+
+// impl_scalar_mul_lazy!(MatRef<'a, RhsE>, into_const);
+impl_scalar_mul_lazy!(MatMut<'a, RhsE>, into_const);
+impl_scalar_mul_lazy!(&'a MatRef<'_, RhsE>, as_ref);
+impl_scalar_mul_lazy!(&'a MatMut<'_, RhsE>, as_ref);
+impl_scalar_mul_lazy!(&'a Mat<RhsE>, as_ref);
+
+// owned matrices are scaled eagerly
+impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+    Mul<Scale<RhsE>> for Mat<LhsE>
+{
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn mul(self, other: Scale<RhsE>) -> Self::Output {
+        self.as_ref().mul(other).to_owned()
+    }
+}
+
+impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+    Div<Scale<RhsE>> for Mat<LhsE>
+{
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn div(self, other: Scale<RhsE>) -> Self::Output {
+        self.as_ref().div(other).to_owned()
+    }
+}
+
+impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>> Mul<Mat<RhsE>>
+    for Scale<LhsE>
+{
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn mul(self, other: Mat<RhsE>) -> Self::Output {
+        self.mul(other.as_ref()).to_owned()
+    }
+}
+
+mod scaled {
+    use super::*;
+    use crate::assert;
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Mul<MatRef<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[inline]
+        #[track_caller]
+        fn mul(self, rhs: MatRef<'_, RhsE>) -> Self::Output {
+            let lhs = self.mat();
+            assert!(lhs.ncols() == rhs.nrows());
+            let mut out = Mat::zeros(lhs.nrows(), rhs.ncols());
+            crate::linalg::matmul::matmul(
+                out.as_mut(),
+                lhs,
+                rhs,
+                None,
+                self.factor(),
+                get_global_parallelism(),
+            );
+            out
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Mul<ScaledMat<'_, RhsE>> for MatRef<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[inline]
+        #[track_caller]
+        fn mul(self, rhs: ScaledMat<'_, RhsE>) -> Self::Output {
+            let lhs = self;
+            assert!(lhs.ncols() == rhs.nrows());
+            let mut out = Mat::zeros(lhs.nrows(), rhs.ncols());
+            crate::linalg::matmul::matmul(
+                out.as_mut(),
+                lhs,
+                rhs.mat(),
+                None,
+                rhs.factor(),
+                get_global_parallelism(),
+            );
+            out
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Mul<ScaledMat<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[inline]
+        #[track_caller]
+        fn mul(self, rhs: ScaledMat<'_, RhsE>) -> Self::Output {
+            let lhs = self.mat();
+            assert!(lhs.ncols() == rhs.nrows());
+            let mut out = Mat::zeros(lhs.nrows(), rhs.ncols());
+            crate::linalg::matmul::matmul(
+                out.as_mut(),
+                lhs,
+                rhs.mat(),
+                None,
+                self.factor().faer_mul(rhs.factor()),
+                get_global_parallelism(),
+            );
+            out
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Mul<ColRef<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        type Output = Col<E>;
+
+        #[inline]
+        #[track_caller]
+        fn mul(self, rhs: ColRef<'_, RhsE>) -> Self::Output {
+            let lhs = self.mat();
+            assert!(lhs.ncols() == rhs.nrows());
+            let mut out = Col::zeros(lhs.nrows());
+            crate::linalg::matmul::matmul(
+                out.as_mut(),
+                lhs,
+                rhs,
+                None,
+                self.factor(),
+                get_global_parallelism(),
+            );
+            out
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Mul<ScaledMat<'_, RhsE>> for RowRef<'_, LhsE>
+    {
+        type Output = Row<E>;
+
+        #[inline]
+        #[track_caller]
+        fn mul(self, rhs: ScaledMat<'_, RhsE>) -> Self::Output {
+            let lhs = self;
+            assert!(lhs.ncols() == rhs.nrows());
+            let mut out = Row::zeros(rhs.ncols());
+            crate::linalg::matmul::matmul(
+                out.as_mut(),
+                lhs,
+                rhs.mat(),
+                None,
+                rhs.factor(),
+                get_global_parallelism(),
+            );
+            out
+        }
+    }
+
+    impl<'a, E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Mul<Scale<RhsE>> for ScaledMat<'a, LhsE>
+    {
+        type Output = ScaledMat<'a, LhsE>;
+
+        #[inline]
+        fn mul(self, rhs: Scale<RhsE>) -> Self::Output {
+            ScaledMat::new(self.mat(), self.factor().faer_mul(rhs.0.canonicalize()))
+        }
+    }
+
+    impl<'a, E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Div<Scale<RhsE>> for ScaledMat<'a, LhsE>
+    {
+        type Output = ScaledMat<'a, LhsE>;
+
+        #[inline]
+        fn div(self, rhs: Scale<RhsE>) -> Self::Output {
+            ScaledMat::new(
+                self.mat(),
+                self.factor().faer_mul(rhs.0.canonicalize().faer_inv()),
+            )
+        }
+    }
+
+    impl<'a, E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Mul<ScaledMat<'a, RhsE>> for Scale<LhsE>
+    {
+        type Output = ScaledMat<'a, RhsE>;
+
+        #[inline]
+        fn mul(self, rhs: ScaledMat<'a, RhsE>) -> Self::Output {
+            ScaledMat::new(rhs.mat(), self.0.canonicalize().faer_mul(rhs.factor()))
+        }
+    }
+
+    impl<'a, E: Conjugate> Neg for ScaledMat<'a, E>
+    where
+        E::Canonical: ComplexField,
+    {
+        type Output = ScaledMat<'a, E>;
+
+        #[inline]
+        fn neg(self) -> Self::Output {
+            ScaledMat::new(self.mat(), self.factor().faer_neg())
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Add<MatRef<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[track_caller]
+        fn add(self, rhs: MatRef<'_, RhsE>) -> Self::Output {
+            let k = self.factor();
+            zipped_rw!(self.mat(), rhs).map(|unzipped!(lhs, rhs)| {
+                lhs.read()
+                    .canonicalize()
+                    .faer_mul(k)
+                    .faer_add(rhs.read().canonicalize())
+            })
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Sub<MatRef<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[track_caller]
+        fn sub(self, rhs: MatRef<'_, RhsE>) -> Self::Output {
+            let k = self.factor();
+            zipped_rw!(self.mat(), rhs).map(|unzipped!(lhs, rhs)| {
+                lhs.read()
+                    .canonicalize()
+                    .faer_mul(k)
+                    .faer_sub(rhs.read().canonicalize())
+            })
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Add<ScaledMat<'_, RhsE>> for MatRef<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[track_caller]
+        fn add(self, rhs: ScaledMat<'_, RhsE>) -> Self::Output {
+            let k = rhs.factor();
+            zipped_rw!(self, rhs.mat()).map(|unzipped!(lhs, rhs)| {
+                lhs.read()
+                    .canonicalize()
+                    .faer_add(rhs.read().canonicalize().faer_mul(k))
+            })
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Sub<ScaledMat<'_, RhsE>> for MatRef<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[track_caller]
+        fn sub(self, rhs: ScaledMat<'_, RhsE>) -> Self::Output {
+            let k = rhs.factor();
+            zipped_rw!(self, rhs.mat()).map(|unzipped!(lhs, rhs)| {
+                lhs.read()
+                    .canonicalize()
+                    .faer_sub(rhs.read().canonicalize().faer_mul(k))
+            })
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Add<ScaledMat<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[track_caller]
+        fn add(self, rhs: ScaledMat<'_, RhsE>) -> Self::Output {
+            let (k_lhs, k_rhs) = (self.factor(), rhs.factor());
+            zipped_rw!(self.mat(), rhs.mat()).map(|unzipped!(lhs, rhs)| {
+                lhs.read()
+                    .canonicalize()
+                    .faer_mul(k_lhs)
+                    .faer_add(rhs.read().canonicalize().faer_mul(k_rhs))
+            })
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        Sub<ScaledMat<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        type Output = Mat<E>;
+
+        #[track_caller]
+        fn sub(self, rhs: ScaledMat<'_, RhsE>) -> Self::Output {
+            let (k_lhs, k_rhs) = (self.factor(), rhs.factor());
+            zipped_rw!(self.mat(), rhs.mat()).map(|unzipped!(lhs, rhs)| {
+                lhs.read()
+                    .canonicalize()
+                    .faer_mul(k_lhs)
+                    .faer_sub(rhs.read().canonicalize().faer_mul(k_rhs))
+            })
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        PartialEq<MatRef<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        fn eq(&self, other: &MatRef<'_, RhsE>) -> bool {
+            let lhs = self.mat();
+            let k = self.factor();
+            let rhs = *other;
+
+            if (lhs.nrows(), lhs.ncols()) != (rhs.nrows(), rhs.ncols()) {
+                return false;
+            }
+            let m = lhs.nrows();
+            let n = lhs.ncols();
+            for j in 0..n {
+                for i in 0..m {
+                    if !(lhs.read(i, j).canonicalize().faer_mul(k) == rhs.read(i, j).canonicalize())
+                    {
+                        return false;
+                    }
+                }
+            }
+
+            true
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        PartialEq<ScaledMat<'_, RhsE>> for MatRef<'_, LhsE>
+    {
+        fn eq(&self, other: &ScaledMat<'_, RhsE>) -> bool {
+            other.eq(self)
+        }
+    }
+
+    impl<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>
+        PartialEq<ScaledMat<'_, RhsE>> for ScaledMat<'_, LhsE>
+    {
+        fn eq(&self, other: &ScaledMat<'_, RhsE>) -> bool {
+            let (lhs, k_lhs) = (self.mat(), self.factor());
+            let (rhs, k_rhs) = (other.mat(), other.factor());
+
+            if (lhs.nrows(), lhs.ncols()) != (rhs.nrows(), rhs.ncols()) {
+                return false;
+            }
+            let m = lhs.nrows();
+            let n = lhs.ncols();
+            for j in 0..n {
+                for i in 0..m {
+                    if !(lhs.read(i, j).canonicalize().faer_mul(k_lhs)
+                        == rhs.read(i, j).canonicalize().faer_mul(k_rhs))
+                    {
+                        return false;
+                    }
+                }
+            }
 
-impl_div_scalar!(MatRef<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_div_scalar!(MatMut<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_div_scalar!(Mat<LhsE>, Scale<RhsE>, Mat<E>);
-impl_div_scalar!(&MatRef<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_div_scalar!(&MatMut<'_, LhsE>, Scale<RhsE>, Mat<E>);
-impl_div_scalar!(&Mat<LhsE>, Scale<RhsE>, Mat<E>);
+            true
+        }
+    }
 
-// impl_scalar_mul!(Scale<LhsE>, MatRef<'_, RhsE>, Mat<E>);
-impl_scalar_mul!(Scale<LhsE>, MatMut<'_, RhsE>, Mat<E>);
-impl_scalar_mul!(Scale<LhsE>, Mat<RhsE>, Mat<E>);
-impl_scalar_mul!(Scale<LhsE>, &MatRef<'_, RhsE>, Mat<E>);
-impl_scalar_mul!(Scale<LhsE>, &MatMut<'_, RhsE>, Mat<E>);
-impl_scalar_mul!(Scale<LhsE>, &Mat<RhsE>, Mat<E>);
+    macro_rules! impl_partial_eq_scaled {
+        ($lhs: ty, $rhs: ty) => {
+            impl<
+                    E: ComplexField,
+                    LhsE: Conjugate<Canonical = E>,
+                    RhsE: Conjugate<Canonical = E>,
+                > PartialEq<$rhs> for $lhs
+            {
+                fn eq(&self, other: &$rhs) -> bool {
+                    self.as_ref().eq(&other.as_ref())
+                }
+            }
+        };
+    }
+
+    // impl_mul!(ScaledMat<'_, LhsE>, MatRef<'_, RhsE>, Mat<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, MatMut<'_, RhsE>, Mat<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, Mat<RhsE>, Mat<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, &MatRef<'_, RhsE>, Mat<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, &MatMut<'_, RhsE>, Mat<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, &Mat<RhsE>, Mat<E>);
+
+    // impl_mul!(MatRef<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_mul!(MatMut<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_mul!(Mat<LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_mul!(&MatRef<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_mul!(&MatMut<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_mul!(&Mat<LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+
+    // impl_mul!(ScaledMat<'_, LhsE>, ColRef<'_, RhsE>, Col<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, ColMut<'_, RhsE>, Col<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, Col<RhsE>, Col<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, &ColRef<'_, RhsE>, Col<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, &ColMut<'_, RhsE>, Col<E>);
+    impl_mul!(ScaledMat<'_, LhsE>, &Col<RhsE>, Col<E>);
+
+    // impl_mul!(RowRef<'_, LhsE>, ScaledMat<'_, RhsE>, Row<E>);
+    impl_mul!(RowMut<'_, LhsE>, ScaledMat<'_, RhsE>, Row<E>);
+    impl_mul!(Row<LhsE>, ScaledMat<'_, RhsE>, Row<E>);
+    impl_mul!(&RowRef<'_, LhsE>, ScaledMat<'_, RhsE>, Row<E>);
+    impl_mul!(&RowMut<'_, LhsE>, ScaledMat<'_, RhsE>, Row<E>);
+    impl_mul!(&Row<LhsE>, ScaledMat<'_, RhsE>, Row<E>);
+
+    // impl_add_sub!(ScaledMat<'_, LhsE>, MatRef<'_, RhsE>, Mat<E>);
+    impl_add_sub!(ScaledMat<'_, LhsE>, MatMut<'_, RhsE>, Mat<E>);
+    impl_add_sub!(ScaledMat<'_, LhsE>, Mat<RhsE>, Mat<E>);
+    impl_add_sub!(ScaledMat<'_, LhsE>, &MatRef<'_, RhsE>, Mat<E>);
+    impl_add_sub!(ScaledMat<'_, LhsE>, &MatMut<'_, RhsE>, Mat<E>);
+    impl_add_sub!(ScaledMat<'_, LhsE>, &Mat<RhsE>, Mat<E>);
+
+    // impl_add_sub!(MatRef<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_add_sub!(MatMut<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_add_sub!(Mat<LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_add_sub!(&MatRef<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_add_sub!(&MatMut<'_, LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+    impl_add_sub!(&Mat<LhsE>, ScaledMat<'_, RhsE>, Mat<E>);
+
+    // impl_partial_eq_scaled!(ScaledMat<'_, LhsE>, MatRef<'_, RhsE>);
+    impl_partial_eq_scaled!(ScaledMat<'_, LhsE>, MatMut<'_, RhsE>);
+    impl_partial_eq_scaled!(ScaledMat<'_, LhsE>, Mat<RhsE>);
+
+    // impl_partial_eq_scaled!(MatRef<'_, LhsE>, ScaledMat<'_, RhsE>);
+    impl_partial_eq_scaled!(MatMut<'_, LhsE>, ScaledMat<'_, RhsE>);
+    impl_partial_eq_scaled!(Mat<LhsE>, ScaledMat<'_, RhsE>);
+}
 
 impl_mul_primitive!(MatRef<'_, RhsE>, Mat<E>);
 impl_mul_primitive!(MatMut<'_, RhsE>, Mat<E>);
@@ -1967,6 +2513,158 @@ impl_mul_primitive!(&DiagRef<'_, RhsE>, Diag<E>);
 impl_mul_primitive!(&DiagMut<'_, RhsE>, Diag<E>);
 impl_mul_primitive!(&Diag<RhsE>, Diag<E>);
 
+impl<E: ComplexField, LhsE: Conjugate<Canonical = E>> Mul<ScaleReal<E::Real>> for MatRef<'_, LhsE> {
+    type Output = Mat<E>;
+
+    fn mul(self, rhs: ScaleReal<E::Real>) -> Self::Output {
+        zipped_rw!(self).map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(rhs.0))
+    }
+}
+impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<MatRef<'_, RhsE>> for ScaleReal<E::Real> {
+    type Output = Mat<E>;
+
+    fn mul(self, rhs: MatRef<'_, RhsE>) -> Self::Output {
+        zipped_rw!(rhs).map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(self.0))
+    }
+}
+
+impl<E: ComplexField, LhsE: Conjugate<Canonical = E>> Mul<ScaleReal<E::Real>> for ColRef<'_, LhsE> {
+    type Output = Col<E>;
+
+    fn mul(self, rhs: ScaleReal<E::Real>) -> Self::Output {
+        zipped_rw!(self).map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(rhs.0))
+    }
+}
+impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<ColRef<'_, RhsE>> for ScaleReal<E::Real> {
+    type Output = Col<E>;
+
+    fn mul(self, rhs: ColRef<'_, RhsE>) -> Self::Output {
+        zipped_rw!(rhs).map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(self.0))
+    }
+}
+
+impl<E: ComplexField, LhsE: Conjugate<Canonical = E>> Mul<ScaleReal<E::Real>> for RowRef<'_, LhsE> {
+    type Output = Row<E>;
+
+    fn mul(self, rhs: ScaleReal<E::Real>) -> Self::Output {
+        zipped_rw!(self).map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(rhs.0))
+    }
+}
+impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<RowRef<'_, RhsE>> for ScaleReal<E::Real> {
+    type Output = Row<E>;
+
+    fn mul(self, rhs: RowRef<'_, RhsE>) -> Self::Output {
+        zipped_rw!(rhs).map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(self.0))
+    }
+}
+
+impl<E: ComplexField, LhsE: Conjugate<Canonical = E>> Mul<ScaleReal<E::Real>>
+    for DiagRef<'_, LhsE>
+{
+    type Output = Diag<E>;
+
+    fn mul(self, rhs: ScaleReal<E::Real>) -> Self::Output {
+        zipped_rw!(self.column_vector())
+            .map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(rhs.0))
+            .column_vector_into_diagonal()
+    }
+}
+impl<E: ComplexField, RhsE: Conjugate<Canonical = E>> Mul<DiagRef<'_, RhsE>>
+    for ScaleReal<E::Real>
+{
+    type Output = Diag<E>;
+
+    fn mul(self, rhs: DiagRef<'_, RhsE>) -> Self::Output {
+        zipped_rw!(rhs.column_vector())
+            .map(|unzipped!(x)| x.read().canonicalize().faer_scale_real(self.0))
+            .column_vector_into_diagonal()
+    }
+}
+
+// impl_mul_scalar_real!(MatRef<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_mul_scalar_real!(MatMut<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_mul_scalar_real!(Mat<LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_mul_scalar_real!(&MatRef<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_mul_scalar_real!(&MatMut<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_mul_scalar_real!(&Mat<LhsE>, ScaleReal<E::Real>, Mat<E>);
+
+impl_div_scalar_real!(MatRef<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_div_scalar_real!(MatMut<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_div_scalar_real!(Mat<LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_div_scalar_real!(&MatRef<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_div_scalar_real!(&MatMut<'_, LhsE>, ScaleReal<E::Real>, Mat<E>);
+impl_div_scalar_real!(&Mat<LhsE>, ScaleReal<E::Real>, Mat<E>);
+
+// impl_scalar_real_mul!(ScaleReal<E::Real>, MatRef<'_, RhsE>, Mat<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, MatMut<'_, RhsE>, Mat<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, Mat<RhsE>, Mat<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &MatRef<'_, RhsE>, Mat<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &MatMut<'_, RhsE>, Mat<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &Mat<RhsE>, Mat<E>);
+
+// impl_mul_scalar_real!(ColRef<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_mul_scalar_real!(ColMut<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_mul_scalar_real!(Col<LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_mul_scalar_real!(&ColRef<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_mul_scalar_real!(&ColMut<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_mul_scalar_real!(&Col<LhsE>, ScaleReal<E::Real>, Col<E>);
+
+impl_div_scalar_real!(ColRef<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_div_scalar_real!(ColMut<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_div_scalar_real!(Col<LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_div_scalar_real!(&ColRef<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_div_scalar_real!(&ColMut<'_, LhsE>, ScaleReal<E::Real>, Col<E>);
+impl_div_scalar_real!(&Col<LhsE>, ScaleReal<E::Real>, Col<E>);
+
+// impl_scalar_real_mul!(ScaleReal<E::Real>, ColRef<'_, RhsE>, Col<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, ColMut<'_, RhsE>, Col<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, Col<RhsE>, Col<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &ColRef<'_, RhsE>, Col<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &ColMut<'_, RhsE>, Col<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &Col<RhsE>, Col<E>);
+
+// impl_mul_scalar_real!(RowRef<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_mul_scalar_real!(RowMut<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_mul_scalar_real!(Row<LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_mul_scalar_real!(&RowRef<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_mul_scalar_real!(&RowMut<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_mul_scalar_real!(&Row<LhsE>, ScaleReal<E::Real>, Row<E>);
+
+impl_div_scalar_real!(RowRef<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_div_scalar_real!(RowMut<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_div_scalar_real!(Row<LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_div_scalar_real!(&RowRef<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_div_scalar_real!(&RowMut<'_, LhsE>, ScaleReal<E::Real>, Row<E>);
+impl_div_scalar_real!(&Row<LhsE>, ScaleReal<E::Real>, Row<E>);
+
+// impl_scalar_real_mul!(ScaleReal<E::Real>, RowRef<'_, RhsE>, Row<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, RowMut<'_, RhsE>, Row<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, Row<RhsE>, Row<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &RowRef<'_, RhsE>, Row<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &RowMut<'_, RhsE>, Row<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &Row<RhsE>, Row<E>);
+
+// impl_mul_scalar_real!(DiagRef<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_mul_scalar_real!(DiagMut<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_mul_scalar_real!(Diag<LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_mul_scalar_real!(&DiagRef<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_mul_scalar_real!(&DiagMut<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_mul_scalar_real!(&Diag<LhsE>, ScaleReal<E::Real>, Diag<E>);
+
+impl_div_scalar_real!(DiagRef<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_div_scalar_real!(DiagMut<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_div_scalar_real!(Diag<LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_div_scalar_real!(&DiagRef<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_div_scalar_real!(&DiagMut<'_, LhsE>, ScaleReal<E::Real>, Diag<E>);
+impl_div_scalar_real!(&Diag<LhsE>, ScaleReal<E::Real>, Diag<E>);
+
+// impl_scalar_real_mul!(ScaleReal<E::Real>, DiagRef<'_, RhsE>, Diag<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, DiagMut<'_, RhsE>, Diag<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, Diag<RhsE>, Diag<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &DiagRef<'_, RhsE>, Diag<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &DiagMut<'_, RhsE>, Diag<E>);
+impl_scalar_real_mul!(ScaleReal<E::Real>, &Diag<RhsE>, Diag<E>);
+
 impl<LhsE: ComplexField, RhsE: Conjugate<Canonical = LhsE>> MulAssign<Scale<RhsE>>
     for MatMut<'_, LhsE>
 {
@@ -2027,6 +2725,45 @@ impl_mul_assign_primitive!(Row<LhsE>);
 impl_mul_assign_primitive!(DiagMut<'_, LhsE>);
 impl_mul_assign_primitive!(Diag<LhsE>);
 
+impl<LhsE: ComplexField> MulAssign<ScaleReal<LhsE::Real>> for MatMut<'_, LhsE> {
+    fn mul_assign(&mut self, rhs: ScaleReal<LhsE::Real>) {
+        zipped_rw!(self.as_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(rhs.0)))
+    }
+}
+impl<LhsE: ComplexField> MulAssign<ScaleReal<LhsE::Real>> for ColMut<'_, LhsE> {
+    fn mul_assign(&mut self, rhs: ScaleReal<LhsE::Real>) {
+        zipped_rw!(self.as_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(rhs.0)))
+    }
+}
+impl<LhsE: ComplexField> MulAssign<ScaleReal<LhsE::Real>> for RowMut<'_, LhsE> {
+    fn mul_assign(&mut self, rhs: ScaleReal<LhsE::Real>) {
+        zipped_rw!(self.as_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(rhs.0)))
+    }
+}
+impl<LhsE: ComplexField> MulAssign<ScaleReal<LhsE::Real>> for DiagMut<'_, LhsE> {
+    fn mul_assign(&mut self, rhs: ScaleReal<LhsE::Real>) {
+        zipped_rw!(self.as_mut().column_vector_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(rhs.0)))
+    }
+}
+
+impl_mul_assign_scalar_real!(Mat<LhsE>, ScaleReal<LhsE::Real>);
+impl_mul_assign_scalar_real!(Col<LhsE>, ScaleReal<LhsE::Real>);
+impl_mul_assign_scalar_real!(Row<LhsE>, ScaleReal<LhsE::Real>);
+impl_mul_assign_scalar_real!(Diag<LhsE>, ScaleReal<LhsE::Real>);
+
+impl_div_assign_scalar_real!(MatMut<'_, LhsE>, ScaleReal<LhsE::Real>);
+impl_div_assign_scalar_real!(Mat<LhsE>, ScaleReal<LhsE::Real>);
+impl_div_assign_scalar_real!(ColMut<'_, LhsE>, ScaleReal<LhsE::Real>);
+impl_div_assign_scalar_real!(Col<LhsE>, ScaleReal<LhsE::Real>);
+impl_div_assign_scalar_real!(RowMut<'_, LhsE>, ScaleReal<LhsE::Real>);
+impl_div_assign_scalar_real!(Row<LhsE>, ScaleReal<LhsE::Real>);
+impl_div_assign_scalar_real!(DiagMut<'_, LhsE>, ScaleReal<LhsE::Real>);
+impl_div_assign_scalar_real!(Diag<LhsE>, ScaleReal<LhsE::Real>);
+
 #[cfg(feature = "sparse")]
 mod sparse {
     macro_rules! impl_mul_primitive_sparse {
@@ -3452,6 +4189,62 @@ mod test {
         assert_eq!(-A, expected);
     }
 
+    #[test]
+    fn test_add_sub_neg_col_row() {
+        let (A, B) = matrices();
+        let (a, b) = (A.col(0).to_owned(), B.col(1).to_owned());
+
+        let sum = Col::from_fn(3, |i| A.read(i, 0) + B.read(i, 1));
+        let diff = Col::from_fn(3, |i| A.read(i, 0) - B.read(i, 1));
+        assert!(&a + &b == sum);
+        assert!(a.as_ref() + b.as_ref() == sum);
+        assert!(a.clone() - b.clone() == diff);
+        assert!(-&a == Col::from_fn(3, |i| -A.read(i, 0)));
+
+        let (r, s) = (A.row(1).to_owned(), B.row(2).to_owned());
+        assert!(&r + &s == Row::from_fn(2, |j| A.read(1, j) + B.read(2, j)));
+        assert!(r.as_ref() - s.as_ref() == Row::from_fn(2, |j| A.read(1, j) - B.read(2, j)));
+        assert!(-r.as_ref() == Row::from_fn(2, |j| -A.read(1, j)));
+    }
+
+    #[test]
+    fn test_add_sub_assign() {
+        let (A, B) = matrices();
+
+        let mut C = A.clone();
+        C += &B;
+        assert_matrix_approx_eq(C.clone(), &(&A + &B));
+        C -= B.as_ref();
+        assert_matrix_approx_eq(C.clone(), &A);
+
+        let mut C = A.clone();
+        {
+            let mut view = C.as_mut();
+            view += B.as_ref();
+            view -= &A;
+        }
+        assert_matrix_approx_eq(C, &B);
+
+        let mut c = A.col(0).to_owned();
+        c += B.col(0);
+        assert!(c == A.col(0) + B.col(0));
+        c -= &c.clone();
+        assert!(c == Col::<f64>::zeros(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_adding_cols_of_different_sizes_should_panic() {
+        _ = Col::<f64>::zeros(2) + Col::<f64>::zeros(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_assign_matrices_of_different_sizes_should_panic() {
+        let (mut A, _) = matrices();
+        A += Mat::<f64>::zeros(2, 3);
+    }
+
     #[test]
     fn test_scalar_mul() {
         use crate::scale;
@@ -3461,12 +4254,12 @@ mod test {
         let expected = Mat::from_fn(A.nrows(), A.ncols(), |i, j| A.read(i, j) * scale.value());
 
         {
-            assert_matrix_approx_eq(A.as_ref() * scale, &expected);
-            assert_matrix_approx_eq(&A * scale, &expected);
-            assert_matrix_approx_eq(A.as_ref() * scale, &expected);
-            assert_matrix_approx_eq(&A * scale, &expected);
-            assert_matrix_approx_eq(A.as_ref() * scale, &expected);
-            assert_matrix_approx_eq(&A * scale, &expected);
+            assert_matrix_approx_eq((A.as_ref() * scale).to_owned(), &expected);
+            assert_matrix_approx_eq((&A * scale).to_owned(), &expected);
+            assert_matrix_approx_eq((A.as_ref() * scale).to_owned(), &expected);
+            assert_matrix_approx_eq((&A * scale).to_owned(), &expected);
+            assert_matrix_approx_eq((A.as_ref() * scale).to_owned(), &expected);
+            assert_matrix_approx_eq((&A * scale).to_owned(), &expected);
             assert_matrix_approx_eq(A.clone() * scale, &expected);
             assert_matrix_approx_eq(A.clone() * scale, &expected);
             assert_matrix_approx_eq(A * scale, &expected);
@@ -3474,18 +4267,97 @@ mod test {
 
         let (A, _) = matrices();
         {
-            assert_matrix_approx_eq(scale * A.as_ref(), &expected);
-            assert_matrix_approx_eq(scale * &A, &expected);
-            assert_matrix_approx_eq(scale * A.as_ref(), &expected);
-            assert_matrix_approx_eq(scale * &A, &expected);
-            assert_matrix_approx_eq(scale * A.as_ref(), &expected);
-            assert_matrix_approx_eq(scale * &A, &expected);
+            assert_matrix_approx_eq((scale * A.as_ref()).to_owned(), &expected);
+            assert_matrix_approx_eq((scale * &A).to_owned(), &expected);
+            assert_matrix_approx_eq((scale * A.as_ref()).to_owned(), &expected);
+            assert_matrix_approx_eq((scale * &A).to_owned(), &expected);
+            assert_matrix_approx_eq((scale * A.as_ref()).to_owned(), &expected);
+            assert_matrix_approx_eq((scale * &A).to_owned(), &expected);
             assert_matrix_approx_eq(scale * A.clone(), &expected);
             assert_matrix_approx_eq(scale * A.clone(), &expected);
             assert_matrix_approx_eq(scale * A, &expected);
         }
     }
 
+    #[test]
+    fn test_scaled_mat() {
+        use crate::{col, complex_native::c64, row, scale};
+
+        let (A, B) = matrices();
+        let k = scale(3.0);
+        let A_k = Mat::from_fn(A.nrows(), A.ncols(), |i, j| A.read(i, j) * 3.0);
+        let B_k = Mat::from_fn(B.nrows(), B.ncols(), |i, j| B.read(i, j) * 3.0);
+
+        let lazy = &A * k;
+        assert!(lazy.mat() == A.as_ref());
+        assert!(lazy.factor() == 3.0);
+        assert!(lazy == A_k);
+        assert!(A_k == scale(3.0) * A.as_ref());
+        assert!(&A / scale(0.5) == &A * scale(2.0));
+
+        assert_matrix_approx_eq(lazy * B.transpose(), &(&A_k * B.transpose()));
+        assert_matrix_approx_eq(A.transpose() * lazy, &(A.transpose() * &A_k));
+        assert_matrix_approx_eq(
+            lazy * (B.transpose() * scale(2.0)),
+            &(&A_k * (B.transpose() * scale(2.0)).to_owned()),
+        );
+
+        let x = col![1.5, -2.0];
+        let y = row![0.5, -1.0, 2.0];
+        let lazy_x = lazy * &x;
+        let expected_x = &A_k * &x;
+        for i in 0..3 {
+            assert_approx_eq!(lazy_x.read(i), expected_x.read(i));
+        }
+        let y_lazy = &y * lazy;
+        let expected_y = &y * &A_k;
+        for j in 0..2 {
+            assert_approx_eq!(y_lazy.read(j), expected_y.read(j));
+        }
+
+        assert_matrix_approx_eq(lazy + &B, &(&A_k + &B));
+        assert_matrix_approx_eq(&B - lazy, &(&B - &A_k));
+        assert_matrix_approx_eq(lazy - &B * scale(3.0), &(&A_k - &B_k));
+        assert_matrix_approx_eq(-lazy * scale(2.0) + &A_k, &(-&A_k));
+        assert_matrix_approx_eq(
+            (scale(2.0) * lazy / scale(4.0)).to_owned(),
+            &(&A_k * scale(0.5)).to_owned(),
+        );
+
+        // the factor is applied to the canonical values of a conjugated view
+        let Z = Mat::from_fn(3, 2, |i, j| c64::new(i as f64 + 1.0, j as f64 - 0.5));
+        let w = c64::new(0.0, 1.0);
+        let lazy = Z.as_ref().conjugate() * scale(w);
+        let expected = Mat::from_fn(3, 2, |i, j| Z.read(i, j).conj() * w);
+        assert!(lazy == expected);
+        assert!(lazy.to_owned() == expected);
+        assert!(lazy * Z.transpose() == &expected * Z.transpose());
+    }
+
+    #[test]
+    fn test_scalar_real_mul() {
+        use crate::{complex_native::c64, scale, scale_real};
+
+        let A = Mat::from_fn(3, 4, |i, j| c64::new(i as f64 - 1.0, j as f64 + 0.5));
+        let expected = (scale(c64::new(2.5, 0.0)) * &A).to_owned();
+
+        assert!(scale_real(2.5) * &A == expected);
+        assert!(&A * scale_real(2.5) == expected);
+        assert!(2.5f64 * &A == expected);
+        assert!(A.as_ref().conjugate() * scale_real(2.5) == expected.conjugate());
+        assert!(scale_real(2.5) * A.col(1) == expected.col(1));
+        assert!(A.row(2) * scale_real(2.5) == expected.row(2));
+
+        let mut B = A.clone();
+        B *= scale_real(2.5);
+        assert!(B == expected);
+        B /= scale_real(2.5);
+        assert!(B == A);
+        let mut col = B.col_mut(0);
+        col *= scale_real(2.5);
+        assert!(B.col(0) == expected.col(0));
+    }
+
     #[test]
     fn test_diag_mul() {
         let (A, _) = matrices();
@@ -3529,6 +4401,67 @@ mod test {
         );
         assert!(&perm_left * &A == &pl * &A);
         assert!(&A * &perm_right == &A * &pr);
+
+        // composition agrees with the product of the dense matrices
+        let ql = Perm::<usize>::new_checked(
+            Box::new([2, 0, 5, 1, 3, 4]),
+            Box::new([1, 3, 0, 4, 5, 2]),
+            6,
+        );
+        let plql = pl.compose(ql.as_ref());
+        assert!(plql.to_dense_matrix::<f64>() == &perm_left * ql.to_dense_matrix::<f64>());
+        assert!(&plql * &A == &pl * (&ql * &A));
+        let At = A.transpose().to_owned();
+        assert!(&At * &plql == (&At * &pl) * &ql);
+        assert!(
+            pl.as_ref().inverse().to_owned().compose(pl.as_ref())
+                == pl.as_ref().compose(pl.as_ref().inverse())
+        );
+
+        let mut x: Vec<usize> = (0..6).map(|i| 10 * i).collect();
+        plql.apply_to_slice(&mut x);
+        let x_col = Col::from_fn(6, |i| x[i] as f64);
+        assert!(x_col == &plql * Col::from_fn(6, |i| (10 * i) as f64));
+        plql.as_ref().inverse().apply_to_slice(&mut x);
+        assert!(x == (0..6).map(|i| 10 * i).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_matmul() {
+        let (A, B) = matrices();
+        let B = B.transpose().to_owned();
+        let x = Col::from_fn(2, |i| (i + 1) as f64);
+
+        let expected = Mat::from_fn(3, 3, |i, j| {
+            A.read(i, 0) * B.read(0, j) + A.read(i, 1) * B.read(1, j)
+        });
+        assert_matrix_approx_eq(A.as_ref() * B.as_ref(), &expected);
+        assert_matrix_approx_eq(&A * &B, &expected);
+        assert_matrix_approx_eq(A.as_ref() * &B, &expected);
+        assert_matrix_approx_eq(&A * B.as_ref(), &expected);
+        assert_matrix_approx_eq(A.clone() * B.clone(), &expected);
+
+        let expected = Col::from_fn(3, |i| A.read(i, 0) + 2.0 * A.read(i, 1));
+        let y = &A * &x;
+        assert!(y.nrows() == 3);
+        for i in 0..3 {
+            assert_approx_eq!(y.read(i), expected.read(i));
+        }
+        assert!(A.as_ref() * x.as_ref() == y);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multiplying_matrices_of_incompatible_sizes_should_panic() {
+        let (A, B) = matrices();
+        _ = &A * &B;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multiplying_matrix_and_col_of_incompatible_sizes_should_panic() {
+        let (A, _) = matrices();
+        _ = &A * Col::<f64>::zeros(3);
     }
 
     #[test]
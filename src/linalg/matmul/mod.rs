@@ -1548,6 +1548,132 @@ fn matmul_with_conj_impl<E: ComplexField>(
     }
 }
 
+/// Computes `[alpha * acc] + beta * Op_lhs(lhs) * Op_rhs(rhs)` for a row-major `lhs` and a
+/// column-major `rhs`, as a sequence of contiguous inner products.
+///
+/// This is the layout of `A^T * B` when `A` and `B` are both column-major, e.g. when forming the
+/// normal equations `A^T * A`. The reduction dimension is split into blocks that fit in the cache,
+/// so that the rows of `lhs` and the columns of `rhs` stay resident while they are being reused,
+/// and `lhs` never needs to be copied to column-major storage.
+fn matmul_transposed_lhs<E: ComplexField>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    conj_lhs: Conj,
+    rhs: MatRef<'_, E>,
+    conj_rhs: Conj,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    const BLOCK_BYTES: usize = 128 * 1024;
+
+    let mut acc = acc;
+    let m = acc.nrows();
+    let n = acc.ncols();
+    let k = lhs.ncols();
+
+    if Ord::max(m, n) > 1 && crate::utils::thread::parallelism_degree(parallelism) > 1 {
+        // the entries of the output are independent, so the larger dimension is split between
+        // the threads
+        if n >= m {
+            let bs = n / 2;
+            let (mut acc_left, mut acc_right) = acc.split_at_col_mut(bs);
+            let (rhs_left, rhs_right) = rhs.split_at_col(bs);
+            crate::utils::thread::join_raw(
+                |parallelism| {
+                    matmul_transposed_lhs(
+                        acc_left.rb_mut(),
+                        lhs,
+                        conj_lhs,
+                        rhs_left,
+                        conj_rhs,
+                        alpha,
+                        beta,
+                        parallelism,
+                    )
+                },
+                |parallelism| {
+                    matmul_transposed_lhs(
+                        acc_right.rb_mut(),
+                        lhs,
+                        conj_lhs,
+                        rhs_right,
+                        conj_rhs,
+                        alpha,
+                        beta,
+                        parallelism,
+                    )
+                },
+                parallelism,
+            );
+        } else {
+            let bs = m / 2;
+            let (mut acc_top, mut acc_bot) = acc.split_at_row_mut(bs);
+            let (lhs_top, lhs_bot) = lhs.split_at_row(bs);
+            crate::utils::thread::join_raw(
+                |parallelism| {
+                    matmul_transposed_lhs(
+                        acc_top.rb_mut(),
+                        lhs_top,
+                        conj_lhs,
+                        rhs,
+                        conj_rhs,
+                        alpha,
+                        beta,
+                        parallelism,
+                    )
+                },
+                |parallelism| {
+                    matmul_transposed_lhs(
+                        acc_bot.rb_mut(),
+                        lhs_bot,
+                        conj_lhs,
+                        rhs,
+                        conj_rhs,
+                        alpha,
+                        beta,
+                        parallelism,
+                    )
+                },
+                parallelism,
+            );
+        }
+        return;
+    }
+
+    let block_k = Ord::max(
+        BLOCK_BYTES / ((m + n) * Ord::max(core::mem::size_of::<E>(), 1)),
+        256,
+    );
+
+    match alpha {
+        Some(alpha) => zipped_rw!(acc.rb_mut())
+            .for_each(|unzipped!(mut acc)| acc.write(acc.read().faer_mul(alpha))),
+        None => zipped_rw!(acc.rb_mut()).for_each(|unzipped!(mut acc)| acc.write(E::faer_zero())),
+    }
+
+    // the products are accumulated directly into the destination, one block of the inner
+    // dimension at a time, so that the blocks of `lhs` and `rhs` stay in cache
+    let mut depth = 0usize;
+    while depth < k {
+        let block = min(block_k, k - depth);
+        let lhs = lhs.subcols(depth, block);
+        let rhs = rhs.subrows(depth, block);
+        for j in 0..n {
+            for i in 0..m {
+                let dot = inner_prod::inner_prod_with_conj(
+                    lhs.row(i).transpose(),
+                    conj_lhs,
+                    rhs.col(j),
+                    conj_rhs,
+                );
+                acc.write(i, j, acc.read(i, j).faer_add(beta.faer_mul(dot)));
+            }
+        }
+        depth += block;
+    }
+}
+
 #[doc(hidden)]
 pub fn matmul_with_conj_gemm_dispatch<E: ComplexField>(
     mut acc: MatMut<'_, E>,
@@ -1860,6 +1986,11 @@ pub fn matmul_with_conj_gemm_dispatch<E: ComplexField>(
             return;
         }
     } else {
+        if lhs.col_stride() == 1 && rhs.row_stride() == 1 && k >= 4 * Ord::max(m, n) {
+            matmul_transposed_lhs(acc, lhs, conj_lhs, rhs, conj_rhs, alpha, beta, parallelism);
+            return;
+        }
+
         let arch = E::Simd::default();
         let lane_count = arch.dispatch(SimdLaneCount::<E> {
             __marker: PhantomData,
@@ -2003,17 +2134,103 @@ pub fn matmul_with_conj<E: ComplexField>(
     beta: E,
     parallelism: Parallelism,
 ) {
+    if let Err(err) =
+        try_matmul_with_conj(acc, lhs, conj_lhs, rhs, conj_rhs, alpha, beta, parallelism)
+    {
+        panic!("{err}");
+    }
+}
+
+/// Error returned by [`try_matmul`] and [`try_matmul_with_conj`] when the dimensions of the
+/// operands are not compatible.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatmulError {
+    /// The number of columns of `lhs` differs from the number of rows of `rhs`.
+    InnerDimensionMismatch {
+        /// Number of columns of `lhs`.
+        lhs_ncols: usize,
+        /// Number of rows of `rhs`.
+        rhs_nrows: usize,
+    },
+    /// The shape of `acc` differs from `(lhs.nrows(), rhs.ncols())`.
+    OutputShapeMismatch {
+        /// Shape of `acc`.
+        acc_shape: (usize, usize),
+        /// Shape of the product `lhs * rhs`.
+        expected_shape: (usize, usize),
+    },
+}
+
+impl core::fmt::Display for MatmulError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            MatmulError::InnerDimensionMismatch {
+                lhs_ncols,
+                rhs_nrows,
+            } => write!(
+                f,
+                "matmul: lhs has {lhs_ncols} columns, but rhs has {rhs_nrows} rows",
+            ),
+            MatmulError::OutputShapeMismatch {
+                acc_shape,
+                expected_shape,
+            } => write!(
+                f,
+                "matmul: acc has shape {acc_shape:?}, but the product has shape {expected_shape:?}",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for MatmulError {}
+
+fn check_matmul_dims<E: Entity, LhsE: Entity, RhsE: Entity>(
+    acc: MatRef<'_, E>,
+    lhs: MatRef<'_, LhsE>,
+    rhs: MatRef<'_, RhsE>,
+) -> Result<(), MatmulError> {
+    if lhs.ncols() != rhs.nrows() {
+        return Err(MatmulError::InnerDimensionMismatch {
+            lhs_ncols: lhs.ncols(),
+            rhs_nrows: rhs.nrows(),
+        });
+    }
+    if acc.shape() != (lhs.nrows(), rhs.ncols()) {
+        return Err(MatmulError::OutputShapeMismatch {
+            acc_shape: acc.shape(),
+            expected_shape: (lhs.nrows(), rhs.ncols()),
+        });
+    }
+    Ok(())
+}
+
+/// Same as [`matmul_with_conj`], except that incompatible dimensions are reported as an error
+/// instead of a panic, in which case `acc` is left untouched.
+///
+/// # Errors
+///
+/// Returns [`MatmulError::InnerDimensionMismatch`] if `lhs.ncols() != rhs.nrows()`, and
+/// [`MatmulError::OutputShapeMismatch`] if the shape of `acc` is not
+/// `(lhs.nrows(), rhs.ncols())`.
+#[inline]
+pub fn try_matmul_with_conj<E: ComplexField>(
+    acc: impl As2DMut<E>,
+    lhs: impl As2D<E>,
+    conj_lhs: Conj,
+    rhs: impl As2D<E>,
+    conj_rhs: Conj,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) -> Result<(), MatmulError> {
     let mut acc = acc;
     let acc = acc.as_2d_mut();
     let lhs = lhs.as_2d_ref();
     let rhs = rhs.as_2d_ref();
 
-    assert!(all(
-        acc.nrows() == lhs.nrows(),
-        acc.ncols() == rhs.ncols(),
-        lhs.ncols() == rhs.nrows(),
-    ));
+    check_matmul_dims(acc.rb(), lhs, rhs)?;
     matmul_with_conj_gemm_dispatch(acc, lhs, conj_lhs, rhs, conj_rhs, alpha, beta, parallelism);
+    Ok(())
 }
 
 /// Computes the matrix product `[alpha * acc] + beta * lhs * rhs` and
@@ -2032,6 +2249,18 @@ pub fn matmul_with_conj<E: ComplexField>(
 ///  - `acc.ncols() == rhs.ncols()`
 ///  - `lhs.ncols() == rhs.nrows()`
 ///
+/// # Transposed operands
+///
+/// Strided and transposed views are consumed directly, so `A^T * B` (and the normal equations
+/// `A^T * A`) should be computed by passing `a.transpose()` as `lhs`, rather than by copying the
+/// transpose into a new matrix first. For element types without a dedicated gemm backend, when
+/// `A` is tall, the product is evaluated as a blocked sequence of contiguous inner products over
+/// the columns of `A`.
+///
+/// The same holds for conjugated views: `A^H * B` should be computed by passing `a.adjoint()` as
+/// `lhs`. The conjugation is a property of the view, which is forwarded to the kernels so that the
+/// elements are conjugated as they are loaded, and no conjugated copy of `A` is created.
+///
 /// # Example
 ///
 /// ```
@@ -2073,13 +2302,98 @@ pub fn matmul<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<C
     beta: E,
     parallelism: Parallelism,
 ) {
+    if let Err(err) = try_matmul(acc, lhs, rhs, alpha, beta, parallelism) {
+        panic!("{err}");
+    }
+}
+
+/// Same as [`matmul`], except that incompatible dimensions are reported as an error instead of a
+/// panic, in which case `acc` is left untouched.
+///
+/// This is useful when the dimensions come from an untrusted source, e.g. a request to a server,
+/// which should be rejected rather than unwinding.
+///
+/// # Errors
+///
+/// Returns [`MatmulError::InnerDimensionMismatch`] if `lhs.ncols() != rhs.nrows()`, and
+/// [`MatmulError::OutputShapeMismatch`] if the shape of `acc` is not
+/// `(lhs.nrows(), rhs.ncols())`.
+///
+/// # Example
+///
+/// ```
+/// use faer::{
+///     linalg::matmul::{try_matmul, MatmulError},
+///     Mat, Parallelism,
+/// };
+///
+/// let lhs = Mat::<f64>::zeros(2, 3);
+/// let rhs = Mat::<f64>::zeros(4, 5);
+/// let mut acc = Mat::<f64>::zeros(2, 5);
+///
+/// let result = try_matmul(&mut acc, &lhs, &rhs, None, 1.0, Parallelism::None);
+/// assert_eq!(
+///     result,
+///     Err(MatmulError::InnerDimensionMismatch {
+///         lhs_ncols: 3,
+///         rhs_nrows: 4,
+///     }),
+/// );
+/// ```
+pub fn try_matmul<
+    E: ComplexField,
+    LhsE: Conjugate<Canonical = E>,
+    RhsE: Conjugate<Canonical = E>,
+>(
+    acc: impl As2DMut<E>,
+    lhs: impl As2D<LhsE>,
+    rhs: impl As2D<RhsE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) -> Result<(), MatmulError> {
     let mut acc = acc;
     let acc = acc.as_2d_mut();
     let lhs = lhs.as_2d_ref();
     let rhs = rhs.as_2d_ref();
     let (lhs, conj_lhs) = lhs.canonicalize();
     let (rhs, conj_rhs) = rhs.canonicalize();
-    matmul_with_conj::<E>(acc, lhs, conj_lhs, rhs, conj_rhs, alpha, beta, parallelism);
+    try_matmul_with_conj::<E>(acc, lhs, conj_lhs, rhs, conj_rhs, alpha, beta, parallelism)
+}
+
+/// Same as [`matmul`], except that `alpha` and `beta` are real factors, e.g.
+/// `acc = 0.5 * acc + 2.0 * lhs * rhs` for complex matrices.
+///
+/// The factors are converted to `E` and forwarded to [`matmul`]. Scaling by `alpha` and `beta`
+/// only costs $O(mn)$ operations, against $O(mnk)$ for the product itself, so this function is
+/// provided for convenience and isn't measurably faster than [`matmul`] with complex factors.
+/// Elementwise scaling by real factors, on the other hand, is cheaper with
+/// [`ScaleReal`](crate::ScaleReal).
+///
+/// # Panics
+///
+/// Panics if the matrix dimensions are not compatible for matrix multiplication, see [`matmul`].
+#[track_caller]
+pub fn matmul_scale_real<
+    E: ComplexField,
+    LhsE: Conjugate<Canonical = E>,
+    RhsE: Conjugate<Canonical = E>,
+>(
+    acc: impl As2DMut<E>,
+    lhs: impl As2D<LhsE>,
+    rhs: impl As2D<RhsE>,
+    alpha: Option<E::Real>,
+    beta: E::Real,
+    parallelism: Parallelism,
+) {
+    matmul(
+        acc,
+        lhs,
+        rhs,
+        alpha.map(E::faer_from_real),
+        E::faer_from_real(beta),
+        parallelism,
+    );
 }
 
 /// Triangular matrix multiplication module, where some of the operands are treated as triangular
@@ -2096,6 +2410,62 @@ mod tests {
     use assert_approx_eq::assert_approx_eq;
     use num_complex::Complex32;
 
+    #[test]
+    fn test_matmul_scale_real() {
+        let lhs = Mat::from_fn(3, 4, |i, j| c64::new(i as f64, j as f64));
+        let rhs = Mat::from_fn(4, 2, |i, j| c64::new(j as f64, -(i as f64)));
+        let acc0 = Mat::from_fn(3, 2, |i, j| c64::new(1.0, (i + j) as f64));
+
+        let mut acc = acc0.clone();
+        matmul_scale_real(&mut acc, &lhs, &rhs, Some(0.5), 2.0, Parallelism::None);
+        let mut target = acc0.clone();
+        matmul(
+            &mut target,
+            &lhs,
+            &rhs,
+            Some(c64::new(0.5, 0.0)),
+            c64::new(2.0, 0.0),
+            Parallelism::None,
+        );
+        assert!(acc == target);
+    }
+
+    #[test]
+    fn test_try_matmul() {
+        let lhs = Mat::<f64>::from_fn(3, 4, |i, j| (i + j) as f64);
+        let rhs = Mat::<f64>::from_fn(4, 2, |i, j| (i * j) as f64);
+
+        let mut acc = Mat::<f64>::zeros(3, 2);
+        try_matmul(&mut acc, &lhs, &rhs, None, 1.0, Parallelism::None).unwrap();
+        assert!(acc == &lhs * &rhs);
+
+        let mut acc = Mat::<f64>::from_fn(3, 2, |_, _| 7.0);
+        assert!(
+            try_matmul(
+                &mut acc,
+                &lhs,
+                rhs.transpose(),
+                None,
+                1.0,
+                Parallelism::None
+            ) == Err(MatmulError::InnerDimensionMismatch {
+                lhs_ncols: 4,
+                rhs_nrows: 2,
+            })
+        );
+        let mut wrong_acc = Mat::<f64>::from_fn(2, 3, |_, _| 7.0);
+        assert!(
+            try_matmul(&mut wrong_acc, &lhs, &rhs, None, 1.0, Parallelism::None)
+                == Err(MatmulError::OutputShapeMismatch {
+                    acc_shape: (2, 3),
+                    expected_shape: (3, 2),
+                })
+        );
+        // the destination is untouched on failure
+        assert!(acc == Mat::<f64>::from_fn(3, 2, |_, _| 7.0));
+        assert!(wrong_acc == Mat::<f64>::from_fn(2, 3, |_, _| 7.0));
+    }
+
     #[test]
     fn test_stack_mat() {
         stack_mat!([16, 16], m, 3, 3, 1, 3, f64);
@@ -2368,6 +2738,133 @@ mod tests {
         }
     }
 
+    fn check_matmul_adjoint<E: ComplexField>(random: impl Fn() -> E) {
+        let one = E::faer_one();
+        let par = Parallelism::None;
+        let tol = E::Real::faer_from_f64(1e-10);
+        let zero = E::Real::faer_zero();
+
+        // (m, n, k) covering the inner product, outer product, matrix-vector, small and large
+        // matrix product paths
+        let shapes = [
+            (1, 1, 5),
+            (4, 3, 1),
+            (6, 1, 5),
+            (1, 6, 5),
+            (5, 4, 3),
+            (64, 48, 80),
+        ];
+        for (m, n, k) in shapes {
+            let a = Mat::<E>::from_fn(k, m, |_, _| random());
+            let b = Mat::<E>::from_fn(k, n, |_, _| random());
+            let bh = Mat::<E>::from_fn(n, k, |_, _| random());
+            let a_conj = Mat::<E>::from_fn(m, k, |i, j| a.read(j, i).faer_conj());
+            let b_conj = Mat::<E>::from_fn(k, n, |i, j| bh.read(j, i).faer_conj());
+
+            let mut acc = Mat::<E>::zeros(m, n);
+            let mut target = Mat::<E>::zeros(m, n);
+            matmul(&mut acc, a.adjoint(), &b, None, one, par);
+            matmul(&mut target, &a_conj, &b, None, one, par);
+            assert!(acc.approx_eq(&target, tol, zero));
+
+            matmul(&mut acc, a.adjoint(), bh.adjoint(), None, one, par);
+            matmul(&mut target, &a_conj, &b_conj, None, one, par);
+            assert!(acc.approx_eq(&target, tol, zero));
+        }
+    }
+
+    #[test]
+    fn test_matmul_adjoint() {
+        use crate::{complex_native::c64, quad::Double};
+        use num_complex::Complex;
+
+        // the adjoint is a view over the same memory, with the conjugation as a flag
+        let a = Mat::<c64>::from_fn(3, 4, |i, j| c64::new(i as f64, j as f64));
+        let (a_adjoint, conj) = a.adjoint().canonicalize();
+        assert!(conj == Conj::Yes);
+        assert!(a_adjoint.as_ptr() == a.as_ptr());
+
+        check_matmul_adjoint(|| c64::new(rand::random(), rand::random()));
+        check_matmul_adjoint(|| Complex {
+            re: Double(rand::random(), 0.0),
+            im: Double(rand::random(), 0.0),
+        });
+    }
+
+    #[test]
+    fn test_matmul_transposed_lhs() {
+        use crate::quad::Double;
+        use num_complex::Complex64;
+
+        for (m, n, k) in [(1, 3, 3000), (3, 2, 3000), (5, 5, 700), (4, 16, 64)] {
+            let a = Mat::<Double<f64>>::from_fn(k, m, |_, _| Double(rand::random(), 0.0));
+            let b = Mat::<Double<f64>>::from_fn(k, n, |_, _| Double(rand::random(), 0.0));
+            let acc_init = Mat::<Double<f64>>::from_fn(m, n, |_, _| Double(rand::random(), 0.0));
+
+            for parallelism in [Parallelism::None, Parallelism::Rayon(3)] {
+                for alpha in [None, Some(Double(0.5, 0.0))] {
+                    let mut acc = acc_init.clone();
+                    let mut target = acc_init.clone();
+                    matmul_with_conj_gemm_dispatch(
+                        acc.as_mut(),
+                        a.transpose(),
+                        Conj::No,
+                        b.as_ref(),
+                        Conj::No,
+                        alpha,
+                        Double(2.0, 0.0),
+                        parallelism,
+                    );
+                    matmul_with_conj_fallback(
+                        target.as_mut(),
+                        a.transpose(),
+                        Conj::No,
+                        b.as_ref(),
+                        Conj::No,
+                        alpha,
+                        Double(2.0, 0.0),
+                        Parallelism::None,
+                    );
+                    assert!((&acc - &target).norm_max() < Double(1e-20, 0.0));
+                }
+            }
+
+            let a = Mat::<Complex64>::from_fn(k, m, |_, _| {
+                Complex64::new(rand::random(), rand::random())
+            });
+            let b = Mat::<Complex64>::from_fn(k, n, |_, _| {
+                Complex64::new(rand::random(), rand::random())
+            });
+            for conj_a in [Conj::No, Conj::Yes] {
+                for conj_b in [Conj::No, Conj::Yes] {
+                    let mut acc = Mat::<Complex64>::zeros(m, n);
+                    let mut target = Mat::<Complex64>::zeros(m, n);
+                    matmul_with_conj_gemm_dispatch(
+                        acc.as_mut(),
+                        a.transpose(),
+                        conj_a,
+                        b.as_ref(),
+                        conj_b,
+                        None,
+                        Complex64::new(1.0, 0.0),
+                        Parallelism::None,
+                    );
+                    matmul_with_conj_fallback(
+                        target.as_mut(),
+                        a.transpose(),
+                        conj_a,
+                        b.as_ref(),
+                        conj_b,
+                        None,
+                        Complex64::new(1.0, 0.0),
+                        Parallelism::None,
+                    );
+                    assert!((&acc - &target).norm_max() < 1e-10);
+                }
+            }
+        }
+    }
+
     fn generate_structured_matrix(
         is_dst: bool,
         nrows: usize,
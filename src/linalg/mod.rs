@@ -49,6 +49,21 @@
 //! preferred CPU layout for SIMD operations. And for native types, since [`Group<T>` is just
 //! `T`](Entity#impl-Entity-for-f64), the entity layer is a no-op, and the matrix layout is
 //! compatible with the classic contiguous layout that's commonly used by other libraries.
+//!
+//! # Non-finite inputs
+//! The decompositions do not reject inputs containing infinities or NaNs, and they always
+//! terminate on such inputs. Instead, the non-finite values propagate to the output, so that
+//! [`MatRef::is_all_finite`] can be used either on the input, to reject it up front, or on the
+//! results. Only the part of the input that is actually read by the algorithm is relevant, e.g. a
+//! NaN in the unused triangle of a self-adjoint matrix has no effect.
+//!
+//! - The direct factorizations (LU, QR, and the Cholesky-like factorizations) perform a number
+//!   of operations that only depends on the dimensions of the input. Non-finite values propagate
+//!   through the arithmetic to the factors, so at least one of them is not finite. The pivoting
+//!   is still deterministic, but the chosen permutation is unspecified. The $LL^H$ Cholesky
+//!   factorization may instead return an error, since a NaN pivot is not positive.
+//! - The iterative algorithms (SVD and eigenvalue decompositions) check the input before
+//!   iterating, and fill all their outputs with NaN if it contains any non-finite value.
 
 use crate::{
     mat::{self, matalloc::align_for, *},
@@ -63,15 +78,17 @@ pub mod zip;
 
 #[cfg(feature = "qr")]
 pub mod householder;
-#[doc(hidden)]
 pub mod jacobi;
 
+pub mod blas1;
 pub mod matmul;
 pub mod triangular_inverse;
 pub mod triangular_solve;
 
 #[cfg(feature = "cholesky")]
 pub mod cholesky;
+#[cfg(feature = "qr")]
+pub mod lq;
 #[cfg(feature = "lu")]
 pub mod lu;
 #[cfg(feature = "qr")]
@@ -82,14 +99,23 @@ pub mod evd;
 #[cfg(feature = "svd")]
 pub mod svd;
 
+#[cfg(feature = "svd")]
+pub mod csd;
+
 /// High level linear system solvers.
 pub mod solvers;
 
+pub mod orthonormalize;
+pub mod residual;
+
+pub(crate) mod closed_form;
 pub(crate) mod kron_impl;
 mod mat_ops;
 pub(crate) mod reductions;
 
 pub use kron_impl::kron;
+#[cfg(feature = "svd")]
+pub use orthonormalize::subspace_angles;
 
 #[inline]
 pub(crate) fn col_stride<Unit: 'static>(nrows: usize) -> usize {
@@ -125,6 +151,21 @@ pub fn temp_mat_req<E: Entity>(nrows: usize, ncols: usize) -> Result<StackReq, S
     req
 }
 
+/// In debug builds, fills the strictly upper triangular part of `mat` with NaNs.
+///
+/// Used by the routines that are documented to only read the lower triangular part of a Hermitian
+/// matrix, so that an accidental dependency on the other half shows up as NaNs in the output
+/// instead of silently reading stale values.
+#[inline]
+pub(crate) fn debug_poison_strictly_upper<E: ComplexField>(mat: MatMut<'_, E>) {
+    if cfg!(debug_assertions) {
+        crate::zipped_rw!(mat)
+            .for_each_triangular_upper(zip::Diag::Skip, |crate::unzipped!(mut x)| {
+                x.write(E::faer_nan())
+            });
+    }
+}
+
 /// Creates a temporary matrix of constant values, from the given memory stack.
 pub fn temp_mat_constant<E: ComplexField>(
     nrows: usize,
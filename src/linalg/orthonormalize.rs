@@ -0,0 +1,264 @@
+//! Orthonormalization of the columns of a matrix, operating directly on the stored columns.
+//!
+//! Unlike the [QR decomposition](crate::linalg::qr), which stores an implicit representation of
+//! the orthonormal factor, these routines overwrite the input with an explicit orthonormal basis,
+//! which is convenient for building Krylov and other subspace bases one column at a time.
+//!
+//! Orthonormal bases are also used to compare subspaces through their principal angles, which are
+//! invariant under a change of basis within each subspace.
+
+use crate::{
+    linalg::matmul::inner_prod::inner_prod_with_conj, unzipped, zipped_rw, Col, ColMut, ColRef,
+    ComplexField, Conj, MatMut, RealField,
+};
+#[cfg(feature = "svd")]
+use crate::{Mat, MatRef};
+use reborrow::*;
+
+/// A second orthogonalization pass is performed whenever the norm of a column drops below this
+/// fraction of its norm before the pass.
+///
+/// This is the criterion of Kahan and Parlett ("twice is enough"): if the norm still drops by more
+/// than this factor after the second pass, the column is numerically in the span of the previous
+/// ones.
+const KAPPA: f64 = core::f64::consts::FRAC_1_SQRT_2;
+
+/// Subtracts the projection of `v` onto each of the columns of `q`, using the updated `v` for
+/// each successive projection.
+fn project_out<'a, E: ComplexField>(q: impl Iterator<Item = ColRef<'a, E>>, v: ColMut<'_, E>) {
+    let mut v = v;
+    for q in q {
+        let r = inner_prod_with_conj(q, Conj::Yes, v.rb(), Conj::No).faer_neg();
+        zipped_rw!(v.rb_mut(), q).for_each(|unzipped!(mut v, q)| {
+            v.write(v.read().faer_add(r.faer_mul(q.read())));
+        });
+    }
+}
+
+/// Orthonormalizes the columns of `a` in place using the modified Gram-Schmidt process, and
+/// returns the norm of each column after it has been orthogonalized against the previous ones.
+///
+/// On output, the nonzero columns of `a` are orthonormal, and span the same space as the columns
+/// of the input. Each column is projected out of the previous nonzero columns once, and a second
+/// time if its norm dropped sharply during the first pass, which recovers orthogonality to working
+/// precision.
+///
+/// A column is considered collapsed if it is numerically in the span of the previous ones, i.e. if
+/// its norm still drops sharply during the second pass, or if what remains of it is no larger than
+/// the rounding error of the projections, `nrows * epsilon` times its original norm. In particular,
+/// zero columns are always collapsed. Collapsed columns are set to zero, and their returned norm is
+/// zero, so that the rank deficiency of the input can be read off the returned values.
+///
+/// For a full rank input, the returned norms are the absolute values of the diagonal of the $R$
+/// factor of the QR decomposition.
+pub fn modified_gram_schmidt<E: ComplexField>(a: MatMut<'_, E>) -> Col<E::Real> {
+    let mut a = a;
+    let (m, n) = a.shape();
+    let kappa = E::Real::faer_from_f64(KAPPA);
+    let rel_tol = E::Real::faer_epsilon().faer_mul(E::Real::faer_from_f64(Ord::max(m, 1) as f64));
+    let mut norms = Col::<E::Real>::zeros(n);
+
+    for j in 0..n {
+        let (prev, rest) = a.rb_mut().split_at_col_mut(j);
+        let prev = prev.rb();
+        let mut v = rest.col_mut(0);
+
+        let mut norm = v.norm_l2();
+        let tol = rel_tol.faer_mul(norm);
+        let mut collapsed = true;
+        for _ in 0..2 {
+            project_out(
+                (0..j)
+                    .filter(|&k| norms.read(k) != E::Real::faer_zero())
+                    .map(|k| prev.col(k)),
+                v.rb_mut(),
+            );
+            let new_norm = v.norm_l2();
+            let kept = new_norm >= kappa.faer_mul(norm);
+            norm = new_norm;
+            if norm <= tol {
+                break;
+            }
+            if kept {
+                collapsed = false;
+                break;
+            }
+        }
+
+        if collapsed {
+            v.fill_zero();
+        } else {
+            let inv = norm.faer_inv();
+            zipped_rw!(v.rb_mut()).for_each(|unzipped!(mut v)| {
+                v.write(v.read().faer_scale_real(inv));
+            });
+            norms.write(j, norm);
+        }
+    }
+
+    norms
+}
+
+/// Returns an orthonormal basis of the column space of `a`.
+#[cfg(feature = "svd")]
+fn orthonormal_basis<E: ComplexField>(a: MatRef<'_, E>) -> Mat<E> {
+    let mut q = a.to_owned();
+    let norms = modified_gram_schmidt(q.as_mut());
+    let cols = (0..a.ncols())
+        .filter(|&j| norms.read(j) != E::Real::faer_zero())
+        .collect::<alloc::vec::Vec<_>>();
+    Mat::from_fn(a.nrows(), cols.len(), |i, j| q.read(i, cols[j]))
+}
+
+/// Returns the principal angles between the column spaces of `a` and `b`, in nondecreasing order.
+///
+/// The columns of each matrix are first orthonormalized with [`modified_gram_schmidt`], yielding
+/// bases $Q_A$ and $Q_B$. The cosines of the angles are the singular values of $Q_A^H Q_B$, and
+/// their sines are the singular values of $Q_B - Q_A Q_A^H Q_B$. Each angle is computed from
+/// whichever of the two is less sensitive to rounding errors, so that both small and large angles
+/// are accurate.
+///
+/// The number of angles is the smaller of the dimensions of the two column spaces. Columns that
+/// are numerically dependent on the previous ones don't contribute to the dimension.
+///
+/// # Panics
+/// Panics if `a.nrows() != b.nrows()`.
+#[cfg(feature = "svd")]
+#[track_caller]
+pub fn subspace_angles<E: ComplexField>(a: MatRef<'_, E>, b: MatRef<'_, E>) -> Col<E::Real> {
+    assert!(a.nrows() == b.nrows());
+
+    let mut qa = orthonormal_basis(a);
+    let mut qb = orthonormal_basis(b);
+    if qa.ncols() < qb.ncols() {
+        core::mem::swap(&mut qa, &mut qb);
+    }
+
+    let k = qb.ncols();
+    if k == 0 {
+        return Col::zeros(0);
+    }
+
+    let proj = qa.adjoint() * &qb;
+    let residual = &qb - &qa * &proj;
+    let cos = proj.singular_values();
+    let sin = residual.singular_values();
+
+    let one = E::Real::faer_one();
+    let half = E::Real::faer_from_f64(0.5);
+    Col::from_fn(k, |i| {
+        let cos = cos[i];
+        if cos.faer_mul(cos) < half {
+            crate::utils::math::acos(if cos > one { one } else { cos })
+        } else {
+            let sin = sin[k - 1 - i];
+            crate::utils::math::asin(if sin > one { one } else { sin })
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Mat};
+
+    fn orthonormality_error<E: ComplexField>(q: crate::MatRef<'_, E>) -> E::Real {
+        let qhq = q.adjoint() * q;
+        let mut err = E::Real::faer_zero();
+        for j in 0..q.ncols() {
+            for i in 0..q.ncols() {
+                let expected = if i == j && q.col(j).norm_l2() != E::Real::faer_zero() {
+                    E::faer_one()
+                } else {
+                    E::faer_zero()
+                };
+                let e = qhq.read(i, j).faer_sub(expected).faer_abs();
+                if e > err {
+                    err = e;
+                }
+            }
+        }
+        err
+    }
+
+    #[test]
+    fn test_mgs() {
+        let a = Mat::from_fn(7, 4, |i, j| {
+            c64::new((i + 2 * j) as f64 + 1.0, (i * j) as f64)
+        });
+        let mut q = a.clone();
+        let norms = modified_gram_schmidt(q.as_mut());
+        assert!(orthonormality_error(q.as_ref()) < 1e-14);
+
+        let r = q.adjoint() * &a;
+        assert!((&q * &r - &a).norm_max() < 1e-12);
+        for j in 0..4 {
+            assert!((r.read(j, j).abs() - norms.read(j)).abs() < 1e-12);
+        }
+
+        // nearly dependent columns need the second pass
+        let eps = 1e-10f64;
+        let a = Mat::from_fn(5, 2, |i, j| 1.0 + if j == 1 && i == 0 { eps } else { 0.0 });
+        let mut q = a.clone();
+        let norms = modified_gram_schmidt(q.as_mut());
+        assert!(norms.read(1) > 0.0);
+        assert!(orthonormality_error(q.as_ref()) < 1e-14);
+    }
+
+    #[test]
+    fn test_mgs_rank_deficient() {
+        let a = Mat::from_fn(6, 5, |i, j| match j {
+            0 => i as f64,
+            1 => 2.0 * i as f64,
+            2 => 0.0,
+            3 => 1.0,
+            _ => (i as f64) - 3.0,
+        });
+        let mut q = a.clone();
+        let norms = modified_gram_schmidt(q.as_mut());
+
+        let collapsed: Vec<bool> = norms.iter().map(|&x| x == 0.0).collect();
+        assert!(collapsed == [false, true, true, false, true]);
+        assert!(orthonormality_error(q.as_ref()) < 1e-14);
+        for j in [1, 2, 4] {
+            assert!(q.col(j).norm_max() == 0.0);
+        }
+
+        let mut empty = Mat::<f64>::zeros(3, 0);
+        assert!(modified_gram_schmidt(empty.as_mut()).nrows() == 0);
+    }
+
+    #[test]
+    #[cfg(feature = "svd")]
+    fn test_subspace_angles() {
+        for t in [1e-9, 0.3, 1.2, core::f64::consts::FRAC_PI_2] {
+            let a = crate::mat![[1.0, 0.0], [0.0, 1.0], [0.0, 0.0], [0.0, 0.0f64]];
+            let b = crate::mat![[2.0, 2.0], [0.0, t.cos()], [0.0, t.sin()], [0.0, 0.0f64],];
+            let angles = subspace_angles(a.as_ref(), b.as_ref());
+            assert!(angles.nrows() == 2);
+            assert!(angles.read(0).abs() < 1e-15);
+            assert!((angles.read(1) - t).abs() < 1e-15 * (1.0 + t));
+
+            // the angles only depend on the column spaces
+            let c = crate::mat![[1.0, 3.0, 0.5], [-2.0, 1.0, 2.0f64]];
+            let bc = &b * &c;
+            let angles2 = subspace_angles(bc.as_ref(), a.as_ref());
+            assert!((&angles2 - &angles).norm_max() < 1e-14);
+        }
+
+        let a = Mat::from_fn(5, 1, |i, _| c64::new(i as f64, 1.0));
+        let b = Mat::from_fn(5, 2, |i, j| {
+            if j == 0 {
+                a.read(i, 0) * c64::new(0.0, 2.0)
+            } else {
+                c64::new(1.0, (i * i) as f64)
+            }
+        });
+        let angles = subspace_angles(a.as_ref(), b.as_ref());
+        assert!(angles.nrows() == 1);
+        assert!(angles.read(0).abs() < 1e-14);
+
+        let z = Mat::<f64>::zeros(4, 2);
+        assert!(subspace_angles(z.as_ref(), z.as_ref()).nrows() == 0);
+    }
+}
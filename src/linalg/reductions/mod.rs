@@ -3,4 +3,5 @@ const LINEAR_IMPL_THRESHOLD: usize = 128;
 pub mod norm_l1;
 pub mod norm_l2;
 pub mod norm_max;
+pub mod spectral_norm;
 pub mod sum;
@@ -1,7 +1,10 @@
 use super::LINEAR_IMPL_THRESHOLD;
 use crate::{
+    assert,
+    col::ColMut,
     complex_native::*,
     mat::MatRef,
+    row::RowMut,
     utils::{simd::*, slice::*},
 };
 use coe::Coerce;
@@ -268,6 +271,45 @@ pub fn norm_l1<E: ComplexField>(mut mat: MatRef<'_, E>) -> E::Real {
     }
 }
 
+/// Computes the L1 norm of each row of `mat`, traversing it in column-major order.
+fn row_norms_l1_column_major<E: ComplexField>(mat: MatRef<'_, E>, out: ColMut<'_, E::Real>) {
+    let mut out = out;
+    out.fill_zero();
+    for j in 0..mat.ncols() {
+        for i in 0..mat.nrows() {
+            let val = mat.read(i, j);
+            out.write(
+                i,
+                out.read(i).faer_add(
+                    val.faer_real()
+                        .faer_abs()
+                        .faer_add(val.faer_imag().faer_abs()),
+                ),
+            );
+        }
+    }
+}
+
+/// Computes the L1 norm of each column of `mat`, and stores the results in `out`.
+#[track_caller]
+pub fn col_norms_l1<E: ComplexField>(mat: MatRef<'_, E>, out: RowMut<'_, E::Real>) {
+    assert!(out.ncols() == mat.ncols());
+    let mut out = out;
+    if mat.nrows() > 1 && mat.row_stride().unsigned_abs() > mat.col_stride().unsigned_abs() {
+        row_norms_l1_column_major(mat.transpose(), out.transpose_mut());
+    } else {
+        for j in 0..mat.ncols() {
+            out.write(j, norm_l1(mat.col(j).as_2d()));
+        }
+    }
+}
+
+/// Computes the L1 norm of each row of `mat`, and stores the results in `out`.
+#[track_caller]
+pub fn row_norms_l1<E: ComplexField>(mat: MatRef<'_, E>, out: ColMut<'_, E::Real>) {
+    col_norms_l1(mat.transpose(), out.transpose_mut());
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{assert, prelude::*, unzipped, zipped_rw};
@@ -296,4 +338,30 @@ mod tests {
         let target = 0.3 * 10000000.0f64;
         assert!(relative_err(mat.norm_l1(), target) < 1e-14);
     }
+
+    #[test]
+    fn test_axis_norms_l1_max() {
+        let mat = Mat::from_fn(7, 5, |i, j| c64::new(i as f64 - 3.0, 2.0 - j as f64));
+        let row_major = mat.transpose().to_owned();
+        let row_major = row_major.transpose();
+
+        for mat in [mat.as_ref(), row_major, mat.as_ref().subrows(1, 5)] {
+            let (l1, max) = (mat.col_norms_l1(), mat.col_norms_max());
+            for j in 0..mat.ncols() {
+                assert!(l1.read(j) == mat.col(j).norm_l1());
+                assert!(max.read(j) == mat.col(j).norm_max());
+            }
+            let (l1, max) = (mat.row_norms_l1(), mat.row_norms_max());
+            for i in 0..mat.nrows() {
+                assert!(l1.read(i) == mat.row(i).norm_l1());
+                assert!(max.read(i) == mat.row(i).norm_max());
+            }
+        }
+
+        // strided columns of a row-major matrix with negative entries
+        let mat = mat![[-5.0, 1.0], [1.0, -2.0f64]];
+        let row_major = mat.transpose().to_owned();
+        assert!(row_major.transpose().col_norms_max() == row![5.0, 2.0]);
+        assert!(row_major.transpose().col(0).norm_max() == 5.0);
+    }
 }
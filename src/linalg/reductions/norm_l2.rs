@@ -1,7 +1,10 @@
 use super::LINEAR_IMPL_THRESHOLD;
 use crate::{
+    assert,
+    col::{Col, ColMut},
     complex_native::*,
     mat::MatRef,
+    row::RowMut,
     utils::{simd::*, slice::*},
 };
 use faer_entity::*;
@@ -307,16 +310,77 @@ pub fn norm_l2<E: ComplexField>(mut mat: MatRef<'_, E>) -> E::Real {
             }
         }
 
-        if acc_small >= E::Real::faer_one() {
-            acc_small.faer_sqrt().faer_mul(half_big)
-        } else if acc_big <= E::Real::faer_one() {
-            acc_big.faer_sqrt().faer_mul(half_small)
-        } else {
-            acc.faer_sqrt()
+        finish_scaled::<E>(acc_small, acc, acc_big)
+    }
+}
+
+/// Combines the sums of squares of the values scaled down, unscaled, and scaled up, picking the
+/// one that neither overflowed nor underflowed.
+#[inline]
+fn finish_scaled<E: ComplexField>(acc_small: E::Real, acc: E::Real, acc_big: E::Real) -> E::Real {
+    let half_small = E::Real::faer_min_positive_sqrt();
+    let half_big = E::Real::faer_min_positive_sqrt_inv();
+
+    if acc_small >= E::Real::faer_one() {
+        acc_small.faer_sqrt().faer_mul(half_big)
+    } else if acc_big <= E::Real::faer_one() {
+        acc_big.faer_sqrt().faer_mul(half_small)
+    } else {
+        acc.faer_sqrt()
+    }
+}
+
+/// Computes the L2 norm of each row of `mat`, traversing it in column-major order.
+fn row_norms_l2_column_major<E: ComplexField>(mat: MatRef<'_, E>, out: ColMut<'_, E::Real>) {
+    let m = mat.nrows();
+    let half_small = E::Real::faer_min_positive_sqrt();
+    let half_big = E::Real::faer_min_positive_sqrt_inv();
+
+    let mut out = out;
+    let mut acc_small = Col::<E::Real>::zeros(m);
+    let mut acc_big = Col::<E::Real>::zeros(m);
+    out.fill_zero();
+
+    for j in 0..mat.ncols() {
+        for i in 0..m {
+            let val = mat.read(i, j);
+            let val_small = val.faer_scale_power_of_two(half_small);
+            let val_big = val.faer_scale_power_of_two(half_big);
+
+            acc_small.write(i, acc_small.read(i).faer_add(val_small.faer_abs2()));
+            out.write(i, out.read(i).faer_add(val.faer_abs2()));
+            acc_big.write(i, acc_big.read(i).faer_add(val_big.faer_abs2()));
+        }
+    }
+
+    for i in 0..m {
+        out.write(
+            i,
+            finish_scaled::<E>(acc_small.read(i), out.read(i), acc_big.read(i)),
+        );
+    }
+}
+
+/// Computes the L2 norm of each column of `mat`, and stores the results in `out`.
+#[track_caller]
+pub fn col_norms_l2<E: ComplexField>(mat: MatRef<'_, E>, out: RowMut<'_, E::Real>) {
+    assert!(out.ncols() == mat.ncols());
+    let mut out = out;
+    if mat.nrows() > 1 && mat.row_stride().unsigned_abs() > mat.col_stride().unsigned_abs() {
+        row_norms_l2_column_major(mat.transpose(), out.transpose_mut());
+    } else {
+        for j in 0..mat.ncols() {
+            out.write(j, norm_l2(mat.col(j).as_2d()));
         }
     }
 }
 
+/// Computes the L2 norm of each row of `mat`, and stores the results in `out`.
+#[track_caller]
+pub fn row_norms_l2<E: ComplexField>(mat: MatRef<'_, E>, out: ColMut<'_, E::Real>) {
+    col_norms_l2(mat.transpose(), out.transpose_mut());
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{assert, prelude::*, unzipped, zipped_rw};
@@ -345,4 +409,40 @@ mod tests {
         let target = (0.3 * 0.3 * 10000000.0f64).sqrt();
         assert!(relative_err(mat.norm_l2(), target) < 1e-14);
     }
+
+    #[test]
+    fn test_axis_norms_l2() {
+        let relative_err = |a: f64, b: f64| (a - b).abs() / f64::max(a.abs(), b.abs());
+
+        for (m, n) in [(9, 10), (1023, 5), (1, 7), (0, 3)] {
+            for factor in [1.0, 1e250, 1e-250] {
+                let mat = Mat::from_fn(m, n, |i, j| factor * ((i + 2 * j) as f64 - 3.0));
+                let row_major = mat.transpose().to_owned();
+                let row_major = row_major.transpose();
+
+                for mat in [mat.as_ref(), row_major, mat.as_ref().reverse_cols()] {
+                    let col_norms = mat.col_norms_l2();
+                    for j in 0..n {
+                        let target = mat.col(j).norm_l2();
+                        let norm = col_norms.read(j);
+                        assert!(norm == target || relative_err(norm, target) < 1e-14);
+                    }
+                    let row_norms = mat.row_norms_l2();
+                    for i in 0..m {
+                        let target = mat.row(i).norm_l2();
+                        let norm = row_norms.read(i);
+                        assert!(norm == target || relative_err(norm, target) < 1e-14);
+                    }
+                }
+            }
+        }
+
+        let mut mat = mat![[3.0, 0.0, 1e-300], [4.0, 0.0, 1e-300f64]];
+        mat.normalize_cols_in_place();
+        assert!(mat.col(1).norm_max() == 0.0);
+        for j in [0, 2] {
+            assert!((mat.col(j).norm_l2() - 1.0).abs() < 1e-15);
+        }
+        assert!((mat.read(0, 0) - 0.6).abs() < 1e-15);
+    }
 }
@@ -1,6 +1,9 @@
 use crate::{
+    assert,
+    col::ColMut,
     complex_native::*,
     mat::MatRef,
+    row::RowMut,
     utils::{simd::*, slice::*},
 };
 use faer_entity::*;
@@ -102,7 +105,7 @@ pub fn norm_max<E: ComplexField>(mut mat: MatRef<'_, E>) -> E::Real {
                         2 * mat.nrows(),
                         mat.ncols(),
                         1,
-                        2 * mat.col_stride(),
+                        mat.col_stride().wrapping_mul(2),
                     )
                 };
                 return coe::coerce_static(norm_max_contiguous::<f32>(mat));
@@ -114,7 +117,7 @@ pub fn norm_max<E: ComplexField>(mut mat: MatRef<'_, E>) -> E::Real {
                         2 * mat.nrows(),
                         mat.ncols(),
                         1,
-                        2 * mat.col_stride(),
+                        mat.col_stride().wrapping_mul(2),
                     )
                 };
                 return coe::coerce_static(norm_max_contiguous::<f64>(mat));
@@ -135,8 +138,8 @@ pub fn norm_max<E: ComplexField>(mut mat: MatRef<'_, E>) -> E::Real {
         for j in 0..n {
             for i in 0..m {
                 let val = mat.read(i, j);
-                let re = val.faer_real();
-                let im = val.faer_imag();
+                let re = val.faer_real().faer_abs();
+                let im = val.faer_imag().faer_abs();
                 acc = if re > acc { re } else { acc };
                 acc = if im > acc { im } else { acc };
             }
@@ -144,3 +147,40 @@ pub fn norm_max<E: ComplexField>(mut mat: MatRef<'_, E>) -> E::Real {
         acc
     }
 }
+
+/// Computes the maximum norm of each row of `mat`, traversing it in column-major order.
+fn row_norms_max_column_major<E: ComplexField>(mat: MatRef<'_, E>, out: ColMut<'_, E::Real>) {
+    let mut out = out;
+    out.fill_zero();
+    for j in 0..mat.ncols() {
+        for i in 0..mat.nrows() {
+            let val = mat.read(i, j);
+            let re = val.faer_real().faer_abs();
+            let im = val.faer_imag().faer_abs();
+            let mut acc = out.read(i);
+            acc = if re > acc { re } else { acc };
+            acc = if im > acc { im } else { acc };
+            out.write(i, acc);
+        }
+    }
+}
+
+/// Computes the maximum norm of each column of `mat`, and stores the results in `out`.
+#[track_caller]
+pub fn col_norms_max<E: ComplexField>(mat: MatRef<'_, E>, out: RowMut<'_, E::Real>) {
+    assert!(out.ncols() == mat.ncols());
+    let mut out = out;
+    if mat.nrows() > 1 && mat.row_stride().unsigned_abs() > mat.col_stride().unsigned_abs() {
+        row_norms_max_column_major(mat.transpose(), out.transpose_mut());
+    } else {
+        for j in 0..mat.ncols() {
+            out.write(j, norm_max(mat.col(j).as_2d()));
+        }
+    }
+}
+
+/// Computes the maximum norm of each row of `mat`, and stores the results in `out`.
+#[track_caller]
+pub fn row_norms_max<E: ComplexField>(mat: MatRef<'_, E>, out: ColMut<'_, E::Real>) {
+    col_norms_max(mat.transpose(), out.transpose_mut());
+}
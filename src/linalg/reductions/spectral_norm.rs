@@ -0,0 +1,115 @@
+use crate::{col::Col, linalg::matmul::matmul, mat::MatRef, unzipped, zipped_rw, Parallelism};
+use faer_entity::*;
+
+/// Estimates the spectral norm (the largest singular value) of `mat` by power iteration on
+/// $A^H A$.
+///
+/// The iteration stops once two successive estimates are within a relative distance `tol` of each
+/// other, or after `max_iters` iterations. Returns the estimate, along with `true` if the stopping
+/// criterion was met, or `false` if the iteration budget was exhausted first.
+///
+/// The estimate is always a lower bound of the exact value, up to rounding errors.
+#[track_caller]
+pub fn spectral_norm<E: ComplexField>(
+    mat: MatRef<'_, E>,
+    tol: E::Real,
+    max_iters: usize,
+    parallelism: Parallelism,
+) -> (E::Real, bool) {
+    let m = mat.nrows();
+    let n = mat.ncols();
+    let zero = E::Real::faer_zero();
+
+    if m == 0 || n == 0 || mat.norm_l2() == zero {
+        return (zero, true);
+    }
+
+    // start from a fixed vector with distinct positive entries. unlike a coordinate vector, it
+    // is very unlikely to be orthogonal to the dominant right singular vector
+    let mut v = Col::<E>::from_fn(n, |j| {
+        let x = (j as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 11;
+        E::faer_from_f64(1.0 + x as f64 / (1u64 << 53) as f64)
+    });
+    let norm_inv = v.norm_l2().faer_inv();
+    zipped_rw!(v.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(norm_inv)));
+
+    let mut av = Col::<E>::zeros(m);
+
+    let mut estimate = zero;
+    for _ in 0..max_iters {
+        matmul(
+            av.as_mut(),
+            mat,
+            v.as_ref(),
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        let sigma = av.norm_l2();
+        let converged = (sigma.faer_sub(estimate)).faer_abs() <= tol.faer_mul(sigma);
+        estimate = sigma;
+        if converged {
+            return (estimate, true);
+        }
+
+        matmul(
+            v.as_mut(),
+            mat.adjoint(),
+            av.as_ref(),
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        let norm_inv = v.norm_l2().faer_inv();
+        zipped_rw!(v.as_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(norm_inv)));
+    }
+
+    (estimate, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert, prelude::*, Parallelism};
+
+    #[test]
+    #[cfg(feature = "svd")]
+    fn test_spectral_norm() {
+        let a = mat![[3.0, 0.0, 0.0], [0.0, -5.0, 0.0], [0.0, 0.0, 1.0f64]];
+        let (norm, converged) = a.spectral_norm(1e-14, 100);
+        assert!(converged);
+        assert!((norm - 5.0).abs() < 1e-12);
+
+        let a = Mat::from_fn(13, 7, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+        let exact = a.singular_values()[0];
+        let (norm, converged) = super::spectral_norm(a.as_ref(), 1e-14, 1000, Parallelism::None);
+        assert!(converged);
+        assert!((norm - exact).abs() < 1e-6 * exact);
+
+        let a = Mat::from_fn(4, 6, |i, j| c64::new(i as f64 + 1.0, j as f64 - 2.0));
+        let exact = a.singular_values()[0];
+        let (norm, converged) = a.spectral_norm(1e-14, 1000);
+        assert!(converged);
+        assert!((norm - exact).abs() < 1e-6 * exact);
+
+        let (norm, converged) = Mat::<f64>::zeros(3, 4).spectral_norm(1e-14, 10);
+        assert!(all(norm == 0.0, converged));
+        let (_, converged) = a.spectral_norm(0.0, 1);
+        assert!(!converged);
+    }
+
+    #[test]
+    fn test_spectral_norm_generic_start() {
+        // A^H A = [[3, 0, 0], [0, 2.5, 1], [0, 1, 2.5]], whose dominant eigenvector (0, 1, 1) is
+        // orthogonal to the column of A with the largest norm
+        let a = 3.5f64.sqrt();
+        let b = 1.5f64.sqrt();
+        let p = (a + b) / 2.0;
+        let q = (a - b) / 2.0;
+        let mat = mat![[3.0f64.sqrt(), 0.0, 0.0], [0.0, p, q], [0.0, q, p]];
+
+        let (norm, converged) = mat.spectral_norm(1e-14, 1000);
+        assert!(converged);
+        assert!((norm - a).abs() < 1e-6 * a);
+    }
+}
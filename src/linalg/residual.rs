@@ -0,0 +1,199 @@
+//! Diagnostics for assessing the accuracy of a computed solution $X$ of a linear system
+//! $AX = B$.
+//!
+//! When the right-hand side has several columns, each column is treated as a separate system, and
+//! the worst error among them is reported.
+
+use crate::{
+    assert, linalg::matmul::matmul, unzipped, zipped_rw, ComplexField, Mat, MatRef, Parallelism,
+};
+use faer_entity::*;
+
+/// Returns the residual $B - AX$.
+///
+/// # Panics
+/// Panics if any of the following conditions are violated:
+/// * `a.ncols() == x.nrows()`.
+/// * `a.nrows() == b.nrows()`.
+/// * `x.ncols() == b.ncols()`.
+#[track_caller]
+pub fn residual<E: ComplexField>(
+    a: MatRef<'_, E>,
+    x: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    parallelism: Parallelism,
+) -> Mat<E> {
+    assert!(all(
+        a.ncols() == x.nrows(),
+        a.nrows() == b.nrows(),
+        x.ncols() == b.ncols(),
+    ));
+    let mut r = b.to_owned();
+    matmul(
+        r.as_mut(),
+        a,
+        x,
+        Some(E::faer_one()),
+        E::faer_one().faer_neg(),
+        parallelism,
+    );
+    r
+}
+
+fn ratio<E: RealField>(num: E, den: E) -> E {
+    if num == E::faer_zero() {
+        E::faer_zero()
+    } else {
+        num.faer_div(den)
+    }
+}
+
+fn abs<E: ComplexField>(mat: MatRef<'_, E>) -> Mat<E::Real> {
+    Mat::from_fn(mat.nrows(), mat.ncols(), |i, j| mat.read(i, j).faer_abs())
+}
+
+fn max<E: RealField>(values: impl Iterator<Item = E>) -> E {
+    values.fold(
+        E::faer_zero(),
+        |acc, value| if value > acc { value } else { acc },
+    )
+}
+
+fn col_norm_inf<E: ComplexField>(mat: MatRef<'_, E>, j: usize) -> E::Real {
+    max((0..mat.nrows()).map(|i| mat.read(i, j).faer_abs()))
+}
+
+/// Returns the normwise relative residual
+/// $$\max_j \frac{\|B_j - AX_j\|_\infty}{\|A\|_\infty \|X_j\|_\infty + \|B_j\|_\infty},$$
+/// where $X_j$ and $B_j$ are the columns of $X$ and $B$.
+///
+/// This is the normwise backward error of $X$, i.e. the smallest $\varepsilon$ such that each
+/// column of $X$ solves a system with a perturbed matrix $A + \Delta A$ and right-hand side
+/// $B_j + \Delta B_j$, where $\|\Delta A\|_\infty \le \varepsilon \|A\|_\infty$ and $\|\Delta
+/// B_j\|_\infty \le \varepsilon \|B_j\|_\infty$.
+///
+/// # Panics
+/// Panics under the same conditions as [`residual`].
+#[track_caller]
+pub fn relative_residual_norm<E: ComplexField>(
+    a: MatRef<'_, E>,
+    x: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    parallelism: Parallelism,
+) -> E::Real {
+    let r = residual(a, x, b, parallelism);
+
+    let norm_a = max((0..a.nrows()).map(|i| {
+        (0..a.ncols()).fold(E::Real::faer_zero(), |acc, j| {
+            acc.faer_add(a.read(i, j).faer_abs())
+        })
+    }));
+
+    max((0..r.ncols()).map(|j| {
+        ratio(
+            col_norm_inf(r.as_ref(), j),
+            norm_a
+                .faer_mul(col_norm_inf(x, j))
+                .faer_add(col_norm_inf(b, j)),
+        )
+    }))
+}
+
+/// Returns the componentwise backward error of Oettli and Prager
+/// $$\max_{i, j} \frac{|B - AX|_{ij}}{(|A| |X| + |B|)_{ij}},$$
+/// where $|\cdot|$ denotes the elementwise absolute value.
+///
+/// This is the smallest $\varepsilon$ such that each column of $X$ solves a system with a
+/// perturbed matrix $A + \Delta A$ and right-hand side $B_j + \Delta B_j$, where
+/// $|\Delta A| \le \varepsilon |A|$ and $|\Delta B_j| \le \varepsilon |B_j|$ elementwise. In
+/// particular, it is sensitive to the sparsity pattern and scaling of the system, unlike
+/// [`relative_residual_norm`].
+///
+/// If a component of the residual is nonzero while the corresponding denominator is zero, the
+/// result is infinite.
+///
+/// # Panics
+/// Panics under the same conditions as [`residual`].
+#[track_caller]
+pub fn componentwise_backward_error<E: ComplexField>(
+    a: MatRef<'_, E>,
+    x: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    parallelism: Parallelism,
+) -> E::Real {
+    let r = residual(a, x, b, parallelism);
+
+    let mut den = abs(b);
+    matmul(
+        den.as_mut(),
+        abs(a).as_ref(),
+        abs(x).as_ref(),
+        Some(E::Real::faer_one()),
+        E::Real::faer_one(),
+        parallelism,
+    );
+
+    let mut err = E::Real::faer_zero();
+    zipped_rw!(r.as_ref(), den.as_ref()).for_each(|unzipped!(r, den)| {
+        let elem_err = ratio(r.read().faer_abs(), den.read());
+        if elem_err > err {
+            err = elem_err;
+        }
+    });
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, mat};
+
+    #[test]
+    fn test_residual() {
+        let a = mat![[2.0, 1.0], [1.0, 3.0f64]];
+        let x = mat![[1.0], [1.0f64]];
+        let b = mat![[3.0], [5.0f64]];
+
+        let r = residual(a.as_ref(), x.as_ref(), b.as_ref(), Parallelism::None);
+        assert!(r == mat![[0.0], [1.0]]);
+
+        // ||r|| = 1, ||A|| = 4, ||x|| = 1, ||b|| = 5
+        let err = relative_residual_norm(a.as_ref(), x.as_ref(), b.as_ref(), Parallelism::None);
+        assert!((err - 1.0 / 9.0).abs() < 1e-15);
+
+        // |A||x| + |b| = [6, 9]
+        let err =
+            componentwise_backward_error(a.as_ref(), x.as_ref(), b.as_ref(), Parallelism::None);
+        assert!((err - 1.0 / 9.0).abs() < 1e-15);
+
+        let b = mat![[3.0, 0.0], [4.0, 0.0f64]];
+        let x = mat![[1.0, 0.0], [1.0, 0.0f64]];
+        assert!(
+            relative_residual_norm(a.as_ref(), x.as_ref(), b.as_ref(), Parallelism::None) == 0.0
+        );
+        assert!(
+            componentwise_backward_error(a.as_ref(), x.as_ref(), b.as_ref(), Parallelism::None)
+                == 0.0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lu")]
+    fn test_residual_after_solve() {
+        use crate::linalg::solvers::SpSolver;
+
+        let n = 20;
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let a = Mat::from_fn(n, n, random);
+        let b = Mat::from_fn(n, 3, random);
+        let x = a.partial_piv_lu().solve(&b);
+
+        assert!(
+            relative_residual_norm(a.as_ref(), x.as_ref(), b.as_ref(), Parallelism::None) < 1e-13
+        );
+        assert!(
+            componentwise_backward_error(a.as_ref(), x.as_ref(), b.as_ref(), Parallelism::None)
+                < 1e-12
+        );
+    }
+}
@@ -6,7 +6,16 @@ use dyn_stack::*;
 use reborrow::*;
 
 #[cfg(feature = "cholesky")]
-pub use crate::linalg::cholesky::llt::CholeskyError;
+pub use crate::linalg::cholesky::llt::{CholeskyError, CholeskyStats};
+
+// block size of a householder sequence of length `size`, taking the global override into account
+#[cfg(feature = "qr")]
+fn qr_blocksize(recommended: usize, size: usize) -> usize {
+    match get_global_blocking_params().qr_block {
+        Some(blocksize) => Ord::min(blocksize, Ord::max(size, 1)),
+        None => recommended,
+    }
+}
 
 #[track_caller]
 fn solve_with_conj_impl<
@@ -156,6 +165,10 @@ pub trait SpSolverLstsqCore<E: Entity>: SpSolverCore<E> {
 }
 
 /// Solver that can compute solution of a linear system.
+///
+/// The right-hand side can either be a matrix or a single column. The in-place methods overwrite
+/// it with the solution, while the other methods leave it unmodified and return the solution in a
+/// newly allocated [`Mat`] or [`Col`] respectively.
 pub trait SpSolver<E: ComplexField>: SpSolverCore<E> {
     /// Solves the equation `self * X = rhs` when self is square, and stores the result in `rhs`.
     fn solve_in_place(&self, rhs: impl ColBatchMut<E>);
@@ -230,6 +243,84 @@ impl<E: ComplexField, Dec: ?Sized + SolverLstsqCore<E>> SolverLstsq<E> for Dec {
 
 impl<E: ComplexField, Dec: ?Sized + SolverCore<E>> Solver<E> for Dec {}
 
+/// Solves the equation `(A + U * transpose(V)) * X = rhs`, given a solver for `A`, and returns the
+/// result.
+///
+/// The solution is computed with the Woodbury identity, which only requires solving systems with
+/// `A`, and factorizing the `k×k` capacitance matrix `I + transpose(V) * A^-1 * U`, where `k` is
+/// the number of columns of `U` and `V`. This is much cheaper than factorizing the updated matrix
+/// when `k` is small. Rank one updates use the Sherman-Morrison formula directly.
+///
+/// The updated matrix is assumed to be invertible, otherwise the capacitance matrix is singular
+/// and the result contains infinite or NaN values.
+///
+/// # Panics
+/// Panics if `A` is not square, or if `u`, `v` and `rhs` don't have the same number of rows as
+/// `A`, or if `u` and `v` don't have the same number of columns.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::solvers::woodbury, mat, Mat};
+///
+/// let a = mat![[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0f64]];
+/// let u = mat![[1.0], [0.0], [2.0]];
+/// let v = mat![[0.5], [1.0], [0.0]];
+/// let rhs = mat![[1.0], [2.0], [3.0]];
+///
+/// let x = woodbury(&a.partial_piv_lu(), u.as_ref(), v.as_ref(), rhs.as_ref());
+/// let updated = &a + &u * v.transpose();
+/// assert!((&updated * &x - &rhs).norm_max() < 1e-12);
+/// ```
+#[cfg(feature = "lu")]
+#[track_caller]
+pub fn woodbury<E: ComplexField>(
+    a_solver: &(impl ?Sized + SpSolver<E>),
+    u: MatRef<'_, E>,
+    v: MatRef<'_, E>,
+    rhs: MatRef<'_, E>,
+) -> Mat<E> {
+    let n = a_solver.nrows();
+    let k = u.ncols();
+    assert!(all(
+        a_solver.ncols() == n,
+        u.nrows() == n,
+        v.nrows() == n,
+        v.ncols() == k,
+        rhs.nrows() == n,
+    ));
+
+    // A^-1 * rhs and A^-1 * U
+    let mut x = a_solver.solve(rhs);
+    let z = a_solver.solve(u);
+    if k == 0 {
+        return x;
+    }
+
+    // W = C^-1 * transpose(V) * A^-1 * rhs, where C is the capacitance matrix
+    let mut w = v.transpose() * &x;
+    if k == 1 {
+        let c = E::faer_one().faer_add(v.col(0).transpose() * z.col(0));
+        let c_inv = c.faer_inv();
+        zipped_rw!(&mut w).for_each(|unzipped!(mut w)| w.write(w.read().faer_mul(c_inv)));
+    } else {
+        let mut c = v.transpose() * &z;
+        zipped_rw!(c.as_mut().diagonal_mut().column_vector_mut())
+            .for_each(|unzipped!(mut c)| c.write(c.read().faer_add(E::faer_one())));
+        c.partial_piv_lu().solve_in_place(&mut w);
+    }
+
+    // X = A^-1 * rhs - A^-1 * U * W
+    crate::linalg::matmul::matmul(
+        x.as_mut(),
+        z.as_ref(),
+        w.as_ref(),
+        Some(E::faer_one()),
+        E::faer_one().faer_neg(),
+        get_global_parallelism(),
+    );
+    x
+}
+
 /// Cholesky decomposition.
 #[cfg(feature = "cholesky")]
 #[derive(Debug)]
@@ -247,6 +338,16 @@ pub struct Lblt<E: Entity> {
     perm_inv: alloc::vec::Vec<usize>,
 }
 
+/// Cholesky decomposition with diagonal pivoting, for positive semidefinite matrices.
+#[cfg(feature = "cholesky")]
+#[derive(Debug)]
+pub struct PivCholesky<E: Entity> {
+    factors: Mat<E>,
+    rank: usize,
+    perm: alloc::vec::Vec<usize>,
+    perm_inv: alloc::vec::Vec<usize>,
+}
+
 /// LU decomposition with partial pivoting.
 #[cfg(feature = "lu")]
 #[derive(Debug)]
@@ -285,6 +386,14 @@ pub struct ColPivQr<E: Entity> {
     col_perm_inv: alloc::vec::Vec<usize>,
 }
 
+/// LQ decomposition.
+#[cfg(feature = "qr")]
+#[derive(Debug)]
+pub struct Lq<E: Entity> {
+    factors: Mat<E>,
+    householder: Mat<E>,
+}
+
 /// Singular value decomposition.
 #[cfg(feature = "svd")]
 #[derive(Debug)]
@@ -316,6 +425,20 @@ pub struct Eigendecomposition<E: Entity> {
     u: Mat<E>,
 }
 
+/// Ordering criterion for the eigenvalues of an [`Eigendecomposition`].
+#[cfg(feature = "evd")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EigOrder {
+    /// Eigenvalues are sorted by their real part, in nondecreasing order.
+    AscendingReal,
+    /// Eigenvalues are sorted by their real part, in nonincreasing order.
+    DescendingReal,
+    /// Eigenvalues are sorted by their magnitude, in nondecreasing order.
+    AscendingMagnitude,
+    /// Eigenvalues are sorted by their magnitude, in nonincreasing order.
+    DescendingMagnitude,
+}
+
 #[cfg(feature = "cholesky")]
 impl<E: ComplexField> Cholesky<E> {
     /// Returns the Cholesky factorization of the input
@@ -323,7 +446,11 @@ impl<E: ComplexField> Cholesky<E> {
     ///
     /// The factorization is such that $A = LL^H$, where $L$ is lower triangular.
     ///
-    /// The matrix is interpreted as Hermitian, but only the provided side is accessed.
+    /// The matrix is interpreted as Hermitian, but only the provided side is accessed. The other
+    /// side may contain arbitrary values, see [`Side`].
+    ///
+    /// The input matrix is copied and left unmodified. See [`Self::try_new_in_place`] for a
+    /// version that reuses the storage of an owned matrix instead.
     #[track_caller]
     pub fn try_new<ViewE: Conjugate<Canonical = E>>(
         matrix: MatRef<'_, ViewE>,
@@ -332,7 +459,6 @@ impl<E: ComplexField> Cholesky<E> {
         assert!(matrix.nrows() == matrix.ncols());
 
         let dim = matrix.nrows();
-        let parallelism = get_global_parallelism();
 
         let mut factors = Mat::<E>::zeros(dim, dim);
         match side {
@@ -350,7 +476,37 @@ impl<E: ComplexField> Cholesky<E> {
             }
         }
 
-        let params = Default::default();
+        Self::try_new_in_place(factors, Side::Lower)
+    }
+
+    /// Returns the Cholesky factorization of `matrix`, computed in its own storage, which is
+    /// consumed, or an error if the matrix is not positive definite.
+    ///
+    /// This is equivalent to [`Self::try_new`], but avoids copying the input matrix when the
+    /// caller no longer needs it.
+    ///
+    /// The matrix is interpreted as Hermitian, but only the provided side is read. The other side
+    /// may contain arbitrary values, see [`Side`].
+    #[track_caller]
+    pub fn try_new_in_place(matrix: Mat<E>, side: Side) -> Result<Self, CholeskyError> {
+        assert!(matrix.nrows() == matrix.ncols());
+
+        let dim = matrix.nrows();
+        let parallelism = get_global_parallelism();
+
+        let mut factors = matrix;
+        if side == Side::Upper {
+            for j in 0..dim {
+                for i in j + 1..dim {
+                    factors.write(i, j, factors.read(j, i).faer_conj());
+                }
+            }
+        }
+
+        let params = crate::linalg::cholesky::llt::compute::LltParams {
+            blocksize: get_global_blocking_params().chol_block,
+            ..Default::default()
+        };
 
         crate::linalg::cholesky::llt::compute::cholesky_in_place(
             factors.as_mut(),
@@ -373,6 +529,14 @@ impl<E: ComplexField> Cholesky<E> {
         self.factors.nrows()
     }
 
+    /// Returns the pivot statistics of the Cholesky decomposition.
+    ///
+    /// A large [`CholeskyStats::pivot_ratio`] indicates that the matrix is close to singular, in
+    /// which case solutions computed with the decomposition may be inaccurate.
+    pub fn stats(&self) -> CholeskyStats<E::Real> {
+        crate::linalg::cholesky::llt::compute::cholesky_stats(self.factors.as_ref())
+    }
+
     /// Returns the factor $L$ of the Cholesky decomposition.
     pub fn compute_l(&self) -> Mat<E> {
         let mut factor = self.factors.to_owned();
@@ -475,7 +639,8 @@ impl<E: ComplexField> SolverCore<E> for Cholesky<E> {
 impl<E: ComplexField> Lblt<E> {
     /// Returns the Bunch-Kaufman factorization of the input matrix.
     ///
-    /// The matrix is interpreted as Hermitian, but only the provided side is accessed.
+    /// The matrix is interpreted as Hermitian, but only the provided side is accessed. The other
+    /// side may contain arbitrary values, see [`Side`].
     #[track_caller]
     pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>, side: Side) -> Self {
         assert!(matrix.nrows() == matrix.ncols());
@@ -533,6 +698,43 @@ impl<E: ComplexField> Lblt<E> {
     fn dim(&self) -> usize {
         self.factors.nrows()
     }
+
+    /// Returns the symmetric permutation $P$ due to pivoting, such that
+    /// $PAP^\top = LBL^H$.
+    pub fn permutation(&self) -> PermRef<'_, usize> {
+        unsafe { PermRef::new_unchecked(&self.perm, &self.perm_inv, self.dim()) }
+    }
+
+    /// Returns the unit lower triangular factor $L$ of the Bunch-Kaufman decomposition.
+    pub fn compute_l(&self) -> Mat<E> {
+        let mut factor = self.factors.to_owned();
+        zipped_rw!(factor.as_mut())
+            .for_each_triangular_upper(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
+                dst.write(E::faer_zero())
+            });
+        factor
+            .as_mut()
+            .diagonal_mut()
+            .column_vector_mut()
+            .fill(E::faer_one());
+        factor
+    }
+
+    /// Returns the block diagonal factor $B$ of the Bunch-Kaufman decomposition, made up of
+    /// $1\times 1$ and $2\times 2$ Hermitian blocks.
+    pub fn compute_b(&self) -> Mat<E> {
+        let n = self.dim();
+        let mut factor = Mat::<E>::zeros(n, n);
+        for j in 0..n {
+            factor.write(j, j, E::faer_from_real(self.factors.read(j, j).faer_real()));
+        }
+        for j in 0..n.saturating_sub(1) {
+            let s = self.subdiag.read(j);
+            factor.write(j + 1, j, s);
+            factor.write(j, j + 1, s.faer_conj());
+        }
+        factor
+    }
 }
 
 #[cfg(feature = "cholesky")]
@@ -659,22 +861,154 @@ impl<E: ComplexField> SolverCore<E> for Lblt<E> {
     }
 }
 
+#[cfg(feature = "cholesky")]
+impl<E: ComplexField> PivCholesky<E> {
+    /// Returns the pivoted Cholesky factorization of the input matrix, or an error if the matrix
+    /// is not positive semidefinite.
+    ///
+    /// The factorization is such that $PAP^\top \approx LL^H$, where $L$ is lower trapezoidal,
+    /// with as many columns as the numerical rank of $A$. The largest remaining diagonal element
+    /// is chosen as the pivot at each step, and the factorization stops once it becomes smaller
+    /// than or equal to $n \varepsilon \max_i a_{ii}$. See [`Self::try_new_with_tolerance`] for a
+    /// version with a user-provided tolerance.
+    ///
+    /// The matrix is interpreted as Hermitian, but only the provided side is accessed. The other
+    /// side may contain arbitrary values, see [`Side`].
+    #[track_caller]
+    pub fn try_new<ViewE: Conjugate<Canonical = E>>(
+        matrix: MatRef<'_, ViewE>,
+        side: Side,
+    ) -> Result<Self, CholeskyError> {
+        Self::try_new_impl(matrix, side, None)
+    }
+
+    /// Returns the pivoted Cholesky factorization of the input matrix, or an error if the matrix
+    /// is not positive semidefinite.
+    ///
+    /// This is equivalent to [`Self::try_new`], except that the factorization stops once the
+    /// largest remaining diagonal element is smaller than or equal to `tolerance`.
+    #[track_caller]
+    pub fn try_new_with_tolerance<ViewE: Conjugate<Canonical = E>>(
+        matrix: MatRef<'_, ViewE>,
+        side: Side,
+        tolerance: E::Real,
+    ) -> Result<Self, CholeskyError> {
+        Self::try_new_impl(matrix, side, Some(tolerance))
+    }
+
+    #[track_caller]
+    fn try_new_impl<ViewE: Conjugate<Canonical = E>>(
+        matrix: MatRef<'_, ViewE>,
+        side: Side,
+        tolerance: Option<E::Real>,
+    ) -> Result<Self, CholeskyError> {
+        assert!(matrix.nrows() == matrix.ncols());
+
+        let dim = matrix.nrows();
+        let parallelism = get_global_parallelism();
+
+        let mut factors = Mat::<E>::zeros(dim, dim);
+        let mut perm = alloc::vec![0; dim];
+        let mut perm_inv = alloc::vec![0; dim];
+
+        match side {
+            Side::Lower => {
+                zipped_rw!(factors.as_mut(), matrix).for_each_triangular_lower(
+                    crate::linalg::zip::Diag::Include,
+                    |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                );
+            }
+            Side::Upper => {
+                zipped_rw!(factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
+                    crate::linalg::zip::Diag::Include,
+                    |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                );
+            }
+        }
+
+        let (info, _) = crate::linalg::cholesky::piv_llt::compute::cholesky_in_place(
+            factors.as_mut(),
+            &mut perm,
+            &mut perm_inv,
+            tolerance,
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::linalg::cholesky::piv_llt::compute::cholesky_in_place_req::<usize, E>(
+                    dim,
+                    parallelism,
+                )
+                .unwrap(),
+            )),
+            Default::default(),
+        )?;
+
+        Ok(Self {
+            factors,
+            rank: info.rank,
+            perm,
+            perm_inv,
+        })
+    }
+
+    fn dim(&self) -> usize {
+        self.factors.nrows()
+    }
+
+    /// Returns the numerical rank of the matrix, which is the number of columns of $L$.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns the symmetric permutation $P$ due to pivoting, such that
+    /// $PAP^\top \approx LL^H$.
+    pub fn permutation(&self) -> PermRef<'_, usize> {
+        unsafe { PermRef::new_unchecked(&self.perm, &self.perm_inv, self.dim()) }
+    }
+
+    /// Returns the lower trapezoidal factor $L$ of the pivoted Cholesky decomposition, with
+    /// [`Self::rank`] columns.
+    pub fn compute_l(&self) -> Mat<E> {
+        let mut factor = self.factors.get(.., ..self.rank).to_owned();
+        zipped_rw!(factor.as_mut())
+            .for_each_triangular_upper(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
+                dst.write(E::faer_zero())
+            });
+        factor
+    }
+}
+
 #[cfg(feature = "lu")]
 impl<E: ComplexField> PartialPivLu<E> {
     /// Returns the LU decomposition of the input matrix with partial (row) pivoting.
     ///
-    /// The factorization is such that $PA = LU$, where $L$ is lower triangular, $U$ is unit
-    /// upper triangular, and $P$ is the permutation arising from the pivoting.
+    /// The factorization is such that $PA = LU$, where $L$ is unit lower triangular, $U$ is
+    /// upper triangular, and $P$ is the permutation arising from the pivoting. Equivalently,
+    /// $A = P^\top LU$.
+    ///
+    /// The input matrix is copied and left unmodified. See [`Self::new_in_place`] for a version
+    /// that reuses the storage of an owned matrix instead.
     #[track_caller]
     pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        Self::new_in_place(matrix.to_owned())
+    }
+
+    /// Returns the LU decomposition of `matrix`, computed in its own storage, which is consumed.
+    ///
+    /// This is equivalent to [`Self::new`], but avoids copying the input matrix when the caller
+    /// no longer needs it.
+    #[track_caller]
+    pub fn new_in_place(matrix: Mat<E>) -> Self {
         assert!(matrix.nrows() == matrix.ncols());
 
         let dim = matrix.nrows();
         let parallelism = get_global_parallelism();
 
-        let mut factors = matrix.to_owned();
+        let mut factors = matrix;
 
-        let params = Default::default();
+        let params = crate::linalg::lu::partial_pivoting::compute::PartialPivLuComputeParams {
+            blocksize: get_global_blocking_params().lu_block,
+            ..Default::default()
+        };
 
         let mut row_perm = alloc::vec![0usize; dim];
         let mut row_perm_inv = alloc::vec![0usize; dim];
@@ -709,6 +1043,9 @@ impl<E: ComplexField> PartialPivLu<E> {
     }
 
     /// Returns the row permutation due to pivoting.
+    ///
+    /// This is the compact representation of $P$, such that `row_permutation() * A` equals
+    /// $LU$.
     pub fn row_permutation(&self) -> PermRef<'_, usize> {
         unsafe { PermRef::new_unchecked(&self.row_perm, &self.row_perm_inv, self.nrows()) }
     }
@@ -718,6 +1055,31 @@ impl<E: ComplexField> PartialPivLu<E> {
         self.n_transpositions
     }
 
+    /// Returns the permutation $P$ of the LU decomposition as an explicit dense matrix, such that
+    /// $PA = LU$.
+    ///
+    /// [`Self::row_permutation`] should be preferred for computations, since applying the
+    /// compact permutation is much cheaper than a dense matrix multiplication.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use faer::{mat, Mat};
+    ///
+    /// let a = mat![[1.0, 2.0, 0.0], [4.0, 1.0, 3.0], [2.0, 5.0, 1.0f64]];
+    /// let lu = a.partial_piv_lu();
+    ///
+    /// let p = lu.compute_p();
+    /// let l = lu.compute_l();
+    /// let u = lu.compute_u();
+    ///
+    /// assert!((&p * &a - &l * &u).norm_max() < 1e-14);
+    /// assert!((&a - p.transpose() * &l * &u).norm_max() < 1e-14);
+    /// ```
+    pub fn compute_p(&self) -> Mat<E> {
+        self.row_permutation().to_dense_matrix()
+    }
+
     /// Returns the factor $L$ of the LU decomposition.
     pub fn compute_l(&self) -> Mat<E> {
         let mut factor = self.factors.to_owned();
@@ -849,16 +1211,28 @@ impl<E: ComplexField> SolverCore<E> for PartialPivLu<E> {
 impl<E: ComplexField> FullPivLu<E> {
     /// Returns the LU decomposition of the input matrix with row and column pivoting.
     ///
-    /// The factorization is such that $PAQ^\top = LU$, where $L$ is lower triangular, $U$ is unit
+    /// The factorization is such that $PAQ^\top = LU$, where $L$ is unit lower triangular, $U$ is
     /// upper triangular, and $P$ is the permutation arising from row pivoting and $Q$ is the
     /// permutation due to column pivoting.
+    ///
+    /// The input matrix is copied and left unmodified. See [`Self::new_in_place`] for a version
+    /// that reuses the storage of an owned matrix instead.
     #[track_caller]
     pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        Self::new_in_place(matrix.to_owned())
+    }
+
+    /// Returns the LU decomposition of `matrix`, computed in its own storage, which is consumed.
+    ///
+    /// This is equivalent to [`Self::new`], but avoids copying the input matrix when the caller
+    /// no longer needs it.
+    #[track_caller]
+    pub fn new_in_place(matrix: Mat<E>) -> Self {
         let m = matrix.nrows();
         let n = matrix.ncols();
         let parallelism = get_global_parallelism();
 
-        let mut factors = matrix.to_owned();
+        let mut factors = matrix;
 
         let params = Default::default();
 
@@ -1065,16 +1439,30 @@ impl<E: ComplexField> Qr<E> {
     /// Returns the QR decomposition of the input matrix without pivoting.
     ///
     /// The factorization is such that $A = QR$, where $R$ is upper trapezoidal and $Q$ is unitary.
+    ///
+    /// The input matrix is copied and left unmodified. See [`Self::new_in_place`] for a version
+    /// that reuses the storage of an owned matrix instead.
     #[track_caller]
     pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        Self::new_in_place(matrix.to_owned())
+    }
+
+    /// Returns the QR decomposition of `matrix`, computed in its own storage, which is consumed.
+    ///
+    /// This is equivalent to [`Self::new`], but avoids copying the input matrix when the caller
+    /// no longer needs it.
+    #[track_caller]
+    pub fn new_in_place(matrix: Mat<E>) -> Self {
         let parallelism = get_global_parallelism();
         let nrows = matrix.nrows();
         let ncols = matrix.ncols();
 
-        let mut factors = matrix.to_owned();
+        let mut factors = matrix;
         let size = Ord::min(nrows, ncols);
-        let blocksize =
-            crate::linalg::qr::no_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+        let blocksize = qr_blocksize(
+            crate::linalg::qr::no_pivoting::compute::recommended_blocksize::<E>(nrows, ncols),
+            size,
+        );
         let mut householder = Mat::<E>::zeros(blocksize, size);
 
         let params = Default::default();
@@ -1289,36 +1677,44 @@ impl<E: ComplexField> SpSolverLstsqCore<E> for Qr<E> {
 impl<E: ComplexField> SolverLstsqCore<E> for Qr<E> {}
 
 #[cfg(feature = "qr")]
-impl<E: ComplexField> ColPivQr<E> {
-    /// Returns the QR decomposition of the input matrix with column pivoting.
+impl<E: ComplexField> Lq<E> {
+    /// Returns the LQ decomposition of the input matrix.
     ///
-    /// The factorization is such that $AP^\top = QR$, where $R$ is upper trapezoidal, $Q$ is
-    /// unitary, and $P$ is a permutation matrix.
+    /// The factorization is such that $A = LQ$, where $L$ is lower trapezoidal and $Q$ is unitary.
+    ///
+    /// The input matrix is copied and left unmodified. See [`Self::new_in_place`] for a version
+    /// that reuses the storage of an owned matrix instead.
     #[track_caller]
     pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        Self::new_in_place(matrix.to_owned())
+    }
+
+    /// Returns the LQ decomposition of `matrix`, computed in its own storage, which is consumed.
+    ///
+    /// This is equivalent to [`Self::new`], but avoids copying the input matrix when the caller
+    /// no longer needs it.
+    #[track_caller]
+    pub fn new_in_place(matrix: Mat<E>) -> Self {
         let parallelism = get_global_parallelism();
         let nrows = matrix.nrows();
         let ncols = matrix.ncols();
 
-        let mut factors = matrix.to_owned();
+        let mut factors = matrix;
         let size = Ord::min(nrows, ncols);
-        let blocksize =
-            crate::linalg::qr::col_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+        let blocksize = qr_blocksize(
+            crate::linalg::lq::compute::recommended_blocksize::<E>(nrows, ncols),
+            size,
+        );
         let mut householder = Mat::<E>::zeros(blocksize, size);
 
         let params = Default::default();
 
-        let mut col_perm = alloc::vec![0usize; ncols];
-        let mut col_perm_inv = alloc::vec![0usize; ncols];
-
-        crate::linalg::qr::col_pivoting::compute::qr_in_place(
+        crate::linalg::lq::compute::lq_in_place(
             factors.as_mut(),
             householder.as_mut(),
-            &mut col_perm,
-            &mut col_perm_inv,
             parallelism,
             PodStack::new(&mut GlobalPodBuffer::new(
-                crate::linalg::qr::col_pivoting::compute::qr_in_place_req::<usize, E>(
+                crate::linalg::lq::compute::lq_in_place_req::<E>(
                     nrows,
                     ncols,
                     blocksize,
@@ -1333,70 +1729,314 @@ impl<E: ComplexField> ColPivQr<E> {
         Self {
             factors,
             householder,
-            col_perm,
-            col_perm_inv,
         }
     }
 
-    /// Returns the column permutation matrix $P$ of the QR decomposition.
-    pub fn col_permutation(&self) -> PermRef<'_, usize> {
-        unsafe { PermRef::new_unchecked(&self.col_perm, &self.col_perm_inv, self.ncols()) }
-    }
-
     fn blocksize(&self) -> usize {
         self.householder.nrows()
     }
 
-    /// Returns the factor $R$ of the QR decomposition.
-    pub fn compute_r(&self) -> Mat<E> {
+    /// Returns the factor $L$ of the LQ decomposition.
+    pub fn compute_l(&self) -> Mat<E> {
         let mut factor = self.factors.to_owned();
         zipped_rw!(factor.as_mut())
-            .for_each_triangular_lower(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
+            .for_each_triangular_upper(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
                 dst.write(E::faer_zero())
             });
         factor
     }
 
-    /// Returns the factor $Q$ of the QR decomposition.
+    /// Returns the factor $Q$ of the LQ decomposition.
     pub fn compute_q(&self) -> Mat<E> {
-        Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), false)
+        self.__compute_q_impl(false)
     }
 
-    /// Returns the top $r$ rows of the factor $R$ of the QR decomposition, where $r =
+    /// Returns the leftmost $r$ columns of the factor $L$ of the LQ decomposition, where $r =
     /// \min(\text{nrows}(A), \text{ncols}(A))$.
-    pub fn compute_thin_r(&self) -> Mat<E> {
+    pub fn compute_thin_l(&self) -> Mat<E> {
         let m = self.nrows();
         let n = self.ncols();
-        let mut factor = self.factors.as_ref().subrows(0, Ord::min(m, n)).to_owned();
+        let mut factor = self.factors.as_ref().subcols(0, Ord::min(m, n)).to_owned();
         zipped_rw!(factor.as_mut())
-            .for_each_triangular_lower(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
+            .for_each_triangular_upper(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
                 dst.write(E::faer_zero())
             });
         factor
     }
 
-    /// Returns the leftmost $r$ columns of the factor $R$ of the QR decomposition, where $r =
+    /// Returns the top $r$ rows of the factor $Q$ of the LQ decomposition, where $r =
     /// \min(\text{nrows}(A), \text{ncols}(A))$.
     pub fn compute_thin_q(&self) -> Mat<E> {
-        Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), true)
+        self.__compute_q_impl(true)
     }
-}
-#[cfg(feature = "qr")]
-impl<E: ComplexField> SpSolverCore<E> for ColPivQr<E> {
+
+    fn __compute_q_impl(&self, thin: bool) -> Mat<E> {
+        // the householder sequence is that of the QR decomposition of A^T, whose factor Q is the
+        // transpose of ours
+        Qr::__compute_q_impl(
+            self.factors.as_ref().transpose(),
+            self.householder.as_ref(),
+            thin,
+        )
+        .transpose()
+        .to_owned()
+    }
+
+    /// Returns the minimum-norm solution $X$ of the underdetermined system $AX = B$, where $A$ is
+    /// the wide matrix used to construct this decomposition, and is assumed to have full row rank.
+    ///
+    /// The right-hand side has as many rows as $A$, and the solution has as many rows as $A$ has
+    /// columns.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if $A$ has more rows than columns.
+    /// - Panics if `rhs` doesn't have the same number of rows as $A$.
     #[track_caller]
-    fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-        assert!(self.nrows() == self.ncols());
-        self.solve_lstsq_in_place_with_conj_impl(rhs, conj);
+    pub fn solve_min_norm<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.solve_min_norm_with_conj_impl(rhs, Conj::No)
     }
 
+    /// Returns the minimum-norm solution $X$ of the underdetermined system $\bar{A}X = B$, where
+    /// $A$ is the wide matrix used to construct this decomposition, and is assumed to have full row
+    /// rank.
+    ///
+    /// The right-hand side has as many rows as $A$, and the solution has as many rows as $A$ has
+    /// columns.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if $A$ has more rows than columns.
+    /// - Panics if `rhs` doesn't have the same number of rows as $A$.
     #[track_caller]
-    fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-        assert!(self.nrows() == self.ncols());
+    pub fn solve_min_norm_conj<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.solve_min_norm_with_conj_impl(rhs, Conj::Yes)
+    }
+
+    #[track_caller]
+    fn solve_min_norm_with_conj_impl<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+        conj: Conj,
+    ) -> B::Owned {
+        let m = self.nrows();
+        let n = self.ncols();
+        assert!(all(m <= n, rhs.as_2d_ref().nrows() == m));
+
+        let mut rhs = B::new_owned_copied(&rhs);
+        let ncols = rhs.as_2d_ref().ncols();
+        B::resize_owned(&mut rhs, n, ncols);
+        self.solve_min_norm_in_place_with_conj_impl(rhs.as_2d_mut(), conj);
+        rhs
+    }
 
+    #[track_caller]
+    fn solve_min_norm_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
         let parallelism = get_global_parallelism();
         let rhs_ncols = rhs.ncols();
 
-        crate::linalg::qr::col_pivoting::solve::solve_transpose_in_place(
+        crate::linalg::lq::solve::solve_in_place(
+            self.factors.as_ref(),
+            self.householder.as_ref(),
+            conj,
+            rhs,
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::linalg::lq::solve::solve_in_place_req::<E>(
+                    self.ncols(),
+                    self.blocksize(),
+                    rhs_ncols,
+                )
+                .unwrap(),
+            )),
+        );
+    }
+}
+#[cfg(feature = "qr")]
+impl<E: ComplexField> SpSolverCore<E> for Lq<E> {
+    #[track_caller]
+    fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        assert!(self.nrows() == self.ncols());
+        self.solve_min_norm_in_place_with_conj_impl(rhs, conj)
+    }
+
+    #[track_caller]
+    fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        assert!(self.nrows() == self.ncols());
+
+        // A^T = Q^T L^T is the QR decomposition of A^T
+        let parallelism = get_global_parallelism();
+        let rhs_ncols = rhs.ncols();
+
+        crate::linalg::qr::no_pivoting::solve::solve_in_place(
+            self.factors.as_ref().transpose(),
+            self.householder.as_ref(),
+            conj,
+            rhs,
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::linalg::qr::no_pivoting::solve::solve_in_place_req::<E>(
+                    self.ncols(),
+                    self.blocksize(),
+                    rhs_ncols,
+                )
+                .unwrap(),
+            )),
+        );
+    }
+
+    fn nrows(&self) -> usize {
+        self.factors.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.factors.ncols()
+    }
+}
+#[cfg(feature = "qr")]
+impl<E: ComplexField> SolverCore<E> for Lq<E> {
+    fn reconstruct(&self) -> Mat<E> {
+        self.compute_thin_l() * self.compute_thin_q()
+    }
+
+    fn inverse(&self) -> Mat<E> {
+        assert!(self.nrows() == self.ncols());
+
+        let mut inv = Mat::<E>::identity(self.nrows(), self.ncols());
+        self.solve_in_place_with_conj_impl(inv.as_mut(), Conj::No);
+        inv
+    }
+}
+
+#[cfg(feature = "qr")]
+impl<E: ComplexField> ColPivQr<E> {
+    /// Returns the QR decomposition of the input matrix with column pivoting.
+    ///
+    /// The factorization is such that $AP^\top = QR$, where $R$ is upper trapezoidal, $Q$ is
+    /// unitary, and $P$ is a permutation matrix.
+    ///
+    /// The input matrix is copied and left unmodified. See [`Self::new_in_place`] for a version
+    /// that reuses the storage of an owned matrix instead.
+    #[track_caller]
+    pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        Self::new_in_place(matrix.to_owned())
+    }
+
+    /// Returns the QR decomposition of `matrix`, computed in its own storage, which is consumed.
+    ///
+    /// This is equivalent to [`Self::new`], but avoids copying the input matrix when the caller
+    /// no longer needs it.
+    #[track_caller]
+    pub fn new_in_place(matrix: Mat<E>) -> Self {
+        let parallelism = get_global_parallelism();
+        let nrows = matrix.nrows();
+        let ncols = matrix.ncols();
+
+        let mut factors = matrix;
+        let size = Ord::min(nrows, ncols);
+        let blocksize = qr_blocksize(
+            crate::linalg::qr::col_pivoting::compute::recommended_blocksize::<E>(nrows, ncols),
+            size,
+        );
+        let mut householder = Mat::<E>::zeros(blocksize, size);
+
+        let params = Default::default();
+
+        let mut col_perm = alloc::vec![0usize; ncols];
+        let mut col_perm_inv = alloc::vec![0usize; ncols];
+
+        crate::linalg::qr::col_pivoting::compute::qr_in_place(
+            factors.as_mut(),
+            householder.as_mut(),
+            &mut col_perm,
+            &mut col_perm_inv,
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::linalg::qr::col_pivoting::compute::qr_in_place_req::<usize, E>(
+                    nrows,
+                    ncols,
+                    blocksize,
+                    parallelism,
+                    params,
+                )
+                .unwrap(),
+            )),
+            params,
+        );
+
+        Self {
+            factors,
+            householder,
+            col_perm,
+            col_perm_inv,
+        }
+    }
+
+    /// Returns the column permutation matrix $P$ of the QR decomposition.
+    pub fn col_permutation(&self) -> PermRef<'_, usize> {
+        unsafe { PermRef::new_unchecked(&self.col_perm, &self.col_perm_inv, self.ncols()) }
+    }
+
+    fn blocksize(&self) -> usize {
+        self.householder.nrows()
+    }
+
+    /// Returns the factor $R$ of the QR decomposition.
+    pub fn compute_r(&self) -> Mat<E> {
+        let mut factor = self.factors.to_owned();
+        zipped_rw!(factor.as_mut())
+            .for_each_triangular_lower(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
+                dst.write(E::faer_zero())
+            });
+        factor
+    }
+
+    /// Returns the factor $Q$ of the QR decomposition.
+    pub fn compute_q(&self) -> Mat<E> {
+        Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), false)
+    }
+
+    /// Returns the top $r$ rows of the factor $R$ of the QR decomposition, where $r =
+    /// \min(\text{nrows}(A), \text{ncols}(A))$.
+    pub fn compute_thin_r(&self) -> Mat<E> {
+        let m = self.nrows();
+        let n = self.ncols();
+        let mut factor = self.factors.as_ref().subrows(0, Ord::min(m, n)).to_owned();
+        zipped_rw!(factor.as_mut())
+            .for_each_triangular_lower(crate::linalg::zip::Diag::Skip, |unzipped!(mut dst)| {
+                dst.write(E::faer_zero())
+            });
+        factor
+    }
+
+    /// Returns the leftmost $r$ columns of the factor $R$ of the QR decomposition, where $r =
+    /// \min(\text{nrows}(A), \text{ncols}(A))$.
+    pub fn compute_thin_q(&self) -> Mat<E> {
+        Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), true)
+    }
+}
+#[cfg(feature = "qr")]
+impl<E: ComplexField> SpSolverCore<E> for ColPivQr<E> {
+    #[track_caller]
+    fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        assert!(self.nrows() == self.ncols());
+        self.solve_lstsq_in_place_with_conj_impl(rhs, conj);
+    }
+
+    #[track_caller]
+    fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        assert!(self.nrows() == self.ncols());
+
+        let parallelism = get_global_parallelism();
+        let rhs_ncols = rhs.ncols();
+
+        crate::linalg::qr::col_pivoting::solve::solve_transpose_in_place(
             self.factors.as_ref(),
             self.householder.as_ref(),
             self.col_permutation(),
@@ -1584,6 +2224,14 @@ impl<E: ComplexField> Svd<E> {
     }
 }
 
+// number of singular values greater than `tol`, which are sorted in nonincreasing order
+#[cfg(feature = "svd")]
+fn svd_rank<E: ComplexField>(s: ColRef<'_, E>, tol: E::Real) -> usize {
+    (0..s.nrows())
+        .take_while(|&i| s.read(i).faer_real() > tol)
+        .count()
+}
+
 fn div_by_s<E: ComplexField>(rhs: MatMut<'_, E>, s: ColRef<'_, E>) {
     let mut rhs = rhs;
     for j in 0..rhs.ncols() {
@@ -1593,6 +2241,10 @@ fn div_by_s<E: ComplexField>(rhs: MatMut<'_, E>, s: ColRef<'_, E>) {
     }
 }
 #[cfg(feature = "svd")]
+fn scale_col<E: ComplexField>(col: ColMut<'_, E>, factor: E) {
+    zipped_rw!(col).for_each(|unzipped!(mut x)| x.write(x.read().faer_mul(factor)));
+}
+#[cfg(feature = "svd")]
 impl<E: ComplexField> SpSolverCore<E> for Svd<E> {
     fn nrows(&self) -> usize {
         self.u.nrows()
@@ -1795,7 +2447,8 @@ impl<E: ComplexField> SelfAdjointEigendecomposition<E> {
     /// The factorization is such that $A = U S U^H$, where $S$ is a diagonal matrix, and $U$ is
     /// unitary.
     ///
-    /// Only the provided side is accessed.
+    /// Only the provided side is accessed. The other side may contain arbitrary values, see
+    /// [`Side`].
     #[track_caller]
     pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>, side: Side) -> Self {
         Self::__new_impl(matrix.canonicalize(), side)
@@ -1806,9 +2459,26 @@ impl<E: ComplexField> SelfAdjointEigendecomposition<E> {
         self.u.as_ref()
     }
     /// Returns the factor $S$ of the eigenvalue decomposition.
+    ///
+    /// The eigenvalues are stored in nondecreasing order. See also
+    /// [`Self::real_eigenvalues`].
     pub fn s(&self) -> DiagRef<'_, E> {
         self.s.as_ref().column_vector_as_diagonal()
     }
+
+    /// Returns the eigenvalues as real values, in nondecreasing order.
+    ///
+    /// Since the input matrix is self-adjoint, the diagonal of $S$ is real-valued even when `E`
+    /// is a complex type, and its imaginary part is exactly zero.
+    pub fn real_eigenvalues(&self) -> Col<E::Real> {
+        Col::from_fn(self.s.nrows(), |i| self.s.read(i).faer_real())
+    }
+
+    /// Consumes the decomposition, returning the eigenvalues as real values in nondecreasing
+    /// order, along with the unitary factor $U$.
+    pub fn into_real_parts(self) -> (Col<E::Real>, Mat<E>) {
+        (self.real_eigenvalues(), self.u)
+    }
 }
 #[cfg(feature = "evd")]
 impl<E: ComplexField> SpSolverCore<E> for SelfAdjointEigendecomposition<E> {
@@ -2110,6 +2780,41 @@ impl<E: ComplexField> Eigendecomposition<E> {
         Self::__new_from_complex_impl(matrix.canonicalize())
     }
 
+    /// Sorts the eigenvalues according to the given criterion, and permutes the columns of $U$
+    /// accordingly.
+    ///
+    /// Ties are broken by comparing the real parts, then the absolute values of the imaginary
+    /// parts, in nondecreasing order, and finally the imaginary parts in nonincreasing order. This
+    /// keeps complex conjugate pairs adjacent, with the eigenvalue that has a positive imaginary
+    /// part first.
+    pub fn sort(&mut self, order: EigOrder) {
+        let n = self.s.nrows();
+        let s = self.s.as_ref();
+
+        let cmp = |a: E::Real, b: E::Real| a.partial_cmp(&b).unwrap_or(core::cmp::Ordering::Equal);
+        let mut perm: alloc::vec::Vec<usize> = (0..n).collect();
+        perm.sort_by(|&i, &j| {
+            let x = s.read(i);
+            let y = s.read(j);
+            let primary = match order {
+                EigOrder::AscendingReal => cmp(x.faer_real(), y.faer_real()),
+                EigOrder::DescendingReal => cmp(y.faer_real(), x.faer_real()),
+                EigOrder::AscendingMagnitude => cmp(x.faer_abs(), y.faer_abs()),
+                EigOrder::DescendingMagnitude => cmp(y.faer_abs(), x.faer_abs()),
+            };
+            primary
+                .then_with(|| cmp(x.faer_real(), y.faer_real()))
+                .then_with(|| cmp(x.faer_imag().faer_abs(), y.faer_imag().faer_abs()))
+                .then_with(|| cmp(y.faer_imag(), x.faer_imag()))
+        });
+
+        let u = self.u.as_ref();
+        let sorted_s = Col::<E>::from_fn(n, |i| s.read(perm[i]));
+        let sorted_u = Mat::<E>::from_fn(n, n, |i, j| u.read(i, perm[j]));
+        self.s = sorted_s;
+        self.u = sorted_u;
+    }
+
     /// Returns the factor $U$ of the eigenvalue decomposition.
     pub fn u(&self) -> MatRef<'_, E> {
         self.u.as_ref()
@@ -2120,6 +2825,97 @@ impl<E: ComplexField> Eigendecomposition<E> {
     }
 }
 
+/// Solves the least squares problem $\min \|AX - B\|$, where `rhs` initially contains $B$ in its
+/// top `a.nrows()` rows, and is overwritten by the minimum-norm solution $X$ in its top `a.ncols()`
+/// rows. See [`MatRef::solve_lstsq`] for the selection rule of the factorization.
+#[cfg(all(feature = "qr", feature = "svd"))]
+#[track_caller]
+fn solve_lstsq_any_in_place<E: ComplexField, ViewE: Conjugate<Canonical = E>>(
+    a: MatRef<'_, ViewE>,
+    rhs: MatMut<'_, E>,
+) {
+    let m = a.nrows();
+    let n = a.ncols();
+    let size = Ord::min(m, n);
+    let mut rhs = rhs;
+    assert!(rhs.nrows() == Ord::max(m, n));
+
+    if size == 0 {
+        rhs.fill_zero();
+        return;
+    }
+
+    let parallelism = get_global_parallelism();
+    let tol = E::Real::faer_epsilon().faer_mul(E::Real::faer_from_f64(Ord::max(m, n) as f64));
+    let numerical_rank = |diag: &mut dyn Iterator<Item = E::Real>| {
+        let mut rank = 0usize;
+        let mut threshold = E::Real::faer_zero();
+        for (k, x) in diag.enumerate() {
+            if k == 0 {
+                threshold = tol.faer_mul(x);
+            }
+            if x > threshold {
+                rank += 1;
+            }
+        }
+        rank
+    };
+
+    let qr = if m >= n {
+        ColPivQr::new(a)
+    } else {
+        ColPivQr::new(a.adjoint())
+    };
+    let rank = numerical_rank(&mut (0..size).map(|k| qr.factors.read(k, k).faer_abs()));
+
+    if rank == size && m >= n {
+        qr.solve_lstsq_in_place_with_conj_impl(rhs, Conj::No);
+    } else if rank == size {
+        // A = P^T R^H Q^H, so the minimum-norm solution is X = Q [R_1^{-H} P B; 0]
+        let k = rhs.ncols();
+        let (mut top, mut bot) = rhs.rb_mut().split_at_row_mut(m);
+        crate::perm::permute_rows_in_place(
+            top.rb_mut(),
+            qr.col_permutation(),
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::perm::permute_rows_in_place_req::<usize, E>(m, k).unwrap(),
+            )),
+        );
+        crate::linalg::triangular_solve::solve_lower_triangular_in_place(
+            qr.factors.as_ref().submatrix(0, 0, m, m).adjoint(),
+            top.rb_mut(),
+            parallelism,
+        );
+        bot.fill_zero();
+        crate::linalg::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+            qr.factors.as_ref(),
+            qr.householder.as_ref(),
+            Conj::No,
+            rhs,
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::linalg::householder::apply_block_householder_sequence_on_the_left_in_place_req::<E>(
+                    n,
+                    qr.blocksize(),
+                    k,
+                )
+                .unwrap(),
+            )),
+        );
+    } else {
+        // X = V S^+ U^H B, discarding the negligible singular values
+        let svd = ThinSvd::new(a);
+        let s = svd.s_diagonal();
+        let rank = numerical_rank(&mut (0..size).map(|k| s.read(k).faer_real()));
+        let u = svd.u().subcols(0, rank);
+        let v = svd.v().subcols(0, rank);
+
+        let mut c = u.adjoint() * rhs.rb().subrows(0, m);
+        div_by_s(c.as_mut(), s.subrows(0, rank));
+        rhs.rb_mut().subrows_mut(0, n).copy_from(v * &c);
+    }
+}
+
 impl<E: Conjugate> MatRef<'_, E>
 where
     E::Canonical: ComplexField,
@@ -2245,33 +3041,211 @@ where
     pub fn cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, CholeskyError> {
         Cholesky::try_new(self.as_ref(), side)
     }
-    /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
+
+    /// Returns the Cholesky decomposition of `self`, along with its pivot statistics, which can be
+    /// used to detect matrices that are nearly singular. Only the provided side is accessed.
     #[track_caller]
-    #[doc(alias = "ldl")]
-    #[doc(alias = "ldlt")]
     #[cfg(feature = "cholesky")]
-    pub fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
-        Lblt::new(self.as_ref(), side)
+    pub fn cholesky_with_stats(
+        &self,
+        side: Side,
+    ) -> Result<
+        (
+            Cholesky<E::Canonical>,
+            CholeskyStats<<E::Canonical as ComplexField>::Real>,
+        ),
+        CholeskyError,
+    > {
+        let cholesky = self.cholesky(side)?;
+        let stats = cholesky.stats();
+        Ok((cholesky, stats))
+    }
+
+    /// Assuming `self` is self-adjoint and positive definite, solves the equation
+    /// `self * X = rhs` using the Cholesky decomposition, and returns the result. Only the provided
+    /// side is accessed.
+    ///
+    /// Returns an error if the Cholesky decomposition could not be computed because `self` is not
+    /// numerically positive definite. To solve several systems with the same matrix, the
+    /// decomposition from [`Self::cholesky`] should be reused instead.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn selfadjoint_solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+        side: Side,
+    ) -> Result<B::Owned, CholeskyError> {
+        Ok(self.cholesky(side)?.solve(rhs))
     }
-    /// Returns the LU decomposition of `self` with partial (row) pivoting.
+
+    /// Solves the equation `self * X = rhs` for a square matrix `self`, and returns the result.
+    ///
+    /// The structure of `self` is first detected with [`MatRef::detect_structure`], and the
+    /// cheapest applicable algorithm is used:
+    /// - identity: the right-hand side is copied,
+    /// - diagonal: each row of the right-hand side is divided by the corresponding diagonal
+    ///   element,
+    /// - lower or upper triangular: a triangular solve is performed,
+    /// - otherwise, the LU decomposition with partial pivoting is used.
+    ///
+    /// The detection is a scan over the elements of `self`, that stops early for matrices without
+    /// structure. It can be skipped with [`Self::solve_with_structure`] when the structure is
+    /// already known. To solve several systems with the same general matrix, the decomposition
+    /// from [`Self::partial_piv_lu`] should be reused instead.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square, or if `rhs` doesn't have the same number of rows as `self`.
     #[track_caller]
-    #[doc(alias = "lu")]
     #[cfg(feature = "lu")]
-    pub fn partial_piv_lu(&self) -> PartialPivLu<E::Canonical> {
-        PartialPivLu::<E::Canonical>::new(self.as_ref())
+    pub fn solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.solve_with_structure(rhs, self.detect_structure())
     }
-    /// Returns the LU decomposition of `self` with full pivoting.
+
+    /// Solves the equation `self * X = rhs` for a square matrix `self` with the given structure,
+    /// and returns the result. See [`Self::solve`].
+    ///
+    /// The structure is trusted without being checked, and passing [`MatrixStructure::General`]
+    /// always uses the LU decomposition with partial pivoting.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square, or if `rhs` doesn't have the same number of rows as `self`.
     #[track_caller]
     #[cfg(feature = "lu")]
-    pub fn full_piv_lu(&self) -> FullPivLu<E::Canonical> {
-        FullPivLu::<E::Canonical>::new(self.as_ref())
-    }
+    pub fn solve_with_structure<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+        structure: MatrixStructure,
+    ) -> B::Owned {
+        let n = self.nrows();
+        assert!(all(self.ncols() == n, rhs.as_2d_ref().nrows() == n));
+
+        match structure {
+            MatrixStructure::Identity => B::new_owned_copied(&rhs),
+            MatrixStructure::Diagonal => {
+                let mut sol = B::new_owned_copied(&rhs);
+                let mut x = sol.as_2d_mut();
+                for i in 0..n {
+                    let inv = self.read(i, i).canonicalize().faer_inv();
+                    for j in 0..x.ncols() {
+                        x.write(i, j, x.read(i, j).faer_mul(inv));
+                    }
+                }
+                sol
+            }
+            MatrixStructure::LowerTriangular => self.solve_lower_triangular(rhs),
+            MatrixStructure::UpperTriangular => self.solve_upper_triangular(rhs),
+            _ => self.partial_piv_lu().solve(rhs),
+        }
+    }
+
+    /// Returns the least squares solution of `self * X = rhs`, for a matrix of any shape and
+    /// rank. The solution minimizes $\|AX - B\|_F$, and among all the minimizers, it is the one
+    /// with the smallest norm.
+    ///
+    /// The numerical rank $r$ of $A$ is first estimated from the QR decomposition with column
+    /// pivoting of $A$ if $A$ has at least as many rows as columns, or of $A^H$ otherwise, as the
+    /// number of diagonal elements of $R$ whose absolute value is larger than
+    /// $\max(m, n)\,\varepsilon\,|R_{00}|$. Then:
+    /// - if $r = n \le m$, the unique minimizer is computed from the QR decomposition,
+    /// - if $r = m < n$, the minimum-norm solution of the underdetermined system is computed
+    ///   from the QR decomposition of $A^H$, i.e. the $LQ$ decomposition of $A$,
+    /// - otherwise, the matrix is rank deficient, and the solution is computed from the SVD of
+    ///   $A$, ignoring the singular values that are smaller than or equal to
+    ///   $\max(m, n)\,\varepsilon\,\sigma_{\max}$.
+    ///
+    /// To solve several systems with the same matrix, a decomposition such as [`Self::qr`] should
+    /// be reused instead.
+    ///
+    /// # Panics
+    /// Panics if `rhs` doesn't have the same number of rows as `self`.
+    #[track_caller]
+    #[cfg(all(feature = "qr", feature = "svd"))]
+    pub fn solve_lstsq<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        let m = self.nrows();
+        let n = self.ncols();
+        assert!(rhs.as_2d_ref().nrows() == m);
+
+        let mut sol = B::new_owned_copied(&rhs);
+        let k = sol.as_2d_ref().ncols();
+        B::resize_owned(&mut sol, Ord::max(m, n), k);
+        solve_lstsq_any_in_place(*self, sol.as_2d_mut());
+        B::resize_owned(&mut sol, n, k);
+        sol
+    }
+
+    /// Returns the least squares solution of `self * X = rhs` computed by [`Self::solve_lstsq`],
+    /// along with the norm of the residual $\|AX - B\|_F$, which measures the quality of the fit
+    /// for an overdetermined system.
+    ///
+    /// # Panics
+    /// Panics if `rhs` doesn't have the same number of rows as `self`.
+    #[track_caller]
+    #[cfg(all(feature = "qr", feature = "svd"))]
+    pub fn solve_lstsq_with_residual_norm<
+        ViewE: Conjugate<Canonical = E::Canonical>,
+        B: ColBatch<ViewE>,
+    >(
+        &self,
+        rhs: B,
+    ) -> (B::Owned, <E::Canonical as ComplexField>::Real) {
+        let sol = self.solve_lstsq(&rhs);
+        let mut residual = rhs.as_2d_ref().to_owned();
+        crate::linalg::matmul::matmul(
+            residual.as_mut(),
+            *self,
+            sol.as_2d_ref(),
+            Some(E::Canonical::faer_one()),
+            E::Canonical::faer_one().faer_neg(),
+            get_global_parallelism(),
+        );
+        (sol, residual.norm_l2())
+    }
+    /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
+    #[track_caller]
+    #[doc(alias = "ldl")]
+    #[doc(alias = "ldlt")]
+    #[cfg(feature = "cholesky")]
+    pub fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
+        Lblt::new(self.as_ref(), side)
+    }
+    /// Returns the pivoted Cholesky decomposition of `self`, which may be rank deficient. Only the
+    /// provided side is accessed.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn piv_cholesky(&self, side: Side) -> Result<PivCholesky<E::Canonical>, CholeskyError> {
+        PivCholesky::try_new(self.as_ref(), side)
+    }
+    /// Returns the LU decomposition of `self` with partial (row) pivoting.
+    #[track_caller]
+    #[doc(alias = "lu")]
+    #[cfg(feature = "lu")]
+    pub fn partial_piv_lu(&self) -> PartialPivLu<E::Canonical> {
+        PartialPivLu::<E::Canonical>::new(self.as_ref())
+    }
+    /// Returns the LU decomposition of `self` with full pivoting.
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn full_piv_lu(&self) -> FullPivLu<E::Canonical> {
+        FullPivLu::<E::Canonical>::new(self.as_ref())
+    }
     /// Returns the QR decomposition of `self`.
     #[track_caller]
     #[cfg(feature = "qr")]
     pub fn qr(&self) -> Qr<E::Canonical> {
         Qr::<E::Canonical>::new(self.as_ref())
     }
+    /// Returns the LQ decomposition of `self`.
+    #[track_caller]
+    #[cfg(feature = "qr")]
+    pub fn lq(&self) -> Lq<E::Canonical> {
+        Lq::<E::Canonical>::new(self.as_ref())
+    }
     /// Returns the QR decomposition of `self` with column pivoting.
     #[track_caller]
     #[cfg(feature = "qr")]
@@ -2326,6 +3300,23 @@ where
         }
     }
 
+    /// Returns the eigendecomposition of `self`, as a complex matrix, with the eigenvalues sorted
+    /// according to `order`, and the eigenvectors permuted accordingly.
+    ///
+    /// See [`Eigendecomposition::sort`] for how ties are broken.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn eigendecomposition_sorted<
+        ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>,
+    >(
+        &self,
+        order: EigOrder,
+    ) -> Eigendecomposition<ComplexE> {
+        let mut evd = self.eigendecomposition::<ComplexE>();
+        evd.sort(order);
+        evd
+    }
+
     /// Returns the eigendecomposition of `self`, when `E` is in the complex domain.
     #[track_caller]
     #[cfg(feature = "evd")]
@@ -2350,6 +3341,124 @@ where
         }
     }
 
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `2×2` matrix.
+    #[track_caller]
+    pub fn determinant_2x2(&self) -> E::Canonical {
+        assert!(all(self.nrows() == 2, self.ncols() == 2));
+        crate::linalg::closed_form::determinant_with_conj(*self)
+    }
+
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `3×3` matrix.
+    #[track_caller]
+    pub fn determinant_3x3(&self) -> E::Canonical {
+        assert!(all(self.nrows() == 3, self.ncols() == 3));
+        crate::linalg::closed_form::determinant_with_conj(*self)
+    }
+
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `4×4` matrix.
+    #[track_caller]
+    pub fn determinant_4x4(&self) -> E::Canonical {
+        assert!(all(self.nrows() == 4, self.ncols() == 4));
+        crate::linalg::closed_form::determinant_with_conj(*self)
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `2×2` matrix.
+    #[track_caller]
+    pub fn inverse_2x2(&self) -> Mat<E::Canonical> {
+        assert!(all(self.nrows() == 2, self.ncols() == 2));
+        crate::linalg::closed_form::inverse_with_conj(*self).0
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `3×3` matrix.
+    #[track_caller]
+    pub fn inverse_3x3(&self) -> Mat<E::Canonical> {
+        assert!(all(self.nrows() == 3, self.ncols() == 3));
+        crate::linalg::closed_form::inverse_with_conj(*self).0
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `4×4` matrix.
+    #[track_caller]
+    pub fn inverse_4x4(&self) -> Mat<E::Canonical> {
+        assert!(all(self.nrows() == 4, self.ncols() == 4));
+        crate::linalg::closed_form::inverse_with_conj(*self).0
+    }
+
+    /// Returns the adjugate of `self`, i.e. the transpose of its cofactor matrix, computed with the
+    /// closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix of dimension at most `4`.
+    #[track_caller]
+    pub fn adjugate(&self) -> Mat<E::Canonical> {
+        assert!(all(
+            self.nrows() == self.ncols(),
+            self.nrows() <= crate::linalg::closed_form::MAX_DIM,
+        ));
+        crate::linalg::closed_form::adjugate_with_conj(*self)
+    }
+
+    /// Returns the inverse of `self`, or `None` if it is numerically singular.
+    ///
+    /// `self` is considered singular if one of the pivots of its LU decomposition with partial
+    /// pivoting has an absolute value that is not greater than `tol * self.norm_max()`, or if
+    /// it is NaN. `tol` is relative to the magnitude of the entries, so the same value can be
+    /// used regardless of the scaling and the dimension of the matrix.
+    ///
+    /// Matrices of dimension at most `4` are then inverted with the closed form cofactor
+    /// expansion, and larger ones with the LU decomposition.
+    ///
+    /// # Note
+    /// Explicitly forming the inverse is rarely needed: computing `A⁻¹ B` as an inverse followed
+    /// by a product is slower and less accurate than solving the system with a decomposition of
+    /// `A`, e.g., `A.partial_piv_lu().solve(B)`. Prefer the latter unless the entries of the
+    /// inverse are needed themselves.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn try_inverse(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Option<Mat<E::Canonical>> {
+        assert!(self.nrows() == self.ncols());
+        let lu = self.partial_piv_lu();
+        let threshold = tol.faer_mul(self.canonicalize().0.norm_max());
+        for i in 0..self.nrows() {
+            let pivot = lu.factors.read(i, i).faer_abs();
+            if pivot.faer_is_nan() || threshold.faer_is_nan() || pivot <= threshold {
+                return None;
+            }
+        }
+
+        if self.nrows() <= crate::linalg::closed_form::MAX_DIM {
+            Some(crate::linalg::closed_form::inverse_with_conj(*self).0)
+        } else {
+            Some(lu.inverse())
+        }
+    }
+
     /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
     /// side is accessed. The order of the eigenvalues is currently unspecified.
     #[track_caller]
@@ -2422,6 +3531,44 @@ where
         (0..dim).map(|i| s.read(i).faer_real()).collect()
     }
 
+    /// Returns an orthonormal basis of the null space of `self`, as the columns of a matrix with
+    /// `self.ncols()` rows.
+    ///
+    /// The basis is made of the right singular vectors whose singular values are not greater than
+    /// `tol`, so that the result has `self.ncols() - rank` columns, where `rank` is the number of
+    /// singular values greater than `tol`. An empty result indicates that `self` has full column
+    /// rank.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0f64]];
+    /// let n = a.null_space(1e-10);
+    /// assert!(n.ncols() == 2);
+    /// assert!((&a * &n).norm_max() < 1e-10);
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn null_space(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        let svd = self.svd();
+        let rank = svd_rank(svd.s_diagonal(), tol);
+        svd.v().subcols(rank, self.ncols() - rank).to_owned()
+    }
+
+    /// Returns an orthonormal basis of the column space of `self`, as the columns of a matrix with
+    /// `self.nrows()` rows.
+    ///
+    /// The basis is made of the left singular vectors whose singular values are greater than
+    /// `tol`, so that the number of columns of the result is the numerical rank of `self`.
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn column_space(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        let svd = self.svd();
+        let rank = svd_rank(svd.s_diagonal(), tol);
+        svd.u().subcols(0, rank).to_owned()
+    }
+
     /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
     /// currently unspecified.
     #[track_caller]
@@ -2452,6 +3599,242 @@ where
     pub fn complex_eigenvalues(&self) -> alloc::vec::Vec<E::Canonical> {
         Eigendecomposition::<E::Canonical>::__values_from_complex_impl(self.canonicalize())
     }
+
+    /// Returns the reduction of `self` to real bidiagonal form, as a tuple $(U, d, e, V)$ such that
+    /// $A = U B V^H$, where $d$ and $e$ are the diagonal and off-diagonal of $B$, and the columns
+    /// of $U$ and $V$ are orthonormal.
+    ///
+    /// Let $k = \min(m, n)$. $U$ has shape $(m, k)$, $V$ has shape $(n, k)$, $B$ has shape
+    /// $(k, k)$, $d$ has length $k$, and $e$ has length $k - 1$ (or zero if $k$ is zero).
+    /// If $m \geq n$, then $B$ is upper bidiagonal and $e$ is its superdiagonal. Otherwise, $B$ is
+    /// lower bidiagonal and $e$ is its subdiagonal.
+    ///
+    /// The diagonal and off-diagonal entries of $B$ are nonnegative.
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn bidiagonalize(
+        &self,
+    ) -> (
+        Mat<E::Canonical>,
+        Col<<E::Canonical as ComplexField>::Real>,
+        Col<<E::Canonical as ComplexField>::Real>,
+        Mat<E::Canonical>,
+    ) {
+        if self.nrows() < self.ncols() {
+            let (u, d, e, v) = self.adjoint().bidiagonalize();
+            return (v, d, e, u);
+        }
+
+        use crate::linalg::householder::{
+            apply_block_householder_sequence_on_the_left_in_place_req,
+            apply_block_householder_sequence_on_the_left_in_place_with_conj,
+            upgrade_householder_factor,
+        };
+        type Real<E> = <<E as Conjugate>::Canonical as ComplexField>::Real;
+
+        let m = self.nrows();
+        let n = self.ncols();
+        if n == 0 {
+            return (
+                Mat::zeros(m, 0),
+                Col::zeros(0),
+                Col::zeros(0),
+                Mat::zeros(0, 0),
+            );
+        }
+        let parallelism = get_global_parallelism();
+        let blocksize =
+            crate::linalg::qr::no_pivoting::compute::recommended_blocksize::<E::Canonical>(m, n);
+
+        let mut bid = self.to_owned();
+        let mut householder_left = Mat::<E::Canonical>::zeros(blocksize, n);
+        let mut householder_right = Mat::<E::Canonical>::zeros(blocksize, n - 1);
+        crate::linalg::svd::bidiag::bidiagonalize_in_place(
+            bid.as_mut(),
+            householder_left.as_mut().row_mut(0).transpose_mut(),
+            householder_right.as_mut().row_mut(0).transpose_mut(),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::linalg::svd::bidiag::bidiagonalize_in_place_req::<E::Canonical>(
+                    m,
+                    n,
+                    parallelism,
+                )
+                .unwrap(),
+            )),
+        );
+
+        // the reflectors are computed one at a time, upgrade them to blocked form
+        let mut j_base = 0;
+        while j_base < n {
+            let bs = Ord::min(blocksize, n - j_base);
+            let mut householder = householder_left.as_mut().submatrix_mut(0, j_base, bs, bs);
+            for j in 0..bs {
+                householder.write(j, j, householder.read(0, j));
+            }
+            let essentials = bid.as_ref().submatrix(j_base, j_base, m - j_base, bs);
+            upgrade_householder_factor(householder, essentials, bs, 1, parallelism);
+            j_base += bs;
+        }
+        let right_essentials = bid
+            .as_ref()
+            .submatrix(0, 1, m, n - 1)
+            .transpose()
+            .to_owned();
+        let mut j_base = 0;
+        while j_base < n - 1 {
+            let bs = Ord::min(blocksize, n - 1 - j_base);
+            let mut householder = householder_right.as_mut().submatrix_mut(0, j_base, bs, bs);
+            for j in 0..bs {
+                householder.write(j, j, householder.read(0, j));
+            }
+            let essentials =
+                right_essentials
+                    .as_ref()
+                    .submatrix(j_base, j_base, n - 1 - j_base, bs);
+            upgrade_householder_factor(householder, essentials, bs, 1, parallelism);
+            j_base += bs;
+        }
+
+        let mut u = Mat::<E::Canonical>::identity(m, n);
+        apply_block_householder_sequence_on_the_left_in_place_with_conj(
+            bid.as_ref(),
+            householder_left.as_ref(),
+            Conj::No,
+            u.as_mut(),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                apply_block_householder_sequence_on_the_left_in_place_req::<E::Canonical>(
+                    m, blocksize, n,
+                )
+                .unwrap(),
+            )),
+        );
+        let mut v = Mat::<E::Canonical>::identity(n, n);
+        apply_block_householder_sequence_on_the_left_in_place_with_conj(
+            right_essentials.as_ref(),
+            householder_right.as_ref(),
+            Conj::No,
+            v.as_mut().submatrix_mut(1, 0, n - 1, n),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                apply_block_householder_sequence_on_the_left_in_place_req::<E::Canonical>(
+                    n - 1,
+                    blocksize,
+                    n,
+                )
+                .unwrap(),
+            )),
+        );
+
+        // scale the columns of u and v by unit factors p and q so that the entries of
+        // B = diag(p)^H bid diag(q) become real and nonnegative
+        let phase = |x: E::Canonical| {
+            let abs = x.faer_abs();
+            if abs == Real::<E>::faer_zero() {
+                (E::Canonical::faer_one(), abs)
+            } else {
+                (x.faer_scale_real(abs.faer_inv()), abs)
+            }
+        };
+        let mut d = Col::<Real<E>>::zeros(n);
+        let mut e = Col::<Real<E>>::zeros(n - 1);
+        let mut q = E::Canonical::faer_one();
+        for i in 0..n {
+            let (p, abs) = phase(bid.read(i, i).faer_mul(q));
+            d.write(i, abs);
+            scale_col(u.as_mut().col_mut(i), p);
+            scale_col(v.as_mut().col_mut(i), q);
+            if i + 1 < n {
+                let (phase_e, abs) = phase(bid.read(i, i + 1));
+                e.write(i, abs);
+                q = p.faer_mul(phase_e.faer_conj());
+            }
+        }
+
+        (u, d, e, v)
+    }
+
+    /// Returns the reduction of `self` to upper Hessenberg form, as a pair $(Q, H)$ where $Q$ is
+    /// unitary and $H$ is upper Hessenberg, such that $A = Q H Q^H$.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn hessenberg(&self) -> (Mat<E::Canonical>, Mat<E::Canonical>) {
+        let (mut h, householder) = self.hessenberg_packed();
+        let n = h.nrows();
+        let parallelism = get_global_parallelism();
+
+        let mut q = Mat::<E::Canonical>::identity(n, n);
+        if n > 1 {
+            crate::linalg::householder::apply_block_householder_sequence_on_the_right_in_place_with_conj(
+                h.as_ref().submatrix(1, 0, n - 1, n - 1),
+                householder.as_ref(),
+                Conj::No,
+                q.as_mut().submatrix_mut(1, 1, n - 1, n - 1),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    crate::linalg::householder::apply_block_householder_sequence_on_the_right_in_place_req::<
+                        E::Canonical,
+                    >(n - 1, householder.nrows(), n - 1)
+                    .unwrap(),
+                )),
+            );
+        }
+
+        for j in 0..n {
+            for i in j + 2..n {
+                h.write(i, j, E::Canonical::faer_zero());
+            }
+        }
+        (q, h)
+    }
+
+    /// Returns the reduction of `self` to upper Hessenberg form, with the unitary factor stored
+    /// in packed form.
+    ///
+    /// The first matrix contains $H$ in its upper Hessenberg part, and the essential parts of the
+    /// Householder reflectors below the first subdiagonal, while the second matrix contains the
+    /// triangular factors of the blocked Householder sequence. The unitary factor can be applied
+    /// with the functions from [`crate::linalg::householder`], using the submatrix of the first
+    /// matrix that starts at row `1` and column `0` as the Householder basis, and acting on the
+    /// last `n - 1` rows (or columns) of the other operand.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn hessenberg_packed(&self) -> (Mat<E::Canonical>, Mat<E::Canonical>) {
+        assert!(self.nrows() == self.ncols());
+        let n = self.nrows();
+        let parallelism = get_global_parallelism();
+
+        let mut h = self.to_owned();
+        if n < 2 {
+            return (h, Mat::zeros(0, n.saturating_sub(1)));
+        }
+
+        let householder_blocksize = crate::linalg::qr::no_pivoting::compute::recommended_blocksize::<
+            E::Canonical,
+        >(n - 1, n - 1);
+        let mut householder = Mat::<E::Canonical>::zeros(householder_blocksize, n - 1);
+        crate::linalg::evd::hessenberg::make_hessenberg_in_place(
+            h.as_mut(),
+            householder.as_mut().transpose_mut(),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                crate::linalg::evd::hessenberg::make_hessenberg_in_place_req::<E::Canonical>(
+                    n,
+                    householder_blocksize,
+                    parallelism,
+                )
+                .unwrap(),
+            )),
+        );
+        (h, householder)
+    }
 }
 
 impl<E: Conjugate> MatMut<'_, E>
@@ -2547,6 +3930,92 @@ where
     pub fn cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, CholeskyError> {
         self.as_ref().cholesky(side)
     }
+
+    /// Returns the Cholesky decomposition of `self`, along with its pivot statistics, which can be
+    /// used to detect matrices that are nearly singular. Only the provided side is accessed.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn cholesky_with_stats(
+        &self,
+        side: Side,
+    ) -> Result<
+        (
+            Cholesky<E::Canonical>,
+            CholeskyStats<<E::Canonical as ComplexField>::Real>,
+        ),
+        CholeskyError,
+    > {
+        self.as_ref().cholesky_with_stats(side)
+    }
+
+    /// Assuming `self` is self-adjoint and positive definite, solves the equation
+    /// `self * X = rhs` using the Cholesky decomposition, and returns the result. Only the provided
+    /// side is accessed.
+    ///
+    /// Returns an error if the Cholesky decomposition could not be computed because `self` is not
+    /// numerically positive definite. To solve several systems with the same matrix, the
+    /// decomposition from [`Self::cholesky`] should be reused instead.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn selfadjoint_solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+        side: Side,
+    ) -> Result<B::Owned, CholeskyError> {
+        self.as_ref().selfadjoint_solve(rhs, side)
+    }
+
+    /// Solves the equation `self * X = rhs` for a square matrix `self`, and returns the result.
+    /// See [`MatRef::solve`].
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().solve(rhs)
+    }
+
+    /// Solves the equation `self * X = rhs` for a square matrix `self` with the given structure,
+    /// and returns the result. See [`MatRef::solve_with_structure`].
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn solve_with_structure<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+        structure: MatrixStructure,
+    ) -> B::Owned {
+        self.as_ref().solve_with_structure(rhs, structure)
+    }
+
+    /// Returns the least squares solution of `self * X = rhs`, for a matrix of any shape and
+    /// rank.
+    ///
+    /// See [`MatRef::solve_lstsq`] for the choice of the decomposition.
+    #[track_caller]
+    #[cfg(all(feature = "qr", feature = "svd"))]
+    pub fn solve_lstsq<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().solve_lstsq(rhs)
+    }
+
+    /// Returns the least squares solution of `self * X = rhs`, along with the norm of the
+    /// residual.
+    ///
+    /// See [`MatRef::solve_lstsq_with_residual_norm`].
+    #[track_caller]
+    #[cfg(all(feature = "qr", feature = "svd"))]
+    pub fn solve_lstsq_with_residual_norm<
+        ViewE: Conjugate<Canonical = E::Canonical>,
+        B: ColBatch<ViewE>,
+    >(
+        &self,
+        rhs: B,
+    ) -> (B::Owned, <E::Canonical as ComplexField>::Real) {
+        self.as_ref().solve_lstsq_with_residual_norm(rhs)
+    }
     /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
     #[track_caller]
     #[doc(alias = "ldl")]
@@ -2555,6 +4024,13 @@ where
     pub fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
         self.as_ref().lblt(side)
     }
+    /// Returns the pivoted Cholesky decomposition of `self`, which may be rank deficient. Only the
+    /// provided side is accessed.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn piv_cholesky(&self, side: Side) -> Result<PivCholesky<E::Canonical>, CholeskyError> {
+        self.as_ref().piv_cholesky(side)
+    }
     /// Returns the LU decomposition of `self` with partial (row) pivoting.
     #[track_caller]
     #[doc(alias = "lu")]
@@ -2574,6 +4050,12 @@ where
     pub fn qr(&self) -> Qr<E::Canonical> {
         self.as_ref().qr()
     }
+    /// Returns the LQ decomposition of `self`.
+    #[track_caller]
+    #[cfg(feature = "qr")]
+    pub fn lq(&self) -> Lq<E::Canonical> {
+        self.as_ref().lq()
+    }
     /// Returns the QR decomposition of `self` with column pivoting.
     #[track_caller]
     #[cfg(feature = "qr")]
@@ -2615,6 +4097,21 @@ where
         self.as_ref().eigendecomposition::<ComplexE>()
     }
 
+    /// Returns the eigendecomposition of `self`, as a complex matrix, with the eigenvalues sorted
+    /// according to `order`, and the eigenvectors permuted accordingly.
+    ///
+    /// See [`Eigendecomposition::sort`] for how ties are broken.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn eigendecomposition_sorted<
+        ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>,
+    >(
+        &self,
+        order: EigOrder,
+    ) -> Eigendecomposition<ComplexE> {
+        self.as_ref().eigendecomposition_sorted::<ComplexE>(order)
+    }
+
     /// Returns the eigendecomposition of `self`, when `E` is in the complex domain.
     #[track_caller]
     #[cfg(feature = "evd")]
@@ -2629,6 +4126,100 @@ where
         self.as_ref().determinant()
     }
 
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `2×2` matrix.
+    #[track_caller]
+    pub fn determinant_2x2(&self) -> E::Canonical {
+        self.as_ref().determinant_2x2()
+    }
+
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `3×3` matrix.
+    #[track_caller]
+    pub fn determinant_3x3(&self) -> E::Canonical {
+        self.as_ref().determinant_3x3()
+    }
+
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `4×4` matrix.
+    #[track_caller]
+    pub fn determinant_4x4(&self) -> E::Canonical {
+        self.as_ref().determinant_4x4()
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `2×2` matrix.
+    #[track_caller]
+    pub fn inverse_2x2(&self) -> Mat<E::Canonical> {
+        self.as_ref().inverse_2x2()
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `3×3` matrix.
+    #[track_caller]
+    pub fn inverse_3x3(&self) -> Mat<E::Canonical> {
+        self.as_ref().inverse_3x3()
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `4×4` matrix.
+    #[track_caller]
+    pub fn inverse_4x4(&self) -> Mat<E::Canonical> {
+        self.as_ref().inverse_4x4()
+    }
+
+    /// Returns the adjugate of `self`, i.e. the transpose of its cofactor matrix, computed with the
+    /// closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix of dimension at most `4`.
+    #[track_caller]
+    pub fn adjugate(&self) -> Mat<E::Canonical> {
+        self.as_ref().adjugate()
+    }
+
+    /// Returns the inverse of `self`, or `None` if it is numerically singular.
+    ///
+    /// `self` is considered singular if one of the pivots of its LU decomposition with partial
+    /// pivoting has an absolute value that is not greater than `tol * self.norm_max()`, or if
+    /// it is NaN. `tol` is relative to the magnitude of the entries, so the same value can be
+    /// used regardless of the scaling and the dimension of the matrix.
+    ///
+    /// Matrices of dimension at most `4` are then inverted with the closed form cofactor
+    /// expansion, and larger ones with the LU decomposition.
+    ///
+    /// # Note
+    /// Explicitly forming the inverse is rarely needed: computing `A⁻¹ B` as an inverse followed
+    /// by a product is slower and less accurate than solving the system with a decomposition of
+    /// `A`, e.g., `A.partial_piv_lu().solve(B)`. Prefer the latter unless the entries of the
+    /// inverse are needed themselves.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn try_inverse(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Option<Mat<E::Canonical>> {
+        self.as_ref().try_inverse(tol)
+    }
+
     /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
     /// side is accessed. The order of the eigenvalues is currently unspecified.
     #[track_caller]
@@ -2648,6 +4239,24 @@ where
         self.as_ref().singular_values()
     }
 
+    /// Returns an orthonormal basis of the null space of `self`.
+    ///
+    /// See [`MatRef::null_space`].
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn null_space(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        self.as_ref().null_space(tol)
+    }
+
+    /// Returns an orthonormal basis of the column space of `self`.
+    ///
+    /// See [`MatRef::column_space`].
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn column_space(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        self.as_ref().column_space(tol)
+    }
+
     /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
     /// currently unspecified.
     #[track_caller]
@@ -2665,6 +4274,43 @@ where
     pub fn complex_eigenvalues(&self) -> alloc::vec::Vec<E::Canonical> {
         self.as_ref().complex_eigenvalues()
     }
+
+    /// Returns the reduction of `self` to upper Hessenberg form, as a pair $(Q, H)$ where $Q$ is
+    /// unitary and $H$ is upper Hessenberg, such that $A = Q H Q^H$.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn hessenberg(&self) -> (Mat<E::Canonical>, Mat<E::Canonical>) {
+        self.as_ref().hessenberg()
+    }
+
+    /// Returns the reduction of `self` to real bidiagonal form, as a tuple $(U, d, e, V)$ such that
+    /// $A = U B V^H$. See [`MatRef::bidiagonalize`].
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn bidiagonalize(
+        &self,
+    ) -> (
+        Mat<E::Canonical>,
+        Col<<E::Canonical as ComplexField>::Real>,
+        Col<<E::Canonical as ComplexField>::Real>,
+        Mat<E::Canonical>,
+    ) {
+        self.as_ref().bidiagonalize()
+    }
+
+    /// Returns the reduction of `self` to upper Hessenberg form, with the unitary factor stored
+    /// in packed form. See [`MatRef::hessenberg_packed`].
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn hessenberg_packed(&self) -> (Mat<E::Canonical>, Mat<E::Canonical>) {
+        self.as_ref().hessenberg_packed()
+    }
 }
 
 impl<E: Conjugate> Mat<E>
@@ -2760,6 +4406,92 @@ where
     pub fn cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, CholeskyError> {
         self.as_ref().cholesky(side)
     }
+
+    /// Returns the Cholesky decomposition of `self`, along with its pivot statistics, which can be
+    /// used to detect matrices that are nearly singular. Only the provided side is accessed.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn cholesky_with_stats(
+        &self,
+        side: Side,
+    ) -> Result<
+        (
+            Cholesky<E::Canonical>,
+            CholeskyStats<<E::Canonical as ComplexField>::Real>,
+        ),
+        CholeskyError,
+    > {
+        self.as_ref().cholesky_with_stats(side)
+    }
+
+    /// Assuming `self` is self-adjoint and positive definite, solves the equation
+    /// `self * X = rhs` using the Cholesky decomposition, and returns the result. Only the provided
+    /// side is accessed.
+    ///
+    /// Returns an error if the Cholesky decomposition could not be computed because `self` is not
+    /// numerically positive definite. To solve several systems with the same matrix, the
+    /// decomposition from [`Self::cholesky`] should be reused instead.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn selfadjoint_solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+        side: Side,
+    ) -> Result<B::Owned, CholeskyError> {
+        self.as_ref().selfadjoint_solve(rhs, side)
+    }
+
+    /// Solves the equation `self * X = rhs` for a square matrix `self`, and returns the result.
+    /// See [`MatRef::solve`].
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().solve(rhs)
+    }
+
+    /// Solves the equation `self * X = rhs` for a square matrix `self` with the given structure,
+    /// and returns the result. See [`MatRef::solve_with_structure`].
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn solve_with_structure<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+        structure: MatrixStructure,
+    ) -> B::Owned {
+        self.as_ref().solve_with_structure(rhs, structure)
+    }
+
+    /// Returns the least squares solution of `self * X = rhs`, for a matrix of any shape and
+    /// rank.
+    ///
+    /// See [`MatRef::solve_lstsq`] for the choice of the decomposition.
+    #[track_caller]
+    #[cfg(all(feature = "qr", feature = "svd"))]
+    pub fn solve_lstsq<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().solve_lstsq(rhs)
+    }
+
+    /// Returns the least squares solution of `self * X = rhs`, along with the norm of the
+    /// residual.
+    ///
+    /// See [`MatRef::solve_lstsq_with_residual_norm`].
+    #[track_caller]
+    #[cfg(all(feature = "qr", feature = "svd"))]
+    pub fn solve_lstsq_with_residual_norm<
+        ViewE: Conjugate<Canonical = E::Canonical>,
+        B: ColBatch<ViewE>,
+    >(
+        &self,
+        rhs: B,
+    ) -> (B::Owned, <E::Canonical as ComplexField>::Real) {
+        self.as_ref().solve_lstsq_with_residual_norm(rhs)
+    }
     /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
     #[track_caller]
     #[doc(alias = "ldl")]
@@ -2768,6 +4500,13 @@ where
     pub fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
         self.as_ref().lblt(side)
     }
+    /// Returns the pivoted Cholesky decomposition of `self`, which may be rank deficient. Only the
+    /// provided side is accessed.
+    #[track_caller]
+    #[cfg(feature = "cholesky")]
+    pub fn piv_cholesky(&self, side: Side) -> Result<PivCholesky<E::Canonical>, CholeskyError> {
+        self.as_ref().piv_cholesky(side)
+    }
     /// Returns the LU decomposition of `self` with partial (row) pivoting.
     #[track_caller]
     #[doc(alias = "lu")]
@@ -2787,6 +4526,12 @@ where
     pub fn qr(&self) -> Qr<E::Canonical> {
         self.as_ref().qr()
     }
+    /// Returns the LQ decomposition of `self`.
+    #[track_caller]
+    #[cfg(feature = "qr")]
+    pub fn lq(&self) -> Lq<E::Canonical> {
+        self.as_ref().lq()
+    }
     /// Returns the QR decomposition of `self` with column pivoting.
     #[track_caller]
     #[cfg(feature = "qr")]
@@ -2828,6 +4573,21 @@ where
         self.as_ref().eigendecomposition::<ComplexE>()
     }
 
+    /// Returns the eigendecomposition of `self`, as a complex matrix, with the eigenvalues sorted
+    /// according to `order`, and the eigenvectors permuted accordingly.
+    ///
+    /// See [`Eigendecomposition::sort`] for how ties are broken.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn eigendecomposition_sorted<
+        ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>,
+    >(
+        &self,
+        order: EigOrder,
+    ) -> Eigendecomposition<ComplexE> {
+        self.as_ref().eigendecomposition_sorted::<ComplexE>(order)
+    }
+
     /// Returns the eigendecomposition of `self`, when `E` is in the complex domain.
     #[track_caller]
     #[cfg(feature = "evd")]
@@ -2842,6 +4602,100 @@ where
         self.as_ref().determinant()
     }
 
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `2×2` matrix.
+    #[track_caller]
+    pub fn determinant_2x2(&self) -> E::Canonical {
+        self.as_ref().determinant_2x2()
+    }
+
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `3×3` matrix.
+    #[track_caller]
+    pub fn determinant_3x3(&self) -> E::Canonical {
+        self.as_ref().determinant_3x3()
+    }
+
+    /// Returns the determinant of `self`, computed with the closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `4×4` matrix.
+    #[track_caller]
+    pub fn determinant_4x4(&self) -> E::Canonical {
+        self.as_ref().determinant_4x4()
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `2×2` matrix.
+    #[track_caller]
+    pub fn inverse_2x2(&self) -> Mat<E::Canonical> {
+        self.as_ref().inverse_2x2()
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `3×3` matrix.
+    #[track_caller]
+    pub fn inverse_3x3(&self) -> Mat<E::Canonical> {
+        self.as_ref().inverse_3x3()
+    }
+
+    /// Returns the inverse of `self`, computed from its adjugate with the closed form cofactor
+    /// expansion. If `self` is singular, the result contains non finite values.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a `4×4` matrix.
+    #[track_caller]
+    pub fn inverse_4x4(&self) -> Mat<E::Canonical> {
+        self.as_ref().inverse_4x4()
+    }
+
+    /// Returns the adjugate of `self`, i.e. the transpose of its cofactor matrix, computed with the
+    /// closed form cofactor expansion.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix of dimension at most `4`.
+    #[track_caller]
+    pub fn adjugate(&self) -> Mat<E::Canonical> {
+        self.as_ref().adjugate()
+    }
+
+    /// Returns the inverse of `self`, or `None` if it is numerically singular.
+    ///
+    /// `self` is considered singular if one of the pivots of its LU decomposition with partial
+    /// pivoting has an absolute value that is not greater than `tol * self.norm_max()`, or if
+    /// it is NaN. `tol` is relative to the magnitude of the entries, so the same value can be
+    /// used regardless of the scaling and the dimension of the matrix.
+    ///
+    /// Matrices of dimension at most `4` are then inverted with the closed form cofactor
+    /// expansion, and larger ones with the LU decomposition.
+    ///
+    /// # Note
+    /// Explicitly forming the inverse is rarely needed: computing `A⁻¹ B` as an inverse followed
+    /// by a product is slower and less accurate than solving the system with a decomposition of
+    /// `A`, e.g., `A.partial_piv_lu().solve(B)`. Prefer the latter unless the entries of the
+    /// inverse are needed themselves.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "lu")]
+    pub fn try_inverse(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Option<Mat<E::Canonical>> {
+        self.as_ref().try_inverse(tol)
+    }
+
     /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
     /// side is accessed. The order of the eigenvalues is currently unspecified.
     #[track_caller]
@@ -2861,6 +4715,24 @@ where
         self.as_ref().singular_values()
     }
 
+    /// Returns an orthonormal basis of the null space of `self`.
+    ///
+    /// See [`MatRef::null_space`].
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn null_space(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        self.as_ref().null_space(tol)
+    }
+
+    /// Returns an orthonormal basis of the column space of `self`.
+    ///
+    /// See [`MatRef::column_space`].
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn column_space(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        self.as_ref().column_space(tol)
+    }
+
     /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
     /// currently unspecified.
     #[track_caller]
@@ -2878,6 +4750,43 @@ where
     pub fn complex_eigenvalues(&self) -> alloc::vec::Vec<E::Canonical> {
         self.as_ref().complex_eigenvalues()
     }
+
+    /// Returns the reduction of `self` to upper Hessenberg form, as a pair $(Q, H)$ where $Q$ is
+    /// unitary and $H$ is upper Hessenberg, such that $A = Q H Q^H$.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn hessenberg(&self) -> (Mat<E::Canonical>, Mat<E::Canonical>) {
+        self.as_ref().hessenberg()
+    }
+
+    /// Returns the reduction of `self` to real bidiagonal form, as a tuple $(U, d, e, V)$ such that
+    /// $A = U B V^H$. See [`MatRef::bidiagonalize`].
+    #[track_caller]
+    #[cfg(feature = "svd")]
+    pub fn bidiagonalize(
+        &self,
+    ) -> (
+        Mat<E::Canonical>,
+        Col<<E::Canonical as ComplexField>::Real>,
+        Col<<E::Canonical as ComplexField>::Real>,
+        Mat<E::Canonical>,
+    ) {
+        self.as_ref().bidiagonalize()
+    }
+
+    /// Returns the reduction of `self` to upper Hessenberg form, with the unitary factor stored
+    /// in packed form. See [`MatRef::hessenberg_packed`].
+    ///
+    /// # Panics
+    /// Panics if `self` is not a square matrix.
+    #[track_caller]
+    #[cfg(feature = "evd")]
+    pub fn hessenberg_packed(&self) -> (Mat<E::Canonical>, Mat<E::Canonical>) {
+        self.as_ref().hessenberg_packed()
+    }
 }
 
 #[cfg(test)]
@@ -2970,27 +4879,196 @@ mod tests {
         let sol = decomp.solve_conj_transpose(&rhs);
         check_mat_approx_eq(H.adjoint() * &sol, &rhs);
 
-        check_mat_approx_eq(decomp.reconstruct(), H);
-        check_mat_approx_eq(H * decomp.inverse(), I);
+        check_mat_approx_eq(decomp.reconstruct(), H);
+        check_mat_approx_eq(H * decomp.inverse(), I);
+    }
+
+    fn test_solver_lstsq(
+        H: impl AsMatRef<c64, R = usize, C = usize>,
+        decomp: &dyn SolverLstsqCore<c64>,
+    ) {
+        let H = H.as_mat_ref();
+
+        let m = H.nrows();
+        let k = 2;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let rhs = Mat::from_fn(m, k, random);
+
+        let sol = decomp.solve_lstsq(&rhs);
+        check_mat_approx_eq(H.adjoint() * H * &sol, H.adjoint() * &rhs);
+
+        let sol = decomp.solve_lstsq_conj(&rhs);
+        check_mat_approx_eq(H.transpose() * H.conjugate() * &sol, H.transpose() * &rhs);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "cholesky",
+        feature = "lu",
+        feature = "qr",
+        feature = "svd",
+        feature = "evd"
+    ))]
+    fn test_trivial_sizes() {
+        let tol = 1e-14;
+
+        let H = Mat::<c64>::zeros(0, 0);
+        test_solver(&H, &H.cholesky(Side::Lower).unwrap());
+        test_solver(&H, &H.lblt(Side::Lower));
+        test_solver(&H, &H.partial_piv_lu());
+        test_solver(&H, &H.full_piv_lu());
+        test_solver(&H, &H.qr());
+        test_solver(&H, &H.col_piv_qr());
+        test_solver(&H, &H.svd());
+        test_solver(&H, &H.thin_svd());
+        test_solver(&H, &H.selfadjoint_eigendecomposition(Side::Lower));
+        let evd = H.complex_eigendecomposition();
+        assert!(all(
+            evd.u().shape() == (0, 0),
+            evd.s().column_vector().nrows() == 0
+        ));
+        assert!(H.determinant() == c64::faer_one());
+        assert!(H.singular_values().is_empty());
+        assert!(H.selfadjoint_eigenvalues(Side::Lower).is_empty());
+        assert!(H.complex_eigenvalues().is_empty());
+
+        for (m, n) in [(0, 3), (3, 0)] {
+            let A = Mat::<c64>::zeros(m, n);
+            let svd = A.svd();
+            assert!(all(svd.u().shape() == (m, m), svd.v().shape() == (n, n)));
+            check_mat_approx_eq(svd.u() * svd.u().adjoint(), Mat::<c64>::identity(m, m));
+            check_mat_approx_eq(svd.v() * svd.v().adjoint(), Mat::<c64>::identity(n, n));
+            let qr = A.qr();
+            assert!(all(
+                qr.compute_q().shape() == (m, m),
+                qr.compute_r().shape() == (m, n)
+            ));
+            check_mat_approx_eq(
+                qr.compute_q() * qr.compute_q().adjoint(),
+                Mat::<c64>::identity(m, m),
+            );
+        }
+
+        // 1x1 inputs produce the scalar result directly
+        let a = c64::new(3.0, -4.0);
+        let H = Mat::from_fn(1, 1, |_, _| a);
+        let spd = Mat::from_fn(1, 1, |_, _| c64::new(4.0, 0.0));
+
+        test_solver(&spd, &spd.cholesky(Side::Lower).unwrap());
+        test_solver(&spd, &spd.lblt(Side::Lower));
+        test_solver(&H, &H.partial_piv_lu());
+        test_solver(&H, &H.full_piv_lu());
+        test_solver(&H, &H.qr());
+        test_solver(&H, &H.col_piv_qr());
+        test_solver(&H, &H.svd());
+        test_solver(&H, &H.thin_svd());
+        test_solver(&spd, &spd.selfadjoint_eigendecomposition(Side::Lower));
+
+        assert!(
+            (spd.cholesky(Side::Lower).unwrap().compute_l().read(0, 0) - c64::new(2.0, 0.0)).abs()
+                < tol
+        );
+        let lu = H.partial_piv_lu();
+        assert!(all(
+            lu.compute_l().read(0, 0) == c64::faer_one(),
+            lu.compute_u().read(0, 0) == a,
+        ));
+        let qr = H.qr();
+        assert!((qr.compute_q().read(0, 0).abs() - 1.0).abs() < tol);
+        assert!((qr.compute_r().read(0, 0).abs() - 5.0).abs() < tol);
+        assert!((H.svd().s_diagonal().read(0) - c64::new(5.0, 0.0)).abs() < tol);
+        assert!((H.singular_values()[0] - 5.0).abs() < tol);
+        assert!((H.complex_eigenvalues()[0] - a).abs() < tol);
+        let evd = H.complex_eigendecomposition();
+        assert!((evd.s().column_vector().read(0) - a).abs() < tol);
+        assert!((evd.u().read(0, 0).abs() - 1.0).abs() < tol);
+        assert!((spd.selfadjoint_eigenvalues(Side::Lower)[0] - 4.0).abs() < tol);
+        assert!((H.determinant() - a).abs() < tol);
+
+        let x = Mat::from_fn(1, 1, |_, _| -2.5f64);
+        assert!((x.eigenvalues::<c64>()[0] - c64::new(-2.5, 0.0)).abs() < tol);
+        assert!((x.singular_values()[0] - 2.5).abs() < tol);
+        assert!(x.cholesky(Side::Lower).is_err());
     }
 
-    fn test_solver_lstsq(
-        H: impl AsMatRef<c64, R = usize, C = usize>,
-        decomp: &dyn SolverLstsqCore<c64>,
-    ) {
-        let H = H.as_mat_ref();
+    #[test]
+    #[cfg(all(
+        feature = "cholesky",
+        feature = "lu",
+        feature = "qr",
+        feature = "svd",
+        feature = "evd"
+    ))]
+    fn test_non_finite_inputs() {
+        let n = 6;
+
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let mut H = Mat::from_fn(n, n, |i, j| {
+                if i == j {
+                    10.0
+                } else {
+                    1.0 / (1 + i + j) as f64
+                }
+            });
+            H.write(3, 1, bad);
+            H.write(1, 3, bad);
 
-        let m = H.nrows();
-        let k = 2;
+            let lu = H.partial_piv_lu();
+            assert!(!(lu.compute_l().is_all_finite() && lu.compute_u().is_all_finite()));
+            assert!(lu.row_permutation().arrays() == H.partial_piv_lu().row_permutation().arrays());
 
-        let random = |_, _| c64::new(rand::random(), rand::random());
-        let rhs = Mat::from_fn(m, k, random);
+            let lu = H.full_piv_lu();
+            assert!(!(lu.compute_l().is_all_finite() && lu.compute_u().is_all_finite()));
+            let lu2 = H.full_piv_lu();
+            assert!(all(
+                lu.row_permutation().arrays() == lu2.row_permutation().arrays(),
+                lu.col_permutation().arrays() == lu2.col_permutation().arrays(),
+            ));
 
-        let sol = decomp.solve_lstsq(&rhs);
-        check_mat_approx_eq(H.adjoint() * H * &sol, H.adjoint() * &rhs);
+            let qr = H.qr();
+            assert!(!(qr.compute_q().is_all_finite() && qr.compute_r().is_all_finite()));
 
-        let sol = decomp.solve_lstsq_conj(&rhs);
-        check_mat_approx_eq(H.transpose() * H.conjugate() * &sol, H.transpose() * &rhs);
+            let qr = H.col_piv_qr();
+            assert!(!(qr.compute_q().is_all_finite() && qr.compute_r().is_all_finite()));
+            assert!(qr.col_permutation().arrays() == H.col_piv_qr().col_permutation().arrays());
+
+            let lblt = H.lblt(Side::Lower);
+            assert!(!(lblt.compute_l().is_all_finite() && lblt.compute_b().is_all_finite()));
+            assert!(lblt.permutation().arrays() == H.lblt(Side::Lower).permutation().arrays());
+            if let Ok(llt) = H.cholesky(Side::Lower) {
+                assert!(!llt.compute_l().is_all_finite());
+            }
+
+            let is_nan = |m: MatRef<'_, f64>| {
+                let mut nan = true;
+                for j in 0..m.ncols() {
+                    for i in 0..m.nrows() {
+                        nan &= m.read(i, j).is_nan();
+                    }
+                }
+                nan
+            };
+            let svd = H.svd();
+            assert!(all(
+                is_nan(svd.u()),
+                is_nan(svd.v()),
+                is_nan(svd.s_diagonal().as_2d()),
+            ));
+            let evd = H.selfadjoint_eigendecomposition(Side::Lower);
+            assert!(all(
+                is_nan(evd.u()),
+                is_nan(evd.s().column_vector().as_2d())
+            ));
+            assert!(H
+                .selfadjoint_eigenvalues(Side::Lower)
+                .iter()
+                .all(|x| x.is_nan()));
+            assert!(H
+                .eigenvalues::<c64>()
+                .iter()
+                .all(|x| x.re.is_nan() && x.im.is_nan()));
+        }
     }
 
     #[test]
@@ -3006,6 +5084,110 @@ mod tests {
         test_solver_real(&H, &H.lblt(Side::Upper));
     }
 
+    #[test]
+    #[cfg(all(feature = "qr", feature = "svd"))]
+    fn test_solve_lstsq_any_shape() {
+        let random = |_, _| c64::new(rand::random(), rand::random());
+
+        for (m, n, rank) in [
+            (7, 4, 4),
+            (4, 7, 4),
+            (5, 5, 5),
+            (7, 4, 2),
+            (4, 7, 2),
+            (5, 5, 3),
+        ] {
+            let A = Mat::from_fn(m, rank, random) * Mat::from_fn(rank, n, random);
+            let B = Mat::from_fn(m, 3, random);
+
+            // the pseudoinverse gives the minimum-norm least squares solution
+            let expected = A.svd().pseudoinverse() * &B;
+            let X = A.solve_lstsq(&B);
+            assert!(X.shape() == (n, 3));
+            assert!((&X - &expected).norm_max() < 1e-10);
+
+            // conjugated views and single columns
+            let b = Col::from_fn(n, |i| random(i, 0));
+            let x = A.adjoint().solve_lstsq(b.as_ref().conjugate());
+            assert!(x.nrows() == m);
+            let expected = A.adjoint().svd().pseudoinverse() * b.as_ref().conjugate();
+            assert!((&x - &expected).norm_max() < 1e-10);
+
+            let (X, residual) = A.solve_lstsq_with_residual_norm(&B);
+            assert!((residual - (&A * &X - &B).norm_l2()).abs() < 1e-10);
+            if rank == m {
+                assert!(residual < 1e-10);
+            }
+        }
+
+        let X = Mat::<f64>::zeros(0, 3).solve_lstsq(Mat::<f64>::zeros(0, 2));
+        assert!(all(X.shape() == (3, 2), X.norm_max() == 0.0));
+        let X = Mat::<f64>::zeros(3, 0).solve_lstsq(Mat::<f64>::ones(3, 2));
+        assert!(X.shape() == (0, 2));
+    }
+
+    #[test]
+    #[cfg(feature = "cholesky")]
+    fn test_piv_cholesky() {
+        let n = 8;
+        let r = 3;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let B = Mat::from_fn(n, r, random);
+        let H = &B * B.adjoint();
+
+        for side in [Side::Lower, Side::Upper] {
+            let chol = H.piv_cholesky(side).unwrap();
+            assert!(chol.rank() == r);
+
+            let L = chol.compute_l();
+            assert!(L.shape() == (n, r));
+            for j in 0..r {
+                for i in 0..j {
+                    assert!(L.read(i, j) == c64::faer_zero());
+                }
+                // pivots are non-increasing
+                if j > 0 {
+                    assert!(L.read(j, j).re <= L.read(j - 1, j - 1).re);
+                }
+            }
+
+            let P = chol.permutation();
+            let PHPt = Mat::from_fn(n, n, |i, j| H.read(P.arrays().0[i], P.arrays().0[j]));
+            check_mat_approx_eq(&L * L.adjoint(), PHPt);
+        }
+
+        // a full rank matrix gives the usual factorization, up to permutation
+        let H = &H + Mat::<c64>::identity(n, n);
+        assert!(H.piv_cholesky(Side::Lower).unwrap().rank() == n);
+
+        // the tolerance only keeps the dominant directions
+        let D = Mat::from_fn(3, 3, |i, j| if i == j { [1e-3, 1.0, 1e-9][i] } else { 0.0 });
+        let chol = PivCholesky::try_new_with_tolerance(D.as_ref(), Side::Lower, 1e-6).unwrap();
+        assert!(all(
+            chol.rank() == 2,
+            chol.permutation().arrays().0 == [1, 0, 2],
+        ));
+
+        assert!(
+            Mat::<f64>::zeros(3, 3)
+                .piv_cholesky(Side::Lower)
+                .unwrap()
+                .rank()
+                == 0
+        );
+        assert!(
+            Mat::<f64>::zeros(0, 0)
+                .piv_cholesky(Side::Lower)
+                .unwrap()
+                .rank()
+                == 0
+        );
+        assert!((-Mat::<f64>::identity(2, 2))
+            .piv_cholesky(Side::Lower)
+            .is_err());
+    }
+
     #[test]
     #[cfg(feature = "cholesky")]
     fn test_lblt() {
@@ -3019,6 +5201,25 @@ mod tests {
         test_solver(&H, &H.lblt(Side::Upper));
     }
 
+    #[test]
+    #[cfg(feature = "cholesky")]
+    fn test_lblt_factors() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let H = &H + H.adjoint();
+
+        let decomp = H.lblt(Side::Lower);
+        let p = decomp.permutation();
+        let l = decomp.compute_l();
+        let b = decomp.compute_b();
+
+        check_mat_approx_eq(&b, b.adjoint().to_owned());
+        check_mat_approx_eq(p * &H * p.inverse(), &l * &b * l.adjoint());
+        check_mat_approx_eq(decomp.reconstruct(), &H);
+    }
+
     #[test]
     #[cfg(feature = "cholesky")]
     fn test_cholesky() {
@@ -3032,6 +5233,127 @@ mod tests {
         test_solver(&H, &H.cholesky(Side::Upper).unwrap());
     }
 
+    #[test]
+    #[cfg(feature = "cholesky")]
+    fn test_selfadjoint_solve() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(n, n, random);
+        let H = A.adjoint() * &A + Mat::<c64>::identity(n, n);
+        let B = Mat::from_fn(n, 3, random);
+
+        let X = H.selfadjoint_solve(&B, Side::Lower).unwrap();
+        check_mat_approx_eq(&H * &X, &B);
+        let X = H.as_ref().selfadjoint_solve(&B, Side::Upper).unwrap();
+        check_mat_approx_eq(&H * &X, &B);
+        let x = H.selfadjoint_solve(B.col(0), Side::Lower).unwrap();
+        check_mat_approx_eq(x.as_2d(), X.col(0).as_2d());
+
+        let indefinite = mat![[1.0, 0.0], [0.0, -1.0f64]];
+        assert!(indefinite
+            .selfadjoint_solve(col![1.0, 1.0f64], Side::Lower)
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "cholesky")]
+    #[cfg(feature = "evd")]
+    fn test_cholesky_stats() {
+        let n = 7;
+        let D = Mat::<f64>::from_fn(n, n, |i, j| if i == j { (i + 1) as f64 } else { 0.0 });
+        let (_, stats) = D.cholesky_with_stats(Side::Lower).unwrap();
+        assert!((stats.min_pivot - 1.0).abs() < 1e-14);
+        assert!((stats.max_pivot - n as f64).abs() < 1e-14 * n as f64);
+
+        let hilbert = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let (llt, stats) = hilbert.cholesky_with_stats(Side::Upper).unwrap();
+        assert!(stats.pivot_ratio() > 1e6);
+        assert!(stats.min_pivot == llt.stats().min_pivot);
+
+        let s = hilbert.selfadjoint_eigenvalues(Side::Lower);
+        assert!(stats.min_pivot >= s[0] * (1.0 - 1e-6));
+        assert!(stats.max_pivot <= s[n - 1] * (1.0 + 1e-6));
+    }
+
+    #[test]
+    #[cfg(feature = "cholesky")]
+    #[cfg(feature = "lu")]
+    #[cfg(feature = "qr")]
+    fn test_new_in_place() {
+        let n = 7;
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let H = &H * H.adjoint();
+
+        check_mat_approx_eq(
+            Cholesky::try_new_in_place(H.clone(), Side::Lower)
+                .unwrap()
+                .compute_l(),
+            H.cholesky(Side::Lower).unwrap().compute_l(),
+        );
+        check_mat_approx_eq(
+            Cholesky::try_new_in_place(H.clone(), Side::Upper)
+                .unwrap()
+                .compute_l(),
+            H.cholesky(Side::Upper).unwrap().compute_l(),
+        );
+        check_mat_approx_eq(
+            PartialPivLu::new_in_place(H.clone()).compute_u(),
+            H.partial_piv_lu().compute_u(),
+        );
+        check_mat_approx_eq(
+            FullPivLu::new_in_place(H.clone()).compute_u(),
+            H.full_piv_lu().compute_u(),
+        );
+        check_mat_approx_eq(Qr::new_in_place(H.clone()).compute_r(), H.qr().compute_r());
+        check_mat_approx_eq(
+            ColPivQr::new_in_place(H.clone()).compute_r(),
+            H.col_piv_qr().compute_r(),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cholesky")]
+    #[cfg(feature = "evd")]
+    fn test_selfadjoint_ignores_other_side() {
+        for n in [7, 100] {
+            let random = |_, _| c64::new(rand::random(), rand::random());
+            let A = Mat::from_fn(n, n, random);
+            let H = A.adjoint() * &A + Mat::<c64>::identity(n, n);
+            let eigs = H.selfadjoint_eigenvalues(Side::Lower);
+            let tol = 1e-10 * n as f64;
+
+            for side in [Side::Lower, Side::Upper] {
+                // only the triangle selected by `side` holds meaningful values
+                let half = Mat::from_fn(n, n, |i, j| match side {
+                    Side::Lower if i < j => c64::new(f64::NAN, f64::NAN),
+                    Side::Upper if i > j => c64::new(f64::NAN, f64::NAN),
+                    _ => H.read(i, j),
+                });
+
+                let decomps: [&dyn SolverCore<c64>; 4] = [
+                    &half.cholesky(side).unwrap(),
+                    &Cholesky::try_new_in_place(half.clone(), side).unwrap(),
+                    &half.lblt(side),
+                    &half.selfadjoint_eigendecomposition(side),
+                ];
+                for decomp in decomps {
+                    assert!((decomp.reconstruct() - &H).norm_max() < tol);
+                }
+
+                let s = half.selfadjoint_eigenvalues(side);
+                for (s, eig) in s.iter().zip(&eigs) {
+                    assert!((s - eig).abs() < tol);
+                }
+
+                let B = Mat::from_fn(n, 2, random);
+                let X = half.selfadjoint_solve(&B, side).unwrap();
+                assert!((&H * &X - &B).norm_max() < tol);
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "lu")]
     fn test_partial_piv_lu() {
@@ -3041,6 +5363,62 @@ mod tests {
         let H = Mat::from_fn(n, n, random);
 
         test_solver(&H, &H.partial_piv_lu());
+
+        let lu = H.partial_piv_lu();
+        let P = lu.compute_p();
+        let L = lu.compute_l();
+        let U = lu.compute_u();
+        assert!(P == lu.row_permutation().to_dense_matrix::<c64>());
+        assert!((&P * &H - &L * &U).norm_max() < 1e-12);
+        assert!((&H - P.transpose() * &L * &U).norm_max() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "lu")]
+    fn test_solve_out_of_place() {
+        let n = 5;
+
+        let random = |_, _| rand::random::<f64>();
+        let A = Mat::from_fn(n, n, random);
+        let B = Mat::from_fn(n, 2, random);
+        let b = Col::from_fn(n, |i| B.read(i, 0));
+        let lu = A.partial_piv_lu();
+
+        let X: Mat<f64> = lu.solve(&B);
+        let x: Col<f64> = lu.solve(b.as_ref());
+        check_mat_approx_eq(&A * &X, &B);
+        check_mat_approx_eq((&A * &x).as_2d(), b.as_2d());
+        check_mat_approx_eq(x.as_2d(), X.col(0).as_2d());
+        assert!(b.as_2d() == B.col(0).as_2d());
+    }
+
+    #[test]
+    #[cfg(all(feature = "lu", feature = "cholesky"))]
+    fn test_transpose_solve_in_place() {
+        let n = 7;
+        let k = 3;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(n, n, random);
+        let rhs = Mat::from_fn(n, k, random);
+
+        let H = A.adjoint() * &A + Mat::<c64>::identity(n, n);
+        let decomps: [&dyn SolverCore<c64>; 3] = [
+            &A.partial_piv_lu(),
+            &A.full_piv_lu(),
+            &H.cholesky(Side::Lower).unwrap(),
+        ];
+        let mats = [&A, &A, &H];
+
+        for (decomp, M) in decomps.into_iter().zip(mats) {
+            let mut sol = rhs.clone();
+            decomp.solve_transpose_in_place(sol.as_mut());
+            check_mat_approx_eq(M.transpose() * &sol, &rhs);
+
+            let mut sol = rhs.clone();
+            decomp.solve_conj_transpose_in_place(sol.as_mut());
+            check_mat_approx_eq(M.adjoint() * &sol, &rhs);
+        }
     }
 
     #[test]
@@ -3095,6 +5473,123 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(all(feature = "lu", feature = "qr", feature = "cholesky"))]
+    fn test_blocking_params() {
+        let n = 70;
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let P = &H * H.adjoint() + Mat::<c64>::identity(n, n);
+
+        // the block sizes only affect performance, so concurrently running tests are unaffected
+        for blocksize in [1, 3, 8, 64, 1000] {
+            let params = BlockingParams {
+                lu_block: Some(blocksize),
+                qr_block: Some(blocksize),
+                chol_block: Some(blocksize),
+            };
+            set_global_blocking_params(params);
+            assert!(get_global_blocking_params() == params);
+
+            check_mat_approx_eq(H.partial_piv_lu().reconstruct(), &H);
+            check_mat_approx_eq(H.qr().reconstruct(), &H);
+            check_mat_approx_eq(H.col_piv_qr().reconstruct(), &H);
+            check_mat_approx_eq(H.lq().reconstruct(), &H);
+            check_mat_approx_eq(P.cholesky(Side::Lower).unwrap().reconstruct(), &P);
+        }
+
+        set_global_blocking_params(BlockingParams {
+            lu_block: Some(0),
+            ..Default::default()
+        });
+        assert!(get_global_blocking_params().lu_block == Some(1));
+        set_global_blocking_params(BlockingParams::default());
+        assert!(get_global_blocking_params() == BlockingParams::default());
+    }
+
+    #[test]
+    #[cfg(feature = "lu")]
+    fn test_structured_solve() {
+        let n = 6;
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let G = Mat::from_fn(n, n, random) + Mat::<c64>::identity(n, n) * scale(c64::new(4.0, 0.0));
+        let B = Mat::from_fn(n, 3, random);
+
+        let L = Mat::from_fn(n, n, |i, j| {
+            if i >= j {
+                G.read(i, j)
+            } else {
+                c64::faer_zero()
+            }
+        });
+        let D = Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                G.read(i, j)
+            } else {
+                c64::faer_zero()
+            }
+        });
+        let I = Mat::<c64>::identity(n, n);
+
+        for (A, structure) in [
+            (I.clone(), MatrixStructure::Identity),
+            (D.clone(), MatrixStructure::Diagonal),
+            (L.clone(), MatrixStructure::LowerTriangular),
+            (L.adjoint().to_owned(), MatrixStructure::UpperTriangular),
+            (G.clone(), MatrixStructure::General),
+        ] {
+            assert!(A.detect_structure() == structure);
+            check_mat_approx_eq(&A * A.solve(&B), &B);
+            check_mat_approx_eq(
+                &A * A.solve_with_structure(&B, MatrixStructure::General),
+                &B,
+            );
+            // conjugated views and single columns go through the same dispatch
+            check_mat_approx_eq(A.conjugate() * A.as_ref().conjugate().solve(&B), &B);
+            let x = A.solve(B.col(0));
+            check_mat_approx_eq((&A * &x).as_2d(), B.col(0).as_2d());
+        }
+
+        assert!(all(
+            Mat::<f64>::zeros(2, 3).detect_structure() == MatrixStructure::Diagonal,
+            Mat::<f64>::identity(2, 3).detect_structure() == MatrixStructure::Diagonal,
+            Mat::<f64>::zeros(0, 0).detect_structure() == MatrixStructure::Identity,
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "qr")]
+    fn test_lq() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+
+        let lq = H.lq();
+        test_solver(&H, &lq);
+
+        for (m, n) in [(7, 5), (5, 7), (7, 7)] {
+            let H = Mat::from_fn(m, n, random);
+            let lq = H.lq();
+            check_mat_approx_eq(lq.compute_l() * lq.compute_q(), &H);
+            check_mat_approx_eq(lq.compute_thin_l() * lq.compute_thin_q(), &H);
+            assert!(lq.compute_thin_q().shape() == (Ord::min(m, n), n));
+
+            if m <= n {
+                let B = Mat::from_fn(m, 3, random);
+                let X = lq.solve_min_norm(&B);
+                assert!(X.nrows() == n);
+                check_mat_approx_eq(&H * &X, &B);
+                // the minimum-norm solution lies in the row space of H
+                let Q = lq.compute_thin_q();
+                check_mat_approx_eq(Q.adjoint() * (&Q * &X), &X);
+
+                let X = lq.solve_min_norm_conj(B.col(0));
+                check_mat_approx_eq((H.conjugate() * &X).as_2d(), B.col(0).as_2d());
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "qr")]
     fn test_col_piv_qr() {
@@ -3251,6 +5746,33 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "evd")]
+    fn test_selfadjoint_eigendecomposition_real_parts() {
+        let n = 8;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let H = &H + H.adjoint();
+
+        for side in [Side::Lower, Side::Upper] {
+            let evd = H.selfadjoint_eigendecomposition(side);
+            let s = evd.real_eigenvalues();
+            for i in 0..n {
+                assert!(evd.s().column_vector().read(i).im == 0.0);
+                assert!(evd.s().column_vector().read(i).re == s.read(i));
+            }
+            for i in 0..n - 1 {
+                assert!(s.read(i) <= s.read(i + 1));
+            }
+
+            let (s, u) = evd.into_real_parts();
+            let s = Col::<c64>::from_fn(n, |i| c64::new(s.read(i), 0.0));
+            check_mat_approx_eq(u.adjoint() * &u, Mat::<c64>::identity(n, n));
+            check_mat_approx_eq(&u * s.column_vector_as_diagonal() * u.adjoint(), &H);
+        }
+    }
+
     #[test]
     #[cfg(feature = "evd")]
     #[cfg(feature = "lu")]
@@ -3298,6 +5820,51 @@ mod tests {
         check_mat_approx_eq(u * &s, &H * u);
     }
 
+    #[test]
+    #[cfg(feature = "evd")]
+    fn test_sorted_eigendecomposition() {
+        let n = 9;
+
+        let random = |_, _| rand::random::<f64>() - 0.5;
+        let H_real = Mat::from_fn(n, n, random);
+        let H = Mat::from_fn(n, n, |i, j| c64::new(H_real.read(i, j), 0.0));
+
+        for order in [
+            EigOrder::AscendingReal,
+            EigOrder::DescendingReal,
+            EigOrder::AscendingMagnitude,
+            EigOrder::DescendingMagnitude,
+        ] {
+            let eigen = H_real.eigendecomposition_sorted::<c64>(order);
+            let s = eigen.s();
+            let u = eigen.u();
+            check_mat_approx_eq(u * s, &H * u);
+
+            let s = s.column_vector();
+            for i in 0..n - 1 {
+                let (x, y) = (s.read(i), s.read(i + 1));
+                assert!(match order {
+                    EigOrder::AscendingReal => x.re <= y.re,
+                    EigOrder::DescendingReal => x.re >= y.re,
+                    EigOrder::AscendingMagnitude => x.faer_abs() <= y.faer_abs(),
+                    EigOrder::DescendingMagnitude => x.faer_abs() >= y.faer_abs(),
+                });
+            }
+
+            // complex conjugate pairs stay adjacent
+            let mut i = 0;
+            while i < n {
+                if s.read(i).im != 0.0 {
+                    assert!(s.read(i).im > 0.0);
+                    assert!(s.read(i + 1) == s.read(i).faer_conj());
+                    i += 2;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "evd")]
     fn this_other_tree_has_correct_maximum_eigenvalue_20() {
@@ -3370,6 +5937,72 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "lu")]
+    fn test_closed_form_inverse() {
+        for n in 1..=4 {
+            let random = |_, _| c64::new(rand::random(), rand::random());
+            let A = Mat::from_fn(n, n, random);
+            let I = Mat::<c64>::identity(n, n);
+
+            let (det, inv) = match n {
+                2 => (A.determinant_2x2(), A.inverse_2x2()),
+                3 => (A.determinant_3x3(), A.inverse_3x3()),
+                4 => (A.determinant_4x4(), A.inverse_4x4()),
+                _ => (A.read(0, 0), A.try_inverse(0.0).unwrap()),
+            };
+            assert!((det - A.determinant()).abs() < 1e-10);
+            check_mat_approx_eq(&A * &inv, &I);
+            check_mat_approx_eq(A.adjugate(), scale(det) * inv);
+            check_mat_approx_eq(
+                A.as_ref().adjoint().adjugate(),
+                A.adjugate().adjoint().to_owned(),
+            );
+            check_mat_approx_eq(A.try_inverse(0.0).unwrap() * &A, &I);
+        }
+
+        let A = Mat::from_fn(7, 7, |_, _| rand::random::<f64>());
+        check_mat_approx_eq(A.try_inverse(0.0).unwrap() * &A, Mat::<f64>::identity(7, 7));
+
+        let singular = mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0f64]];
+        assert!(singular.try_inverse(1e-12).is_none());
+        assert!(Mat::<f64>::zeros(6, 6).try_inverse(0.0).is_none());
+
+        // the tolerance is relative to the entries, so scaling the matrix doesn't change the
+        // result, and small and large matrices use the same criterion
+        for n in [3, 4, 5, 8] {
+            let nearly_singular = Mat::from_fn(n, n, |i, j| {
+                if i == j {
+                    if i == n - 1 {
+                        1e-9
+                    } else {
+                        1.0
+                    }
+                } else {
+                    0.0
+                }
+            });
+            for factor in [1e-6, 1.0, 1e6] {
+                let A = (scale(factor) * &nearly_singular).to_owned();
+                assert!(A.try_inverse(1e-12).is_some());
+                assert!(A.try_inverse(1e-6).is_none());
+            }
+        }
+
+        // the determinant underflows, but the matrix is well conditioned
+        let A = scale(1e-2) * Mat::<f64>::identity(200, 200);
+        let inv = A.try_inverse(0.0).unwrap();
+        check_mat_approx_eq(inv, scale(1e2) * Mat::<f64>::identity(200, 200));
+
+        let mut singular = Mat::from_fn(8, 8, |_, _| rand::random::<f64>());
+        let first = singular.col(0).to_owned();
+        singular.col_mut(7).copy_from(&first);
+        assert!(singular.try_inverse(1e-10).is_none());
+        let mut nan = Mat::<f64>::identity(8, 8);
+        nan.write(3, 3, f64::NAN);
+        assert!(nan.try_inverse(0.0).is_none());
+    }
+
     #[test]
     #[cfg(feature = "lu")]
     fn test_plu() {
@@ -3404,4 +6037,154 @@ mod tests {
         let diff = (p * a * q.inverse()) - (l * u);
         assert!(diff.norm_max() < 1e-12);
     }
+
+    #[test]
+    #[cfg(feature = "evd")]
+    fn test_hessenberg() {
+        for n in [0, 1, 2, 7, 300] {
+            let A = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+            let (Q, H) = A.hessenberg();
+            check_mat_approx_eq(Q.adjoint() * &Q, Mat::<c64>::identity(n, n));
+            check_mat_approx_eq(&Q * &H * Q.adjoint(), &A);
+            for j in 0..n {
+                for i in j + 2..n {
+                    assert!(H.read(i, j) == c64::faer_zero());
+                }
+            }
+
+            let (packed, _) = A.hessenberg_packed();
+            for j in 0..n {
+                for i in 0..Ord::min(j + 2, n) {
+                    assert!(packed.read(i, j) == H.read(i, j));
+                }
+            }
+        }
+
+        let A = Mat::from_fn(9, 9, |_, _| rand::random::<f64>());
+        let (Q, H) = A.as_ref().hessenberg();
+        check_mat_approx_eq(&Q * &H * Q.transpose(), &A);
+    }
+
+    #[test]
+    #[cfg(feature = "svd")]
+    fn test_bidiagonalize() {
+        for (m, n) in [
+            (0, 0),
+            (3, 0),
+            (0, 3),
+            (1, 1),
+            (7, 4),
+            (4, 7),
+            (6, 6),
+            (150, 90),
+            (90, 150),
+        ] {
+            let A = Mat::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()));
+            let (U, d, e, V) = A.bidiagonalize();
+            let k = Ord::min(m, n);
+            assert!(U.shape() == (m, k));
+            assert!(V.shape() == (n, k));
+            assert!(d.nrows() == k);
+            assert!(e.nrows() == k.saturating_sub(1));
+
+            let B = Mat::from_fn(k, k, |i, j| {
+                let x = if i == j {
+                    d.read(i)
+                } else if (m >= n && j == i + 1) || (m < n && i == j + 1) {
+                    e.read(Ord::min(i, j))
+                } else {
+                    0.0
+                };
+                assert!(x >= 0.0);
+                c64::new(x, 0.0)
+            });
+            check_mat_approx_eq(U.adjoint() * &U, Mat::<c64>::identity(k, k));
+            check_mat_approx_eq(V.adjoint() * &V, Mat::<c64>::identity(k, k));
+            check_mat_approx_eq(&U * &B * V.adjoint(), &A);
+        }
+
+        let A = Mat::from_fn(8, 5, |_, _| rand::random::<f64>());
+        let (U, d, e, V) = A.bidiagonalize();
+        let B = Mat::from_fn(5, 5, |i, j| {
+            if i == j {
+                d.read(i)
+            } else if j == i + 1 {
+                e.read(i)
+            } else {
+                0.0
+            }
+        });
+        check_mat_approx_eq(&U * &B * V.transpose(), &A);
+    }
+
+    #[test]
+    fn test_woodbury() {
+        let n = 20;
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let mut A = Mat::from_fn(n, n, random);
+        for i in 0..n {
+            A.write(i, i, A.read(i, i) + c64::new(n as f64, 0.0));
+        }
+        let lu = A.partial_piv_lu();
+        let llt = (&A * A.adjoint()).cholesky(Side::Lower).unwrap();
+
+        for k in [0, 1, 3] {
+            let U = Mat::from_fn(n, k, random);
+            let V = Mat::from_fn(n, k, random);
+            let rhs = Mat::from_fn(n, 2, random);
+
+            let updated = &A + &U * V.transpose();
+            let sol = woodbury(&lu, U.as_ref(), V.as_ref(), rhs.as_ref());
+            check_mat_approx_eq(&updated * &sol, &rhs);
+
+            let decomp: &dyn SolverCore<c64> = &llt;
+            let updated = &A * A.adjoint() + &U * V.transpose();
+            let sol = woodbury(decomp, U.as_ref(), V.as_ref(), rhs.as_ref());
+            check_mat_approx_eq(&updated * &sol, &rhs);
+        }
+
+        let A = Mat::from_fn(n, n, |i, j| if i == j { 2.0 } else { 0.0 });
+        let u = Mat::from_fn(n, 1, |i, _| (i + 1) as f64);
+        let v = Mat::from_fn(n, 1, |i, _| if i == 0 { 1.0 } else { 0.0 });
+        let rhs = Mat::from_fn(n, 1, |_, _| 1.0);
+        let sol = woodbury(&A.partial_piv_lu(), u.as_ref(), v.as_ref(), rhs.as_ref());
+        check_mat_approx_eq((&A + &u * v.transpose()) * &sol, &rhs);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_woodbury_mismatched_rank() {
+        let A = Mat::<f64>::identity(4, 4);
+        let U = Mat::<f64>::zeros(4, 2);
+        let V = Mat::<f64>::zeros(4, 3);
+        let rhs = Mat::<f64>::zeros(4, 1);
+        woodbury(&A.partial_piv_lu(), U.as_ref(), V.as_ref(), rhs.as_ref());
+    }
+
+    #[test]
+    fn test_null_space() {
+        let m = 8;
+        let n = 6;
+        let r = 3;
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(m, r, random) * Mat::from_fn(r, n, random);
+
+        let N = A.null_space(1e-10);
+        assert!(N.nrows() == n);
+        assert!(N.ncols() == n - r);
+        check_mat_approx_eq(&A * &N, Mat::<c64>::zeros(m, n - r));
+        check_mat_approx_eq(N.adjoint() * &N, Mat::<c64>::identity(n - r, n - r));
+
+        let C = A.column_space(1e-10);
+        assert!(C.nrows() == m);
+        assert!(C.ncols() == r);
+        check_mat_approx_eq(C.adjoint() * &C, Mat::<c64>::identity(r, r));
+        check_mat_approx_eq(&C * (C.adjoint() * &A), &A);
+
+        let A = Mat::from_fn(m, n, random);
+        assert!(A.null_space(1e-10).shape() == (n, 0));
+        assert!(A.column_space(1e-10).shape() == (m, n));
+        assert!(Mat::<f64>::zeros(m, n).column_space(0.0).shape() == (m, 0));
+        assert!(Mat::<f64>::zeros(m, n).null_space(0.0).shape() == (n, n));
+    }
 }
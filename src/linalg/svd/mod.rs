@@ -423,6 +423,7 @@ fn compute_svd_big<E: ComplexField>(
     ),
     epsilon: E::Real,
     zero_threshold: E::Real,
+    deflation_tol: Option<E::Real>,
     parallelism: Parallelism,
     stack: &mut PodStack,
 ) {
@@ -463,6 +464,23 @@ fn compute_svd_big<E: ComplexField>(
         }
     });
 
+    if let Some(deflation_tol) = deflation_tol {
+        // off-diagonal elements that are small relative to the largest element are deflated
+        let mut max_val = E::Real::faer_zero();
+        for x in diag.iter().chain(subdiag.iter()) {
+            let val = x.faer_abs();
+            if val > max_val {
+                max_val = val;
+            }
+        }
+        let threshold = deflation_tol.faer_mul(max_val);
+        for x in subdiag.iter_mut() {
+            if x.faer_abs() <= threshold {
+                *x = E::faer_zero();
+            }
+        }
+    }
+
     let mut j_base = 0;
     while j_base < n {
         let bs = Ord::min(householder_blocksize, n - j_base);
@@ -569,7 +587,33 @@ fn compute_svd_big<E: ComplexField>(
 /// SVD tuning parameters.
 #[derive(Default, Copy, Clone)]
 #[non_exhaustive]
-pub struct SvdParams {}
+pub struct SvdParams {
+    /// Relative tolerance below which off-diagonal elements of the bidiagonal matrix $B$ are
+    /// treated as zero, i.e. elements smaller than `deflation_tol * ‖B‖` are deflated before
+    /// computing its singular values.
+    ///
+    /// Defaults to `None`, in which case only the usual deflation criteria, based on the working
+    /// epsilon, are used. When it is provided, it is clamped below by the working epsilon
+    /// (`E::Real::faer_epsilon()` or the one passed to [`compute_svd_custom_epsilon`]), and
+    /// singular values below `8 * deflation_tol * ‖B‖`, which is the size of the perturbation
+    /// introduced by the deflation, are set to zero, which makes rank determination more
+    /// reliable. The working epsilon itself is left unchanged.
+    pub deflation_tol: Option<f64>,
+}
+
+impl SvdParams {
+    #[inline]
+    fn deflation_tol<E: RealField>(&self, epsilon: E) -> Option<E> {
+        self.deflation_tol.map(|tol| {
+            let tol = E::faer_from_f64(tol);
+            if tol > epsilon {
+                tol
+            } else {
+                epsilon
+            }
+        })
+    }
+}
 
 /// Computes the size and alignment of required workspace for performing a singular value
 /// decomposition. $U$ and $V$ may be computed fully, partially, or not computed at all.
@@ -674,6 +718,9 @@ pub fn compute_svd_req<E: ComplexField>(
 /// minimum of `matrix.nrows()` and `matrix.ncols()`, in which case only the singular vectors
 /// corresponding to the provided column storage are computed.
 ///
+/// If `matrix` contains any non-finite value, the iterative part of the algorithm is skipped, and
+/// `s`, `u` and `v` are filled with NaN.
+///
 /// # Panics
 /// Panics if any of the conditions described above is violated, or if the type `E` does not have a
 /// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
@@ -758,6 +805,7 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
         return;
     }
 
+    let mut s = s;
     let mut u = u;
     let mut v = v;
     let mut matrix = matrix;
@@ -771,28 +819,27 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
     let n = matrix.ncols();
 
     if n == 0 {
+        // the singular vectors of an empty matrix are an arbitrary unitary basis
         if let Some(mut u) = u {
             zipped_rw!(u.rb_mut()).for_each(|unzipped!(mut dst)| dst.write(E::faer_zero()));
-            zipped_rw!(u
-                .submatrix_mut(0, 0, n, n)
-                .diagonal_mut()
-                .column_vector_mut())
-            .for_each(|unzipped!(mut dst)| dst.write(E::faer_one()));
+            zipped_rw!(u.diagonal_mut().column_vector_mut())
+                .for_each(|unzipped!(mut dst)| dst.write(E::faer_one()));
         }
 
         return;
     }
 
-    let _ = params;
+    let deflation_tol = params.deflation_tol(epsilon);
 
     if m as f64 / n as f64 <= 11.0 / 6.0 {
         squareish_svd(
             matrix,
-            s,
+            s.rb_mut(),
             u.rb_mut(),
             v.rb_mut(),
             epsilon,
             zero_threshold,
+            deflation_tol,
             parallelism,
             stack,
         );
@@ -831,11 +878,12 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
             // r = u s v
             squareish_svd(
                 r.rb(),
-                s,
+                s.rb_mut(),
                 u.rb_mut().map(|u| u.submatrix_mut(0, 0, n, n)),
                 v.rb_mut(),
                 epsilon,
                 zero_threshold,
+                deflation_tol,
                 parallelism,
                 stack,
             );
@@ -868,6 +916,25 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
         }
     }
 
+    if let Some(deflation_tol) = deflation_tol {
+        // singular values below the deflation threshold are numerically zero
+        let mut max_val = E::Real::faer_zero();
+        for i in 0..n {
+            let val = s.read(i).faer_abs();
+            if val > max_val {
+                max_val = val;
+            }
+        }
+        let threshold = E::Real::faer_from_f64(8.0)
+            .faer_mul(deflation_tol)
+            .faer_mul(max_val);
+        for i in 0..n {
+            if s.read(i).faer_abs() <= threshold {
+                s.write(i, E::faer_zero());
+            }
+        }
+    }
+
     if do_transpose {
         // conjugate u and v
         if let Some(u) = u {
@@ -886,6 +953,7 @@ fn squareish_svd<E: ComplexField>(
     mut v: Option<MatMut<E>>,
     epsilon: E::Real,
     zero_threshold: E::Real,
+    deflation_tol: Option<E::Real>,
     parallelism: Parallelism,
     stack: &mut PodStack,
 ) {
@@ -911,6 +979,7 @@ fn squareish_svd<E: ComplexField>(
                 compute_bidiag_real_svd::<E::Real>,
                 coe::coerce_static(epsilon),
                 coe::coerce_static(zero_threshold),
+                deflation_tol.map(coe::coerce_static),
                 parallelism,
                 stack,
             );
@@ -924,6 +993,7 @@ fn squareish_svd<E: ComplexField>(
             compute_bidiag_cplx_svd::<E>,
             coe::coerce_static(epsilon),
             coe::coerce_static(zero_threshold),
+            deflation_tol.map(coe::coerce_static),
             parallelism,
             stack,
         );
@@ -967,6 +1037,7 @@ mod tests {
                 compute_bidiag_real_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<f64>(
                     m,
@@ -1012,6 +1083,7 @@ mod tests {
                 compute_bidiag_real_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<f64>(
                     m,
@@ -1054,6 +1126,7 @@ mod tests {
                 compute_bidiag_real_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<f64>(
                     m,
@@ -1514,6 +1587,7 @@ mod tests {
                 compute_bidiag_cplx_svd::<c64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<c64>(
                     m,
@@ -1556,6 +1630,7 @@ mod tests {
                 compute_bidiag_cplx_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<c64>(
                     m,
@@ -1651,4 +1726,54 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_real_rank_deficient() {
+        for n in [4, 8, 32, 64, 256] {
+            let m = n + 3;
+            let rank = 2;
+            let x = Mat::from_fn(m, rank, |_, _| rand::random::<f64>());
+            let y = Mat::from_fn(n, rank, |_, _| rand::random::<f64>());
+            let mat = &x * y.transpose();
+
+            let mut s = Mat::zeros(m, n);
+            let mut u = Mat::zeros(m, m);
+            let mut v = Mat::zeros(n, n);
+
+            let params = SvdParams {
+                deflation_tol: Some(1e-13),
+            };
+            compute_svd(
+                mat.as_ref(),
+                s.as_mut().diagonal_mut().column_vector_mut(),
+                Some(u.as_mut()),
+                Some(v.as_mut()),
+                crate::Parallelism::None,
+                make_stack!(compute_svd_req::<f64>(
+                    m,
+                    n,
+                    ComputeVectors::Full,
+                    ComputeVectors::Full,
+                    crate::Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            for i in 0..n {
+                if i < rank {
+                    assert!(s.read(i, i) > 0.0);
+                } else {
+                    assert!(s.read(i, i) == 0.0);
+                }
+            }
+
+            let reconstructed = &u * &s * v.transpose();
+            for j in 0..n {
+                for i in 0..m {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                }
+            }
+        }
+    }
 }
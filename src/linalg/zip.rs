@@ -1482,9 +1482,28 @@ fn for_each_mat<
     >,
 >(
     z: Z,
-    mut f: impl FnMut(<Z as MatIndex>::Item),
+    f: impl FnMut(<Z as MatIndex>::Item),
 ) {
     let layout = Z::preferred_layout(&z);
+    for_each_mat_with_layout(z, layout, f);
+}
+
+#[inline(always)]
+fn for_each_mat_with_layout<
+    Z: MatIndex<
+        Dyn: MatIndex<
+            Item = Z::Item,
+            Slice = Z::Slice,
+            Rows = usize,
+            Cols = usize,
+            Index = (usize, usize),
+        >,
+    >,
+>(
+    z: Z,
+    layout: Z::LayoutTransform,
+    mut f: impl FnMut(<Z as MatIndex>::Item),
+) {
     let mut z = Z::with_layout(z, layout);
 
     let m = Z::Dyn::nrows(&z);
@@ -1514,6 +1533,36 @@ fn for_each_mat<
     }
 }
 
+#[inline(always)]
+fn try_for_each_mat_with_layout<
+    Z: MatIndex<
+        Dyn: MatIndex<
+            Item = Z::Item,
+            Slice = Z::Slice,
+            Rows = usize,
+            Cols = usize,
+            Index = (usize, usize),
+        >,
+    >,
+    Err,
+>(
+    z: Z,
+    layout: Z::LayoutTransform,
+    mut f: impl FnMut(<Z as MatIndex>::Item) -> Result<(), Err>,
+) -> Result<(), Err> {
+    let mut z = Z::with_layout(z, layout);
+
+    let m = Z::Dyn::nrows(&z);
+    let n = Z::Dyn::ncols(&z);
+
+    for j in 0..n {
+        for i in 0..m {
+            f(unsafe { Z::Dyn::get_unchecked(&mut z, (i, j)) })?;
+        }
+    }
+    Ok(())
+}
+
 // TODO:
 // - for_each_vec_with_index
 
@@ -2067,9 +2116,22 @@ fn for_each_col<
     >,
 >(
     z: Z,
-    mut f: impl FnMut(<Z as MatIndex>::Item),
+    f: impl FnMut(<Z as MatIndex>::Item),
 ) {
     let layout = Z::preferred_layout(&z);
+    for_each_col_with_layout(z, layout, f);
+}
+
+#[inline(always)]
+fn for_each_col_with_layout<
+    Z: MatIndex<
+        Dyn: MatIndex<Rows = usize, Cols = (), Index = usize, Item = Z::Item, Slice = Z::Slice>,
+    >,
+>(
+    z: Z,
+    layout: Z::LayoutTransform,
+    mut f: impl FnMut(<Z as MatIndex>::Item),
+) {
     let mut z = Z::with_layout(z, layout);
 
     let m = Z::Dyn::nrows(&z);
@@ -2088,6 +2150,26 @@ fn for_each_col<
     }
 }
 
+#[inline(always)]
+fn try_for_each_col_with_layout<
+    Z: MatIndex<
+        Dyn: MatIndex<Rows = usize, Cols = (), Index = usize, Item = Z::Item, Slice = Z::Slice>,
+    >,
+    Err,
+>(
+    z: Z,
+    layout: Z::LayoutTransform,
+    mut f: impl FnMut(<Z as MatIndex>::Item) -> Result<(), Err>,
+) -> Result<(), Err> {
+    let mut z = Z::with_layout(z, layout);
+
+    let m = Z::Dyn::nrows(&z);
+    for i in 0..m {
+        f(unsafe { Z::Dyn::get_unchecked(&mut z, i) })?;
+    }
+    Ok(())
+}
+
 #[inline(always)]
 fn for_each_col_with_index<
     Idx,
@@ -2206,9 +2288,22 @@ fn for_each_row<
     >,
 >(
     z: Z,
-    mut f: impl FnMut(<Z as MatIndex>::Item),
+    f: impl FnMut(<Z as MatIndex>::Item),
 ) {
     let layout = Z::preferred_layout(&z);
+    for_each_row_with_layout(z, layout, f);
+}
+
+#[inline(always)]
+fn for_each_row_with_layout<
+    Z: MatIndex<
+        Dyn: MatIndex<Rows = (), Cols = usize, Index = usize, Item = Z::Item, Slice = Z::Slice>,
+    >,
+>(
+    z: Z,
+    layout: Z::LayoutTransform,
+    mut f: impl FnMut(<Z as MatIndex>::Item),
+) {
     let mut z = Z::with_layout(z, layout);
 
     let n = Z::Dyn::ncols(&z);
@@ -2227,6 +2322,26 @@ fn for_each_row<
     }
 }
 
+#[inline(always)]
+fn try_for_each_row_with_layout<
+    Z: MatIndex<
+        Dyn: MatIndex<Rows = (), Cols = usize, Index = usize, Item = Z::Item, Slice = Z::Slice>,
+    >,
+    Err,
+>(
+    z: Z,
+    layout: Z::LayoutTransform,
+    mut f: impl FnMut(<Z as MatIndex>::Item) -> Result<(), Err>,
+) -> Result<(), Err> {
+    let mut z = Z::with_layout(z, layout);
+
+    let n = Z::Dyn::ncols(&z);
+    for j in 0..n {
+        f(unsafe { Z::Dyn::get_unchecked(&mut z, j) })?;
+    }
+    Ok(())
+}
+
 impl<
         R: Shape,
         C: Shape,
@@ -2248,11 +2363,40 @@ impl<
     > LastEq<R, C, M>
 {
     /// Applies `f` to each element of `self`.
+    ///
+    /// The elements are visited in an order that follows the memory layout of the first matrix
+    /// of the zipped views, so that contiguous memory is traversed sequentially. Given the row
+    /// stride `rs` and the column stride `cs` of the first matrix, the layout transform is selected
+    /// as follows, where the first matching rule applies:
+    /// * if `nrows > 1` and `rs == 1`, [`MatLayoutTransform::None`]: the columns are visited from
+    ///   left to right, and each column from top to bottom, i.e., `(0, 0), (1, 0), ..., (0, 1),
+    ///   ...`.
+    /// * if `nrows > 1` and `rs == -1`, [`MatLayoutTransform::ReverseRows`]: the columns are
+    ///   visited from left to right, and each column from bottom to top.
+    /// * if `ncols > 1` and `cs == 1`, [`MatLayoutTransform::Transpose`]: the rows are visited
+    ///   from top to bottom, and each row from left to right, i.e., `(0, 0), (0, 1), ..., (1, 0),
+    ///   ...`.
+    /// * if `ncols > 1` and `cs == -1`, [`MatLayoutTransform::TransposeReverseRows`]: the rows
+    ///   are visited from top to bottom, and each row from right to left.
+    /// * otherwise, [`MatLayoutTransform::None`].
+    ///
+    /// The same order is used by [`Self::for_each_with_index`] and [`Self::map`]. Closures with
+    /// order-sensitive side effects should use [`Self::for_each_in_logical_order`] instead.
     #[inline(always)]
     pub fn for_each(self, f: impl FnMut(<Self as MatIndex>::Item)) {
         for_each_mat(self, f);
     }
 
+    /// Applies `f` to each element of `self`, in column-major order regardless of the memory
+    /// layout of the matrices, i.e., `(0, 0), (1, 0), ..., (nrows - 1, 0), (0, 1), ...`.
+    ///
+    /// This may be slower than [`Self::for_each`] when the matrices are not stored in column-major
+    /// order.
+    #[inline(always)]
+    pub fn for_each_in_logical_order(self, f: impl FnMut(<Self as MatIndex>::Item)) {
+        for_each_mat_with_layout(self, MatLayoutTransform::None, f);
+    }
+
     /// Applies `f` to each element of `self`, while passing the indices of the position of the
     /// current element.
     #[inline(always)]
@@ -2320,6 +2464,37 @@ impl<
         out.into_shape(m, n)
     }
 
+    /// Applies `f` to each element of `self` and collects its result into a new matrix, or returns
+    /// the first error returned by `f`.
+    ///
+    /// The elements are visited in the same order as [`Self::for_each_in_logical_order`], and the
+    /// iteration stops at the first error, in which case the partially written output is
+    /// discarded.
+    #[inline(always)]
+    pub fn try_map<E: Entity, Err>(
+        self,
+        f: impl FnMut(<Self as MatIndex>::Item) -> Result<E, Err>,
+    ) -> Result<Mat<E, R, C>, Err> {
+        let (m, n) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Mat::<E>::with_capacity(m.unbound(), n.unbound());
+        let rs = 1;
+        let cs = out.col_stride();
+        let out_view =
+            unsafe { mat::from_raw_parts_mut::<'_, E, _, _>(out.as_ptr_mut(), m, n, rs, cs) };
+        let mut f = f;
+        try_for_each_mat_with_layout(
+            ZipEq::new(out_view, self),
+            MatLayoutTransform::None,
+            #[inline(always)]
+            |Zip(mut out, item)| {
+                out.write(f(item)?);
+                Ok(())
+            },
+        )?;
+        unsafe { out.set_dims(m.unbound(), n.unbound()) };
+        Ok(out.into_shape(m, n))
+    }
+
     /// Applies `f` to each element of `self` and collect its result into a new matrix.
     #[inline(always)]
     pub fn map_with_index<E: Entity>(
@@ -2362,11 +2537,26 @@ impl<
     > LastEq<(), C, M>
 {
     /// Applies `f` to each element of `self`.
+    ///
+    /// If the first vector of the zipped views has a stride equal to `-1` and more than one
+    /// element, the elements are visited in reverse order ([`VecLayoutTransform::Reverse`]), so
+    /// that its memory is traversed sequentially. Otherwise, they are visited in increasing order
+    /// of their indices.
+    ///
+    /// Closures with order-sensitive side effects should use
+    /// [`Self::for_each_in_logical_order`] instead.
     #[inline(always)]
     pub fn for_each(self, f: impl FnMut(<Self as MatIndex>::Item)) {
         for_each_row(self, f);
     }
 
+    /// Applies `f` to each element of `self`, in increasing order of their indices regardless
+    /// of the memory layout of the vectors.
+    #[inline(always)]
+    pub fn for_each_in_logical_order(self, f: impl FnMut(<Self as MatIndex>::Item)) {
+        for_each_row_with_layout(self, VecLayoutTransform::None, f);
+    }
+
     /// Applies `f` to each element of `self`, while passing in the index of the current element.
     #[inline(always)]
     pub fn for_each_with_index(self, f: impl FnMut(Idx<C>, <Self as MatIndex>::Item)) {
@@ -2388,6 +2578,34 @@ impl<
         out.into_shape(n)
     }
 
+    /// Applies `f` to each element of `self` and collects its result into a new row, or returns
+    /// the first error returned by `f`.
+    ///
+    /// The elements are visited in the same order as [`Self::for_each_in_logical_order`], and the
+    /// iteration stops at the first error, in which case the partially written output is
+    /// discarded.
+    #[inline(always)]
+    pub fn try_map<E: Entity, Err>(
+        self,
+        f: impl FnMut(<Self as MatIndex>::Item) -> Result<E, Err>,
+    ) -> Result<Row<E, C>, Err> {
+        let (_, n) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Row::<E>::with_capacity(n.unbound());
+        let out_view = unsafe { row::from_raw_parts_mut::<'_, E, _>(out.as_ptr_mut(), n, 1) };
+        let mut f = f;
+        try_for_each_row_with_layout(
+            ZipEq::new(out_view, self),
+            VecLayoutTransform::None,
+            #[inline(always)]
+            |Zip(mut out, item)| {
+                out.write(f(item)?);
+                Ok(())
+            },
+        )?;
+        unsafe { out.set_ncols(n.unbound()) };
+        Ok(out.into_shape(n))
+    }
+
     /// Applies `f` to each element of `self` and collect its result into a new row.
     #[inline(always)]
     pub fn map_with_index<E: Entity>(
@@ -2427,11 +2645,26 @@ impl<
     > LastEq<R, (), M>
 {
     /// Applies `f` to each element of `self`.
+    ///
+    /// If the first vector of the zipped views has a stride equal to `-1` and more than one
+    /// element, the elements are visited in reverse order ([`VecLayoutTransform::Reverse`]), so
+    /// that its memory is traversed sequentially. Otherwise, they are visited in increasing order
+    /// of their indices.
+    ///
+    /// Closures with order-sensitive side effects should use
+    /// [`Self::for_each_in_logical_order`] instead.
     #[inline(always)]
     pub fn for_each(self, f: impl FnMut(<Self as MatIndex>::Item)) {
         for_each_col(self, f);
     }
 
+    /// Applies `f` to each element of `self`, in increasing order of their indices regardless
+    /// of the memory layout of the vectors.
+    #[inline(always)]
+    pub fn for_each_in_logical_order(self, f: impl FnMut(<Self as MatIndex>::Item)) {
+        for_each_col_with_layout(self, VecLayoutTransform::None, f);
+    }
+
     /// Applies `f` to each element of `self`, while passing in the index of the current element.
     #[inline(always)]
     pub fn for_each_with_index(self, f: impl FnMut(Idx<R>, <Self as MatIndex>::Item)) {
@@ -2453,6 +2686,34 @@ impl<
         out.into_shape(m)
     }
 
+    /// Applies `f` to each element of `self` and collects its result into a new column, or returns
+    /// the first error returned by `f`.
+    ///
+    /// The elements are visited in the same order as [`Self::for_each_in_logical_order`], and the
+    /// iteration stops at the first error, in which case the partially written output is
+    /// discarded.
+    #[inline(always)]
+    pub fn try_map<E: Entity, Err>(
+        self,
+        f: impl FnMut(<Self as MatIndex>::Item) -> Result<E, Err>,
+    ) -> Result<Col<E, R>, Err> {
+        let (m, _) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Col::<E>::with_capacity(m.unbound());
+        let out_view = unsafe { col::from_raw_parts_mut::<'_, E, _>(out.as_ptr_mut(), m, 1) };
+        let mut f = f;
+        try_for_each_col_with_layout(
+            ZipEq::new(out_view, self),
+            VecLayoutTransform::None,
+            #[inline(always)]
+            |Zip(mut out, item)| {
+                out.write(f(item)?);
+                Ok(())
+            },
+        )?;
+        unsafe { out.set_nrows(m.unbound()) };
+        Ok(out.into_shape(m))
+    }
+
     /// Applies `f` to each element of `self` and collect its result into a new column.
     #[inline(always)]
     pub fn map_with_index<E: Entity>(
@@ -2507,11 +2768,26 @@ impl<
     > ZipEq<(), C, Head, Tail>
 {
     /// Applies `f` to each element of `self`.
+    ///
+    /// If the first vector of the zipped views has a stride equal to `-1` and more than one
+    /// element, the elements are visited in reverse order ([`VecLayoutTransform::Reverse`]), so
+    /// that its memory is traversed sequentially. Otherwise, they are visited in increasing order
+    /// of their indices.
+    ///
+    /// Closures with order-sensitive side effects should use
+    /// [`Self::for_each_in_logical_order`] instead.
     #[inline(always)]
     pub fn for_each(self, f: impl FnMut(<Self as MatIndex>::Item)) {
         for_each_row(self, f);
     }
 
+    /// Applies `f` to each element of `self`, in increasing order of their indices regardless
+    /// of the memory layout of the vectors.
+    #[inline(always)]
+    pub fn for_each_in_logical_order(self, f: impl FnMut(<Self as MatIndex>::Item)) {
+        for_each_row_with_layout(self, VecLayoutTransform::None, f);
+    }
+
     /// Applies `f` to each element of `self`, while passing in the index of the current element.
     #[inline(always)]
     pub fn for_each_with_index(self, f: impl FnMut(Idx<C>, <Self as MatIndex>::Item)) {
@@ -2532,6 +2808,52 @@ impl<
         unsafe { out.set_ncols(n.unbound()) };
         out.into_shape(n)
     }
+
+    /// Applies `f` to each element of `self` and collects its result into a new row, or returns
+    /// the first error returned by `f`.
+    ///
+    /// The elements are visited in the same order as [`Self::for_each_in_logical_order`], and the
+    /// iteration stops at the first error, in which case the partially written output is
+    /// discarded.
+    #[inline(always)]
+    pub fn try_map<E: Entity, Err>(
+        self,
+        f: impl FnMut(<Self as MatIndex>::Item) -> Result<E, Err>,
+    ) -> Result<Row<E, C>, Err> {
+        let (_, n) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Row::<E>::with_capacity(n.unbound());
+        let out_view = unsafe { row::from_raw_parts_mut::<'_, E, _>(out.as_ptr_mut(), n, 1) };
+        let mut f = f;
+        try_for_each_row_with_layout(
+            ZipEq::new(out_view, self),
+            VecLayoutTransform::None,
+            #[inline(always)]
+            |Zip(mut out, item)| {
+                out.write(f(item)?);
+                Ok(())
+            },
+        )?;
+        unsafe { out.set_ncols(n.unbound()) };
+        Ok(out.into_shape(n))
+    }
+
+    /// Applies `f` to each element of `self` and collect its result into a new row.
+    #[inline(always)]
+    pub fn map_with_index<E: Entity>(
+        self,
+        f: impl FnMut(Idx<C>, <Self as MatIndex>::Item) -> E,
+    ) -> Row<E, C> {
+        let (_, n) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Row::<E>::with_capacity(n.unbound());
+        let out_view = unsafe { row::from_raw_parts_mut::<'_, E, _>(out.as_ptr_mut(), n, 1) };
+        let mut f = f;
+        ZipEq::new(out_view, self).for_each_with_index(
+            #[inline(always)]
+            |j, Zip(mut out, item)| out.write(f(j, item)),
+        );
+        unsafe { out.set_ncols(n.unbound()) };
+        out.into_shape(n)
+    }
 }
 
 impl<
@@ -2569,11 +2891,26 @@ impl<
     > ZipEq<R, (), Head, Tail>
 {
     /// Applies `f` to each element of `self`.
+    ///
+    /// If the first vector of the zipped views has a stride equal to `-1` and more than one
+    /// element, the elements are visited in reverse order ([`VecLayoutTransform::Reverse`]), so
+    /// that its memory is traversed sequentially. Otherwise, they are visited in increasing order
+    /// of their indices.
+    ///
+    /// Closures with order-sensitive side effects should use
+    /// [`Self::for_each_in_logical_order`] instead.
     #[inline(always)]
     pub fn for_each(self, f: impl FnMut(<Self as MatIndex>::Item)) {
         for_each_col(self, f);
     }
 
+    /// Applies `f` to each element of `self`, in increasing order of their indices regardless
+    /// of the memory layout of the vectors.
+    #[inline(always)]
+    pub fn for_each_in_logical_order(self, f: impl FnMut(<Self as MatIndex>::Item)) {
+        for_each_col_with_layout(self, VecLayoutTransform::None, f);
+    }
+
     /// Applies `f` to each element of `self`, while passing in the index of the current element.
     #[inline(always)]
     pub fn for_each_with_index(self, f: impl FnMut(Idx<R>, <Self as MatIndex>::Item)) {
@@ -2594,6 +2931,52 @@ impl<
         unsafe { out.set_nrows(m.unbound()) };
         out.into_shape(m)
     }
+
+    /// Applies `f` to each element of `self` and collects its result into a new column, or returns
+    /// the first error returned by `f`.
+    ///
+    /// The elements are visited in the same order as [`Self::for_each_in_logical_order`], and the
+    /// iteration stops at the first error, in which case the partially written output is
+    /// discarded.
+    #[inline(always)]
+    pub fn try_map<E: Entity, Err>(
+        self,
+        f: impl FnMut(<Self as MatIndex>::Item) -> Result<E, Err>,
+    ) -> Result<Col<E, R>, Err> {
+        let (m, _) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Col::<E>::with_capacity(m.unbound());
+        let out_view = unsafe { col::from_raw_parts_mut::<'_, E, _>(out.as_ptr_mut(), m, 1) };
+        let mut f = f;
+        try_for_each_col_with_layout(
+            ZipEq::new(out_view, self),
+            VecLayoutTransform::None,
+            #[inline(always)]
+            |Zip(mut out, item)| {
+                out.write(f(item)?);
+                Ok(())
+            },
+        )?;
+        unsafe { out.set_nrows(m.unbound()) };
+        Ok(out.into_shape(m))
+    }
+
+    /// Applies `f` to each element of `self` and collect its result into a new column.
+    #[inline(always)]
+    pub fn map_with_index<E: Entity>(
+        self,
+        f: impl FnMut(Idx<R>, <Self as MatIndex>::Item) -> E,
+    ) -> Col<E, R> {
+        let (m, _) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Col::<E>::with_capacity(m.unbound());
+        let out_view = unsafe { col::from_raw_parts_mut::<'_, E, _>(out.as_ptr_mut(), m, 1) };
+        let mut f = f;
+        ZipEq::new(out_view, self).for_each_with_index(
+            #[inline(always)]
+            |i, Zip(mut out, item)| out.write(f(i, item)),
+        );
+        unsafe { out.set_nrows(m.unbound()) };
+        out.into_shape(m)
+    }
 }
 
 impl<
@@ -2632,11 +3015,40 @@ impl<
     > ZipEq<R, C, Head, Tail>
 {
     /// Applies `f` to each element of `self`.
+    ///
+    /// The elements are visited in an order that follows the memory layout of the first matrix
+    /// of the zipped views, so that contiguous memory is traversed sequentially. Given the row
+    /// stride `rs` and the column stride `cs` of the first matrix, the layout transform is selected
+    /// as follows, where the first matching rule applies:
+    /// * if `nrows > 1` and `rs == 1`, [`MatLayoutTransform::None`]: the columns are visited from
+    ///   left to right, and each column from top to bottom, i.e., `(0, 0), (1, 0), ..., (0, 1),
+    ///   ...`.
+    /// * if `nrows > 1` and `rs == -1`, [`MatLayoutTransform::ReverseRows`]: the columns are
+    ///   visited from left to right, and each column from bottom to top.
+    /// * if `ncols > 1` and `cs == 1`, [`MatLayoutTransform::Transpose`]: the rows are visited
+    ///   from top to bottom, and each row from left to right, i.e., `(0, 0), (0, 1), ..., (1, 0),
+    ///   ...`.
+    /// * if `ncols > 1` and `cs == -1`, [`MatLayoutTransform::TransposeReverseRows`]: the rows
+    ///   are visited from top to bottom, and each row from right to left.
+    /// * otherwise, [`MatLayoutTransform::None`].
+    ///
+    /// The same order is used by [`Self::for_each_with_index`] and [`Self::map`]. Closures with
+    /// order-sensitive side effects should use [`Self::for_each_in_logical_order`] instead.
     #[inline(always)]
     pub fn for_each(self, f: impl FnMut(<Self as MatIndex>::Item)) {
         for_each_mat(self, f);
     }
 
+    /// Applies `f` to each element of `self`, in column-major order regardless of the memory
+    /// layout of the matrices, i.e., `(0, 0), (1, 0), ..., (nrows - 1, 0), (0, 1), ...`.
+    ///
+    /// This may be slower than [`Self::for_each`] when the matrices are not stored in column-major
+    /// order.
+    #[inline(always)]
+    pub fn for_each_in_logical_order(self, f: impl FnMut(<Self as MatIndex>::Item)) {
+        for_each_mat_with_layout(self, MatLayoutTransform::None, f);
+    }
+
     /// Applies `f` to each element of `self`, while passing the indices of the position of the
     /// current element.
     #[inline(always)]
@@ -2703,6 +3115,58 @@ impl<
         unsafe { out.set_dims(m.unbound(), n.unbound()) };
         out.into_shape(m, n)
     }
+
+    /// Applies `f` to each element of `self` and collects its result into a new matrix, or returns
+    /// the first error returned by `f`.
+    ///
+    /// The elements are visited in the same order as [`Self::for_each_in_logical_order`], and the
+    /// iteration stops at the first error, in which case the partially written output is
+    /// discarded.
+    #[inline(always)]
+    pub fn try_map<E: Entity, Err>(
+        self,
+        f: impl FnMut(<Self as MatIndex>::Item) -> Result<E, Err>,
+    ) -> Result<Mat<E, R, C>, Err> {
+        let (m, n) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Mat::<E>::with_capacity(m.unbound(), n.unbound());
+        let rs = 1;
+        let cs = out.col_stride();
+        let out_view =
+            unsafe { mat::from_raw_parts_mut::<'_, E, _, _>(out.as_ptr_mut(), m, n, rs, cs) };
+        let mut f = f;
+        try_for_each_mat_with_layout(
+            ZipEq::new(out_view, self),
+            MatLayoutTransform::None,
+            #[inline(always)]
+            |Zip(mut out, item)| {
+                out.write(f(item)?);
+                Ok(())
+            },
+        )?;
+        unsafe { out.set_dims(m.unbound(), n.unbound()) };
+        Ok(out.into_shape(m, n))
+    }
+
+    /// Applies `f` to each element of `self` and collect its result into a new matrix.
+    #[inline(always)]
+    pub fn map_with_index<E: Entity>(
+        self,
+        f: impl FnMut(Idx<R>, Idx<C>, <Self as MatIndex>::Item) -> E,
+    ) -> Mat<E, R, C> {
+        let (m, n) = (Self::nrows(&self), Self::ncols(&self));
+        let mut out = Mat::<E>::with_capacity(m.unbound(), n.unbound());
+        let rs = 1;
+        let cs = out.col_stride();
+        let out_view =
+            unsafe { mat::from_raw_parts_mut::<'_, E, _, _>(out.as_ptr_mut(), m, n, rs, cs) };
+        let mut f = f;
+        ZipEq::new(out_view, self).for_each_with_index(
+            #[inline(always)]
+            |i, j, Zip(mut out, item)| out.write(f(i, j, item)),
+        );
+        unsafe { out.set_dims(m.unbound(), n.unbound()) };
+        out.into_shape(m, n)
+    }
 }
 
 #[cfg(test)]
@@ -3032,4 +3496,192 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_zip_four_with_index() {
+        let (m, n) = (4, 3);
+        let a = Mat::from_fn(m, n, |i, j| (i + 10 * j) as f64);
+        let b = Mat::from_fn(n, m, |i, j| (j + 100 * i) as f64);
+        let c = Mat::from_fn(m, n, |i, j| (i * j) as f64);
+        let target = Mat::from_fn(m, n, |i, j| {
+            (i + 10 * j) as f64 + (i + 100 * j) as f64 + (i * j) as f64 + (i + j) as f64
+        });
+
+        let mut dst = Mat::<f64>::zeros(m, n);
+        zipped_rw!(dst.as_mut(), a.as_ref(), b.transpose(), c.as_ref()).for_each_with_index(
+            |i, j, unzipped!(mut dst, a, b, c)| {
+                dst.write(a.read() + b.read() + c.read() + (i + j) as f64)
+            },
+        );
+        assert!(dst == target);
+
+        for diag in [Diag::Include, Diag::Skip] {
+            let mut lower = Mat::<f64>::zeros(m, n);
+            zipped_rw!(lower.as_mut(), a.as_ref(), b.transpose(), c.as_ref())
+                .for_each_triangular_lower_with_index(diag, |i, j, unzipped!(mut dst, a, b, c)| {
+                    assert!(if diag == Diag::Include { i >= j } else { i > j });
+                    dst.write(a.read() + b.read() + c.read() + (i + j) as f64)
+                });
+            let mut upper = Mat::<f64>::zeros(m, n);
+            zipped_rw!(upper.as_mut(), a.as_ref(), b.transpose(), c.as_ref())
+                .for_each_triangular_upper_with_index(diag, |i, j, unzipped!(mut dst, a, b, c)| {
+                    assert!(if diag == Diag::Include { i <= j } else { i < j });
+                    dst.write(a.read() + b.read() + c.read() + (i + j) as f64)
+                });
+            for j in 0..n {
+                for i in 0..m {
+                    let on_diag = diag == Diag::Include && i == j;
+                    assert!(
+                        lower.read(i, j)
+                            == if i > j || on_diag {
+                                target.read(i, j)
+                            } else {
+                                0.0
+                            }
+                    );
+                    assert!(
+                        upper.read(i, j)
+                            == if i < j || on_diag {
+                                target.read(i, j)
+                            } else {
+                                0.0
+                            }
+                    );
+                }
+            }
+        }
+
+        let mapped = zipped_rw!(a.as_ref(), b.transpose(), c.as_ref()).map_with_index(
+            |i, j, unzipped!(a, b, c)| a.read() + b.read() + c.read() + (i + j) as f64,
+        );
+        assert!(mapped == target);
+
+        let mut dst = Col::<f64>::zeros(m);
+        zipped_rw!(dst.as_mut(), a.col(1), b.row(1).transpose(), c.col(1)).for_each_with_index(
+            |i, unzipped!(mut dst, a, b, c)| {
+                dst.write(a.read() + b.read() + c.read() + (i + 1) as f64)
+            },
+        );
+        assert!(dst == target.col(1));
+
+        let mut dst = Row::<f64>::zeros(n);
+        zipped_rw!(dst.as_mut(), a.row(2), b.col(2).transpose(), c.row(2)).for_each_with_index(
+            |j, unzipped!(mut dst, a, b, c)| {
+                dst.write(a.read() + b.read() + c.read() + (j + 2) as f64)
+            },
+        );
+        assert!(dst == target.row(2));
+    }
+
+    #[test]
+    fn test_try_map() {
+        let a = mat![[1.0, 4.0, 9.0], [16.0, 25.0, 36.0f64]];
+        let b = mat![[0.0, 1.0, -1.0], [2.0, -2.0, 3.0f64]];
+        let sqrt = |x: f64| if x >= 0.0 { Ok(x.sqrt()) } else { Err(x) };
+
+        let root = zipped_rw!(a.as_ref()).try_map(|unzipped!(a)| sqrt(a.read()));
+        assert!(root == Ok(mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+        // the first failure in column-major order is returned, and `f` is not called afterwards
+        let mut calls = 0;
+        let diff = zipped_rw!(a.as_ref(), b.as_ref()).try_map(|unzipped!(a, b)| {
+            calls += 1;
+            sqrt(b.read() * a.read())
+        });
+        assert!(diff == Err(-50.0));
+        assert!(calls == 4);
+
+        let col =
+            zipped_rw!(a.col(2), b.col(2)).try_map(|unzipped!(a, b)| sqrt(a.read() + b.read()));
+        assert!(col == Ok(col![8.0f64.sqrt(), 39.0f64.sqrt()]));
+        let col = zipped_rw!(b.col(2)).try_map(|unzipped!(b)| sqrt(b.read()));
+        assert!(col == Err(-1.0));
+
+        let row = zipped_rw!(a.row(0)).try_map(|unzipped!(a)| sqrt(a.read()));
+        assert!(row == Ok(row![1.0, 2.0, 3.0]));
+        let row =
+            zipped_rw!(a.row(1), b.row(1)).try_map(|unzipped!(a, b)| sqrt(b.read() - a.read()));
+        assert!(row == Err(-14.0));
+    }
+
+    #[test]
+    fn test_for_each_in_logical_order() {
+        let m = 3;
+        let n = 4;
+        let a = Mat::from_fn(m, n, |i, j| (i + m * j) as f64);
+        let col_major = (0..m * n).map(|k| k as f64).collect::<alloc::vec::Vec<_>>();
+
+        for transpose in [false, true] {
+            for reverse_rows in [false, true] {
+                for reverse_cols in [false, true] {
+                    // view with the same values as `a`, but different strides
+                    let mut storage = Mat::<f64>::zeros(
+                        if transpose { n } else { m },
+                        if transpose { m } else { n },
+                    );
+                    let mut view = if transpose {
+                        storage.as_mut().transpose_mut()
+                    } else {
+                        storage.as_mut()
+                    };
+                    if reverse_rows {
+                        view = view.reverse_rows_mut();
+                    }
+                    if reverse_cols {
+                        view = view.reverse_cols_mut();
+                    }
+                    zipped_rw!(view.rb_mut(), a.as_ref())
+                        .for_each(|unzipped!(mut dst, src)| dst.write(src.read()));
+
+                    let mut visited = alloc::vec::Vec::new();
+                    zipped_rw!(view.rb()).for_each_in_logical_order(|unzipped!(x)| {
+                        visited.push(x.read());
+                    });
+                    assert!(visited == col_major);
+
+                    let mut visited = alloc::vec::Vec::new();
+                    zipped_rw!(view.rb(), a.as_ref()).for_each_in_logical_order(
+                        |unzipped!(x, y)| {
+                            assert!(x.read() == y.read());
+                            visited.push(y.read());
+                        },
+                    );
+                    assert!(visited == col_major);
+                }
+            }
+        }
+
+        // the default traversal follows the memory layout instead
+        let row_major = a.transpose().to_owned();
+        let mut visited = alloc::vec::Vec::new();
+        zipped_rw!(row_major.transpose()).for_each(|unzipped!(x)| visited.push(x.read()));
+        assert!(
+            visited
+                == (0..m * n)
+                    .map(|k| a.read(k / n, k % n))
+                    .collect::<alloc::vec::Vec<_>>()
+        );
+
+        let c = crate::col::from_slice::<f64>(&col_major);
+        for view in [c.as_ref(), c.as_ref().reverse_rows()] {
+            let mut visited = alloc::vec::Vec::new();
+            zipped_rw!(view).for_each_in_logical_order(|unzipped!(x)| visited.push(x.read()));
+            assert!(
+                visited
+                    == (0..m * n)
+                        .map(|i| view.read(i))
+                        .collect::<alloc::vec::Vec<_>>()
+            );
+
+            let mut visited = alloc::vec::Vec::new();
+            zipped_rw!(view.transpose(), view.transpose())
+                .for_each_in_logical_order(|unzipped!(x, _)| visited.push(x.read()));
+            assert!(
+                visited
+                    == (0..m * n)
+                        .map(|i| view.read(i))
+                        .collect::<alloc::vec::Vec<_>>()
+            );
+        }
+    }
 }
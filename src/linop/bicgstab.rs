@@ -4,7 +4,7 @@ use crate::{
     prelude::*,
     ComplexField, Parallelism, RealField,
 };
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::ControlFlow};
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use equator::assert;
 use reborrow::*;
@@ -114,6 +114,7 @@ pub struct BicgInfo<E: ComplexField> {
 
 /// Algorithm error.
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub enum BicgError<E: ComplexField> {
     /// Convergence failure.
     NoConvergence {
@@ -122,6 +123,15 @@ pub enum BicgError<E: ComplexField> {
         /// Relative residual at the final step.
         rel_residual: E::Real,
     },
+    /// The solve was aborted by the monitor.
+    Aborted {
+        /// Absolute residual at the final step.
+        abs_residual: E::Real,
+        /// Relative residual at the final step.
+        rel_residual: E::Real,
+        /// Number of iterations executed by the algorithm.
+        iter_count: usize,
+    },
 }
 
 /// Executes BiCGSTAB using the provided preconditioners.
@@ -139,6 +149,41 @@ pub fn bicgstab<E: ComplexField>(
     callback: impl FnMut(MatRef<'_, E>),
     parallelism: Parallelism,
     stack: &mut PodStack,
+) -> Result<BicgInfo<E>, BicgError<E>> {
+    bicgstab_with_monitor(
+        out,
+        left_precond,
+        right_precond,
+        mat,
+        rhs,
+        params,
+        callback,
+        |_, _| ControlFlow::Continue(()),
+        parallelism,
+        stack,
+    )
+}
+
+/// Executes BiCGSTAB using the provided preconditioners, and calls `monitor` after each iteration
+/// with the number of iterations executed so far and the norm of the residual.
+///
+/// The solve is aborted with [`BicgError::Aborted`] if `monitor` returns [`ControlFlow::Break`],
+/// unless the residual has already converged.
+///
+/// # Note
+/// This function is also optimized for a RHS with multiple columns.
+#[track_caller]
+pub fn bicgstab_with_monitor<E: ComplexField>(
+    out: MatMut<'_, E>,
+    left_precond: impl Precond<E>,
+    right_precond: impl Precond<E>,
+    mat: impl LinOp<E>,
+    rhs: MatRef<'_, E>,
+    params: BicgParams<E>,
+    callback: impl FnMut(MatRef<'_, E>),
+    monitor: impl FnMut(usize, E::Real) -> ControlFlow<()>,
+    parallelism: Parallelism,
+    stack: &mut PodStack,
 ) -> Result<BicgInfo<E>, BicgError<E>> {
     #[track_caller]
     fn implementation<E: ComplexField>(
@@ -149,6 +194,7 @@ pub fn bicgstab<E: ComplexField>(
         rhs: MatRef<'_, E>,
         params: BicgParams<E>,
         callback: &mut dyn FnMut(MatRef<'_, E>),
+        monitor: &mut dyn FnMut(usize, E::Real) -> ControlFlow<()>,
         parallelism: Parallelism,
         stack: &mut PodStack,
     ) -> Result<BicgInfo<E>, BicgError<E>> {
@@ -303,6 +349,7 @@ pub fn bicgstab<E: ComplexField>(
             }
             let norm = s.norm_l2();
             if norm < threshold {
+                let _ = monitor(iter + 1, norm);
                 return Ok(BicgInfo {
                     abs_residual: norm,
                     rel_residual: norm.faer_div(b_norm),
@@ -351,6 +398,7 @@ pub fn bicgstab<E: ComplexField>(
             callback(x.rb());
 
             let norm = r.norm_l2();
+            let flow = monitor(iter + 1, norm);
             if norm < threshold {
                 return Ok(BicgInfo {
                     abs_residual: norm,
@@ -359,6 +407,13 @@ pub fn bicgstab<E: ComplexField>(
                     __private: PhantomData,
                 });
             }
+            if flow.is_break() {
+                return Err(BicgError::Aborted {
+                    abs_residual: norm,
+                    rel_residual: norm.faer_div(b_norm),
+                    iter_count: iter + 1,
+                });
+            }
 
             let (mut rtt, mut stack) = temp_mat_uninit::<E>(k, k, stack.rb_mut());
             {
@@ -412,6 +467,7 @@ pub fn bicgstab<E: ComplexField>(
         rhs,
         params,
         &mut { callback },
+        &mut { monitor },
         parallelism,
         stack,
     )
@@ -467,4 +523,65 @@ mod tests {
         assert!(result.is_ok());
         assert!((A * out - rhs).norm_l2() <= params.rel_tolerance * rhs.norm_l2());
     }
+
+    #[test]
+    fn test_bicgstab_monitor() {
+        let n = 30;
+        let ref A = Mat::<f64>::from_fn(n, n, |i, j| {
+            if i == j {
+                3.0
+            } else if i + 1 == j {
+                -1.0
+            } else if i == j + 1 {
+                0.5
+            } else {
+                0.0
+            }
+        });
+        let ref rhs = Mat::<f64>::from_fn(n, 1, |i, _| (i % 4) as f64);
+        let params = BicgParams::default();
+        let precond = crate::linop::IdentityPrecond { dim: n };
+        let ref mut buf = GlobalPodBuffer::new(
+            bicgstab_req(precond, precond, A.as_ref(), 1, Parallelism::None).unwrap(),
+        );
+
+        let mut history = alloc::vec::Vec::new();
+        let ref mut out = Mat::<f64>::zeros(n, 1);
+        let result = bicgstab_with_monitor(
+            out.as_mut(),
+            precond,
+            precond,
+            A.as_ref(),
+            rhs.as_ref(),
+            params,
+            |_| {},
+            |iter, residual| {
+                history.push((iter, residual));
+                ControlFlow::Continue(())
+            },
+            Parallelism::None,
+            PodStack::new(buf),
+        )
+        .unwrap();
+        assert!(history.len() == result.iter_count);
+        assert!(history.last().unwrap().1 == result.abs_residual);
+
+        let ref mut out = Mat::<f64>::zeros(n, 1);
+        let result = bicgstab_with_monitor(
+            out.as_mut(),
+            precond,
+            precond,
+            A.as_ref(),
+            rhs.as_ref(),
+            params,
+            |_| {},
+            |_, _| ControlFlow::Break(()),
+            Parallelism::None,
+            PodStack::new(buf),
+        );
+        assert!(matches!(
+            result,
+            Err(BicgError::Aborted { iter_count: 1, .. })
+        ));
+    }
 }
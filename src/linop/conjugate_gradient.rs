@@ -7,7 +7,7 @@ use crate::{
     prelude::*,
     ComplexField, Parallelism, RealField,
 };
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::ControlFlow};
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use equator::assert;
 use reborrow::*;
@@ -44,6 +44,7 @@ pub struct CgInfo<E: ComplexField> {
 
 /// Algorithm error.
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub enum CgError<E: ComplexField> {
     /// Operator was detected to not be positive definite.
     NonPositiveDefiniteOperator,
@@ -56,6 +57,15 @@ pub enum CgError<E: ComplexField> {
         /// Relative residual at the final step.
         rel_residual: E::Real,
     },
+    /// The solve was aborted by the monitor.
+    Aborted {
+        /// Absolute residual at the final step.
+        abs_residual: E::Real,
+        /// Relative residual at the final step.
+        rel_residual: E::Real,
+        /// Number of iterations executed by the algorithm.
+        iter_count: usize,
+    },
 }
 
 impl<E: ComplexField> Default for CgParams<E> {
@@ -134,6 +144,41 @@ pub fn conjugate_gradient<E: ComplexField>(
     callback: impl FnMut(MatRef<'_, E>),
     parallelism: Parallelism,
     stack: &mut PodStack,
+) -> Result<CgInfo<E>, CgError<E>> {
+    conjugate_gradient_with_monitor(
+        out,
+        precond,
+        mat,
+        rhs,
+        params,
+        callback,
+        |_, _| ControlFlow::Continue(()),
+        parallelism,
+        stack,
+    )
+}
+
+/// Executes the conjugate gradient using the provided preconditioner, and calls `monitor` after
+/// each iteration with the number of iterations executed so far and the norm of the residual.
+///
+/// The solve is aborted with [`CgError::Aborted`] if `monitor` returns [`ControlFlow::Break`],
+/// unless the residual has already converged. This can be used to log or plot the convergence
+/// history, or to implement custom stopping criteria.
+///
+/// # Note
+/// This function is also optimized for a RHS with multiple columns.
+#[inline]
+#[track_caller]
+pub fn conjugate_gradient_with_monitor<E: ComplexField>(
+    out: MatMut<'_, E>,
+    precond: impl Precond<E>,
+    mat: impl LinOp<E>,
+    rhs: MatRef<'_, E>,
+    params: CgParams<E>,
+    callback: impl FnMut(MatRef<'_, E>),
+    monitor: impl FnMut(usize, E::Real) -> ControlFlow<()>,
+    parallelism: Parallelism,
+    stack: &mut PodStack,
 ) -> Result<CgInfo<E>, CgError<E>> {
     #[track_caller]
     fn implementation<E: ComplexField>(
@@ -144,6 +189,7 @@ pub fn conjugate_gradient<E: ComplexField>(
 
         params: CgParams<E>,
         callback: &mut dyn FnMut(MatRef<'_, E>),
+        monitor: &mut dyn FnMut(usize, E::Real) -> ControlFlow<()>,
         parallelism: Parallelism,
         mut stack: &mut PodStack,
     ) -> Result<CgInfo<E>, CgError<E>> {
@@ -236,6 +282,7 @@ pub fn conjugate_gradient<E: ComplexField>(
                     ptAp.rb_mut(),
                     perm,
                     perm_inv,
+                    None,
                     parallelism,
                     stack.rb_mut(),
                     Default::default(),
@@ -290,6 +337,7 @@ pub fn conjugate_gradient<E: ComplexField>(
             }
 
             let abs_residual = r.norm_l2();
+            let flow = monitor(iter + 1, abs_residual);
             if abs_residual < threshold {
                 return Ok(CgInfo {
                     abs_residual,
@@ -298,6 +346,13 @@ pub fn conjugate_gradient<E: ComplexField>(
                     __private: PhantomData,
                 });
             }
+            if flow.is_break() {
+                return Err(CgError::Aborted {
+                    abs_residual,
+                    rel_residual: abs_residual.faer_div(b_norm),
+                    iter_count: iter + 1,
+                });
+            }
 
             M.apply(z.rb_mut(), r.rb(), parallelism, stack.rb_mut());
 
@@ -319,6 +374,7 @@ pub fn conjugate_gradient<E: ComplexField>(
                     rtz.rb_mut(),
                     perm,
                     perm_inv,
+                    None,
                     parallelism,
                     stack.rb_mut(),
                     Default::default(),
@@ -374,6 +430,7 @@ pub fn conjugate_gradient<E: ComplexField>(
         rhs,
         params,
         &mut { callback },
+        &mut { monitor },
         parallelism,
         stack,
     )
@@ -418,6 +475,75 @@ mod tests {
         assert!(result.iter_count <= 1);
     }
 
+    #[test]
+    fn test_cg_monitor() {
+        let n = 30;
+        let ref A = Mat::<f64>::from_fn(n, n, |i, j| match i.abs_diff(j) {
+            0 => 2.5,
+            1 => -1.0,
+            _ => 0.0,
+        });
+        let ref rhs = Mat::<f64>::from_fn(n, 1, |i, _| (i % 4) as f64);
+        let params = CgParams::default();
+        let precond = linop::IdentityPrecond { dim: n };
+        let ref mut buf = GlobalPodBuffer::new(
+            conjugate_gradient_req(precond, A.as_ref(), 1, Parallelism::None).unwrap(),
+        );
+
+        let mut history = alloc::vec::Vec::new();
+        let ref mut out = Mat::<f64>::zeros(n, 1);
+        let result = conjugate_gradient_with_monitor(
+            out.as_mut(),
+            precond,
+            A.as_ref(),
+            rhs.as_ref(),
+            params,
+            |_| {},
+            |iter, residual| {
+                history.push((iter, residual));
+                ControlFlow::Continue(())
+            },
+            Parallelism::None,
+            PodStack::new(buf),
+        )
+        .unwrap();
+        assert!(history.len() == result.iter_count);
+        for (idx, &(iter, _)) in history.iter().enumerate() {
+            assert!(iter == idx + 1);
+        }
+        assert!(history.last().unwrap().1 == result.abs_residual);
+
+        let ref mut out = Mat::<f64>::zeros(n, 1);
+        let result = conjugate_gradient_with_monitor(
+            out.as_mut(),
+            precond,
+            A.as_ref(),
+            rhs.as_ref(),
+            params,
+            |_| {},
+            |iter, _| {
+                if iter == 2 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+            Parallelism::None,
+            PodStack::new(buf),
+        );
+        match result {
+            Err(CgError::Aborted {
+                abs_residual,
+                iter_count,
+                ..
+            }) => {
+                assert!(iter_count == 2);
+                assert!(abs_residual == history[1].1);
+            }
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn test_cg_breakdown() {
         let ref mut rng = StdRng::seed_from_u64(0);
@@ -0,0 +1,211 @@
+use super::*;
+use crate::assert;
+
+/// Owned matrix of booleans, stored in column-major order.
+///
+/// Masks are usually obtained from the elementwise comparison methods of matrices, such as
+/// [`MatRef::gt`], and can be used to pick elements from one of two matrices with [`select`].
+///
+/// # Example
+///
+/// ```
+/// use faer::{mat, mat::select};
+///
+/// let a = mat![[1.0, -2.0], [-3.0, 4.0]];
+///
+/// let positive = a.gt(0.0);
+/// assert!(positive.count_nonzero() == 2);
+///
+/// // clamp the negative entries to zero
+/// let clamped = select(&positive, a.as_ref(), faer::Mat::<f64>::zeros(2, 2).as_ref());
+/// assert!(clamped == mat![[1.0, 0.0], [0.0, 4.0]]);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mask {
+    nrows: usize,
+    ncols: usize,
+    data: alloc::vec::Vec<bool>,
+}
+
+impl Mask {
+    /// Returns a new mask with dimensions `(nrows, ncols)`, filled with the provided function.
+    #[inline]
+    pub fn from_fn(nrows: usize, ncols: usize, f: impl FnMut(usize, usize) -> bool) -> Self {
+        let mut f = f;
+        let mut data = alloc::vec::Vec::with_capacity(nrows * ncols);
+        for j in 0..ncols {
+            for i in 0..nrows {
+                data.push(f(i, j));
+            }
+        }
+        Self { nrows, ncols, data }
+    }
+
+    /// Returns the number of rows of the mask.
+    #[inline(always)]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns of the mask.
+    #[inline(always)]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Reads the value of the element at the given indices.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `row < self.nrows()`.
+    /// * `col < self.ncols()`.
+    #[inline]
+    #[track_caller]
+    pub fn read(&self, row: usize, col: usize) -> bool {
+        assert!(all(row < self.nrows, col < self.ncols));
+        self.data[row + col * self.nrows]
+    }
+
+    /// Writes the value to the element at the given indices.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `row < self.nrows()`.
+    /// * `col < self.ncols()`.
+    #[inline]
+    #[track_caller]
+    pub fn write(&mut self, row: usize, col: usize, value: bool) {
+        assert!(all(row < self.nrows, col < self.ncols));
+        self.data[row + col * self.nrows] = value;
+    }
+
+    /// Returns the number of elements that are `true`.
+    #[inline]
+    pub fn count_nonzero(&self) -> usize {
+        self.data.iter().filter(|&&x| x).count()
+    }
+
+    /// Returns `true` if any of the elements is `true`.
+    #[inline]
+    pub fn any(&self) -> bool {
+        self.data.iter().any(|&x| x)
+    }
+
+    /// Returns `true` if all of the elements are `true`.
+    #[inline]
+    pub fn all(&self) -> bool {
+        self.data.iter().all(|&x| x)
+    }
+
+    /// Returns the elementwise negation of `self`.
+    #[inline]
+    pub fn not(&self) -> Self {
+        Self::from_fn(self.nrows, self.ncols, |i, j| !self.read(i, j))
+    }
+
+    /// Returns the elementwise conjunction of `self` and `other`.
+    ///
+    /// # Panics
+    /// The function panics if `self` and `other` don't have the same dimensions.
+    #[inline]
+    #[track_caller]
+    pub fn and(&self, other: &Self) -> Self {
+        assert!(all(self.nrows == other.nrows, self.ncols == other.ncols));
+        Self::from_fn(self.nrows, self.ncols, |i, j| {
+            self.read(i, j) && other.read(i, j)
+        })
+    }
+
+    /// Returns the elementwise disjunction of `self` and `other`.
+    ///
+    /// # Panics
+    /// The function panics if `self` and `other` don't have the same dimensions.
+    #[inline]
+    #[track_caller]
+    pub fn or(&self, other: &Self) -> Self {
+        assert!(all(self.nrows == other.nrows, self.ncols == other.ncols));
+        Self::from_fn(self.nrows, self.ncols, |i, j| {
+            self.read(i, j) || other.read(i, j)
+        })
+    }
+}
+
+impl core::fmt::Debug for Mask {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct DebugRow<'a>(&'a Mask, usize);
+        impl core::fmt::Debug for DebugRow<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_list()
+                    .entries((0..self.0.ncols).map(|j| self.0.read(self.1, j)))
+                    .finish()
+            }
+        }
+        f.debug_list()
+            .entries((0..self.nrows).map(|i| DebugRow(self, i)))
+            .finish()
+    }
+}
+
+/// Returns a matrix whose elements are taken from `a` where `mask` is `true`, and from `b`
+/// otherwise.
+///
+/// # Panics
+/// The function panics if `mask`, `a` and `b` don't all have the same dimensions.
+#[track_caller]
+pub fn select<E: ComplexField, A: Conjugate<Canonical = E>, B: Conjugate<Canonical = E>>(
+    mask: &Mask,
+    a: MatRef<'_, A>,
+    b: MatRef<'_, B>,
+) -> Mat<E> {
+    assert!(all(
+        a.nrows() == mask.nrows(),
+        a.ncols() == mask.ncols(),
+        b.nrows() == mask.nrows(),
+        b.ncols() == mask.ncols(),
+    ));
+    Mat::from_fn(mask.nrows(), mask.ncols(), |i, j| {
+        if mask.read(i, j) {
+            a.read(i, j).canonicalize()
+        } else {
+            b.read(i, j).canonicalize()
+        }
+    })
+}
+
+#[inline]
+pub(crate) fn compare<E: Entity>(mat: MatRef<'_, E>, f: impl Fn(E) -> bool) -> Mask {
+    Mask::from_fn(mat.nrows(), mat.ncols(), |i, j| f(mat.read(i, j)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, mat};
+
+    #[test]
+    fn test_mask() {
+        let a = mat![[1.0, -2.0, 0.0], [-3.0, 4.0, 5.0f64]];
+
+        assert!(a.gt(0.0) == Mask::from_fn(2, 3, |i, j| a.read(i, j) > 0.0));
+        assert!(a.ge(0.0).count_nonzero() == 4);
+        assert!(a.lt(0.0).count_nonzero() == 2);
+        assert!(a.le(0.0).count_nonzero() == 3);
+        assert!(a.lt(0.0) == a.ge(0.0).not());
+        assert!(a.gt(0.0).or(&a.lt(0.0)) == a.eq_abs(0.0, 0.0).not());
+        assert!(!a.gt(0.0).and(&a.lt(0.0)).any());
+        assert!(a.as_ref().le(5.0).all());
+
+        let b = mat![[10.0, 20.0, 30.0], [40.0, 50.0, 60.0f64]];
+        let c = select(&a.gt(0.0), a.as_ref(), b.as_ref());
+        assert!(c == mat![[1.0, 20.0, 30.0], [40.0, 4.0, 5.0]]);
+
+        let z = mat![[c64::new(1.0, 1.0), c64::new(0.0, 1e-3)]];
+        let mask = z.eq_abs(c64::new(0.0, 0.0), 1e-2);
+        assert!(mask == Mask::from_fn(1, 2, |_, j| j == 1));
+        let w = select(&mask, z.as_ref().conjugate(), z.as_ref());
+        assert!(w == mat![[c64::new(1.0, 1.0), c64::new(0.0, -1e-3)]]);
+
+        let empty = Mat::<f64>::zeros(0, 3).gt(0.0);
+        assert!(all(empty.count_nonzero() == 0, empty.all(), !empty.any()));
+    }
+}
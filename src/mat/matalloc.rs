@@ -7,6 +7,7 @@ pub struct RawMatUnit<T: 'static> {
     pub(crate) ptr: NonNull<T>,
     pub(crate) row_capacity: usize,
     pub(crate) col_capacity: usize,
+    pub(crate) align: usize,
 }
 
 #[repr(C)]
@@ -17,13 +18,15 @@ pub(crate) struct MatUnit<T: 'static> {
 }
 
 impl<T: 'static> RawMatUnit<T> {
-    pub fn new(row_capacity: usize, col_capacity: usize) -> Self {
+    /// `align` must be a power of two, no smaller than `align_for::<T>()`.
+    pub fn new_aligned(row_capacity: usize, col_capacity: usize, align: usize) -> Self {
         let dangling = NonNull::<T>::dangling();
         if core::mem::size_of::<T>() == 0 {
             Self {
                 ptr: dangling,
                 row_capacity,
                 col_capacity,
+                align,
             }
         } else {
             let cap = row_capacity
@@ -38,7 +41,7 @@ impl<T: 'static> RawMatUnit<T> {
 
             use alloc::alloc::{alloc, handle_alloc_error, Layout};
 
-            let layout = Layout::from_size_align(cap_bytes, align_for::<T>())
+            let layout = Layout::from_size_align(cap_bytes, align)
                 .ok()
                 .unwrap_or_else(capacity_overflow);
 
@@ -58,6 +61,7 @@ impl<T: 'static> RawMatUnit<T> {
                 ptr,
                 row_capacity,
                 col_capacity,
+                align,
             }
         }
     }
@@ -76,7 +80,7 @@ impl<T: 'static> Drop for RawMatUnit<T> {
             unsafe {
                 dealloc(
                     self.ptr.as_ptr() as *mut u8,
-                    Layout::from_size_align_unchecked(alloc_size, align_for::<T>()),
+                    Layout::from_size_align_unchecked(alloc_size, self.align),
                 );
             }
         }
@@ -88,13 +92,19 @@ pub struct RawMat<E: Entity> {
     pub(crate) ptr: GroupCopyFor<E, NonNull<E::Unit>>,
     pub(crate) row_capacity: usize,
     pub(crate) col_capacity: usize,
+    pub(crate) align: usize,
 }
 
 impl<E: Entity> RawMat<E> {
     pub fn new(row_capacity: usize, col_capacity: usize) -> Self {
+        Self::new_aligned(row_capacity, col_capacity, align_for::<E::Unit>())
+    }
+
+    /// `align` must be a power of two, no smaller than `align_for::<E::Unit>()`.
+    pub fn new_aligned(row_capacity: usize, col_capacity: usize, align: usize) -> Self {
         // allocate the unit matrices
         let group = E::faer_map(E::UNIT, |()| {
-            RawMatUnit::<E::Unit>::new(row_capacity, col_capacity)
+            RawMatUnit::<E::Unit>::new_aligned(row_capacity, col_capacity, align)
         });
 
         let group = E::faer_map(group, core::mem::ManuallyDrop::new);
@@ -103,6 +113,7 @@ impl<E: Entity> RawMat<E> {
             ptr: into_copy::<E, _>(E::faer_map(group, |mat| mat.ptr)),
             row_capacity,
             col_capacity,
+            align,
         }
     }
 }
@@ -113,6 +124,7 @@ impl<E: Entity> Drop for RawMat<E> {
             ptr,
             row_capacity: self.row_capacity,
             col_capacity: self.col_capacity,
+            align: self.align,
         }));
     }
 }
@@ -157,8 +169,8 @@ impl<T> MatUnit<T> {
             // SAFETY: this shouldn't overflow since we already checked that it's valid during
             // allocation
             let old_layout =
-                unsafe { Layout::from_size_align_unchecked(old_cap_bytes, align_for::<T>()) };
-            let new_layout = Layout::from_size_align(new_cap_bytes, align_for::<T>())
+                unsafe { Layout::from_size_align_unchecked(old_cap_bytes, self.raw.align) };
+            let new_layout = Layout::from_size_align(new_cap_bytes, self.raw.align)
                 .ok()
                 .unwrap_or_else(capacity_overflow);
 
@@ -184,7 +196,11 @@ impl<T> MatUnit<T> {
 
             // allocate new memory region
             let new_ptr = {
-                let m = ManuallyDrop::new(RawMatUnit::<T>::new(new_row_capacity, new_col_capacity));
+                let m = ManuallyDrop::new(RawMatUnit::<T>::new_aligned(
+                    new_row_capacity,
+                    new_col_capacity,
+                    self.raw.align,
+                ));
                 m.ptr.as_ptr()
             };
 
@@ -210,6 +226,7 @@ impl<T> MatUnit<T> {
                 ptr: unsafe { NonNull::new_unchecked(old_ptr) },
                 row_capacity: self.raw.row_capacity,
                 col_capacity: self.raw.col_capacity,
+                align: self.raw.align,
             };
 
             new_ptr
@@ -115,6 +115,13 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         row_stride: isize,
         col_stride: isize,
     ) -> Self {
+        #[cfg(debug_assertions)]
+        crate::utils::assert_strided_offsets_fit(
+            nrows.unbound(),
+            ncols.unbound(),
+            row_stride,
+            col_stride,
+        );
         Self {
             inner: MatImpl {
                 ptr: into_copy::<E, _>(map!(E, ptr, |(ptr)| NonNull::new_unchecked(ptr),)),
@@ -156,6 +163,17 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         map!(E, from_copy::<E, _>(self.inner.ptr), |(ptr)| ptr.as_ptr(),)
     }
 
+    /// Returns the input matrix, after checking that its data pointers are aligned to `align`
+    /// bytes.
+    ///
+    /// See [`MatRef::assert_aligned`] for more details.
+    #[track_caller]
+    #[inline]
+    pub fn assert_aligned_mut(self, align: usize) -> Self {
+        self.rb().assert_aligned(align);
+        self
+    }
+
     /// Returns the row stride of the matrix, specified in number of elements, not in bytes.
     #[inline(always)]
     pub fn row_stride(&self) -> isize {
@@ -697,6 +715,8 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
     /// Returns references to the element at the given indices, or submatrices if either `row`
     /// or `col` is a range, with bound checks.
     ///
+    /// See [`MatRef::get`] for the accepted index types.
+    ///
     /// # Note
     /// The values pointed to by the references are expected to be initialized, even if the
     /// pointed-to value is not read, otherwise the behavior is undefined.
@@ -745,6 +765,8 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
     /// Returns mutable references to the element at the given indices, or submatrices if either
     /// `row` or `col` is a range, with bound checks.
     ///
+    /// See [`MatRef::get`] for the accepted index types.
+    ///
     /// # Note
     /// The values pointed to by the references are expected to be initialized, even if the
     /// pointed-to value is not read, otherwise the behavior is undefined.
@@ -753,6 +775,16 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
     /// The function panics if any of the following conditions are violated:
     /// * `row` must be contained in `[0, self.nrows())`.
     /// * `col` must be contained in `[0, self.ncols())`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, Mat};
+    ///
+    /// let mut a = Mat::<f64>::zeros(3, 3);
+    /// a.as_mut().get_mut(1.., ..2).fill(1.0);
+    ///
+    /// assert!(a == mat![[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 0.0]]);
+    /// ```
     #[inline(always)]
     #[track_caller]
     pub fn get_mut<RowRange, ColRange>(
@@ -832,6 +864,30 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         unsafe { map!(E, self.ptr_inbounds_at_mut(row, col), |(ptr)| &mut *ptr) }
     }
 
+    /// Returns references to the element at the given indices, or `None` if either index is out
+    /// of bounds.
+    ///
+    /// # Note
+    /// The values pointed to by the references are expected to be initialized, even if the
+    /// pointed-to value is not read, otherwise the behavior is undefined.
+    #[inline(always)]
+    pub fn try_get(self, row: usize, col: usize) -> Option<Ref<'a, E>> {
+        self.into_const().try_get(row, col)
+    }
+
+    /// Returns mutable references to the element at the given indices, or `None` if either index
+    /// is out of bounds.
+    ///
+    /// # Note
+    /// The values pointed to by the references are expected to be initialized, even if the
+    /// pointed-to value is not read, otherwise the behavior is undefined.
+    #[inline(always)]
+    pub fn try_get_mut(self, row: usize, col: usize) -> Option<Mut<'a, E>> {
+        let row = self.nrows().idx(row)?;
+        let col = self.ncols().idx(col)?;
+        Some(unsafe { self.at_mut_unchecked(row, col) })
+    }
+
     /// Reads the value of the element at the given indices.
     ///
     /// # Safety
@@ -1023,6 +1079,117 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         );
     }
 
+    /// Adds `lambda` to the elements of the main diagonal of `self`, i.e. the `min(nrows, ncols)`
+    /// elements at positions `(i, i)`. The other elements are not accessed.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let mut matrix = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    /// matrix.as_mut().add_scalar_to_diagonal(10.0);
+    ///
+    /// let expected = mat![[11.0, 2.0, 3.0], [4.0, 15.0, 6.0]];
+    /// assert_eq!(matrix, expected);
+    /// ```
+    #[track_caller]
+    pub fn add_scalar_to_diagonal(self, lambda: E)
+    where
+        E: ComplexField,
+    {
+        let mut diag = self.as_dyn_mut().diagonal_mut().column_vector_mut();
+        zipped_rw!(diag.rb_mut()).for_each(
+            #[inline(always)]
+            |unzipped!(mut x)| x.write(x.read().faer_add(lambda)),
+        );
+    }
+
+    /// Multiplies the `i`-th element of the main diagonal of `self` by `f(i)`, for each `i` in
+    /// `0..min(nrows, ncols)`. The other elements are not accessed.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let mut matrix = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    /// matrix.as_mut().scale_diagonal(|i| (i + 2) as f64);
+    ///
+    /// let expected = mat![[2.0, 2.0], [3.0, 12.0], [5.0, 6.0]];
+    /// assert_eq!(matrix, expected);
+    /// ```
+    #[track_caller]
+    pub fn scale_diagonal(self, f: impl Fn(usize) -> E)
+    where
+        E: ComplexField,
+    {
+        let mut diag = self.as_dyn_mut().diagonal_mut().column_vector_mut();
+        for i in 0..diag.nrows() {
+            let x = diag.read(i);
+            diag.write(i, x.faer_mul(f(i)));
+        }
+    }
+
+    /// Sorts the elements of each column of `self` in the given order.
+    ///
+    /// The sort is stable, so that equal elements keep their relative order. NaN values are
+    /// placed after all the other values of their column, regardless of `order`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, mat::SortOrder};
+    ///
+    /// let mut matrix = mat![[3.0, 1.0], [1.0, 2.0], [2.0, 0.0]];
+    /// matrix.as_mut().sort_each_col(SortOrder::Descending);
+    ///
+    /// let expected = mat![[3.0, 2.0], [2.0, 1.0], [1.0, 0.0]];
+    /// assert_eq!(matrix, expected);
+    /// ```
+    #[track_caller]
+    pub fn sort_each_col(&mut self, order: SortOrder)
+    where
+        E: RealField,
+    {
+        let mut this = self.rb_mut().as_dyn_mut();
+        let mut perm = alloc::vec::Vec::new();
+        for j in 0..this.ncols() {
+            sort_col(this.rb_mut().col_mut(j), order, &mut perm);
+        }
+    }
+
+    /// Sorts the elements of each column of `self` in the given order, and returns the matrix of
+    /// their original row indices, such that the element now at position `(i, j)` was previously
+    /// at position `(indices[(i, j)], j)`.
+    ///
+    /// The sort is performed as in [`Self::sort_each_col`].
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, mat::SortOrder};
+    ///
+    /// let mut matrix = mat![[3.0, 1.0], [1.0, 2.0], [2.0, 0.0]];
+    /// let indices = matrix.as_mut().sort_each_col_with_indices(SortOrder::Ascending);
+    ///
+    /// assert_eq!(matrix, mat![[1.0, 0.0], [2.0, 1.0], [3.0, 2.0]]);
+    /// assert_eq!(indices.read(0, 0), 1);
+    /// assert_eq!(indices.read(0, 1), 2);
+    /// ```
+    #[track_caller]
+    pub fn sort_each_col_with_indices(&mut self, order: SortOrder) -> Mat<usize>
+    where
+        E: RealField,
+    {
+        let mut this = self.rb_mut().as_dyn_mut();
+        let mut indices = Mat::<usize>::from_fn(this.nrows(), this.ncols(), |_, _| 0);
+        let mut perm = alloc::vec::Vec::new();
+        for j in 0..this.ncols() {
+            sort_col(this.rb_mut().col_mut(j), order, &mut perm);
+            for (i, &k) in perm.iter().enumerate() {
+                indices.write(i, j, k);
+            }
+        }
+        indices
+    }
+
     /// Returns a view over the `self`, with the rows in reversed order.
     ///
     /// # Example
@@ -1265,6 +1432,76 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         }
     }
 
+    /// Returns a view over the submatrix made of the rows
+    /// `row_start, row_start + row_step, ..., row_start + (nrows - 1) * row_step` and the columns
+    /// `col_start, col_start + col_step, ..., col_start + (ncols - 1) * col_step`.
+    ///
+    /// See [`MatRef::submatrix_strided`] for more details.
+    #[track_caller]
+    #[inline]
+    pub fn submatrix_strided(
+        self,
+        row_start: usize,
+        row_step: usize,
+        nrows: usize,
+        col_start: usize,
+        col_step: usize,
+        ncols: usize,
+    ) -> MatRef<'a, E> {
+        self.into_const()
+            .submatrix_strided(row_start, row_step, nrows, col_start, col_step, ncols)
+    }
+
+    /// Returns a mutable view over the submatrix made of the rows
+    /// `row_start, row_start + row_step, ..., row_start + (nrows - 1) * row_step` and the columns
+    /// `col_start, col_start + col_step, ..., col_start + (ncols - 1) * col_step`.
+    ///
+    /// See [`MatRef::submatrix_strided`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let mut matrix = mat![
+    ///     [1.0, 5.0, 9.0],
+    ///     [2.0, 6.0, 10.0],
+    ///     [3.0, 7.0, 11.0],
+    ///     [4.0, 8.0, 12.0f64],
+    /// ];
+    ///
+    /// // zero out the odd rows
+    /// matrix
+    ///     .as_mut()
+    ///     .submatrix_strided_mut(1, 2, 2, 0, 1, 3)
+    ///     .fill_zero();
+    ///
+    /// let expected = mat![
+    ///     [1.0, 5.0, 9.0],
+    ///     [0.0, 0.0, 0.0],
+    ///     [3.0, 7.0, 11.0],
+    ///     [0.0, 0.0, 0.0f64],
+    /// ];
+    /// assert_eq!(expected, matrix);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn submatrix_strided_mut(
+        self,
+        row_start: usize,
+        row_step: usize,
+        nrows: usize,
+        col_start: usize,
+        col_step: usize,
+        ncols: usize,
+    ) -> MatMut<'a, E> {
+        // the steps are nonzero, so the selected elements are pairwise distinct
+        unsafe {
+            self.into_const()
+                .submatrix_strided(row_start, row_step, nrows, col_start, col_step, ncols)
+                .const_cast()
+        }
+    }
+
     /// Returns a view over the submatrix starting at row `row_start`, and with number of rows
     /// `nrows`.
     ///
@@ -1618,6 +1855,57 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         self.rb().to_owned()
     }
 
+    /// Returns the elements of the matrix as interleaved real and imaginary parts,
+    /// `[re0, im0, re1, im1, ...]`, stored in column-major order.
+    #[inline]
+    pub fn to_interleaved_complex_vec(&self) -> alloc::vec::Vec<f64>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField<Real = f64>,
+    {
+        self.rb().to_interleaved_complex_vec()
+    }
+
+    /// Returns the elements of the matrix in a new vector, in column-major order, regardless of
+    /// the strides of the matrix.
+    #[inline]
+    pub fn to_col_major_vec(&self) -> alloc::vec::Vec<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.rb().to_col_major_vec()
+    }
+
+    /// Returns the elements of the matrix in a new vector, in row-major order, regardless of the
+    /// strides of the matrix.
+    #[inline]
+    pub fn to_row_major_vec(&self) -> alloc::vec::Vec<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.rb().to_row_major_vec()
+    }
+
+    /// Returns the columns of the matrix as a vector of vectors, such that the `j`-th inner
+    /// vector contains the elements of the `j`-th column.
+    #[inline]
+    pub fn to_vec_of_cols(&self) -> alloc::vec::Vec<alloc::vec::Vec<E::Canonical>>
+    where
+        E: Conjugate,
+    {
+        self.rb().to_vec_of_cols()
+    }
+
+    /// Returns an owning [`Mat`] containing the band of the matrix with `kl` subdiagonals and `ku`
+    /// superdiagonals. The elements outside the band are set to zero.
+    #[inline]
+    pub fn band(&self, kl: usize, ku: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.rb().band(kl, ku)
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -1636,6 +1924,35 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         self.rb().is_all_finite()
     }
 
+    /// Returns the most specific structure of `self`. See [`MatRef::detect_structure`].
+    pub fn detect_structure(&self) -> MatrixStructure
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().detect_structure()
+    }
+
+    /// Hashes the dimensions and the elements of `self`. See [`MatRef::hash_contents`].
+    pub fn hash_contents<H: core::hash::Hasher>(&self, state: &mut H)
+    where
+        E: Conjugate,
+    {
+        self.rb().hash_contents(state)
+    }
+
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`].
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsMatRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        self.rb().approx_eq(other, rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -1663,6 +1980,59 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         self.rb().norm_l2()
     }
 
+    /// Estimates the spectral norm (the largest singular value) of `self` by power iteration,
+    /// without computing a full singular value decomposition.
+    ///
+    /// Returns the estimate, along with `true` if two successive estimates were within a relative
+    /// distance `tol` of each other before `max_iters` iterations elapsed.
+    ///
+    /// The estimate is a lower bound of the exact value, up to rounding errors. Convergence is
+    /// fast when the two largest singular values are well separated.
+    #[inline]
+    #[track_caller]
+    pub fn spectral_norm(&self, tol: E::Real, max_iters: usize) -> (E::Real, bool)
+    where
+        E: ComplexField,
+    {
+        self.rb().spectral_norm(tol, max_iters)
+    }
+
+    /// Returns the Frobenius norm of `self^H * self - I`, which measures how far the columns of
+    /// `self` are from being orthonormal.
+    ///
+    /// See [`MatRef::orthogonality_error`].
+    #[inline]
+    pub fn orthogonality_error(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        self.rb().orthogonality_error()
+    }
+
+    /// Returns `true` if the columns of the real matrix `self` are orthonormal, up to an error of
+    /// `tol` in the Frobenius norm.
+    ///
+    /// See [`MatRef::is_orthogonal`].
+    #[inline]
+    pub fn is_orthogonal(&self, tol: E::Real) -> bool
+    where
+        E: RealField,
+    {
+        self.rb().is_orthogonal(tol)
+    }
+
+    /// Returns `true` if the columns of `self` are orthonormal, up to an error of `tol` in the
+    /// Frobenius norm.
+    ///
+    /// See [`MatRef::is_unitary`].
+    #[inline]
+    pub fn is_unitary(&self, tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        self.rb().is_unitary(tol)
+    }
+
     /// Returns the squared L2 norm of `self`.
     #[inline]
     pub fn squared_norm_l2(&self) -> E::Real
@@ -1672,7 +2042,86 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         self.rb().squared_norm_l2()
     }
 
+    /// Returns the L2 norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_l2(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        self.rb().col_norms_l2()
+    }
+
+    /// Returns the L2 norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_l2(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        self.rb().row_norms_l2()
+    }
+
+    /// Returns the L1 norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_l1(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        self.rb().col_norms_l1()
+    }
+
+    /// Returns the L1 norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_l1(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        self.rb().row_norms_l1()
+    }
+
+    /// Returns the maximum norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_max(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        self.rb().col_norms_max()
+    }
+
+    /// Returns the maximum norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_max(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        self.rb().row_norms_max()
+    }
+
+    /// Scales each column of `self` by the inverse of its L2 norm, so that the nonzero columns have
+    /// unit norm.
+    ///
+    /// Columns whose norm is zero are left unchanged.
+    #[inline]
+    pub fn normalize_cols_in_place(&mut self)
+    where
+        E: ComplexField,
+    {
+        let norms = self.col_norms_l2();
+        let mut this = self.rb_mut().as_dyn_mut();
+        for j in 0..this.ncols() {
+            let norm = norms.as_dyn().read(j);
+            if norm != E::Real::faer_zero() {
+                let inv = norm.faer_inv();
+                zipped_rw!(this.rb_mut().col_mut(j))
+                    .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(inv)));
+            }
+        }
+    }
+
     /// Returns the sum of `self`.
+    ///
+    /// The order in which the elements are accumulated is unspecified, and depends on the memory
+    /// layout of `self`, so the results for two matrices with the same values but different
+    /// layouts may differ by rounding errors.
     #[inline]
     pub fn sum(&self) -> E
     where
@@ -1681,6 +2130,286 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         self.rb().sum()
     }
 
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// greater than `value`.
+    #[inline]
+    pub fn gt(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.rb().gt(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// less than `value`.
+    #[inline]
+    pub fn lt(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.rb().lt(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// greater than or equal to `value`.
+    #[inline]
+    pub fn ge(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.rb().ge(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// less than or equal to `value`.
+    #[inline]
+    pub fn le(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.rb().le(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// within a distance `tol` of `value`.
+    #[inline]
+    pub fn eq_abs(&self, value: E, tol: E::Real) -> Mask
+    where
+        E: ComplexField,
+    {
+        self.rb().eq_abs(value, tol)
+    }
+
+    /// Returns a new matrix containing the elementwise absolute values of `self`.
+    #[inline]
+    pub fn abs(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.rb().abs()
+    }
+
+    /// Replaces the elements of `self` with their absolute values.
+    #[inline]
+    pub fn abs_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.rb_mut().as_dyn_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_abs()))
+    }
+
+    /// Returns a new matrix containing the elements of `self`, clamped to the interval `[lo, hi]`.
+    ///
+    /// NaN values are left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `lo > hi`.
+    #[inline]
+    #[track_caller]
+    pub fn clamp(&self, lo: E, hi: E) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.rb().clamp(lo, hi)
+    }
+
+    /// Clamps the elements of `self` to the interval `[lo, hi]`.
+    ///
+    /// NaN values are left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `lo > hi`.
+    #[inline]
+    #[track_caller]
+    pub fn clamp_in_place(&mut self, lo: E, hi: E)
+    where
+        E: RealField,
+    {
+        assert!(lo <= hi);
+        zipped_rw!(self.rb_mut().as_dyn_mut()).for_each(|unzipped!(mut x)| {
+            let value = x.read();
+            x.write(if value < lo {
+                lo
+            } else if value > hi {
+                hi
+            } else {
+                value
+            })
+        })
+    }
+
+    /// Returns a new matrix containing the elementwise square roots of `self`.
+    #[inline]
+    pub fn sqrt(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.rb().sqrt()
+    }
+
+    /// Replaces the elements of `self` with their square roots.
+    #[inline]
+    pub fn sqrt_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.rb_mut().as_dyn_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_sqrt()))
+    }
+
+    /// Returns a new matrix containing the elementwise reciprocals of `self`.
+    #[inline]
+    pub fn recip(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.rb().recip()
+    }
+
+    /// Replaces the elements of `self` with their reciprocals.
+    #[inline]
+    pub fn recip_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.rb_mut().as_dyn_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_inv()))
+    }
+
+    /// Returns a new matrix containing the elementwise exponentials of `self`.
+    #[inline]
+    pub fn exp(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.rb().exp()
+    }
+
+    /// Replaces the elements of `self` with their exponentials.
+    #[inline]
+    pub fn exp_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.rb_mut().as_dyn_mut())
+            .for_each(|unzipped!(mut x)| x.write(crate::utils::math::exp(x.read())))
+    }
+
+    /// Returns a new matrix containing the elementwise natural logarithms of `self`.
+    #[inline]
+    pub fn ln(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.rb().ln()
+    }
+
+    /// Replaces the elements of `self` with their natural logarithms.
+    #[inline]
+    pub fn ln_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.rb_mut().as_dyn_mut())
+            .for_each(|unzipped!(mut x)| x.write(crate::utils::math::ln(x.read())))
+    }
+
+    /// Returns a new matrix containing the rows of `self`, where row `i` is multiplied by `d[i]`.
+    ///
+    /// This is the product `D * self`, where `D` is the diagonal matrix whose diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.nrows() != self.nrows()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_rows(&self, d: ColRef<'_, E>) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().scale_rows(d)
+    }
+
+    /// Returns a new matrix containing the columns of `self`, where column `j` is multiplied by
+    /// `d[j]`.
+    ///
+    /// This is the product `self * D`, where `D` is the diagonal matrix whose diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.ncols() != self.ncols()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_cols(&self, d: RowRef<'_, E>) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().scale_cols(d)
+    }
+
+    /// Returns a new matrix containing the lower triangular part of `self`, with the elements
+    /// above it set to zero.
+    ///
+    /// See [`MatRef::lower_triangle`].
+    #[inline]
+    pub fn lower_triangle(&self, diag: zip::Diag) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().lower_triangle(diag)
+    }
+
+    /// Returns a new matrix containing the upper triangular part of `self`, with the elements
+    /// below it set to zero.
+    ///
+    /// See [`MatRef::upper_triangle`].
+    #[inline]
+    pub fn upper_triangle(&self, diag: zip::Diag) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().upper_triangle(diag)
+    }
+
+    /// Multiplies row `i` of `self` by `d[i]`, for each `i`.
+    ///
+    /// This replaces `self` with the product `D * self`, where `D` is the diagonal matrix whose
+    /// diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.nrows() != self.nrows()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_rows_in_place(&mut self, d: ColRef<'_, E>)
+    where
+        E: ComplexField,
+    {
+        let this = self.rb_mut().as_dyn_mut();
+        assert!(d.nrows() == this.nrows());
+        let ncols = this.ncols();
+        zipped_rw!(this, crate::mat::from_repeated_col(d, ncols))
+            .for_each(|unzipped!(mut x, d)| x.write(d.read().faer_mul(x.read())))
+    }
+
+    /// Multiplies column `j` of `self` by `d[j]`, for each `j`.
+    ///
+    /// This replaces `self` with the product `self * D`, where `D` is the diagonal matrix whose
+    /// diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.ncols() != self.ncols()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_cols_in_place(&mut self, d: RowRef<'_, E>)
+    where
+        E: ComplexField,
+    {
+        let this = self.rb_mut().as_dyn_mut();
+        assert!(d.ncols() == this.ncols());
+        let nrows = this.nrows();
+        zipped_rw!(this, crate::mat::from_repeated_row(d, nrows))
+            .for_each(|unzipped!(mut x, d)| x.write(x.read().faer_mul(d.read())))
+    }
+
     /// Kronecker product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -1778,6 +2507,15 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         self.into_const().row_iter()
     }
 
+    /// Returns an iterator over the elements of the matrix along with their `(row, col)` indices.
+    ///
+    /// The elements are visited in column-major order, i.e. `(0, 0)`, `(1, 0)`, ...,
+    /// `(nrows - 1, 0)`, `(0, 1)`, etc.
+    #[inline]
+    pub fn enumerate_elements(self) -> iter::EnumerateElemIter<'a, E> {
+        self.into_const().enumerate_elements()
+    }
+
     /// Returns an iterator over the columns of the matrix.
     #[inline]
     pub fn col_iter_mut(self) -> iter::ColIterMut<'a, E, R> {
@@ -1796,6 +2534,15 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
         }
     }
 
+    /// Returns an iterator over the elements of the matrix along with their `(row, col)` indices.
+    ///
+    /// The elements are visited in column-major order, i.e. `(0, 0)`, `(1, 0)`, ...,
+    /// `(nrows - 1, 0)`, `(0, 1)`, etc.
+    #[inline]
+    pub fn enumerate_elements_mut(self) -> iter::EnumerateElemIterMut<'a, E> {
+        iter::EnumerateElemIterMut::new(self.as_dyn_mut())
+    }
+
     #[doc(hidden)]
     #[inline(always)]
     pub unsafe fn const_cast(self) -> MatMut<'a, E, R, C> {
@@ -2060,6 +2807,60 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
             .map(|x| unsafe { x.const_cast() })
     }
 
+    /// Returns a parallel iterator over the columns of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = ColRef<'a, E, R>> {
+        self.into_const().par_col_iter()
+    }
+
+    /// Returns a parallel iterator over the rows of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = RowRef<'a, E>> {
+        self.into_const().par_row_iter()
+    }
+
+    /// Returns a parallel iterator over the columns of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter_mut(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = ColMut<'a, E, R>> {
+        use rayon::prelude::*;
+        self.into_const()
+            .par_col_iter()
+            .map(|x| unsafe { x.const_cast() })
+    }
+
+    /// Returns a parallel iterator over the rows of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter_mut(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = RowMut<'a, E>> {
+        use rayon::prelude::*;
+        self.into_const()
+            .par_row_iter()
+            .map(|x| unsafe { x.const_cast() })
+    }
+
     /// Given a matrix with a single column, returns an object that interprets
     /// the column as a diagonal matrix, whose diagonal elements are values in the column.
     #[track_caller]
@@ -2078,6 +2879,77 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatMut<'a, E, R, C> {
             inner: self.col_mut(unsafe { Idx::<C>::new_unbound(0) }),
         }
     }
+
+    /// Returns a view over the `k`-th superdiagonal of the matrix, made up of the elements at
+    /// indices `(i, i + k)`. The superdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn superdiagonal(self, k: usize) -> ColRef<'a, E> {
+        self.into_const().superdiagonal(k)
+    }
+
+    /// Returns a mutable view over the `k`-th superdiagonal of the matrix, made up of the elements
+    /// at indices `(i, i + k)`. The superdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn superdiagonal_mut(self, k: usize) -> ColMut<'a, E> {
+        unsafe { self.into_const().superdiagonal(k).const_cast() }
+    }
+
+    /// Returns a view over the `k`-th subdiagonal of the matrix, made up of the elements at
+    /// indices `(i + k, i)`. The subdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.nrows()`.
+    #[track_caller]
+    #[inline]
+    pub fn subdiagonal(self, k: usize) -> ColRef<'a, E> {
+        self.into_const().subdiagonal(k)
+    }
+
+    /// Returns a mutable view over the `k`-th subdiagonal of the matrix, made up of the elements
+    /// at indices `(i + k, i)`. The subdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.nrows()`.
+    #[track_caller]
+    #[inline]
+    pub fn subdiagonal_mut(self, k: usize) -> ColMut<'a, E> {
+        unsafe { self.into_const().subdiagonal(k).const_cast() }
+    }
+}
+
+// stable sort of `col` with the NaN values placed last, where `perm` receives the original
+// indices of the sorted elements
+fn sort_col<E: RealField>(col: ColMut<'_, E>, order: SortOrder, perm: &mut alloc::vec::Vec<usize>) {
+    let mut col = col;
+    let values: alloc::vec::Vec<E> = (0..col.nrows()).map(|i| col.read(i)).collect();
+
+    perm.clear();
+    perm.extend(0..values.len());
+    perm.sort_by(|&i, &j| {
+        let (a, b) = (values[i], values[j]);
+        match (a.faer_is_nan(), b.faer_is_nan()) {
+            (false, false) => {
+                let ord = a.partial_cmp(&b).unwrap_or(core::cmp::Ordering::Equal);
+                match order {
+                    SortOrder::Ascending => ord,
+                    SortOrder::Descending => ord.reverse(),
+                }
+            }
+            (a_nan, b_nan) => a_nan.cmp(&b_nan),
+        }
+    });
+
+    for (i, &k) in perm.iter().enumerate() {
+        col.write(i, values[k]);
+    }
 }
 
 impl<'a, E: Entity, N: Shape> MatMut<'a, E, N, N> {
@@ -2178,6 +3050,10 @@ impl<E: Entity, R: Shape, C: Shape> As2DMut<E> for MatMut<'_, E, R, C> {
 /// the same address (such a thing can be achieved with a zero stride, for example), and no two
 /// matrix units may point to the same address.
 ///
+/// # Panics
+/// The function panics if the offset of some element of the matrix is not representable as an
+/// `isize`, i.e. if `(nrows - 1) * |row_stride| + (ncols - 1) * |col_stride| > isize::MAX`.
+///
 /// # Example
 ///
 /// ```
@@ -2203,6 +3079,12 @@ pub unsafe fn from_raw_parts_mut<'a, E: Entity, R: Shape, C: Shape>(
     row_stride: isize,
     col_stride: isize,
 ) -> MatMut<'a, E, R, C> {
+    crate::utils::assert_strided_offsets_fit(
+        nrows.unbound(),
+        ncols.unbound(),
+        row_stride,
+        col_stride,
+    );
     MatMut::__from_raw_parts(ptr, nrows, ncols, row_stride, col_stride)
 }
 
@@ -2344,6 +3226,38 @@ pub fn from_column_major_slice_mut<E: SimpleEntity, R: Shape, C: Shape>(
     from_column_major_slice_mut_generic(slice, nrows, ncols)
 }
 
+/// Creates a `MatMut` over complex values from a slice of interleaved real and imaginary parts,
+/// `[re0, im0, re1, im1, ...]`, and the matrix dimensions. The complex values are interpreted in a
+/// column-major format.
+///
+/// Since [`c64`] stores its real and imaginary parts contiguously, the returned view aliases the
+/// input data without copying it.
+///
+/// # Panics
+/// The function panics if any of the following conditions are violated:
+/// * `2 * nrows * ncols == data.len()`
+///
+/// # Example
+/// ```
+/// use faer::{complex_native::c64, mat};
+///
+/// let mut data = [1.0, 2.0, 3.0, 4.0_f64];
+/// let mut view = mat::from_interleaved_complex_slice_mut(&mut data, 1, 2);
+/// view.write(0, 1, c64::new(-3.0, -4.0));
+///
+/// assert_eq!(data, [1.0, 2.0, -3.0, -4.0]);
+/// ```
+#[track_caller]
+#[inline]
+pub fn from_interleaved_complex_slice_mut<R: Shape, C: Shape>(
+    data: &mut [f64],
+    nrows: R,
+    ncols: C,
+) -> MatMut<'_, c64, R, C> {
+    assert!(data.len() % 2 == 0);
+    from_column_major_slice_mut(bytemuck::cast_slice_mut::<f64, c64>(data), nrows, ncols)
+}
+
 /// Creates a `MatMut` from slice views over the matrix data, and the matrix dimensions.
 /// The data is interpreted in a row-major format, so that the first chunk of `ncols`
 /// values from the slices goes in the first column of the matrix, the second chunk of `ncols`
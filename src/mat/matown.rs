@@ -3,6 +3,7 @@ use crate::{
     assert, debug_assert,
     diag::{DiagMut, DiagRef},
     iter,
+    linalg::zip::Diag,
     mat::matalloc::{align_for, is_vectorizable, MatUnit, RawMat, RawMatUnit},
     utils::DivCeil,
     Idx, IdxInc, Unbind,
@@ -41,9 +42,22 @@ pub struct Mat<E: Entity, R: Shape = usize, C: Shape = usize> {
     inner: MatOwnImpl<E, R, C>,
     row_capacity: usize,
     col_capacity: usize,
+    align: usize,
     __marker: PhantomData<E>,
 }
 
+/// Returns the row capacity allocated for `nrows` rows, rounded up to a multiple of the SIMD
+/// alignment of the element type when it is vectorizable.
+#[inline]
+fn padded_row_capacity<E: Entity>(nrows: usize) -> usize {
+    if is_vectorizable::<E::Unit>() {
+        let align_factor = align_for::<E::Unit>() / core::mem::size_of::<E::Unit>();
+        nrows.msrv_checked_next_multiple_of(align_factor).unwrap()
+    } else {
+        nrows
+    }
+}
+
 impl<E: Entity, R: Shape, C: Shape> Drop for Mat<E, R, C> {
     #[inline]
     fn drop(&mut self) {
@@ -51,6 +65,7 @@ impl<E: Entity, R: Shape, C: Shape> Drop for Mat<E, R, C> {
             ptr: self.inner.ptr,
             row_capacity: self.row_capacity,
             col_capacity: self.col_capacity,
+            align: self.align,
         });
     }
 }
@@ -79,6 +94,7 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
             },
             row_capacity: this.row_capacity,
             col_capacity: this.col_capacity,
+            align: this.align,
             __marker: PhantomData,
         }
     }
@@ -241,6 +257,7 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
             },
             row_capacity: this.row_capacity,
             col_capacity: this.col_capacity,
+            align: this.align,
             __marker: PhantomData,
         }
     }
@@ -439,6 +456,15 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_ref().row_iter()
     }
 
+    /// Returns an iterator over the elements of the matrix along with their `(row, col)` indices.
+    ///
+    /// The elements are visited in column-major order, i.e. `(0, 0)`, `(1, 0)`, ...,
+    /// `(nrows - 1, 0)`, `(0, 1)`, etc.
+    #[inline]
+    pub fn enumerate_elements(&self) -> iter::EnumerateElemIter<'_, E> {
+        self.as_ref().enumerate_elements()
+    }
+
     /// Returns an iterator over the columns of the matrix.
     #[inline]
     pub fn col_iter_mut(&mut self) -> iter::ColIterMut<'_, E, R> {
@@ -451,6 +477,15 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_mut().row_iter_mut()
     }
 
+    /// Returns an iterator over the elements of the matrix along with their `(row, col)` indices.
+    ///
+    /// The elements are visited in column-major order, i.e. `(0, 0)`, `(1, 0)`, ...,
+    /// `(nrows - 1, 0)`, `(0, 1)`, etc.
+    #[inline]
+    pub fn enumerate_elements_mut(&mut self) -> iter::EnumerateElemIterMut<'_, E> {
+        self.as_mut().enumerate_elements_mut()
+    }
+
     #[doc(hidden)]
     #[inline(always)]
     pub unsafe fn const_cast(&self) -> MatMut<'_, E, R, C> {
@@ -704,6 +739,8 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
     /// Returns references to the element at the given indices, or submatrices if either `row` or
     /// `col` is a range, with bound checks.
     ///
+    /// See [`MatRef::get`] for the accepted index types.
+    ///
     /// # Note
     /// The values pointed to by the references are expected to be initialized, even if the
     /// pointed-to value is not read, otherwise the behavior is undefined.
@@ -752,6 +789,8 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
     /// Returns mutable references to the element at the given indices, or submatrices if either
     /// `row` or `col` is a range, with bound checks.
     ///
+    /// See [`MatRef::get`] for the accepted index types.
+    ///
     /// # Note
     /// The values pointed to by the references are expected to be initialized, even if the
     /// pointed-to value is not read, otherwise the behavior is undefined.
@@ -773,6 +812,20 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_mut().get_mut(row, col)
     }
 
+    /// Returns references to the element at the given indices, or `None` if either index is out
+    /// of bounds.
+    #[inline]
+    pub fn try_get(&self, row: usize, col: usize) -> Option<Ref<'_, E>> {
+        self.as_ref().try_get(row, col)
+    }
+
+    /// Returns mutable references to the element at the given indices, or `None` if either index
+    /// is out of bounds.
+    #[inline]
+    pub fn try_get_mut(&mut self, row: usize, col: usize) -> Option<Mut<'_, E>> {
+        self.as_mut().try_get_mut(row, col)
+    }
+
     /// Reads the value of the element at the given indices.
     ///
     /// # Safety
@@ -939,6 +992,29 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_mut().fill(constant)
     }
 
+    /// Sorts the elements of each column of `self` in the given order.
+    ///
+    /// See [`MatMut::sort_each_col`].
+    #[track_caller]
+    pub fn sort_each_col(&mut self, order: SortOrder)
+    where
+        E: RealField,
+    {
+        self.as_mut().sort_each_col(order)
+    }
+
+    /// Sorts the elements of each column of `self` in the given order, and returns the matrix of
+    /// their original row indices.
+    ///
+    /// See [`MatMut::sort_each_col_with_indices`].
+    #[track_caller]
+    pub fn sort_each_col_with_indices(&mut self, order: SortOrder) -> Mat<usize>
+    where
+        E: RealField,
+    {
+        self.as_mut().sort_each_col_with_indices(order)
+    }
+
     /// Returns a view over the transpose of `self`.
     #[inline]
     #[must_use]
@@ -1252,6 +1328,46 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
             .submatrix_mut(row_start, col_start, nrows, ncols)
     }
 
+    /// Returns a view over the submatrix made of the rows
+    /// `row_start, row_start + row_step, ..., row_start + (nrows - 1) * row_step` and the columns
+    /// `col_start, col_start + col_step, ..., col_start + (ncols - 1) * col_step`.
+    ///
+    /// See [`MatRef::submatrix_strided`] for more details.
+    #[track_caller]
+    #[inline]
+    pub fn submatrix_strided(
+        &self,
+        row_start: usize,
+        row_step: usize,
+        nrows: usize,
+        col_start: usize,
+        col_step: usize,
+        ncols: usize,
+    ) -> MatRef<'_, E> {
+        self.as_ref()
+            .submatrix_strided(row_start, row_step, nrows, col_start, col_step, ncols)
+    }
+
+    /// Returns a mutable view over the submatrix made of the rows
+    /// `row_start, row_start + row_step, ..., row_start + (nrows - 1) * row_step` and the columns
+    /// `col_start, col_start + col_step, ..., col_start + (ncols - 1) * col_step`.
+    ///
+    /// See [`MatRef::submatrix_strided`] for more details.
+    #[track_caller]
+    #[inline]
+    pub fn submatrix_strided_mut(
+        &mut self,
+        row_start: usize,
+        row_step: usize,
+        nrows: usize,
+        col_start: usize,
+        col_step: usize,
+        ncols: usize,
+    ) -> MatMut<'_, E> {
+        self.as_mut()
+            .submatrix_strided_mut(row_start, row_step, nrows, col_start, col_step, ncols)
+    }
+
     /// Returns a view over the submatrix starting at row `row_start`, and with number of rows
     /// `nrows`.
     ///
@@ -1582,6 +1698,50 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_mut().column_vector_as_diagonal_mut()
     }
 
+    /// Returns a view over the `k`-th superdiagonal of the matrix, made up of the elements at
+    /// indices `(i, i + k)`. The superdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn superdiagonal(&self, k: usize) -> ColRef<'_, E> {
+        self.as_ref().superdiagonal(k)
+    }
+
+    /// Returns a mutable view over the `k`-th superdiagonal of the matrix, made up of the elements
+    /// at indices `(i, i + k)`. The superdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn superdiagonal_mut(&mut self, k: usize) -> ColMut<'_, E> {
+        self.as_mut().superdiagonal_mut(k)
+    }
+
+    /// Returns a view over the `k`-th subdiagonal of the matrix, made up of the elements at
+    /// indices `(i + k, i)`. The subdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.nrows()`.
+    #[track_caller]
+    #[inline]
+    pub fn subdiagonal(&self, k: usize) -> ColRef<'_, E> {
+        self.as_ref().subdiagonal(k)
+    }
+
+    /// Returns a mutable view over the `k`-th subdiagonal of the matrix, made up of the elements
+    /// at indices `(i + k, i)`. The subdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.nrows()`.
+    #[track_caller]
+    #[inline]
+    pub fn subdiagonal_mut(&mut self, k: usize) -> ColMut<'_, E> {
+        self.as_mut().subdiagonal_mut(k)
+    }
+
     /// Returns an owning [`Mat`] of the data
     #[inline]
     pub fn to_owned(&self) -> Mat<E::Canonical>
@@ -1591,6 +1751,57 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_ref().to_owned()
     }
 
+    /// Returns the elements of the matrix as interleaved real and imaginary parts,
+    /// `[re0, im0, re1, im1, ...]`, stored in column-major order.
+    #[inline]
+    pub fn to_interleaved_complex_vec(&self) -> alloc::vec::Vec<f64>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField<Real = f64>,
+    {
+        self.as_ref().to_interleaved_complex_vec()
+    }
+
+    /// Returns the elements of the matrix in a new vector, in column-major order, regardless of
+    /// the strides of the matrix.
+    #[inline]
+    pub fn to_col_major_vec(&self) -> alloc::vec::Vec<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().to_col_major_vec()
+    }
+
+    /// Returns the elements of the matrix in a new vector, in row-major order, regardless of the
+    /// strides of the matrix.
+    #[inline]
+    pub fn to_row_major_vec(&self) -> alloc::vec::Vec<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().to_row_major_vec()
+    }
+
+    /// Returns the columns of the matrix as a vector of vectors, such that the `j`-th inner
+    /// vector contains the elements of the `j`-th column.
+    #[inline]
+    pub fn to_vec_of_cols(&self) -> alloc::vec::Vec<alloc::vec::Vec<E::Canonical>>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().to_vec_of_cols()
+    }
+
+    /// Returns an owning [`Mat`] containing the band of the matrix with `kl` subdiagonals and `ku`
+    /// superdiagonals. The elements outside the band are set to zero.
+    #[inline]
+    pub fn band(&self, kl: usize, ku: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().band(kl, ku)
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -1609,6 +1820,35 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_ref().is_all_finite()
     }
 
+    /// Returns the most specific structure of `self`. See [`MatRef::detect_structure`].
+    pub fn detect_structure(&self) -> MatrixStructure
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().detect_structure()
+    }
+
+    /// Hashes the dimensions and the elements of `self`. See [`MatRef::hash_contents`].
+    pub fn hash_contents<H: core::hash::Hasher>(&self, state: &mut H)
+    where
+        E: Conjugate,
+    {
+        self.as_ref().hash_contents(state)
+    }
+
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`].
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsMatRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        self.as_ref().approx_eq(other, rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -1636,6 +1876,59 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_ref().norm_l2()
     }
 
+    /// Estimates the spectral norm (the largest singular value) of `self` by power iteration,
+    /// without computing a full singular value decomposition.
+    ///
+    /// Returns the estimate, along with `true` if two successive estimates were within a relative
+    /// distance `tol` of each other before `max_iters` iterations elapsed.
+    ///
+    /// The estimate is a lower bound of the exact value, up to rounding errors. Convergence is
+    /// fast when the two largest singular values are well separated.
+    #[inline]
+    #[track_caller]
+    pub fn spectral_norm(&self, tol: E::Real, max_iters: usize) -> (E::Real, bool)
+    where
+        E: ComplexField,
+    {
+        self.as_ref().spectral_norm(tol, max_iters)
+    }
+
+    /// Returns the Frobenius norm of `self^H * self - I`, which measures how far the columns of
+    /// `self` are from being orthonormal.
+    ///
+    /// See [`MatRef::orthogonality_error`].
+    #[inline]
+    pub fn orthogonality_error(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        self.as_ref().orthogonality_error()
+    }
+
+    /// Returns `true` if the columns of the real matrix `self` are orthonormal, up to an error of
+    /// `tol` in the Frobenius norm.
+    ///
+    /// See [`MatRef::is_orthogonal`].
+    #[inline]
+    pub fn is_orthogonal(&self, tol: E::Real) -> bool
+    where
+        E: RealField,
+    {
+        self.as_ref().is_orthogonal(tol)
+    }
+
+    /// Returns `true` if the columns of `self` are orthonormal, up to an error of `tol` in the
+    /// Frobenius norm.
+    ///
+    /// See [`MatRef::is_unitary`].
+    #[inline]
+    pub fn is_unitary(&self, tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        self.as_ref().is_unitary(tol)
+    }
+
     /// Returns the squared L2 norm of `self`.
     #[inline]
     pub fn squared_norm_l2(&self) -> E::Real
@@ -1645,7 +1938,77 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_ref().squared_norm_l2()
     }
 
+    /// Returns the L2 norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_l2(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().col_norms_l2()
+    }
+
+    /// Returns the L2 norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_l2(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().row_norms_l2()
+    }
+
+    /// Returns the L1 norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_l1(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().col_norms_l1()
+    }
+
+    /// Returns the L1 norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_l1(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().row_norms_l1()
+    }
+
+    /// Returns the maximum norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_max(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().col_norms_max()
+    }
+
+    /// Returns the maximum norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_max(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().row_norms_max()
+    }
+
+    /// Scales each column of `self` by the inverse of its L2 norm, so that the nonzero columns have
+    /// unit norm.
+    ///
+    /// Columns whose norm is zero are left unchanged.
+    #[inline]
+    pub fn normalize_cols_in_place(&mut self)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().normalize_cols_in_place()
+    }
+
     /// Returns the sum of `self`.
+    ///
+    /// The order in which the elements are accumulated is unspecified, and depends on the memory
+    /// layout of `self`, so the results for two matrices with the same values but different
+    /// layouts may differ by rounding errors.
     #[inline]
     pub fn sum(&self) -> E
     where
@@ -1654,6 +2017,263 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_ref().sum()
     }
 
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// greater than `value`.
+    #[inline]
+    pub fn gt(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.as_ref().gt(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// less than `value`.
+    #[inline]
+    pub fn lt(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.as_ref().lt(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// greater than or equal to `value`.
+    #[inline]
+    pub fn ge(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.as_ref().ge(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// less than or equal to `value`.
+    #[inline]
+    pub fn le(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        self.as_ref().le(value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// within a distance `tol` of `value`.
+    #[inline]
+    pub fn eq_abs(&self, value: E, tol: E::Real) -> Mask
+    where
+        E: ComplexField,
+    {
+        self.as_ref().eq_abs(value, tol)
+    }
+
+    /// Returns a new matrix containing the elementwise absolute values of `self`.
+    #[inline]
+    pub fn abs(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().abs()
+    }
+
+    /// Replaces the elements of `self` with their absolute values.
+    #[inline]
+    pub fn abs_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        self.as_mut().abs_in_place()
+    }
+
+    /// Returns a new matrix containing the elements of `self`, clamped to the interval `[lo, hi]`.
+    ///
+    /// NaN values are left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `lo > hi`.
+    #[inline]
+    #[track_caller]
+    pub fn clamp(&self, lo: E, hi: E) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().clamp(lo, hi)
+    }
+
+    /// Clamps the elements of `self` to the interval `[lo, hi]`.
+    ///
+    /// NaN values are left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `lo > hi`.
+    #[inline]
+    #[track_caller]
+    pub fn clamp_in_place(&mut self, lo: E, hi: E)
+    where
+        E: RealField,
+    {
+        self.as_mut().clamp_in_place(lo, hi)
+    }
+
+    /// Returns a new matrix containing the elementwise square roots of `self`.
+    #[inline]
+    pub fn sqrt(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().sqrt()
+    }
+
+    /// Replaces the elements of `self` with their square roots.
+    #[inline]
+    pub fn sqrt_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        self.as_mut().sqrt_in_place()
+    }
+
+    /// Returns a new matrix containing the elementwise reciprocals of `self`.
+    #[inline]
+    pub fn recip(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().recip()
+    }
+
+    /// Replaces the elements of `self` with their reciprocals.
+    #[inline]
+    pub fn recip_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        self.as_mut().recip_in_place()
+    }
+
+    /// Returns a new matrix containing the elementwise exponentials of `self`.
+    #[inline]
+    pub fn exp(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().exp()
+    }
+
+    /// Replaces the elements of `self` with their exponentials.
+    #[inline]
+    pub fn exp_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        self.as_mut().exp_in_place()
+    }
+
+    /// Returns a new matrix containing the elementwise natural logarithms of `self`.
+    #[inline]
+    pub fn ln(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().ln()
+    }
+
+    /// Replaces the elements of `self` with their natural logarithms.
+    #[inline]
+    pub fn ln_in_place(&mut self)
+    where
+        E: RealField,
+    {
+        self.as_mut().ln_in_place()
+    }
+
+    /// Returns a new matrix containing the rows of `self`, where row `i` is multiplied by `d[i]`.
+    ///
+    /// This is the product `D * self`, where `D` is the diagonal matrix whose diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.nrows() != self.nrows()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_rows(&self, d: ColRef<'_, E>) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().scale_rows(d)
+    }
+
+    /// Returns a new matrix containing the columns of `self`, where column `j` is multiplied by
+    /// `d[j]`.
+    ///
+    /// This is the product `self * D`, where `D` is the diagonal matrix whose diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.ncols() != self.ncols()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_cols(&self, d: RowRef<'_, E>) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().scale_cols(d)
+    }
+
+    /// Returns a new matrix containing the lower triangular part of `self`, with the elements
+    /// above it set to zero.
+    ///
+    /// See [`MatRef::lower_triangle`].
+    #[inline]
+    pub fn lower_triangle(&self, diag: Diag) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().lower_triangle(diag)
+    }
+
+    /// Returns a new matrix containing the upper triangular part of `self`, with the elements
+    /// below it set to zero.
+    ///
+    /// See [`MatRef::upper_triangle`].
+    #[inline]
+    pub fn upper_triangle(&self, diag: Diag) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().upper_triangle(diag)
+    }
+
+    /// Multiplies row `i` of `self` by `d[i]`, for each `i`.
+    ///
+    /// This replaces `self` with the product `D * self`, where `D` is the diagonal matrix whose
+    /// diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.nrows() != self.nrows()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_rows_in_place(&mut self, d: ColRef<'_, E>)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().scale_rows_in_place(d)
+    }
+
+    /// Multiplies column `j` of `self` by `d[j]`, for each `j`.
+    ///
+    /// This replaces `self` with the product `self * D`, where `D` is the diagonal matrix whose
+    /// diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.ncols() != self.ncols()`.
+    #[inline]
+    #[track_caller]
+    pub fn scale_cols_in_place(&mut self, d: RowRef<'_, E>)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().scale_cols_in_place(d)
+    }
+
     /// Kronecker product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -1887,6 +2507,54 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         self.as_mut().par_row_partition_mut(count)
     }
 
+    /// Returns a parallel iterator over the columns of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter(
+        &self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = ColRef<'_, E, R>> {
+        self.as_ref().par_col_iter()
+    }
+
+    /// Returns a parallel iterator over the rows of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter(
+        &self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = RowRef<'_, E>> {
+        self.as_ref().par_row_iter()
+    }
+
+    /// Returns a parallel iterator over the columns of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter_mut(
+        &mut self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = ColMut<'_, E, R>> {
+        self.as_mut().par_col_iter_mut()
+    }
+
+    /// Returns a parallel iterator over the rows of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter_mut(
+        &mut self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = RowMut<'_, E>> {
+        self.as_mut().par_row_iter_mut()
+    }
+
     #[track_caller]
     #[inline(always)]
     #[doc(hidden)]
@@ -1975,6 +2643,14 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
     /// Resizes the matrix in-place so that the new dimensions are `(new_nrows, new_ncols)`.
     /// New elements are created with the given function `f`, so that elements at indices `(i, j)`
     /// are created by calling `f(i, j)`.
+    ///
+    /// The elements that are within both the old and the new dimensions are retained at the same
+    /// indices, and `f` is never called for them. This also holds when the number of rows exceeds
+    /// the row capacity, in which case the existing columns are relocated to the new column
+    /// stride.
+    ///
+    /// The matrix is reallocated only if the new dimensions exceed the current capacity, with the
+    /// same strategy as [`Self::reserve_exact_reporting`].
     pub fn resize_with(&mut self, new_nrows: R, new_ncols: C, f: impl FnMut(Idx<R>, Idx<C>) -> E) {
         let mut f = f;
         let old_nrows = self.nrows();
@@ -2006,32 +2682,110 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         }
     }
 
+    /// Resizes the matrix in-place so that the new dimensions are `(new_nrows, new_ncols)`, like
+    /// [`Self::resize_with`], then releases the excess capacity if the matrix ends up using less
+    /// than a quarter of it.
+    ///
+    /// The usage is measured as the capacity that [`Self::reserve_exact`] would allocate for the
+    /// new dimensions, i.e. `new_nrows` rounded up to a multiple of the SIMD alignment, times
+    /// `new_ncols`, against `row_capacity * col_capacity`. When it falls below a quarter, the
+    /// matrix is reallocated with that capacity, keeping its alignment. After the call, the
+    /// capacity is therefore at most `4` times the one needed for the new dimensions, and each
+    /// shrinking reallocation copies at most a quarter of the previous capacity. Since growing
+    /// never over-allocates, growing the matrix again after it was shrunk may reallocate it.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::Mat;
+    ///
+    /// let mut m = Mat::<f64>::zeros(1000, 1000);
+    /// m.resize_with_shrink(10, 10, |_, _| 0.0);
+    /// assert!(m.row_capacity() * m.col_capacity() < 1000 * 1000 / 4);
+    /// ```
+    pub fn resize_with_shrink(
+        &mut self,
+        new_nrows: R,
+        new_ncols: C,
+        f: impl FnMut(Idx<R>, Idx<C>) -> E,
+    ) {
+        self.resize_with(new_nrows, new_ncols, f);
+
+        let used = padded_row_capacity::<E>(self.nrows().unbound()) * self.ncols().unbound();
+        let capacity = self.row_capacity().saturating_mul(self.col_capacity());
+        if used.saturating_mul(4) < capacity {
+            self.shrink_capacity();
+        }
+    }
+
+    /// Reallocates the matrix with just enough capacity for its current dimensions, with the
+    /// same row padding as [`Self::reserve_exact`].
+    #[cold]
+    fn shrink_capacity(&mut self) {
+        let (nrows, ncols) = (self.nrows().unbound(), self.ncols().unbound());
+        if size_of::<E::Unit>() == 0 {
+            self.row_capacity = nrows;
+            self.col_capacity = ncols;
+            return;
+        }
+
+        let this = self.as_dyn();
+        let mut new =
+            Mat::<E>::with_capacity_aligned(padded_row_capacity::<E>(nrows), ncols, self.align);
+        new.resize_with(nrows, ncols, |i, j| unsafe {
+            E::faer_from_units(E::faer_deref(this.at_unchecked(i, j)))
+        });
+
+        // `new` takes ownership of the old allocation, and frees it when dropped
+        core::mem::swap(&mut self.inner.ptr, &mut new.inner.ptr);
+        core::mem::swap(&mut self.row_capacity, &mut new.row_capacity);
+        core::mem::swap(&mut self.col_capacity, &mut new.col_capacity);
+        core::mem::swap(&mut self.align, &mut new.align);
+    }
+
     /// Reserves the minimum capacity for `row_capacity` rows and `col_capacity`
     /// columns without reallocating. Does nothing if the capacity is already sufficient.
     ///
+    /// See [`Self::reserve_exact_reporting`] for the growth strategy.
+    ///
     /// # Panics
     /// The function panics if the new total capacity in bytes exceeds `isize::MAX`.
     #[inline]
     pub fn reserve_exact(&mut self, row_capacity: usize, col_capacity: usize) {
+        self.reserve_exact_reporting(row_capacity, col_capacity);
+    }
+
+    /// Reserves the minimum capacity for `row_capacity` rows and `col_capacity`
+    /// columns without reallocating. Does nothing if the capacity is already sufficient.
+    ///
+    /// Returns `true` if the matrix was reallocated, in which case its elements were moved to the
+    /// new allocation, or `false` if the existing capacity was reused.
+    ///
+    /// No amortized over-allocation is performed: the row capacity grows to `row_capacity`, rounded
+    /// up to a multiple of the SIMD alignment of the element type, and the column capacity grows to
+    /// exactly `col_capacity`. A matrix that grows by small increments therefore reallocates on
+    /// every step, unless enough capacity was reserved beforehand.
+    ///
+    /// # Panics
+    /// The function panics if the new total capacity in bytes exceeds `isize::MAX`.
+    #[inline]
+    pub fn reserve_exact_reporting(&mut self, row_capacity: usize, col_capacity: usize) -> bool {
         #[cold]
         fn do_reserve_exact<E: Entity>(
             self_: &mut Mat<E>,
-            mut new_row_capacity: usize,
+            new_row_capacity: usize,
             new_col_capacity: usize,
         ) {
-            if is_vectorizable::<E::Unit>() {
-                let align_factor = align_for::<E::Unit>() / core::mem::size_of::<E::Unit>();
-                new_row_capacity = new_row_capacity
-                    .msrv_checked_next_multiple_of(align_factor)
-                    .unwrap();
-            }
-            let new_row_capacity = Ord::max(new_row_capacity, self_.inner.nrows);
+            let new_row_capacity = Ord::max(
+                padded_row_capacity::<E>(new_row_capacity),
+                self_.inner.nrows,
+            );
             let new_col_capacity = Ord::max(new_col_capacity, self_.inner.ncols);
 
             let nrows = self_.inner.nrows;
             let ncols = self_.inner.ncols;
             let old_row_capacity = self_.row_capacity;
             let old_col_capacity = self_.col_capacity;
+            let align = self_.align;
 
             let mut this = ManuallyDrop::new(core::mem::take(self_));
             {
@@ -2040,6 +2794,7 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
                         ptr,
                         row_capacity: old_row_capacity,
                         col_capacity: old_col_capacity,
+                        align,
                     },
                     nrows,
                     ncols,
@@ -2063,10 +2818,11 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
         }
 
         if self.row_capacity() >= row_capacity && self.col_capacity() >= col_capacity {
-            // do nothing
+            false
         } else if size_of::<E::Unit>() == 0 {
             self.row_capacity = self.row_capacity().max(row_capacity);
             self.col_capacity = self.col_capacity().max(col_capacity);
+            false
         } else {
             let mut tmp = ManuallyDrop::new(Mat::<E> {
                 inner: MatOwnImpl {
@@ -2076,6 +2832,7 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
                 },
                 row_capacity: self.row_capacity,
                 col_capacity: self.col_capacity,
+                align: self.align,
                 __marker: PhantomData,
             });
 
@@ -2092,6 +2849,7 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
             self.row_capacity = tmp.row_capacity;
             self.col_capacity = tmp.col_capacity;
             self.inner.ptr = tmp.inner.ptr;
+            true
         }
     }
 
@@ -2110,6 +2868,22 @@ impl<E: Entity, R: Shape, C: Shape> Mat<E, R, C> {
 }
 
 impl<E: Entity, N: Shape> Mat<E, N, N> {
+    /// Returns a new square matrix, filled with zeros, except the main diagonal which is filled
+    /// with the elements of `diag`.
+    ///
+    /// # Panics
+    /// The function panics if the total capacity in bytes exceeds `isize::MAX`.
+    #[inline]
+    pub fn from_diagonal<ViewE: Conjugate<Canonical = E>>(diag: ColRef<'_, ViewE, N>) -> Self
+    where
+        E: ComplexField,
+    {
+        let n = diag.nrows();
+        let mut matrix = Self::zeros(n, n);
+        matrix.diagonal_mut().column_vector_mut().copy_from(diag);
+        matrix
+    }
+
     /// Returns a view over the diagonal of the matrix.
     #[inline]
     pub fn diagonal(&self) -> DiagRef<'_, E, N> {
@@ -2135,6 +2909,7 @@ impl<E: Entity> Mat<E> {
             },
             row_capacity: 0,
             col_capacity: 0,
+            align: align_for::<E::Unit>(),
             __marker: PhantomData,
         }
     }
@@ -2156,6 +2931,54 @@ impl<E: Entity> Mat<E> {
             },
             row_capacity: raw.row_capacity,
             col_capacity: raw.col_capacity,
+            align: raw.align,
+            __marker: PhantomData,
+        }
+    }
+
+    /// Returns a new matrix with dimensions `(0, 0)`, with enough capacity to hold a maximum of
+    /// `row_capacity` rows and `col_capacity` columns without reallocating, whose storage is
+    /// aligned to at least `align` bytes.
+    ///
+    /// The alignment is kept when the matrix is later reallocated, e.g., by
+    /// [`Self::reserve_exact`] or [`Self::resize_with`]. It only applies to the start of the
+    /// storage: the column stride is chosen as usual, so the following columns are only aligned
+    /// if `align` divides the column stride in bytes. Clones of the matrix use the default
+    /// alignment.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `align` is a power of two.
+    /// * `align >= core::mem::align_of::<E::Unit>()`.
+    /// * The total capacity in bytes does not exceed `isize::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::Mat;
+    ///
+    /// let mut m = Mat::<f64>::with_capacity_aligned(8, 8, 4096);
+    /// m.resize_with(8, 8, |i, j| (i + j) as f64);
+    /// assert!(m.as_ptr() as usize % 4096 == 0);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn with_capacity_aligned(row_capacity: usize, col_capacity: usize, align: usize) -> Self {
+        assert!(all(
+            align.is_power_of_two(),
+            align >= core::mem::align_of::<E::Unit>(),
+        ));
+        // never go below the default alignment, which the simd kernels may rely on
+        let align = Ord::max(align, align_for::<E::Unit>());
+        let raw = ManuallyDrop::new(RawMat::<E>::new_aligned(row_capacity, col_capacity, align));
+        Self {
+            inner: MatOwnImpl {
+                ptr: raw.ptr,
+                nrows: 0,
+                ncols: 0,
+            },
+            row_capacity: raw.row_capacity,
+            col_capacity: raw.col_capacity,
+            align: raw.align,
             __marker: PhantomData,
         }
     }
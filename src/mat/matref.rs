@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
-    assert, debug_assert, diag::DiagRef, iter, iter::chunks::ChunkPolicy, unzipped,
-    utils::bound::*, zipped_rw, Idx, IdxInc, Shape, Unbind,
+    assert, debug_assert, diag::DiagRef, iter, iter::chunks::ChunkPolicy, linalg::zip::Diag,
+    unzipped, utils::bound::*, zipped_rw, Idx, IdxInc, Shape, Unbind,
 };
 use core::ops::Range;
 use generativity::make_guard;
@@ -73,6 +73,13 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         row_stride: isize,
         col_stride: isize,
     ) -> Self {
+        #[cfg(debug_assertions)]
+        crate::utils::assert_strided_offsets_fit(
+            nrows.unbound(),
+            ncols.unbound(),
+            row_stride,
+            col_stride,
+        );
         Self {
             inner: MatImpl {
                 ptr: into_copy::<E, _>(map!(E, ptr, |(ptr)| {
@@ -95,6 +102,38 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         },)
     }
 
+    /// Returns the input matrix, after checking that its data pointers are aligned to `align`
+    /// bytes.
+    ///
+    /// Only the pointers to the first element are checked. This is meant to validate views that
+    /// are about to be passed to kernels with alignment requirements, e.g., views over
+    /// [`Mat::with_capacity_aligned`] storage or over user-provided buffers.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `align` is a power of two.
+    /// * The data pointers of `self` are multiples of `align`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::Mat;
+    ///
+    /// let mut m = Mat::<f64>::with_capacity_aligned(4, 4, 64);
+    /// m.resize_with(4, 4, |i, j| (i + j) as f64);
+    ///
+    /// let view = m.as_ref().assert_aligned(64);
+    /// assert_eq!(view, m);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn assert_aligned(self, align: usize) -> Self {
+        assert!(align.is_power_of_two());
+        let mut aligned = true;
+        E::faer_map(self.as_ptr(), |ptr| aligned &= ptr as usize % align == 0);
+        assert!(aligned);
+        self
+    }
+
     /// Returns the number of rows of the matrix.
     #[inline]
     pub fn nrows(&self) -> R {
@@ -148,6 +187,8 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
     #[doc(hidden)]
     pub unsafe fn overflowing_ptr_at(self, row: IdxInc<R>, col: IdxInc<C>) -> PtrConst<E> {
         unsafe {
+            // the offset is zero whenever one of the indices is past the end. in particular, this
+            // is always the case for empty matrices, whose pointer may be dangling
             let cond = (row != self.nrows()) & (col != self.ncols());
             let offset = (cond as usize).wrapping_neg() as isize
                 & (isize::wrapping_add(
@@ -483,6 +524,19 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         unsafe { map!(E, self.ptr_inbounds_at(row, col), |(ptr)| &*ptr) }
     }
 
+    /// Returns references to the element at the given indices, or `None` if either index is out
+    /// of bounds.
+    ///
+    /// # Note
+    /// The values pointed to by the references are expected to be initialized, even if the
+    /// pointed-to value is not read, otherwise the behavior is undefined.
+    #[inline(always)]
+    pub fn try_get(self, row: usize, col: usize) -> Option<Ref<'a, E>> {
+        let row = self.nrows().idx(row)?;
+        let col = self.ncols().idx(col)?;
+        Some(unsafe { self.at_unchecked(row, col) })
+    }
+
     /// Reads the value of the element at the given indices.
     ///
     /// # Safety
@@ -679,6 +733,88 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         unsafe { self.submatrix_unchecked(row_start, col_start, nrows, ncols) }
     }
 
+    /// Returns a view over the submatrix made of the rows
+    /// `row_start, row_start + row_step, ..., row_start + (nrows - 1) * row_step` and the columns
+    /// `col_start, col_start + col_step, ..., col_start + (ncols - 1) * col_step`.
+    ///
+    /// The view shares the storage of `self`, with its strides multiplied by the steps, so no
+    /// data is copied.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `row_step >= 1` and `col_step >= 1`.
+    /// * `row_start <= self.nrows()` and `col_start <= self.ncols()`.
+    /// * `row_start + (nrows - 1) * row_step < self.nrows()`, if `nrows > 0`.
+    /// * `col_start + (ncols - 1) * col_step < self.ncols()`, if `ncols > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let matrix = mat![
+    ///     [1.0, 5.0, 9.0, 13.0],
+    ///     [2.0, 6.0, 10.0, 14.0],
+    ///     [3.0, 7.0, 11.0, 15.0],
+    ///     [4.0, 8.0, 12.0, 16.0f64],
+    /// ];
+    ///
+    /// let view = matrix.as_ref();
+    /// // every other row and column, starting from the top left corner
+    /// let checkerboard = view.submatrix_strided(0, 2, 2, 0, 2, 2);
+    ///
+    /// let expected = mat![[1.0, 9.0], [3.0, 11.0f64]];
+    /// assert_eq!(expected.as_ref(), checkerboard);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn submatrix_strided(
+        self,
+        row_start: usize,
+        row_step: usize,
+        nrows: usize,
+        col_start: usize,
+        col_step: usize,
+        ncols: usize,
+    ) -> MatRef<'a, E> {
+        #[track_caller]
+        fn check_axis(start: usize, step: usize, len: usize, dim: usize) {
+            assert!(all(step >= 1, start <= dim));
+            if len > 0 {
+                let last = (len - 1)
+                    .checked_mul(step)
+                    .and_then(|offset| offset.checked_add(start));
+                assert!(last.is_some_and(|last| last < dim));
+            }
+        }
+
+        let this = self.as_dyn();
+        check_axis(row_start, row_step, nrows, this.nrows());
+        check_axis(col_start, col_step, ncols, this.ncols());
+
+        // the last index is in bounds whenever the length exceeds one, so the new strides can't
+        // overflow
+        let row_stride = if nrows > 1 {
+            this.row_stride() * row_step as isize
+        } else {
+            this.row_stride()
+        };
+        let col_stride = if ncols > 1 {
+            this.col_stride() * col_step as isize
+        } else {
+            this.col_stride()
+        };
+
+        unsafe {
+            MatRef::__from_raw_parts(
+                this.overflowing_ptr_at(row_start, col_start),
+                nrows,
+                ncols,
+                row_stride,
+                col_stride,
+            )
+        }
+    }
+
     /// Returns a view over the submatrix starting at row `row_start`, and with number of rows
     /// `nrows`.
     ///
@@ -906,6 +1042,102 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         mat
     }
 
+    /// Returns an owning [`Mat`] containing the band of the matrix with `kl` subdiagonals and `ku`
+    /// superdiagonals. The elements outside the band are set to zero.
+    #[inline]
+    pub fn band(&self, kl: usize, ku: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let this = self.as_dyn();
+        let (m, n) = this.shape();
+        let mut band = Mat::<E::Canonical>::zeros(m, n);
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..Ord::min(m, j.saturating_add(kl).saturating_add(1)) {
+                band.write(i, j, this.read(i, j).canonicalize());
+            }
+        }
+        band
+    }
+
+    /// Returns the elements of the matrix as interleaved real and imaginary parts,
+    /// `[re0, im0, re1, im1, ...]`, stored in column-major order.
+    ///
+    /// This is the inverse of [`from_interleaved_complex_slice`].
+    #[inline]
+    pub fn to_interleaved_complex_vec(&self) -> alloc::vec::Vec<f64>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField<Real = f64>,
+    {
+        let this = self.as_dyn();
+        let (m, n) = this.shape();
+        let mut data = alloc::vec::Vec::with_capacity(2 * m * n);
+        for j in 0..n {
+            for i in 0..m {
+                let z = this.read(i, j).canonicalize();
+                data.push(z.faer_real());
+                data.push(z.faer_imag());
+            }
+        }
+        data
+    }
+
+    /// Returns the elements of the matrix in a new vector, in column-major order, regardless of
+    /// the strides of the matrix.
+    ///
+    /// This is the inverse of [`from_column_major_slice`].
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    /// assert!(a.as_ref().to_col_major_vec() == [1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    /// assert!(a.as_ref().to_row_major_vec() == [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert!(a.as_ref().to_vec_of_cols() == [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+    /// ```
+    #[inline]
+    pub fn to_col_major_vec(&self) -> alloc::vec::Vec<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let this = self.as_dyn();
+        let (m, n) = this.shape();
+        let mut data = alloc::vec::Vec::with_capacity(m * n);
+        for j in 0..n {
+            for i in 0..m {
+                data.push(this.read(i, j).canonicalize());
+            }
+        }
+        data
+    }
+
+    /// Returns the elements of the matrix in a new vector, in row-major order, regardless of the
+    /// strides of the matrix.
+    ///
+    /// This is the inverse of [`from_row_major_slice`].
+    #[inline]
+    pub fn to_row_major_vec(&self) -> alloc::vec::Vec<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.transpose().to_col_major_vec()
+    }
+
+    /// Returns the columns of the matrix as a vector of vectors, such that the `j`-th inner
+    /// vector contains the elements of the `j`-th column.
+    #[inline]
+    pub fn to_vec_of_cols(&self) -> alloc::vec::Vec<alloc::vec::Vec<E::Canonical>>
+    where
+        E: Conjugate,
+    {
+        let this = self.as_dyn();
+        (0..this.ncols())
+            .map(|j| this.subcols(j, 1).to_col_major_vec())
+            .collect()
+    }
+
     #[doc(hidden)]
     #[inline(always)]
     pub unsafe fn const_cast(self) -> MatMut<'a, E, R, C> {
@@ -991,6 +1223,12 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
     /// Returns references to the element at the given indices, or submatrices if either `row`
     /// or `col` is a range, with bound checks.
     ///
+    /// This is the equivalent of NumPy-style slicing, e.g. `a[1:3, :2]` is written as
+    /// `a.get(1..3, ..2)`. Any combination of `usize`, `Range`, `RangeInclusive`, `RangeFrom`,
+    /// `RangeTo`, `RangeToInclusive` and `RangeFull` is accepted. The [`Index`](core::ops::Index)
+    /// trait can't be used for slicing, since it must return a reference, while submatrices are
+    /// views returned by value.
+    ///
     /// # Note
     /// The values pointed to by the references are expected to be initialized, even if the
     /// pointed-to value is not read, otherwise the behavior is undefined.
@@ -999,6 +1237,18 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
     /// The function panics if any of the following conditions are violated:
     /// * `row` must be contained in `[0, self.nrows())`.
     /// * `col` must be contained in `[0, self.ncols())`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    /// let a = a.as_ref();
+    ///
+    /// assert!(a.get(1..3, ..2) == mat![[4.0, 5.0], [7.0, 8.0]]);
+    /// assert!(a.get(.., 2) == faer::col![3.0, 6.0, 9.0]);
+    /// assert!(a.get(0, 1) == &2.0);
+    /// ```
     #[inline(always)]
     #[track_caller]
     pub fn get<RowRange, ColRange>(
@@ -1012,6 +1262,25 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         <Self as MatIndex<RowRange, ColRange>>::get(self, row, col)
     }
 
+    /// Returns a new matrix whose elements are computed by applying the vectorized kernel `f` to
+    /// the elements of `self`.
+    ///
+    /// See [`SimdMap`](crate::utils::simd::SimdMap) for the requirements on the kernel.
+    #[inline]
+    pub fn map_simd(&self, f: impl crate::utils::simd::SimdMap<E>) -> Mat<E, R, C>
+    where
+        E: ComplexField,
+    {
+        let mut f = f;
+        let mut out = Mat::<E, R, C>::zeros(self.nrows(), self.ncols());
+        let src = self.as_dyn();
+        let mut dst = out.as_dyn_mut();
+        for j in 0..src.ncols() {
+            crate::utils::simd::map_simd_col(dst.rb_mut().col_mut(j), src.col(j), &mut f);
+        }
+        out
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -1038,6 +1307,134 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         all_finite
     }
 
+    /// Returns the most specific structure of `self`, by checking which elements are exactly zero
+    /// or one.
+    ///
+    /// The scan stops as soon as the matrix is known to be [`MatrixStructure::General`], which is
+    /// usually after a few columns for dense matrices.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, mat::MatrixStructure};
+    ///
+    /// let a = mat![[1.0, 0.0], [2.0, 3.0]];
+    /// assert!(a.detect_structure() == MatrixStructure::LowerTriangular);
+    /// assert!(a.transpose().detect_structure() == MatrixStructure::UpperTriangular);
+    /// ```
+    pub fn detect_structure(&self) -> MatrixStructure
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        let this = self.as_dyn();
+        let (m, n) = this.shape();
+        let zero = E::Canonical::faer_zero();
+        let one = E::Canonical::faer_one();
+
+        let mut is_lower = true;
+        let mut is_upper = true;
+        let mut is_unit = m == n;
+        for j in 0..n {
+            for i in 0..m {
+                let x = this.read(i, j).canonicalize();
+                if i < j {
+                    is_lower &= x == zero;
+                } else if i > j {
+                    is_upper &= x == zero;
+                } else {
+                    is_unit &= x == one;
+                }
+            }
+            if !is_lower && !is_upper {
+                return MatrixStructure::General;
+            }
+        }
+
+        match (is_lower, is_upper) {
+            (true, true) if is_unit => MatrixStructure::Identity,
+            (true, true) => MatrixStructure::Diagonal,
+            (true, false) => MatrixStructure::LowerTriangular,
+            (false, true) => MatrixStructure::UpperTriangular,
+            (false, false) => MatrixStructure::General,
+        }
+    }
+
+    /// Feeds the dimensions of `self` into `state`, followed by the bytes of its elements in
+    /// column-major order, after resolving a pending conjugation.
+    ///
+    /// The result only depends on the logical contents of the matrix, and not on its strides or on
+    /// whether it is a conjugated view, so that a view and its copy hash identically.
+    ///
+    /// # Note
+    /// The elements are hashed bitwise, which is stricter than `==` for floating point values:
+    /// `0.0` and `-0.0` compare equal but hash differently, while two NaNs hash equally if and
+    /// only if their bit patterns are identical, even though they never compare equal.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    /// use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+    /// let b = a.transpose().to_owned();
+    ///
+    /// let hash = |m: faer::MatRef<'_, f64>| {
+    ///     let mut state = DefaultHasher::new();
+    ///     m.hash_contents(&mut state);
+    ///     state.finish()
+    /// };
+    ///
+    /// assert_eq!(hash(a.as_ref()), hash(b.transpose()));
+    /// ```
+    pub fn hash_contents<H: core::hash::Hasher>(&self, state: &mut H)
+    where
+        E: Conjugate,
+    {
+        let this = self.as_dyn();
+        let (m, n) = this.shape();
+        state.write_usize(m);
+        state.write_usize(n);
+        for j in 0..n {
+            for i in 0..m {
+                state.write(bytemuck::bytes_of(&this.read(i, j).canonicalize()));
+            }
+        }
+    }
+
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// Returns `false` without comparing the elements if the dimensions differ. Elements that are
+    /// NaN are never approximately equal to anything.
+    ///
+    /// See also [`assert_matrix_approx_eq!`](crate::assert_matrix_approx_eq), which reports the
+    /// first mismatching element on failure.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+    /// let b = mat![[1.0, 2.0], [3.0, 4.0 + 1e-12]];
+    ///
+    /// assert!(a.approx_eq(&b, 1e-10, 0.0));
+    /// assert!(!a.approx_eq(&b, 0.0, 0.0));
+    /// assert!(!a.approx_eq(b.transpose(), 1e-10, 0.0));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsMatRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        let this = self.as_dyn();
+        let other = other.as_mat_ref();
+        let other = other.as_dyn();
+        this.shape() == other.shape()
+            && approx_eq_mismatches(this, other, rel_tol, abs_tol)
+                .next()
+                .is_none()
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -1065,6 +1462,95 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         crate::linalg::reductions::norm_l2::norm_l2(self.as_dyn())
     }
 
+    /// Estimates the spectral norm (the largest singular value) of `self` by power iteration,
+    /// without computing a full singular value decomposition.
+    ///
+    /// Returns the estimate, along with `true` if two successive estimates were within a relative
+    /// distance `tol` of each other before `max_iters` iterations elapsed.
+    ///
+    /// The estimate is a lower bound of the exact value, up to rounding errors. Convergence is
+    /// fast when the two largest singular values are well separated.
+    #[inline]
+    #[track_caller]
+    pub fn spectral_norm(&self, tol: E::Real, max_iters: usize) -> (E::Real, bool)
+    where
+        E: ComplexField,
+    {
+        crate::linalg::reductions::spectral_norm::spectral_norm(
+            self.as_dyn(),
+            tol,
+            max_iters,
+            crate::get_global_parallelism(),
+        )
+    }
+
+    /// Returns the Frobenius norm of `self^H * self - I`, which measures how far the columns of
+    /// `self` are from being orthonormal.
+    ///
+    /// The inner products of the columns are computed pairwise, without forming `self^H * self`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let s = 1.0 / 2.0f64.sqrt();
+    /// let q = mat![[s, s], [s, -s], [0.0, 0.0]];
+    /// assert!(q.orthogonality_error() < 1e-15);
+    /// assert!(q.is_orthogonal(1e-12));
+    ///
+    /// let a = mat![[1.0, 1.0], [0.0, 1.0f64]];
+    /// assert!(!a.is_orthogonal(1e-12));
+    /// ```
+    #[inline]
+    pub fn orthogonality_error(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        let this = self.as_dyn();
+        let mut err = E::Real::faer_zero();
+        for j in 0..this.ncols() {
+            for i in 0..=j {
+                let mut g = crate::linalg::matmul::inner_prod::inner_prod_with_conj(
+                    this.col(i),
+                    crate::Conj::Yes,
+                    this.col(j),
+                    crate::Conj::No,
+                );
+                if i == j {
+                    g = g.faer_sub(E::faer_one());
+                }
+                let g = g.faer_abs2();
+                // the off-diagonal terms appear twice in the full product
+                err = err.faer_add(if i == j { g } else { g.faer_add(g) });
+            }
+        }
+        err.faer_sqrt()
+    }
+
+    /// Returns `true` if the columns of the real matrix `self` are orthonormal, i.e., if
+    /// `self^T * self` is the identity, up to an error of `tol` in the Frobenius norm.
+    ///
+    /// See [`Self::orthogonality_error`].
+    #[inline]
+    pub fn is_orthogonal(&self, tol: E::Real) -> bool
+    where
+        E: RealField,
+    {
+        self.orthogonality_error() <= tol
+    }
+
+    /// Returns `true` if the columns of `self` are orthonormal, i.e., if `self^H * self` is the
+    /// identity, up to an error of `tol` in the Frobenius norm.
+    ///
+    /// See [`Self::orthogonality_error`].
+    #[inline]
+    pub fn is_unitary(&self, tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        self.orthogonality_error() <= tol
+    }
+
     /// Returns the squared L2 norm of `self`.
     #[inline]
     pub fn squared_norm_l2(&self) -> E::Real
@@ -1075,7 +1561,77 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         norm.faer_mul(norm)
     }
 
+    /// Returns the L2 norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_l2(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        let mut out = Row::<E::Real, C>::zeros(self.ncols());
+        crate::linalg::reductions::norm_l2::col_norms_l2(self.as_dyn(), out.as_dyn_mut());
+        out
+    }
+
+    /// Returns the L2 norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_l2(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        let mut out = Col::<E::Real, R>::zeros(self.nrows());
+        crate::linalg::reductions::norm_l2::row_norms_l2(self.as_dyn(), out.as_dyn_mut());
+        out
+    }
+
+    /// Returns the L1 norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_l1(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        let mut out = Row::<E::Real, C>::zeros(self.ncols());
+        crate::linalg::reductions::norm_l1::col_norms_l1(self.as_dyn(), out.as_dyn_mut());
+        out
+    }
+
+    /// Returns the L1 norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_l1(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        let mut out = Col::<E::Real, R>::zeros(self.nrows());
+        crate::linalg::reductions::norm_l1::row_norms_l1(self.as_dyn(), out.as_dyn_mut());
+        out
+    }
+
+    /// Returns the maximum norm of each column of `self`.
+    #[inline]
+    pub fn col_norms_max(&self) -> Row<E::Real, C>
+    where
+        E: ComplexField,
+    {
+        let mut out = Row::<E::Real, C>::zeros(self.ncols());
+        crate::linalg::reductions::norm_max::col_norms_max(self.as_dyn(), out.as_dyn_mut());
+        out
+    }
+
+    /// Returns the maximum norm of each row of `self`.
+    #[inline]
+    pub fn row_norms_max(&self) -> Col<E::Real, R>
+    where
+        E: ComplexField,
+    {
+        let mut out = Col::<E::Real, R>::zeros(self.nrows());
+        crate::linalg::reductions::norm_max::row_norms_max(self.as_dyn(), out.as_dyn_mut());
+        out
+    }
+
     /// Returns the sum of `self`.
+    ///
+    /// The order in which the elements are accumulated is unspecified, and depends on the memory
+    /// layout of `self`, so the results for two matrices with the same values but different
+    /// layouts may differ by rounding errors.
     #[inline]
     pub fn sum(&self) -> E
     where
@@ -1084,6 +1640,231 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         crate::linalg::reductions::sum::sum(self.as_dyn())
     }
 
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// greater than `value`.
+    #[inline]
+    pub fn gt(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        super::mask::compare(self.as_dyn(), |x| x > value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// less than `value`.
+    #[inline]
+    pub fn lt(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        super::mask::compare(self.as_dyn(), |x| x < value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// greater than or equal to `value`.
+    #[inline]
+    pub fn ge(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        super::mask::compare(self.as_dyn(), |x| x >= value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// less than or equal to `value`.
+    #[inline]
+    pub fn le(&self, value: E) -> Mask
+    where
+        E: RealField,
+    {
+        super::mask::compare(self.as_dyn(), |x| x <= value)
+    }
+
+    /// Returns a mask whose elements are `true` where the corresponding elements of `self` are
+    /// within a distance `tol` of `value`.
+    #[inline]
+    pub fn eq_abs(&self, value: E, tol: E::Real) -> Mask
+    where
+        E: ComplexField,
+    {
+        super::mask::compare(self.as_dyn(), |x| x.faer_sub(value).faer_abs() <= tol)
+    }
+
+    /// Returns a new matrix containing the elementwise absolute values of `self`.
+    #[inline]
+    pub fn abs(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.as_dyn()).map(|unzipped!(x)| x.read().faer_abs())
+    }
+
+    /// Returns a new matrix containing the elements of `self`, clamped to the interval `[lo, hi]`.
+    ///
+    /// NaN values are left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `lo > hi`.
+    #[inline]
+    #[track_caller]
+    pub fn clamp(&self, lo: E, hi: E) -> Mat<E>
+    where
+        E: RealField,
+    {
+        assert!(lo <= hi);
+        zipped_rw!(self.as_dyn()).map(|unzipped!(x)| {
+            let x = x.read();
+            if x < lo {
+                lo
+            } else if x > hi {
+                hi
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Returns a new matrix containing the elementwise square roots of `self`.
+    #[inline]
+    pub fn sqrt(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.as_dyn()).map(|unzipped!(x)| x.read().faer_sqrt())
+    }
+
+    /// Returns a new matrix containing the elementwise reciprocals of `self`.
+    #[inline]
+    pub fn recip(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.as_dyn()).map(|unzipped!(x)| x.read().faer_inv())
+    }
+
+    /// Returns a new matrix containing the elementwise exponentials of `self`.
+    #[inline]
+    pub fn exp(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.as_dyn()).map(|unzipped!(x)| crate::utils::math::exp(x.read()))
+    }
+
+    /// Returns a new matrix containing the elementwise natural logarithms of `self`.
+    #[inline]
+    pub fn ln(&self) -> Mat<E>
+    where
+        E: RealField,
+    {
+        zipped_rw!(self.as_dyn()).map(|unzipped!(x)| crate::utils::math::ln(x.read()))
+    }
+
+    /// Returns a new matrix containing the rows of `self`, where row `i` is multiplied by `d[i]`.
+    ///
+    /// This is the product `D * self`, where `D` is the diagonal matrix whose diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.nrows() != self.nrows()`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat};
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+    /// let d = col![10.0, -1.0];
+    /// assert!(a.scale_rows(d.as_ref()) == mat![[10.0, 20.0], [-3.0, -4.0]]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn scale_rows(&self, d: ColRef<'_, E>) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        let this = self.as_dyn();
+        assert!(d.nrows() == this.nrows());
+        zipped_rw!(this, from_repeated_col(d, this.ncols()))
+            .map(|unzipped!(x, d)| d.read().faer_mul(x.read()))
+    }
+
+    /// Returns a new matrix containing the columns of `self`, where column `j` is multiplied by
+    /// `d[j]`.
+    ///
+    /// This is the product `self * D`, where `D` is the diagonal matrix whose diagonal is `d`.
+    ///
+    /// # Panics
+    /// The function panics if `d.ncols() != self.ncols()`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, row};
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+    /// let d = row![10.0, -1.0];
+    /// assert!(a.scale_cols(d.as_ref()) == mat![[10.0, -2.0], [30.0, -4.0]]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn scale_cols(&self, d: RowRef<'_, E>) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        let this = self.as_dyn();
+        assert!(d.ncols() == this.ncols());
+        zipped_rw!(this, from_repeated_row(d, this.nrows()))
+            .map(|unzipped!(x, d)| x.read().faer_mul(d.read()))
+    }
+
+    /// Returns a new matrix containing the lower triangular part of `self`, with the elements
+    /// above it set to zero.
+    ///
+    /// `diag` specifies whether the diagonal is kept or set to zero.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{linalg::zip::Diag, mat};
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f64]];
+    /// assert!(a.lower_triangle(Diag::Include) == mat![[1.0, 0.0, 0.0], [4.0, 5.0, 0.0]]);
+    /// assert!(a.lower_triangle(Diag::Skip) == mat![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0]]);
+    /// ```
+    #[inline]
+    pub fn lower_triangle(&self, diag: Diag) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        let this = self.as_dyn();
+        let mut out = Mat::<E>::zeros(this.nrows(), this.ncols());
+        zipped_rw!(out.as_mut(), this)
+            .for_each_triangular_lower(diag, |unzipped!(mut dst, src)| dst.write(src.read()));
+        out
+    }
+
+    /// Returns a new matrix containing the upper triangular part of `self`, with the elements
+    /// below it set to zero.
+    ///
+    /// `diag` specifies whether the diagonal is kept or set to zero.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{linalg::zip::Diag, mat};
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f64]];
+    /// assert!(a.upper_triangle(Diag::Include) == mat![[1.0, 2.0, 3.0], [0.0, 5.0, 6.0]]);
+    /// assert!(a.upper_triangle(Diag::Skip) == mat![[0.0, 2.0, 3.0], [0.0, 0.0, 6.0]]);
+    /// ```
+    #[inline]
+    pub fn upper_triangle(&self, diag: Diag) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        let this = self.as_dyn();
+        let mut out = Mat::<E>::zeros(this.nrows(), this.ncols());
+        zipped_rw!(out.as_mut(), this)
+            .for_each_triangular_upper(diag, |unzipped!(mut dst, src)| dst.write(src.read()));
+        out
+    }
+
     /// Kronecker product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -1192,6 +1973,15 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
         }
     }
 
+    /// Returns an iterator over the elements of the matrix along with their `(row, col)` indices.
+    ///
+    /// The elements are visited in column-major order, i.e. `(0, 0)`, `(1, 0)`, ...,
+    /// `(nrows - 1, 0)`, `(0, 1)`, etc.
+    #[inline]
+    pub fn enumerate_elements(self) -> iter::EnumerateElemIter<'a, E> {
+        iter::EnumerateElemIter::new(self.as_dyn())
+    }
+
     /// Returns an iterator that provides successive chunks of the columns of this matrix, with
     /// each having at most `chunk_size` columns.
     ///
@@ -1360,6 +2150,39 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
             .map(|chunk| chunk.transpose())
     }
 
+    /// Returns a parallel iterator over the columns of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = ColRef<'a, E, R>> {
+        use rayon::prelude::*;
+
+        let this = self.as_dyn();
+        (0..this.ncols()).into_par_iter().map(move |j| {
+            this.col(j)
+                .as_shape(unsafe { R::new_unbound(this.nrows()) })
+        })
+    }
+
+    /// Returns a parallel iterator over the rows of the matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = RowRef<'a, E>> {
+        use rayon::prelude::*;
+
+        let this = self.as_dyn();
+        (0..this.nrows()).into_par_iter().map(move |i| this.row(i))
+    }
+
     /// Given a matrix with a single column, returns an object that interprets
     /// the column as a diagonal matrix, whose diagonal elements are values in the column.
     #[track_caller]
@@ -1370,6 +2193,37 @@ impl<'a, E: Entity, R: Shape, C: Shape> MatRef<'a, E, R, C> {
             inner: self.col(unsafe { Idx::<C>::new_unbound(0) }),
         }
     }
+
+    /// Returns a view over the `k`-th superdiagonal of the matrix, made up of the elements at
+    /// indices `(i, i + k)`. The superdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn superdiagonal(self, k: usize) -> ColRef<'a, E> {
+        let this = self.as_dyn();
+        assert!(k <= this.ncols());
+        let size = Ord::min(this.nrows(), this.ncols() - k);
+        unsafe {
+            crate::col::from_raw_parts(
+                this.ptr_at(0, k),
+                size,
+                this.row_stride() + this.col_stride(),
+            )
+        }
+    }
+
+    /// Returns a view over the `k`-th subdiagonal of the matrix, made up of the elements at
+    /// indices `(i + k, i)`. The subdiagonal of index `0` is the main diagonal.
+    ///
+    /// # Panics
+    /// The function panics if `k > self.nrows()`.
+    #[track_caller]
+    #[inline]
+    pub fn subdiagonal(self, k: usize) -> ColRef<'a, E> {
+        self.transpose().superdiagonal(k)
+    }
 }
 
 impl<'a, E: Entity, N: Shape> MatRef<'a, E, N, N> {
@@ -1405,6 +2259,29 @@ impl<'a, E: RealField, R: Shape, C: Shape> MatRef<'a, num_complex::Complex<E>, R
     }
 }
 
+/// Returns the indices of the elements of `lhs` and `rhs` that don't satisfy the criterion of
+/// [`MatRef::approx_eq`], in column-major order. Both matrices must have the same dimensions.
+pub(crate) fn approx_eq_mismatches<'a, E: ComplexField>(
+    lhs: MatRef<'a, E>,
+    rhs: MatRef<'a, E>,
+    rel_tol: E::Real,
+    abs_tol: E::Real,
+) -> impl 'a + Iterator<Item = (usize, usize)> {
+    debug_assert!(lhs.shape() == rhs.shape());
+    let (m, n) = lhs.shape();
+    (0..n)
+        .flat_map(move |j| (0..m).map(move |i| (i, j)))
+        .filter(move |&(i, j)| {
+            let a = lhs.read(i, j);
+            let b = rhs.read(i, j);
+            let diff = a.faer_sub(b).faer_abs();
+            let (a_abs, b_abs) = (a.faer_abs(), b.faer_abs());
+            let max = if a_abs > b_abs { a_abs } else { b_abs };
+            // equal infinities have an undefined distance
+            !(a == b || diff <= abs_tol.faer_add(rel_tol.faer_mul(max)))
+        })
+}
+
 impl<E: Entity, R: Shape, C: Shape> AsMatRef<E> for MatRef<'_, E, R, C> {
     type R = R;
     type C = C;
@@ -1442,6 +2319,10 @@ impl<E: Entity> As2D<E> for MatRef<'_, E> {
 /// matrix unit may be accessed for writes by any other means for the duration of the lifetime
 /// `'a`.
 ///
+/// # Panics
+/// The function panics if the offset of some element of the matrix is not representable as an
+/// `isize`, i.e. if `(nrows - 1) * |row_stride| + (ncols - 1) * |col_stride| > isize::MAX`.
+///
 /// # Example
 ///
 /// ```
@@ -1467,6 +2348,12 @@ pub unsafe fn from_raw_parts<'a, E: Entity, R: Shape, C: Shape>(
     row_stride: isize,
     col_stride: isize,
 ) -> MatRef<'a, E, R, C> {
+    crate::utils::assert_strided_offsets_fit(
+        nrows.unbound(),
+        ncols.unbound(),
+        row_stride,
+        col_stride,
+    );
     MatRef::__from_raw_parts(ptr, nrows, ncols, row_stride, col_stride)
 }
 
@@ -1542,6 +2429,39 @@ pub fn from_column_major_slice<E: SimpleEntity, R: Shape, C: Shape>(
     from_column_major_slice_generic(slice, nrows, ncols)
 }
 
+/// Creates a `MatRef` over complex values from a slice of interleaved real and imaginary parts,
+/// `[re0, im0, re1, im1, ...]`, and the matrix dimensions. The complex values are interpreted in a
+/// column-major format.
+///
+/// Since [`c64`] stores its real and imaginary parts contiguously, the returned view aliases the
+/// input data without copying it.
+///
+/// # Panics
+/// The function panics if any of the following conditions are violated:
+/// * `2 * nrows * ncols == data.len()`
+///
+/// # Example
+/// ```
+/// use faer::{complex_native::c64, mat};
+///
+/// let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0_f64];
+/// let view = mat::from_interleaved_complex_slice(&data, 3, 1);
+///
+/// let expected = mat![[c64::new(1.0, 2.0)], [c64::new(3.0, 4.0)], [c64::new(5.0, 6.0)]];
+/// assert_eq!(expected, view);
+/// assert_eq!(view.to_interleaved_complex_vec(), data);
+/// ```
+#[track_caller]
+#[inline]
+pub fn from_interleaved_complex_slice<R: Shape, C: Shape>(
+    data: &[f64],
+    nrows: R,
+    ncols: C,
+) -> MatRef<'_, c64, R, C> {
+    assert!(data.len() % 2 == 0);
+    from_column_major_slice(bytemuck::cast_slice::<f64, c64>(data), nrows, ncols)
+}
+
 /// Creates a `MatRef` from slice views over the matrix data, and the matrix dimensions.
 /// The data is interpreted in a row-major format, so that the first chunk of `ncols`
 /// values from the slices goes in the first column of the matrix, the second chunk of `ncols`
@@ -0,0 +1,314 @@
+use super::*;
+use crate::{assert, unzipped, zipped_rw};
+
+/// Matrix with dimensions known at compile time, whose elements are stored inline in column-major
+/// order, without any heap allocation.
+///
+/// Views over the matrix can be obtained with [`SMat::as_ref`] and [`SMat::as_mut`], which can be
+/// passed to any function that accepts a [`MatRef`] or a [`MatMut`], such as
+/// [`matmul`](crate::linalg::matmul::matmul).
+///
+/// # Example
+///
+/// ```
+/// use faer::mat::SMat;
+///
+/// let a = SMat::<f64, 2, 2>::from_fn(|i, j| (i + 2 * j) as f64);
+/// let b = SMat::<f64, 2, 2>::identity();
+///
+/// assert!(a.as_ref() * b.as_ref() == a.as_ref());
+/// assert!(a.determinant() == -2.0);
+/// ```
+#[repr(C)]
+pub struct SMat<E: Entity, const R: usize, const C: usize> {
+    data: GroupCopyFor<E, [[E::Unit; R]; C]>,
+}
+
+impl<E: Entity, const R: usize, const C: usize> Copy for SMat<E, R, C> {}
+impl<E: Entity, const R: usize, const C: usize> Clone for SMat<E, R, C> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: Entity, const R: usize, const C: usize> SMat<E, R, C> {
+    /// Returns a new matrix with dimensions `(R, C)`, filled with zeros.
+    #[inline]
+    pub fn zeros() -> Self {
+        Self {
+            data: unsafe { core::mem::zeroed() },
+        }
+    }
+
+    /// Returns a new matrix with dimensions `(R, C)`, filled with the provided function.
+    #[inline]
+    pub fn from_fn(f: impl FnMut(usize, usize) -> E) -> Self {
+        let mut f = f;
+        let mut this = Self::zeros();
+        for j in 0..C {
+            for i in 0..R {
+                this.write(i, j, f(i, j));
+            }
+        }
+        this
+    }
+
+    /// Returns the number of rows of the matrix.
+    #[inline(always)]
+    pub fn nrows(&self) -> usize {
+        R
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline(always)]
+    pub fn ncols(&self) -> usize {
+        C
+    }
+
+    /// Returns a pointer to the data of the matrix.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> PtrConst<E> {
+        let ptr = E::faer_as_ptr(
+            &self.data as *const GroupCopyFor<E, [[E::Unit; R]; C]>
+                as *mut GroupFor<E, [[E::Unit; R]; C]>,
+        );
+        map!(E, ptr, |(ptr)| ptr as *const E::Unit)
+    }
+
+    /// Returns a mutable pointer to the data of the matrix.
+    #[inline(always)]
+    pub fn as_ptr_mut(&mut self) -> PtrMut<E> {
+        let ptr = E::faer_as_ptr(
+            &mut self.data as *mut GroupCopyFor<E, [[E::Unit; R]; C]>
+                as *mut GroupFor<E, [[E::Unit; R]; C]>,
+        );
+        map!(E, ptr, |(ptr)| ptr as *mut E::Unit)
+    }
+
+    /// Returns a view over the matrix.
+    #[inline(always)]
+    pub fn as_ref(&self) -> MatRef<'_, E> {
+        unsafe { super::from_raw_parts(self.as_ptr(), R, C, 1, R as isize) }
+    }
+
+    /// Returns a mutable view over the matrix.
+    #[inline(always)]
+    pub fn as_mut(&mut self) -> MatMut<'_, E> {
+        unsafe { super::from_raw_parts_mut(self.as_ptr_mut(), R, C, 1, R as isize) }
+    }
+
+    /// Reads the value of the element at the given indices.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `row < R`.
+    /// * `col < C`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn read(&self, row: usize, col: usize) -> E {
+        self.as_ref().read(row, col)
+    }
+
+    /// Writes the value to the element at the given indices.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `row < R`.
+    /// * `col < C`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn write(&mut self, row: usize, col: usize, value: E) {
+        self.as_mut().write(row, col, value)
+    }
+
+    /// Returns a new matrix containing the elements of `mat`.
+    ///
+    /// # Panics
+    /// The function panics if `mat` doesn't have dimensions `(R, C)`.
+    #[inline]
+    #[track_caller]
+    pub fn from_ref<ViewE: Conjugate<Canonical = E>>(mat: MatRef<'_, ViewE>) -> Self
+    where
+        E: ComplexField,
+    {
+        assert!(all(mat.nrows() == R, mat.ncols() == C));
+        let mut this = Self::zeros();
+        zipped_rw!(this.as_mut(), mat)
+            .for_each(|unzipped!(mut dst, src)| dst.write(src.read().canonicalize()));
+        this
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> SMat<E, C, R> {
+        SMat::from_fn(|i, j| self.read(j, i))
+    }
+}
+
+impl<E: ComplexField, const N: usize> SMat<E, N, N> {
+    /// Returns the identity matrix with dimensions `(N, N)`.
+    #[inline]
+    pub fn identity() -> Self {
+        let mut this = Self::zeros();
+        for i in 0..N {
+            this.write(i, i, E::faer_one());
+        }
+        this
+    }
+}
+
+macro_rules! impl_closed_form {
+    ($($n: literal),*) => {$(
+        impl<E: ComplexField> SMat<E, $n, $n> {
+            /// Returns the determinant of the matrix, computed with the closed form cofactor
+            /// expansion.
+            #[inline]
+            pub fn determinant(&self) -> E {
+                crate::linalg::closed_form::determinant(self.as_ref())
+            }
+
+            /// Returns the inverse of the matrix, computed from its adjugate with the closed form
+            /// cofactor expansion.
+            ///
+            /// If the matrix is singular, the result contains non finite values.
+            #[inline]
+            pub fn inverse(&self) -> Self {
+                let mut inv = Self::zeros();
+                crate::linalg::closed_form::inverse(inv.as_mut(), self.as_ref());
+                inv
+            }
+
+            /// Returns the adjugate of the matrix, computed with the closed form cofactor
+            /// expansion.
+            #[inline]
+            pub fn adjugate(&self) -> Self {
+                let mut adj = Self::zeros();
+                crate::linalg::closed_form::adjugate(adj.as_mut(), self.as_ref());
+                adj
+            }
+        }
+    )*};
+}
+
+impl_closed_form!(2, 3, 4);
+
+impl<E: Entity, const R: usize, const C: usize> AsMatRef<E> for SMat<E, R, C> {
+    type R = usize;
+    type C = usize;
+
+    #[inline]
+    fn as_mat_ref(&self) -> MatRef<'_, E> {
+        (*self).as_ref()
+    }
+}
+
+impl<E: Entity, const R: usize, const C: usize> AsMatMut<E> for SMat<E, R, C> {
+    #[inline]
+    fn as_mat_mut(&mut self) -> MatMut<'_, E> {
+        (*self).as_mut()
+    }
+}
+
+impl<E: Entity, const R: usize, const C: usize> As2D<E> for SMat<E, R, C> {
+    #[inline]
+    fn as_2d_ref(&self) -> MatRef<'_, E> {
+        (*self).as_ref()
+    }
+}
+
+impl<E: Entity, const R: usize, const C: usize> As2DMut<E> for SMat<E, R, C> {
+    #[inline]
+    fn as_2d_mut(&mut self) -> MatMut<'_, E> {
+        (*self).as_mut()
+    }
+}
+
+impl<E: Entity, const R: usize, const C: usize> core::fmt::Debug for SMat<E, R, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl<E: ComplexField, const R: usize, const C: usize> PartialEq for SMat<E, R, C> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<E: SimpleEntity, const R: usize, const C: usize> core::ops::Index<(usize, usize)>
+    for SMat<E, R, C>
+{
+    type Output = E;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, (row, col): (usize, usize)) -> &E {
+        self.as_ref().get(row, col)
+    }
+}
+
+impl<E: SimpleEntity, const R: usize, const C: usize> core::ops::IndexMut<(usize, usize)>
+    for SMat<E, R, C>
+{
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut E {
+        self.as_mut().get_mut(row, col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::matmul::matmul, Parallelism};
+
+    #[test]
+    fn test_smat() {
+        let mut a = SMat::<f64, 2, 3>::from_fn(|i, j| (i * 3 + j) as f64);
+        assert!(a.as_ref() == crate::mat![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        assert!(a.transpose().as_ref() == a.as_ref().transpose());
+
+        a[(1, 2)] = -1.0;
+        let b = a;
+        assert!(b.read(1, 2) == -1.0);
+
+        let mut c = SMat::<f64, 2, 2>::zeros();
+        matmul(
+            c.as_mut(),
+            a.as_ref(),
+            b.as_ref().transpose(),
+            None,
+            1.0,
+            Parallelism::None,
+        );
+        assert!(c.as_ref() == a.as_ref() * b.as_ref().transpose());
+        assert!(SMat::<f64, 2, 2>::from_ref(c.as_ref()) == c);
+    }
+
+    #[test]
+    fn test_smat_inverse() {
+        let a = SMat::<f64, 2, 2>::from_fn(|i, j| [[4.0, 7.0], [2.0, 6.0]][i][j]);
+        assert!((a.determinant() - 10.0).abs() < 1e-12);
+        let err = a.as_ref() * a.inverse().as_ref() - SMat::<f64, 2, 2>::identity().as_ref();
+        assert!(err.norm_max() < 1e-12);
+
+        let a = SMat::<f64, 3, 3>::from_fn(|i, j| {
+            [[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]][i][j]
+        });
+        assert!((a.determinant() - 6.0).abs() < 1e-12);
+
+        let a = SMat::<c64, 3, 3>::from_fn(|i, j| {
+            c64::new((i * 3 + j) as f64 + 1.0, if i == j { 2.0 } else { -1.0 })
+        });
+        let err = a.as_ref() * a.inverse().as_ref() - SMat::<c64, 3, 3>::identity().as_ref();
+        assert!(err.norm_max() < 1e-12);
+
+        let a = SMat::<f64, 4, 4>::from_fn(|i, j| if i == j { 4.0 } else { (i + 2 * j) as f64 });
+        let err = a.inverse().as_ref() * a.as_ref() - SMat::<f64, 4, 4>::identity().as_ref();
+        assert!(err.norm_max() < 1e-12);
+        let err = a.adjugate().as_ref() - crate::scale(a.determinant()) * a.inverse().as_ref();
+        assert!(err.norm_max() < 1e-10);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{assert, col::*, row::*, utils::slice::*, Conj, Shape};
+use crate::{assert, col::*, complex_native::c64, row::*, utils::slice::*, Conj, Shape};
 use coe::Coerce;
 use core::{marker::PhantomData, ptr::NonNull};
 use faer_entity::*;
@@ -51,6 +51,26 @@ pub trait MatIndex<RowRange, ColRange>: crate::seal::Seal + Sized {
 /// This trait is implemented for types of the matrix family, like [`Mat`],
 /// [`MatRef`], and [`MatMut`], but not for types like [`Col`], [`Row`], or
 /// their families. For a more general trait, see [`As2D`].
+///
+/// It can be used to write functions that accept any of these types without requiring the
+/// caller to convert them to views first. The dimension types can be constrained to `usize`
+/// for functions that only handle dynamically sized matrices.
+///
+/// # Example
+/// ```
+/// use faer::{mat, mat::AsMatRef, Mat};
+///
+/// fn trace(a: impl AsMatRef<f64, R = usize, C = usize>) -> f64 {
+///     let a = a.as_mat_ref();
+///     (0..Ord::min(a.nrows(), a.ncols())).map(|i| a.read(i, i)).sum()
+/// }
+///
+/// let mut a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+/// assert!(trace(&a) == 5.0);
+/// assert!(trace(a.as_ref()) == 5.0);
+/// assert!(trace(a.as_mut()) == 5.0);
+/// assert!(trace(a) == 5.0);
+/// ```
 pub trait AsMatRef<E: Entity> {
     /// Row dimension of the matrix.
     type R: Shape;
@@ -65,6 +85,22 @@ pub trait AsMatRef<E: Entity> {
 /// This trait is implemented for types of the matrix family, like [`Mat`],
 /// [`MatRef`], and [`MatMut`], but not for types like [`Col`], [`Row`], or
 /// their families. For a more general trait, see [`As2D`].
+///
+/// # Example
+/// ```
+/// use faer::{mat, mat::AsMatMut, Mat};
+///
+/// fn negate(mut a: impl AsMatMut<f64, R = usize, C = usize>) {
+///     a.as_mat_mut()
+///         .col_iter_mut()
+///         .for_each(|col| col.iter_mut().for_each(|x| *x = -*x));
+/// }
+///
+/// let mut a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+/// negate(&mut a);
+/// negate(a.as_mut().submatrix_mut(0, 0, 1, 2));
+/// assert!(a == mat![[1.0, 2.0], [-3.0, -4.0]]);
+/// ```
 pub trait AsMatMut<E: Entity>: AsMatRef<E> {
     /// Convert to a mutable matrix view.
     fn as_mat_mut(&mut self) -> MatMut<'_, E, Self::R, Self::C>;
@@ -150,26 +186,64 @@ impl<'a, FromE: Entity, ToE: Entity> Coerce<MatMut<'a, ToE>> for MatMut<'a, From
 mod mat_index;
 
 mod matref;
+pub(crate) use matref::approx_eq_mismatches;
 pub use matref::{
     from_column_major_slice, from_column_major_slice_generic, from_column_major_slice_with_stride,
-    from_column_major_slice_with_stride_generic, from_raw_parts, from_ref, from_ref_generic,
-    from_repeated_col, from_repeated_ref, from_repeated_ref_generic, from_repeated_row,
-    from_row_major_slice, from_row_major_slice_generic, from_row_major_slice_with_stride,
-    from_row_major_slice_with_stride_generic, MatRef,
+    from_column_major_slice_with_stride_generic, from_interleaved_complex_slice, from_raw_parts,
+    from_ref, from_ref_generic, from_repeated_col, from_repeated_ref, from_repeated_ref_generic,
+    from_repeated_row, from_row_major_slice, from_row_major_slice_generic,
+    from_row_major_slice_with_stride, from_row_major_slice_with_stride_generic, MatRef,
 };
 
 mod matmut;
 pub use matmut::{
     from_column_major_slice_mut, from_column_major_slice_mut_generic,
     from_column_major_slice_with_stride_mut, from_column_major_slice_with_stride_mut_generic,
-    from_mut, from_mut_generic, from_raw_parts_mut, from_row_major_slice_mut,
-    from_row_major_slice_mut_generic, from_row_major_slice_with_stride_mut,
-    from_row_major_slice_with_stride_mut_generic, MatMut,
+    from_interleaved_complex_slice_mut, from_mut, from_mut_generic, from_raw_parts_mut,
+    from_row_major_slice_mut, from_row_major_slice_mut_generic,
+    from_row_major_slice_with_stride_mut, from_row_major_slice_with_stride_mut_generic, MatMut,
 };
 
 mod matown;
 pub use matown::Mat;
 
+mod matstatic;
+pub use matstatic::SMat;
+
+mod scaled;
+pub use scaled::ScaledMat;
+
+mod mask;
+pub use mask::{select, Mask};
+
+/// Structure of a matrix, as detected by [`MatRef::detect_structure`].
+///
+/// The variants are ordered from the most to the least specific, and the detection returns the
+/// most specific one that applies.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MatrixStructure {
+    /// The matrix is square, and equal to the identity.
+    Identity,
+    /// All the elements outside the diagonal are zero.
+    Diagonal,
+    /// All the elements above the diagonal are zero.
+    LowerTriangular,
+    /// All the elements below the diagonal are zero.
+    UpperTriangular,
+    /// The matrix has none of the other structures.
+    General,
+}
+
+/// Order in which [`MatMut::sort_each_col`] arranges the elements of each column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    /// The elements are sorted in nondecreasing order.
+    Ascending,
+    /// The elements are sorted in nonincreasing order.
+    Descending,
+}
+
 pub(crate) mod matalloc;
 
 #[track_caller]
@@ -197,7 +271,7 @@ fn from_strided_column_major_slice_assert(
         let last = usize::checked_mul(col_stride, ncols - 1)
             .and_then(|last_col| last_col.checked_add(nrows - 1))
             .unwrap_or(usize::MAX);
-        assert!(last < len);
+        assert!(all(col_stride <= isize::MAX as usize, last < len));
     }
 }
 
@@ -216,7 +290,11 @@ fn from_strided_column_major_slice_mut_assert(
         let last = usize::checked_mul(col_stride, ncols - 1)
             .and_then(|last_col| last_col.checked_add(nrows - 1))
             .unwrap_or(usize::MAX);
-        assert!(all(col_stride >= nrows, last < len));
+        assert!(all(
+            col_stride <= isize::MAX as usize,
+            col_stride >= nrows,
+            last < len,
+        ));
     }
 }
 
@@ -254,4 +332,765 @@ mod tests {
         a.resize_with(50, 2, |_, _| 0.0);
         a.resize_with(60, 1, |_, _| 0.0);
     }
+
+    #[test]
+    fn test_interleaved_complex() {
+        let mut data: alloc::vec::Vec<f64> = (0..24).map(|x| x as f64).collect();
+        let a = from_interleaved_complex_slice(&data, 3, 4);
+        assert!(all(
+            a.nrows() == 3,
+            a.ncols() == 4,
+            a.col_stride() == 3,
+            a.as_ptr() as *const f64 == data.as_ptr(),
+        ));
+        assert!(
+            a == Mat::from_fn(3, 4, |i, j| {
+                let k = (i + 3 * j) as f64;
+                c64::new(2.0 * k, 2.0 * k + 1.0)
+            })
+        );
+        assert!(a.to_interleaved_complex_vec() == data);
+
+        // strided and split complex storage round trip through the interleaved layout
+        let b = a.transpose().to_owned();
+        let c = Mat::<num_complex::Complex64>::from_fn(4, 3, |i, j| {
+            let z = b.read(i, j);
+            num_complex::Complex64::new(z.re, z.im)
+        });
+        assert!(b.to_interleaved_complex_vec() == c.to_interleaved_complex_vec());
+        assert!(a.transpose().to_interleaved_complex_vec() == b.to_interleaved_complex_vec());
+        assert!(
+            from_interleaved_complex_slice(&b.to_interleaved_complex_vec(), 4, 3) == b.as_ref()
+        );
+
+        let mut a = from_interleaved_complex_slice_mut(&mut data, 6, 2);
+        a.write(5, 1, c64::new(-1.0, -2.0));
+        assert!(data[22..] == [-1.0, -2.0]);
+
+        let real = Mat::from_fn(2, 1, |i, _| i as f64 + 1.0);
+        assert!(real.to_interleaved_complex_vec() == [1.0, 0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interleaved_complex_odd_len() {
+        from_interleaved_complex_slice(&[1.0, 2.0, 3.0], 1, 1);
+    }
+
+    #[test]
+    fn test_empty_views() {
+        for (m, n) in [(0, 0), (0, 3), (3, 0)] {
+            let mut a = crate::Mat::<f64>::zeros(m, n);
+            let new = crate::Mat::<f64>::new();
+            let b = crate::Mat::<f64>::zeros(n, m);
+            for a in [
+                if m == 0 && n == 0 {
+                    new.as_ref()
+                } else {
+                    a.as_ref()
+                },
+                a.as_ref(),
+                b.as_ref().transpose(),
+            ] {
+                for i in 0..=m {
+                    for j in 0..=n {
+                        let (tl, tr, bl, br) = a.split_at(i, j);
+                        assert!(all(
+                            (tl.nrows(), tl.ncols()) == (i, j),
+                            (tr.nrows(), tr.ncols()) == (i, n - j),
+                            (bl.nrows(), bl.ncols()) == (m - i, j),
+                            (br.nrows(), br.ncols()) == (m - i, n - j),
+                        ));
+
+                        let sub = a.submatrix(i, j, m - i, n - j);
+                        assert!((sub.nrows(), sub.ncols()) == (m - i, n - j));
+                        let (top, bot) = a.split_at_row(i);
+                        assert!((top.nrows(), bot.nrows()) == (i, m - i));
+                        let (left, right) = a.split_at_col(j);
+                        assert!((left.ncols(), right.ncols()) == (j, n - j));
+                        assert!(a.subrows(i, m - i).nrows() == m - i);
+                        assert!(a.subcols(j, n - j).ncols() == n - j);
+                    }
+                }
+            }
+
+            for i in 0..=m {
+                for j in 0..=n {
+                    let (tl, tr, bl, br) = a.as_mut().split_at_mut(i, j);
+                    assert!(all(
+                        (tl.nrows(), tl.ncols()) == (i, j),
+                        (tr.nrows(), tr.ncols()) == (i, n - j),
+                        (bl.nrows(), bl.ncols()) == (m - i, j),
+                        (br.nrows(), br.ncols()) == (m - i, n - j),
+                    ));
+                    let sub = a.as_mut().submatrix_mut(i, j, m - i, n - j);
+                    assert!((sub.nrows(), sub.ncols()) == (m - i, n - j));
+                }
+            }
+
+            for j in 0..n {
+                let (top, bot) = a.col(j).split_at(0);
+                assert!((top.nrows(), bot.nrows()) == (0, 0));
+                assert!(a.col(j).subrows(0, 0).nrows() == 0);
+            }
+            for i in 0..m {
+                let (left, right) = a.row(i).split_at(0);
+                assert!((left.ncols(), right.ncols()) == (0, 0));
+                assert!(a.row(i).subcols(0, 0).ncols() == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_strided_offsets() {
+        let data = [1.0f64, 2.0, 3.0, 4.0];
+
+        // a single column doesn't use its column stride
+        let view = unsafe { from_raw_parts::<f64, _, _>(data.as_ptr(), 4, 1, 1, isize::MIN) };
+        assert!(view.read(3, 0) == 4.0);
+        let view = unsafe { from_raw_parts::<f64, _, _>(data.as_ptr(), 0, 3, isize::MAX, 1) };
+        assert!(view.ncols() == 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_offsets_overflow() {
+        let data = [1.0f64];
+        let _ = unsafe { from_raw_parts::<f64, _, _>(data.as_ptr(), 3, 2, isize::MAX / 2 + 1, 1) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_offsets_overflow_col() {
+        let mut data = [1.0f64];
+        let _ =
+            unsafe { crate::col::from_raw_parts_mut::<f64, _>(data.as_mut_ptr(), 2, isize::MIN) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_slice_stride_overflow() {
+        let data = [1.0f64];
+        let _ = from_column_major_slice_with_stride(&data, 1, 1, usize::MAX);
+    }
+
+    #[test]
+    fn test_submatrix_strided() {
+        let mut a = Mat::from_fn(7, 6, |i, j| (10 * i + j) as f64);
+
+        let view = a.submatrix_strided(1, 3, 2, 0, 2, 3);
+        assert!(view.nrows() == 2);
+        assert!(view.ncols() == 3);
+        assert!(view.row_stride() == 3 * a.row_stride());
+        assert!(view.col_stride() == 2 * a.col_stride());
+        for i in 0..2 {
+            for j in 0..3 {
+                assert!(view.read(i, j) == a.read(1 + 3 * i, 2 * j));
+            }
+        }
+
+        // strides compose with transposition and reversal
+        let t = a.as_ref().transpose().submatrix_strided(5, 1, 1, 6, 2, 0);
+        assert!(t.nrows() == 1);
+        assert!(t.ncols() == 0);
+        let r = a
+            .as_ref()
+            .reverse_rows()
+            .submatrix_strided(0, 2, 4, 1, 4, 2);
+        for i in 0..4 {
+            for j in 0..2 {
+                assert!(r.read(i, j) == a.read(6 - 2 * i, 1 + 4 * j));
+            }
+        }
+
+        a.submatrix_strided_mut(0, 2, 4, 1, 2, 3).fill(-1.0);
+        for i in 0..7 {
+            for j in 0..6 {
+                let expected = if i % 2 == 0 && j % 2 == 1 {
+                    -1.0
+                } else {
+                    (10 * i + j) as f64
+                };
+                assert!(a.read(i, j) == expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_submatrix_strided_out_of_bounds() {
+        let a = Mat::<f64>::zeros(7, 6);
+        let _ = a.submatrix_strided(1, 3, 3, 0, 1, 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_submatrix_strided_zero_step() {
+        let mut a = Mat::<f64>::zeros(7, 6);
+        let _ = a.submatrix_strided_mut(0, 0, 2, 0, 1, 6);
+    }
+
+    #[test]
+    fn test_hash_contents() {
+        use core::hash::Hasher;
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<E: Conjugate>(m: MatRef<'_, E>) -> u64 {
+            let mut state = DefaultHasher::new();
+            m.hash_contents(&mut state);
+            state.finish()
+        }
+
+        let big = Mat::from_fn(7, 6, |i, j| (10 * i + j) as f64);
+        let view = big.as_ref().submatrix(1, 2, 3, 4).reverse_rows();
+        let owned = view.to_owned();
+        assert!(hash(view) == hash(owned.as_ref()));
+
+        let mut mutated = owned.clone();
+        mutated.write(2, 3, -1.0);
+        assert!(hash(mutated.as_ref()) != hash(owned.as_ref()));
+
+        // same elements in column-major order, different shape
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert!(
+            hash(from_column_major_slice(&data, 2, 3))
+                != hash(from_column_major_slice(&data, 3, 2))
+        );
+        assert!(hash(Mat::<f64>::new().as_ref()) != hash(Mat::<f64>::zeros(0, 1).as_ref()));
+
+        // a conjugated view hashes like its materialized copy, and unlike the original matrix
+        let z = Mat::from_fn(3, 2, |i, j| c64::new(i as f64, j as f64 + 1.0));
+        let conj = z.as_ref().conjugate();
+        assert!(hash(conj) == hash(conj.to_owned().as_ref()));
+        assert!(hash(conj) != hash(z.as_ref()));
+        assert!(hash(conj.conjugate()) == hash(z.as_ref()));
+    }
+
+    #[test]
+    fn test_with_capacity_aligned() {
+        let mut a = Mat::<f64>::with_capacity_aligned(0, 0, 4096);
+        for n in [1, 3, 17, 64, 100] {
+            a.resize_with(n, n + 1, |i, j| (i + j) as f64);
+            assert!(a.as_ptr() as usize % 4096 == 0);
+            a.as_ref().assert_aligned(4096);
+            for j in 0..n + 1 {
+                for i in 0..n {
+                    assert!(a.read(i, j) == (i + j) as f64);
+                }
+            }
+        }
+
+        let mut b = Mat::<num_complex::Complex<f64>>::with_capacity_aligned(3, 3, 256);
+        b.resize_with(9, 2, |i, j| num_complex::Complex::new(i as f64, j as f64));
+        b.as_mut().assert_aligned_mut(256);
+        assert!(b.read(8, 1) == num_complex::Complex::new(8.0, 1.0));
+
+        // alignments smaller than the default don't weaken it
+        let c = Mat::<f64>::with_capacity_aligned(4, 4, 8);
+        assert!(c.as_ptr() as usize % matalloc::align_for::<f64>() == 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_capacity_aligned_not_power_of_two() {
+        let _ = Mat::<f64>::with_capacity_aligned(4, 4, 48);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_capacity_aligned_too_small() {
+        let _ = Mat::<f64>::with_capacity_aligned(4, 4, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_aligned_misaligned() {
+        let mut a = Mat::<f64>::with_capacity_aligned(4, 4, 64);
+        a.resize_with(4, 4, |_, _| 0.0);
+        let _ = a.as_ref().submatrix(1, 0, 1, 1).assert_aligned(64);
+    }
+
+    #[test]
+    fn test_map_simd() {
+        use crate::{complex_native::c64, utils::simd::*, ComplexField};
+
+        struct Affine<E> {
+            a: E,
+            b: E,
+        }
+        impl<E: ComplexField> SimdMap<E> for Affine<E> {
+            #[inline(always)]
+            fn apply<S: pulp::Simd>(
+                &mut self,
+                simd: SimdFor<E, S>,
+                values: SimdGroupFor<E, S>,
+            ) -> SimdGroupFor<E, S> {
+                simd.mul_add_e(simd.splat(self.a), values, simd.splat(self.b))
+            }
+        }
+
+        fn check<E: ComplexField<Real = f64>>(a: E, b: E, x: crate::MatRef<'_, E>) {
+            let f = || Affine { a, b };
+            let expected = Mat::<E>::from_fn(x.nrows(), x.ncols(), |i, j| {
+                a.faer_mul(x.read(i, j)).faer_add(b)
+            });
+            let close = |a: E, b: E| a.faer_sub(b).faer_abs() < 1e-14;
+
+            let y = x.map_simd(f());
+            for j in 0..x.ncols() {
+                let col = x.col(j).map_simd(f());
+                for i in 0..x.nrows() {
+                    assert!(close(y.read(i, j), expected.read(i, j)));
+                    assert!(close(col.read(i), expected.read(i, j)));
+                }
+            }
+            for i in 0..x.nrows() {
+                let row = x.row(i).map_simd(f());
+                for j in 0..x.ncols() {
+                    assert!(close(row.read(j), expected.read(i, j)));
+                }
+            }
+        }
+
+        // lengths that aren't multiples of the register size exercise the partial head and tail
+        for (m, n) in [(0, 3), (1, 1), (3, 2), (7, 5), (16, 3), (33, 4)] {
+            let x = Mat::<f64>::from_fn(m, n, |_, _| rand::random());
+            check(2.5, -1.0, x.as_ref());
+            check(2.5, -1.0, x.transpose());
+            check(2.5, -1.0, x.as_ref().reverse_rows());
+
+            let x = Mat::<c64>::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()));
+            let (a, b) = (c64::new(0.5, 2.0), c64::new(-1.0, 3.0));
+            check(a, b, x.as_ref());
+            check(a, b, x.transpose());
+        }
+    }
+
+    #[test]
+    fn test_enumerate_elements() {
+        let a = crate::mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f64]];
+
+        let elems: alloc::vec::Vec<_> = a.enumerate_elements().map(|(ij, &x)| (ij, x)).collect();
+        assert!(
+            elems
+                == [
+                    ((0, 0), 1.0),
+                    ((1, 0), 4.0),
+                    ((0, 1), 2.0),
+                    ((1, 1), 5.0),
+                    ((0, 2), 3.0),
+                    ((1, 2), 6.0),
+                ]
+        );
+
+        let mut iter = a.transpose().enumerate_elements();
+        assert!(iter.len() == 6);
+        assert!(iter.next() == Some(((0, 0), &1.0)));
+        assert!(iter.next_back() == Some(((2, 1), &6.0)));
+        assert!(iter.nth(1) == Some(((2, 0), &3.0)));
+        assert!(iter.len() == 2);
+        assert!(iter.nth_back(5).is_none());
+        assert!(iter.len() == 0);
+
+        let trace = a
+            .enumerate_elements()
+            .filter(|((i, j), _)| i == j)
+            .fold(0.0, |acc, (_, &x)| acc + x);
+        assert!(trace == 6.0);
+
+        let mut b = a.clone();
+        for ((i, j), x) in b.enumerate_elements_mut().rev() {
+            *x += (10 * i + j) as f64;
+        }
+        assert!(b == crate::mat![[1.0, 3.0, 5.0], [14.0, 16.0, 18.0]]);
+
+        assert!(Mat::<f64>::zeros(0, 3).enumerate_elements().len() == 0);
+        assert!(Mat::<f64>::zeros(3, 0)
+            .enumerate_elements_mut()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_diagonal_updates() {
+        let a = Mat::from_fn(4, 3, |i, j| (10 * i + j) as f64);
+        let lambda = 0.5;
+
+        let mut b = a.clone();
+        b.as_mut().add_scalar_to_diagonal(lambda);
+        assert!(
+            b == Mat::from_fn(4, 3, |i, j| a.read(i, j)
+                + if i == j { lambda } else { 0.0 })
+        );
+
+        let mut b = a.clone();
+        b.as_mut().transpose_mut().scale_diagonal(|i| -(i as f64));
+        assert!(
+            b == Mat::from_fn(4, 3, |i, j| a.read(i, j)
+                * if i == j { -(i as f64) } else { 1.0 })
+        );
+
+        // strided views only touch their own diagonal
+        let mut b = a.clone();
+        b.as_mut()
+            .submatrix_mut(1, 0, 3, 3)
+            .reverse_rows_mut()
+            .add_scalar_to_diagonal(lambda);
+        assert!(
+            b == Mat::from_fn(4, 3, |i, j| a.read(i, j)
+                + if i + j == 3 { lambda } else { 0.0 })
+        );
+
+        let mut b = Mat::<f64>::zeros(0, 3);
+        b.as_mut().add_scalar_to_diagonal(lambda);
+        b.as_mut().scale_diagonal(|_| panic!());
+    }
+
+    #[test]
+    fn test_range_slicing() {
+        let a = Mat::from_fn(4, 5, |i, j| (10 * i + j) as f64);
+        let sub = |i: usize, j: usize, m: usize, n: usize| a.as_ref().submatrix(i, j, m, n);
+
+        let r = a.as_ref();
+        assert!(r.get(1..3, 0..2) == sub(1, 0, 2, 2));
+        assert!(r.get(.., ..) == sub(0, 0, 4, 5));
+        assert!(r.get(..2, 3..) == sub(0, 3, 2, 2));
+        assert!(r.get(1..=2, ..=3) == sub(1, 0, 2, 4));
+        assert!(r.get(2.., 1..1) == sub(2, 1, 2, 0));
+        assert!(r.get(1..3, 4) == sub(1, 4, 2, 1).col(0));
+        assert!(r.get(3, ..) == sub(3, 0, 1, 5).row(0));
+        assert!(a.get(..3, 2..4) == sub(0, 2, 3, 2));
+
+        let mut b = a.clone();
+        b.as_mut().get_mut(1..3, ..2).fill(-1.0);
+        b.get_mut(..1, 4..).fill(-2.0);
+        assert!(
+            b == Mat::from_fn(4, 5, |i, j| match (i, j) {
+                (1..=2, 0..=1) => -1.0,
+                (0, 4) => -2.0,
+                _ => a.read(i, j),
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let a = Mat::from_fn(3, 4, |i, j| c64::new((10 * i + j) as f64, i as f64));
+
+        for view in [
+            a.as_ref(),
+            a.as_ref().transpose(),
+            a.as_ref().reverse_rows_and_cols(),
+            a.as_ref().submatrix(1, 1, 2, 3),
+            a.as_ref().get(.., 0..0),
+        ] {
+            let (m, n) = view.shape();
+            let col_major = view.to_col_major_vec();
+            let row_major = view.to_row_major_vec();
+            let cols = view.to_vec_of_cols();
+
+            assert!(all(
+                col_major.len() == m * n,
+                row_major.len() == m * n,
+                cols.len() == n
+            ));
+            assert!(from_column_major_slice::<c64, _, _>(&col_major, m, n) == view);
+            assert!(from_row_major_slice::<c64, _, _>(&row_major, m, n) == view);
+            for (j, col) in cols.iter().enumerate() {
+                assert!(*col == col_major[j * m..][..m]);
+            }
+        }
+
+        let b = a.as_ref().conjugate();
+        assert!(b.to_col_major_vec() == a.conjugate().to_owned().to_col_major_vec());
+        assert!(a.to_row_major_vec() == a.transpose().to_col_major_vec());
+    }
+
+    #[test]
+    fn test_resize_retains_contents() {
+        let value = |i: usize, j: usize| c64::new(i as f64, j as f64);
+        let fill = c64::new(-1.0, -1.0);
+        let mut a = Mat::from_fn(3, 3, value);
+
+        let mut calls = alloc::vec::Vec::new();
+        a.resize_with(5, 3, |i, j| {
+            calls.push((i, j));
+            value(i, j)
+        });
+        assert!(calls == [(3, 0), (4, 0), (3, 1), (4, 1), (3, 2), (4, 2)]);
+
+        calls.clear();
+        a.resize_with(5, 5, |i, j| {
+            calls.push((i, j));
+            fill
+        });
+        assert!(calls.len() == 10);
+        assert!(calls.iter().all(|&(_, j)| j >= 3));
+        assert!(a.as_ref().submatrix(0, 0, 3, 3) == Mat::from_fn(3, 3, value));
+        assert!(a.as_ref().submatrix(0, 0, 5, 3) == Mat::from_fn(5, 3, value));
+        assert!(a.as_ref().submatrix(0, 3, 5, 2) == Mat::from_fn(5, 2, |_, _| fill));
+
+        // growing past the row capacity relocates the columns to the new column stride
+        let old_row_capacity = a.row_capacity();
+        let m = old_row_capacity + 1;
+        calls.clear();
+        a.resize_with(m, 5, |i, j| {
+            calls.push((i, j));
+            value(i, j)
+        });
+        assert!(a.row_capacity() > old_row_capacity);
+        assert!(a.col_stride() == a.row_capacity() as isize);
+        assert!(calls.len() == (m - 5) * 5);
+        assert!(calls.iter().all(|&(i, _)| i >= 5));
+        assert!(a.as_ref().submatrix(0, 0, 5, 3) == Mat::from_fn(5, 3, value));
+        assert!(a.as_ref().submatrix(0, 3, 5, 2) == Mat::from_fn(5, 2, |_, _| fill));
+
+        // shrinking then growing again only recomputes the erased elements
+        calls.clear();
+        a.resize_with(2, 2, |_, _| unreachable!());
+        a.resize_with(3, 3, |i, j| {
+            calls.push((i, j));
+            value(i, j)
+        });
+        assert!(calls.len() == 5);
+        assert!(a == Mat::from_fn(3, 3, value));
+    }
+
+    #[test]
+    fn test_resize_with_shrink() {
+        let value = |i: usize, j: usize| c64::new(i as f64, j as f64);
+        let mut a = Mat::<c64>::with_capacity_aligned(0, 0, 256);
+        a.resize_with_shrink(100, 100, value);
+        let capacity = a.row_capacity() * a.col_capacity();
+
+        // less than a factor of 4 doesn't reallocate
+        let ptr = a.as_ptr();
+        a.resize_with_shrink(60, 60, |_, _| unreachable!());
+        assert!(a.as_ptr() == ptr);
+        assert!(a.row_capacity() * a.col_capacity() == capacity);
+        a.resize_with_shrink(100, 100, value);
+        assert!(a.as_ptr() == ptr);
+
+        a.resize_with_shrink(40, 30, |_, _| unreachable!());
+        assert!(a.row_capacity() * a.col_capacity() < capacity / 4);
+        assert!(a.row_capacity() >= 40);
+        assert!(a.col_capacity() == 30);
+        assert!(a.as_ptr() as usize % 256 == 0);
+        assert!(a == Mat::from_fn(40, 30, value));
+
+        a.resize_with_shrink(0, 0, |_, _| unreachable!());
+        assert!(a.col_capacity() == 0);
+        a.resize_with_shrink(3, 3, value);
+        assert!(a == Mat::from_fn(3, 3, value));
+
+        // the row capacity is padded in the same way as when growing, so a matrix with few rows
+        // isn't shrunk right after it grows, and keeps its padded column stride
+        let mut padded = Mat::<f64>::new();
+        padded.reserve_exact(3, 5);
+        let mut b = Mat::<f64>::new();
+        b.resize_with_shrink(3, 5, |_, _| 1.0);
+        assert!(b.row_capacity() == padded.row_capacity());
+        b.resize_with_shrink(100, 100, |_, _| 1.0);
+        b.resize_with_shrink(3, 5, |_, _| unreachable!());
+        assert!(all(
+            b.row_capacity() == padded.row_capacity(),
+            b.col_capacity() == 5,
+            b.col_stride() as usize == padded.row_capacity(),
+        ));
+        assert!(b == Mat::<f64>::full(3, 5, 1.0));
+    }
+
+    #[test]
+    fn test_scale_rows_cols() {
+        let a = Mat::from_fn(3, 4, |i, j| (10 * i + j) as f64);
+        let d = crate::col![2.0, -1.0, 0.5];
+        let e = crate::row![1.0, 0.0, -3.0, 4.0];
+
+        let expected = Mat::from_diagonal(d.as_ref()) * &a;
+        assert!(a.scale_rows(d.as_ref()) == expected);
+        let expected = &a * Mat::from_diagonal(e.as_ref().transpose());
+        assert!(a.scale_cols(e.as_ref()) == expected);
+
+        let mut b = a.clone();
+        b.scale_rows_in_place(d.as_ref());
+        b.scale_cols_in_place(e.as_ref());
+        assert!(b == a.scale_rows(d.as_ref()).scale_cols(e.as_ref()));
+
+        // strided views
+        let mut b = a.transpose().to_owned();
+        b.as_mut().transpose_mut().scale_rows_in_place(d.as_ref());
+        assert!(b.transpose() == a.scale_rows(d.as_ref()));
+        assert!(a.transpose().scale_cols(d.as_ref().transpose()) == b);
+
+        let z = Mat::<f64>::zeros(0, 4);
+        assert!(z.scale_cols(e.as_ref()).nrows() == 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_rows_wrong_length() {
+        let a = Mat::<f64>::zeros(3, 4);
+        a.scale_rows(crate::Col::zeros(4).as_ref());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_cols_in_place_wrong_length() {
+        let mut a = Mat::<f64>::zeros(3, 4);
+        a.scale_cols_in_place(crate::Row::zeros(3).as_ref());
+    }
+
+    #[test]
+    fn test_lower_upper_triangle() {
+        use crate::linalg::zip::Diag;
+
+        for (m, n) in [(4, 4), (3, 5), (5, 3), (0, 2)] {
+            let a = Mat::from_fn(m, n, |i, j| (1 + 10 * i + j) as f64);
+            let l = a.lower_triangle(Diag::Include);
+            let sl = a.lower_triangle(Diag::Skip);
+            let u = a.upper_triangle(Diag::Include);
+            let su = a.upper_triangle(Diag::Skip);
+            for j in 0..n {
+                for i in 0..m {
+                    assert!(l.read(i, j) == if i >= j { a.read(i, j) } else { 0.0 });
+                    assert!(sl.read(i, j) == if i > j { a.read(i, j) } else { 0.0 });
+                    assert!(u.read(i, j) == if i <= j { a.read(i, j) } else { 0.0 });
+                    assert!(su.read(i, j) == if i < j { a.read(i, j) } else { 0.0 });
+                }
+            }
+            // L + D + U splitting
+            assert!(&sl + &u == a);
+            assert!(a.transpose().lower_triangle(Diag::Skip) == su.transpose());
+        }
+    }
+
+    #[test]
+    fn test_orthogonality_error() {
+        let a = Mat::from_fn(10, 4, |_, _| c64::new(rand::random(), rand::random()));
+        let q = a.qr().compute_thin_q();
+        assert!(q.orthogonality_error() < 1e-13);
+        assert!(q.is_unitary(1e-13));
+        assert!(q.as_ref().subcols(1, 2).is_unitary(1e-13));
+
+        let expected = (a.adjoint() * &a - Mat::<c64>::identity(4, 4)).norm_l2();
+        assert!((a.orthogonality_error() - expected).abs() < 1e-12 * expected);
+        assert!(!a.is_unitary(1e-2));
+
+        let a = Mat::from_fn(6, 6, |_, _| rand::random::<f64>());
+        let q = a.qr().compute_q();
+        assert!(q.is_orthogonal(1e-13));
+        assert!(q.transpose().is_orthogonal(1e-13));
+        assert!(!a.is_orthogonal(1e-2));
+
+        let mut q = q;
+        q.write(0, 0, q.read(0, 0) + 1e-6);
+        assert!(!q.as_mut().is_orthogonal(1e-10));
+        assert!(q.is_orthogonal(1e-5));
+
+        assert!(Mat::<f64>::zeros(3, 0).orthogonality_error() == 0.0);
+    }
+
+    #[test]
+    fn test_sort_each_col() {
+        let m = 17;
+        let n = 4;
+        // few distinct values, so that there are many ties
+        let a = Mat::from_fn(m, n, |_, _| (rand::random::<u8>() % 5) as f64);
+
+        for order in [SortOrder::Ascending, SortOrder::Descending] {
+            let mut sorted = a.clone();
+            sorted.sort_each_col(order);
+            let mut b = a.clone();
+            let indices = b.sort_each_col_with_indices(order);
+            assert!(b == sorted);
+
+            for j in 0..n {
+                let mut seen = alloc::vec![false; m];
+                for i in 0..m {
+                    let k = indices.read(i, j);
+                    assert!(!seen[k]);
+                    seen[k] = true;
+                    assert!(b.read(i, j) == a.read(k, j));
+                }
+                for i in 1..m {
+                    let (prev, next) = (b.read(i - 1, j), b.read(i, j));
+                    match order {
+                        SortOrder::Ascending => assert!(prev <= next),
+                        SortOrder::Descending => assert!(prev >= next),
+                    }
+                    // stability
+                    if prev == next {
+                        assert!(indices.read(i - 1, j) < indices.read(i, j));
+                    }
+                }
+            }
+        }
+
+        let mut a = crate::mat![[2.0], [f64::NAN], [-1.0], [2.0]];
+        let indices = a.as_mut().sort_each_col_with_indices(SortOrder::Descending);
+        assert!(a.read(0, 0) == 2.0);
+        assert!(a.read(1, 0) == 2.0);
+        assert!(a.read(2, 0) == -1.0);
+        assert!(a.read(3, 0).is_nan());
+        assert!(indices.col(0).try_as_slice() == Some(&[0, 3, 2, 1][..]));
+
+        let mut a = Mat::<f64>::zeros(0, 3);
+        assert!(a.sort_each_col_with_indices(SortOrder::Ascending).shape() == (0, 3));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = crate::mat![[1.0, 2.0, 1e-20], [3.0, 4.0, -5.0f64]];
+        let mut b = a.clone();
+        b.write(1, 1, 4.0 + 1e-12);
+        b.write(0, 2, 0.0);
+
+        assert!(a.approx_eq(&a, 0.0, 0.0));
+        assert!(a.approx_eq(&b, 1e-10, 1e-15));
+        // the relative tolerance alone can't absorb a difference with zero
+        assert!(!a.approx_eq(&b, 1e-10, 0.0));
+        assert!(!a.approx_eq(&b, 0.0, 1e-15));
+        assert!(!a.approx_eq(a.transpose(), 1.0, 1.0));
+        assert!(a.as_ref().approx_eq(b.as_mut(), 1e-10, 1e-15));
+
+        let inf = Mat::<f64>::from_fn(1, 1, |_, _| f64::INFINITY);
+        let nan = Mat::<f64>::from_fn(1, 1, |_, _| f64::NAN);
+        assert!(inf.approx_eq(&inf, 0.0, 0.0));
+        assert!(!nan.approx_eq(&nan, 1.0, 1.0));
+
+        assert!(a.col(2).approx_eq(b.col(2), 0.0, 1e-15));
+        assert!(!a.row(1).approx_eq(b.row(1), 0.0, 0.0));
+        assert!(!a.col(0).approx_eq(b.row(0).transpose(), 0.0, 0.0));
+
+        crate::assert_matrix_approx_eq!(a, b, 1e-10, 1e-15);
+        crate::assert_matrix_approx_eq!(a.as_ref(), &b, 1e-10, 1e-15);
+    }
+
+    #[test]
+    fn test_assert_matrix_approx_eq_message() {
+        extern crate std;
+        use std::{panic::catch_unwind, string::String};
+
+        let message = |f: fn()| {
+            let err = catch_unwind(f).unwrap_err();
+            err.downcast_ref::<String>().unwrap().clone()
+        };
+
+        let msg = message(|| {
+            let a = Mat::<f64>::zeros(2, 3);
+            let b = Mat::<f64>::from_fn(2, 3, |i, j| if j == 0 { 0.0 } else { (i + 1) as f64 });
+            crate::assert_matrix_approx_eq!(a, b, 0.0, 1.5);
+        });
+        assert!(msg.contains("a ~ b"));
+        assert!(msg.contains("2 out of 6 elements differ, the first one at (1, 1)"));
+        assert!(msg.contains("- a[(1, 1)] = 0.0"));
+        assert!(msg.contains("- b[(1, 1)] = 2.0"));
+
+        let msg = message(|| {
+            let a = Mat::<f64>::zeros(2, 3);
+            crate::assert_matrix_approx_eq!(a, a.transpose(), 0.0, 0.0);
+        });
+        assert!(msg.contains("- a has dimensions (2, 3)"));
+        assert!(msg.contains("- a.transpose() has dimensions (3, 2)"));
+    }
 }
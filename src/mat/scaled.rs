@@ -0,0 +1,97 @@
+use super::*;
+use crate::{unzipped, zipped_rw};
+
+/// Lazily scaled matrix view, returned when a borrowed matrix is multiplied by a
+/// [`Scale`](crate::Scale).
+///
+/// No computation happens until the value is used as an operand. Multiplying it by a matrix, a
+/// column or a row forwards the factor to [`matmul`](crate::linalg::matmul::matmul) as its scaling
+/// factor, so the scaled matrix is never stored. Adding it to or subtracting it from a matrix
+/// scales the elements during the same pass that computes the result.
+///
+/// # Example
+/// ```
+/// use faer::{mat, scale};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+/// let b = mat![[0.5, 0.0], [0.0, 0.5f64]];
+///
+/// // a single matrix multiplication, with a scaling factor of `2.0`
+/// let c = &a * scale(2.0) * &b;
+/// assert_eq!(c, mat![[1.0, 2.0], [3.0, 4.0f64]]);
+///
+/// // the scaled matrix can be materialized explicitly
+/// assert_eq!((&a * scale(2.0)).to_owned(), mat![[2.0, 4.0], [6.0, 8.0f64]]);
+/// ```
+#[derive(Copy, Clone)]
+pub struct ScaledMat<'a, E: Conjugate> {
+    mat: MatRef<'a, E>,
+    factor: E::Canonical,
+}
+
+impl<'a, E: Conjugate> ScaledMat<'a, E> {
+    /// Returns a view over `factor * mat`.
+    #[inline]
+    pub fn new(mat: MatRef<'a, E>, factor: E::Canonical) -> Self {
+        Self { mat, factor }
+    }
+
+    /// Returns the unscaled matrix view.
+    #[inline]
+    pub fn mat(&self) -> MatRef<'a, E> {
+        self.mat
+    }
+
+    /// Returns the scaling factor.
+    #[inline]
+    pub fn factor(&self) -> E::Canonical {
+        self.factor
+    }
+
+    /// Returns the number of rows of the matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.mat.nrows()
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.mat.ncols()
+    }
+
+    /// Returns an owning [`Mat`] containing the scaled matrix.
+    #[inline]
+    pub fn to_owned(&self) -> Mat<E::Canonical>
+    where
+        E::Canonical: ComplexField,
+    {
+        let factor = self.factor;
+        zipped_rw!(self.mat).map(|unzipped!(x)| x.read().canonicalize().faer_mul(factor))
+    }
+
+    // lets the operator macros treat `ScaledMat` like the other matrix operands
+    #[inline]
+    pub(crate) fn as_ref(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: Conjugate> From<ScaledMat<'_, E>> for Mat<E::Canonical>
+where
+    E::Canonical: ComplexField,
+{
+    #[inline]
+    fn from(value: ScaledMat<'_, E>) -> Self {
+        value.to_owned()
+    }
+}
+
+impl<E: Conjugate> core::fmt::Debug for ScaledMat<'_, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScaledMat")
+            .field("factor", &self.factor)
+            .field("mat", &self.mat)
+            .finish()
+    }
+}
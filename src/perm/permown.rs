@@ -105,3 +105,30 @@ impl<I: Index, N: Shape> Perm<I, N> {
         }
     }
 }
+
+impl<I: Index> Perm<I> {
+    /// Returns the permutation as an explicit dense matrix $P$.
+    ///
+    /// See [`PermRef::to_dense_matrix`].
+    pub fn to_dense_matrix<E: ComplexField>(&self) -> Mat<E> {
+        self.as_ref().to_dense_matrix()
+    }
+
+    /// Returns the composition of `self` and `other`.
+    ///
+    /// See [`PermRef::compose`].
+    #[inline]
+    #[track_caller]
+    pub fn compose(&self, other: PermRef<'_, I>) -> Perm<I> {
+        self.as_ref().compose(other)
+    }
+
+    /// Permutes the elements of `slice` in place.
+    ///
+    /// See [`PermRef::apply_to_slice`].
+    #[inline]
+    #[track_caller]
+    pub fn apply_to_slice<T>(&self, slice: &mut [T]) {
+        self.as_ref().apply_to_slice(slice)
+    }
+}
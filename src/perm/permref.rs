@@ -197,3 +197,129 @@ impl<'a, 'N, I: Index> PermRef<'a, I, Dim<'N>> {
         }
     }
 }
+
+impl<'a, I: Index> PermRef<'a, I> {
+    /// Returns the permutation as an explicit dense matrix $P$.
+    ///
+    /// Row `i` of $P$ has a single nonzero entry, equal to one, in column `forward[i]`. Multiplying
+    /// a matrix by $P$ on the left therefore computes the same result as `self * matrix`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use faer::{mat, perm::PermRef};
+    ///
+    /// let forward = [1usize, 2, 0];
+    /// let inverse = [2usize, 0, 1];
+    /// let perm = PermRef::new_checked(&forward, &inverse, 3);
+    ///
+    /// let p = perm.to_dense_matrix::<f64>();
+    /// assert_eq!(p, mat![[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]);
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    /// assert_eq!(&p * &a, perm * &a);
+    /// ```
+    pub fn to_dense_matrix<E: ComplexField>(&self) -> Mat<E> {
+        let n = self.len();
+        let mut mat = Mat::<E>::zeros(n, n);
+        for (i, &j) in self.forward.iter().enumerate() {
+            mat.write(i, j.zx(), E::faer_one());
+        }
+        mat
+    }
+
+    /// Returns an owned copy of the permutation.
+    #[inline]
+    pub fn to_owned(self) -> Perm<I> {
+        Perm {
+            forward: self.forward.into(),
+            inverse: self.inverse.into(),
+        }
+    }
+
+    /// Returns the composition of `self` and `other`, i.e. the permutation whose matrix is the
+    /// product $PQ$ of their matrices.
+    ///
+    /// Applying the result to a matrix is equivalent to applying `other` first, then `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` don't have the same dimension.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use faer::{mat, perm::PermRef};
+    ///
+    /// let p = PermRef::new_checked(&[1usize, 2, 0], &[2usize, 0, 1], 3);
+    /// let q = PermRef::new_checked(&[0usize, 2, 1], &[0usize, 2, 1], 3);
+    ///
+    /// let a = mat![[1.0], [2.0], [3.0]];
+    /// assert_eq!(p.compose(q) * &a, p * (q * &a));
+    /// assert_eq!(p.compose(p.inverse()), PermRef::new_checked(&[0, 1, 2], &[0, 1, 2], 3));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn compose(self, other: PermRef<'_, I>) -> Perm<I> {
+        let n = self.len();
+        assert!(other.len() == n);
+        let mut forward = alloc::vec![I::truncate(0); n].into_boxed_slice();
+        let mut inverse = alloc::vec![I::truncate(0); n].into_boxed_slice();
+
+        // row i of the product is row self[i] of other, whose nonzero entry is in column
+        // other[self[i]]
+        for (fwd, &p) in forward.iter_mut().zip(self.forward) {
+            *fwd = other.forward[p.zx()];
+        }
+        for (i, fwd) in forward.iter().enumerate() {
+            inverse[fwd.zx()] = I::truncate(i);
+        }
+
+        Perm { forward, inverse }
+    }
+
+    /// Permutes the elements of `slice` in place, such that the element at index `i` after the
+    /// call is the element that was at index `forward[i]` before it.
+    ///
+    /// This is the same as multiplying the permutation matrix by the slice viewed as a column.
+    ///
+    /// # Panics
+    /// Panics if the length of `slice` doesn't match the dimension of the permutation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use faer::perm::PermRef;
+    ///
+    /// let p = PermRef::new_checked(&[1usize, 2, 0], &[2usize, 0, 1], 3);
+    /// let mut x = ['a', 'b', 'c'];
+    ///
+    /// p.apply_to_slice(&mut x);
+    /// assert_eq!(x, ['b', 'c', 'a']);
+    /// p.inverse().apply_to_slice(&mut x);
+    /// assert_eq!(x, ['a', 'b', 'c']);
+    /// ```
+    #[track_caller]
+    pub fn apply_to_slice<T>(&self, slice: &mut [T]) {
+        let n = self.len();
+        assert!(slice.len() == n);
+
+        // follow each cycle once, swapping the elements into place along the way
+        let mut visited = alloc::vec![false; n];
+        for start in 0..n {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut i = start;
+            loop {
+                let j = self.forward[i].zx();
+                if j == start {
+                    break;
+                }
+                slice.swap(i, j);
+                visited[j] = true;
+                i = j;
+            }
+        }
+    }
+}
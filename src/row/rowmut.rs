@@ -71,6 +71,8 @@ impl<'a, E: Entity, C: Shape> IntoConst for RowMut<'a, E, C> {
 impl<'a, E: Entity, C: Shape> RowMut<'a, E, C> {
     #[inline]
     pub(crate) unsafe fn __from_raw_parts(ptr: PtrMut<E>, ncols: C, col_stride: isize) -> Self {
+        #[cfg(debug_assertions)]
+        crate::utils::assert_strided_offsets_fit(1, ncols.unbound(), 0, col_stride);
         Self {
             inner: VecImpl {
                 ptr: into_copy::<E, _>(E::faer_map(
@@ -726,6 +728,21 @@ impl<'a, E: Entity, C: Shape> RowMut<'a, E, C> {
         (*self).rb().as_2d().is_all_finite()
     }
 
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`](crate::mat::MatRef::approx_eq).
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsRowRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        let other = other.as_row_ref();
+        self.as_ref()
+            .as_2d()
+            .approx_eq(other.as_2d(), rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -1025,12 +1042,16 @@ impl<'a, E: Entity, C: Shape> RowMut<'a, E, C> {
 /// # Safety:
 /// This function has the same safety requirements as
 /// [`mat::from_raw_parts_mut(ptr, 1, ncols, 0, col_stride)`]
+///
+/// # Panics
+/// The function panics if `(ncols - 1) * |col_stride| > isize::MAX`.
 #[inline(always)]
 pub unsafe fn from_raw_parts_mut<'a, E: Entity, C: Shape>(
     ptr: PtrMut<E>,
     ncols: C,
     col_stride: isize,
 ) -> RowMut<'a, E, C> {
+    crate::utils::assert_strided_offsets_fit(1, ncols.unbound(), 0, col_stride);
     RowMut::__from_raw_parts(ptr, ncols, col_stride)
 }
 
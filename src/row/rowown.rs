@@ -34,6 +34,28 @@ impl<E: Entity> Row<E> {
             inner: Col::with_capacity(col_capacity),
         }
     }
+
+    /// Returns a new row made up of `n` copies of `self`, stacked horizontally.
+    ///
+    /// See [`RowRef::repeat`].
+    #[track_caller]
+    pub fn repeat(&self, n: usize) -> Row<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().repeat(n)
+    }
+
+    /// Returns a new matrix with `nrows` rows, each of which is a copy of `self`.
+    ///
+    /// See [`RowRef::tile_into_mat`].
+    #[track_caller]
+    pub fn tile_into_mat(&self, nrows: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().tile_into_mat(nrows)
+    }
 }
 impl<E: Entity, C: Shape> Row<E, C> {
     /// Returns the input matrix with the given shape after checking that it matches the
@@ -305,6 +327,8 @@ impl<E: Entity, C: Shape> Row<E, C> {
     /// Reserves the minimum capacity for `col_capacity` columns without reallocating. Does nothing
     /// if the capacity is already sufficient.
     ///
+    /// See [`Self::reserve_exact_reporting`] for the growth strategy.
+    ///
     /// # Panics
     /// The function panics if the new total capacity in bytes exceeds `isize::MAX`.
     #[inline]
@@ -312,6 +336,22 @@ impl<E: Entity, C: Shape> Row<E, C> {
         self.inner.reserve_exact(col_capacity);
     }
 
+    /// Reserves the minimum capacity for `col_capacity` columns without reallocating. Does nothing
+    /// if the capacity is already sufficient.
+    ///
+    /// Returns `true` if the vector was reallocated, in which case its elements were moved to the
+    /// new allocation, or `false` if the existing capacity was reused.
+    ///
+    /// No amortized over-allocation is performed: the capacity grows to `col_capacity`, rounded up
+    /// to a multiple of the SIMD alignment of the element type.
+    ///
+    /// # Panics
+    /// The function panics if the new total capacity in bytes exceeds `isize::MAX`.
+    #[inline]
+    pub fn reserve_exact_reporting(&mut self, col_capacity: usize) -> bool {
+        self.inner.reserve_exact_reporting(col_capacity)
+    }
+
     /// Resizes the vector in-place so that the new number of columns is `new_ncols`.
     /// New elements are created with the given function `f`, so that elements at index `i`
     /// are created by calling `f(i)`.
@@ -714,6 +754,21 @@ impl<E: Entity, C: Shape> Row<E, C> {
         self.as_ref().is_all_finite()
     }
 
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`](crate::mat::MatRef::approx_eq).
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsRowRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        let other = other.as_row_ref();
+        self.as_ref()
+            .as_2d()
+            .approx_eq(other.as_2d(), rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -67,6 +67,8 @@ impl<E: Entity, C: Shape> IntoConst for RowRef<'_, E, C> {
 
 impl<'a, E: Entity, C: Shape> RowRef<'a, E, C> {
     pub(crate) unsafe fn __from_raw_parts(ptr: PtrConst<E>, ncols: C, col_stride: isize) -> Self {
+        #[cfg(debug_assertions)]
+        crate::utils::assert_strided_offsets_fit(1, ncols.unbound(), 0, col_stride);
         Self {
             inner: VecImpl {
                 ptr: into_copy::<E, _>(E::faer_map(
@@ -471,6 +473,25 @@ impl<'a, E: Entity, C: Shape> RowRef<'a, E, C> {
         )
     }
 
+    /// Returns a new row whose elements are computed by applying the vectorized kernel `f` to
+    /// the elements of `self`.
+    ///
+    /// See [`SimdMap`](crate::utils::simd::SimdMap) for the requirements on the kernel.
+    #[inline]
+    pub fn map_simd(&self, f: impl crate::utils::simd::SimdMap<E>) -> Row<E, C>
+    where
+        E: ComplexField,
+    {
+        let mut f = f;
+        let mut out = Row::<E, C>::zeros(self.ncols());
+        crate::utils::simd::map_simd_col(
+            out.as_dyn_mut().transpose_mut(),
+            self.as_dyn().transpose(),
+            &mut f,
+        );
+        out
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -489,6 +510,21 @@ impl<'a, E: Entity, C: Shape> RowRef<'a, E, C> {
         (*self).rb().as_2d().is_all_finite()
     }
 
+    /// Returns `true` if `self` and `other` have the same dimensions, and every pair of elements
+    /// `a` and `b` at the same indices satisfies `|a - b| <= abs_tol + rel_tol * max(|a|, |b|)`.
+    ///
+    /// See [`MatRef::approx_eq`](crate::mat::MatRef::approx_eq).
+    #[inline]
+    pub fn approx_eq(&self, other: impl AsRowRef<E>, rel_tol: E::Real, abs_tol: E::Real) -> bool
+    where
+        E: ComplexField,
+    {
+        let other = other.as_row_ref();
+        self.as_ref()
+            .as_2d()
+            .approx_eq(other.as_2d(), rel_tol, abs_tol)
+    }
+
     /// Returns the maximum norm of `self`.
     #[inline]
     pub fn norm_max(&self) -> E::Real
@@ -682,12 +718,16 @@ impl<'a, E: Entity, C: Shape> RowRef<'a, E, C> {
 /// # Safety:
 /// This function has the same safety requirements as
 /// [`mat::from_raw_parts(ptr, 1, ncols, 0, col_stride)`]
+///
+/// # Panics
+/// The function panics if `(ncols - 1) * |col_stride| > isize::MAX`.
 #[inline(always)]
 pub unsafe fn from_raw_parts<'a, E: Entity, C: Shape>(
     ptr: PtrConst<E>,
     ncols: C,
     col_stride: isize,
 ) -> RowRef<'a, E, C> {
+    crate::utils::assert_strided_offsets_fit(1, ncols.unbound(), 0, col_stride);
     RowRef::__from_raw_parts(ptr, ncols, col_stride)
 }
 
@@ -717,6 +757,98 @@ pub fn from_slice<E: SimpleEntity>(slice: &[E]) -> RowRef<'_, E> {
     from_slice_generic(slice)
 }
 
+impl<'a, E: Entity> RowRef<'a, E> {
+    /// Returns a new row made up of the elements of `pieces`, stacked horizontally in order.
+    ///
+    /// # Panics
+    /// The function panics if the total number of columns overflows `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{row, row::RowRef};
+    ///
+    /// let a = row![1.0, 2.0];
+    /// let b = row![3.0];
+    /// let c = RowRef::concat(&[a.as_ref(), b.as_ref(), a.as_ref()]);
+    ///
+    /// assert_eq!(c, row![1.0, 2.0, 3.0, 1.0, 2.0]);
+    /// ```
+    #[track_caller]
+    pub fn concat(pieces: &[RowRef<'_, E>]) -> Row<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let mut ncols = 0usize;
+        for piece in pieces {
+            ncols = ncols
+                .checked_add(piece.ncols())
+                .expect("the total number of columns must fit in a usize");
+        }
+
+        let mut out = Row::<E::Canonical>::zeros(ncols);
+        let mut start = 0;
+        for piece in pieces {
+            out.as_mut()
+                .subcols_mut(start, piece.ncols())
+                .copy_from(piece);
+            start += piece.ncols();
+        }
+        out
+    }
+
+    /// Returns a new row made up of `n` copies of `self`, stacked horizontally.
+    ///
+    /// # Panics
+    /// The function panics if the total number of columns overflows `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::row;
+    ///
+    /// let a = row![1.0, 2.0];
+    ///
+    /// assert_eq!(a.as_ref().repeat(3), row![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+    /// ```
+    #[track_caller]
+    pub fn repeat(self, n: usize) -> Row<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let len = self.ncols();
+        let ncols = len
+            .checked_mul(n)
+            .expect("the total number of columns must fit in a usize");
+
+        let mut out = Row::<E::Canonical>::zeros(ncols);
+        for k in 0..n {
+            out.as_mut().subcols_mut(k * len, len).copy_from(self);
+        }
+        out
+    }
+
+    /// Returns a new matrix with `nrows` rows, each of which is a copy of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, row};
+    ///
+    /// let a = row![1.0, 2.0];
+    ///
+    /// assert_eq!(a.as_ref().tile_into_mat(2), mat![[1.0, 2.0], [1.0, 2.0]]);
+    /// ```
+    #[track_caller]
+    pub fn tile_into_mat(self, nrows: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let mut out = Mat::<E::Canonical>::zeros(nrows, self.ncols());
+        for i in 0..nrows {
+            out.as_mut().row_mut(i).copy_from(self);
+        }
+        out
+    }
+}
+
 impl<E: Entity, C: Shape> As2D<E> for RowRef<'_, E, C> {
     #[inline]
     fn as_2d_ref(&self) -> MatRef<'_, E> {
@@ -389,9 +389,12 @@ impl<'a, I: Index, E: Entity, R: Shape, C: Shape> SparseColMatMut<'a, I, E, R, C
         self.symbolic.col_range_unchecked(j)
     }
 
-    /// Returns a reference to the value at the given index, or None if the symbolic structure
+    /// Returns a reference to the value at the given index, or `None` if the symbolic structure
     /// doesn't contain it, or contains multiple indices with the given index.
     ///
+    /// Indices outside the matrix dimensions are not reported through `None`, and cause a panic
+    /// instead.
+    ///
     /// # Panics
     /// Panics if `row >= self.nrows()`  
     /// Panics if `col >= self.ncols()`  
@@ -400,9 +403,12 @@ impl<'a, I: Index, E: Entity, R: Shape, C: Shape> SparseColMatMut<'a, I, E, R, C
         self.into_const().get(row, col)
     }
 
-    /// Returns a reference to the value at the given index, or None if the symbolic structure
+    /// Returns a reference to the value at the given index, or `None` if the symbolic structure
     /// doesn't contain it, or contains multiple values with the given index.
     ///
+    /// Indices outside the matrix dimensions are not reported through `None`, and cause a panic
+    /// instead.
+    ///
     /// # Panics
     /// Panics if `row >= self.nrows()`.  
     /// Panics if `col >= self.ncols()`.  
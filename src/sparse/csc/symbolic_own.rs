@@ -246,6 +246,15 @@ impl<I: Index, R: Shape, C: Shape> SymbolicSparseColMat<I, R, C> {
         &self.row_ind
     }
 
+    /// Returns the number of bytes occupied by the index arrays of the matrix.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        let len = self.col_ptr.len()
+            + self.col_nnz.as_ref().map(|nnz| nnz.len()).unwrap_or(0)
+            + self.row_ind.len();
+        len * core::mem::size_of::<I>()
+    }
+
     /// Returns the row indices of column `j`.
     ///
     /// # Panics
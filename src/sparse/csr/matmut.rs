@@ -386,9 +386,12 @@ impl<'a, I: Index, E: Entity, R: Shape, C: Shape> SparseRowMatMut<'a, I, E, R, C
         self.symbolic.row_range_unchecked(i)
     }
 
-    /// Returns a reference to the value at the given index, or None if the symbolic structure
+    /// Returns a reference to the value at the given index, or `None` if the symbolic structure
     /// doesn't contain it, or contains multiple values with the given index.
     ///
+    /// Indices outside the matrix dimensions are not reported through `None`, and cause a panic
+    /// instead.
+    ///
     /// # Panics
     /// Panics if `row >= self.nrows()`.  
     /// Panics if `col >= self.ncols()`.  
@@ -397,9 +400,12 @@ impl<'a, I: Index, E: Entity, R: Shape, C: Shape> SparseRowMatMut<'a, I, E, R, C
         self.into_const().get(row, col)
     }
 
-    /// Returns a reference to the value at the given index, or None if the symbolic structure
+    /// Returns a reference to the value at the given index, or `None` if the symbolic structure
     /// doesn't contain it, or contains multiple values with the given index.
     ///
+    /// Indices outside the matrix dimensions are not reported through `None`, and cause a panic
+    /// instead.
+    ///
     /// # Panics
     /// Panics if `row >= self.nrows()`  
     /// Panics if `col >= self.ncols()`  
@@ -326,6 +326,24 @@ impl<I: Index, E: Entity, R: Shape, C: Shape> SparseRowMat<I, E, R, C> {
         self.symbolic.col_indices()
     }
 
+    /// Returns the number of bytes occupied by the index arrays of the matrix.
+    #[inline]
+    pub fn index_byte_size(&self) -> usize {
+        self.symbolic.byte_size()
+    }
+
+    /// Returns the number of bytes occupied by the values of the matrix.
+    #[inline]
+    pub fn value_byte_size(&self) -> usize {
+        self.values.len() * core::mem::size_of::<E>()
+    }
+
+    /// Returns the number of bytes occupied by the index arrays and the values of the matrix.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.index_byte_size() + self.value_byte_size()
+    }
+
     /// Returns the column indices of row i.
     ///
     /// # Panics
@@ -401,9 +419,12 @@ impl<I: Index, E: Entity, R: Shape, C: Shape> SparseRowMat<I, E, R, C> {
         self.symbolic.row_range_unchecked(i)
     }
 
-    /// Returns a reference to the value at the given index, or None if the symbolic structure
+    /// Returns a reference to the value at the given index, or `None` if the symbolic structure
     /// doesn't contain it, or contains multiple values with the given index.
     ///
+    /// Indices outside the matrix dimensions are not reported through `None`, and cause a panic
+    /// instead.
+    ///
     /// # Panics
     /// Panics if `row >= self.nrows()`.  
     /// Panics if `col >= self.ncols()`.  
@@ -412,9 +433,12 @@ impl<I: Index, E: Entity, R: Shape, C: Shape> SparseRowMat<I, E, R, C> {
         self.as_ref().get(row, col)
     }
 
-    /// Returns a reference to the value at the given index, or None if the symbolic structure
+    /// Returns a reference to the value at the given index, or `None` if the symbolic structure
     /// doesn't contain it, or contains multiple values with the given index.
     ///
+    /// Indices outside the matrix dimensions are not reported through `None`, and cause a panic
+    /// instead.
+    ///
     /// # Panics
     /// Panics if `row >= self.nrows()`.  
     /// Panics if `col >= self.ncols()`.  
@@ -246,6 +246,15 @@ impl<I: Index, R: Shape, C: Shape> SymbolicSparseRowMat<I, R, C> {
         &self.col_ind
     }
 
+    /// Returns the number of bytes occupied by the index arrays of the matrix.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        let len = self.row_ptr.len()
+            + self.row_nnz.as_ref().map(|nnz| nnz.len()).unwrap_or(0)
+            + self.col_ind.len();
+        len * core::mem::size_of::<I>()
+    }
+
     /// Returns the column indices of row `i`.
     ///
     /// # Panics
@@ -528,10 +528,10 @@ use super::{
     ghost, ghost_permute_hermitian_unsorted, ghost_permute_hermitian_unsorted_symbolic,
     make_raw_req, mem,
     mem::NONE,
-    nomem, triangular_solve, try_collect, try_zeroed,
+    nomem, triangular_solve, try_collect, try_zeroed, try_zeroed_in,
     utils::ghost_adjoint,
-    windows2, FaerError, Index, PermRef, Side, SliceGroup, SliceGroupMut, SparseColMatRef,
-    SupernodalThreshold, SymbolicSparseColMatRef, SymbolicSupernodalParams,
+    windows2, FactorizationVariant, FaerError, Index, PermRef, Side, SliceGroup, SliceGroupMut,
+    SparseColMatRef, SupernodalThreshold, SymbolicSparseColMatRef, SymbolicSupernodalParams,
 };
 pub use crate::linalg::cholesky::{
     bunch_kaufman::compute::BunchKaufmanRegularization,
@@ -543,7 +543,7 @@ use crate::{
     linalg::{temp_mat_req, temp_mat_uninit},
     unzipped,
     utils::bound::{Array, Dim, Idx, MaybeIdx},
-    zipped_rw, ComplexField, Conj, Entity, MatMut, MatRef, Parallelism, SignedIndex,
+    zipped_rw, Col, ComplexField, Conj, Entity, MatMut, MatRef, Parallelism, SignedIndex,
 };
 use core::{cell::Cell, iter::zip};
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
@@ -1357,6 +1357,13 @@ pub mod simplicial {
             &self.row_indices
         }
 
+        /// Returns the number of bytes occupied by the index arrays of the symbolic structure.
+        #[inline]
+        pub fn byte_size(&self) -> usize {
+            (self.col_ptrs.len() + self.row_indices.len() + self.etree.len())
+                * core::mem::size_of::<I>()
+        }
+
         /// Returns the Cholesky factor's symbolic structure.
         #[inline]
         pub fn factor(&self) -> SymbolicSparseColMatRef<'_, I> {
@@ -1862,6 +1869,10 @@ pub mod supernodal {
         /// Solves the equation $\text{Op}(A) x = \text{rhs}$ and stores the result in `rhs`, where
         /// $\text{Op}$ is either the identity or the conjugate, depending on the value of `conj`.
         ///
+        /// All the columns of `rhs` are processed together: each supernode is applied to the whole
+        /// block of right-hand sides with a dense triangular solve and matrix multiplication, which
+        /// is faster than solving with each column separately.
+        ///
         /// # Panics
         /// Panics if `rhs.nrows() != self.symbolic().nrows()`.
         pub fn solve_in_place_with_conj(
@@ -2136,6 +2147,10 @@ pub mod supernodal {
         /// Solves the equation $\text{Op}(A) x = \text{rhs}$ and stores the result in `rhs`, where
         /// $\text{Op}$ is either the identity or the conjugate, depending on the value of `conj`.
         ///
+        /// All the columns of `rhs` are processed together: each supernode is applied to the whole
+        /// block of right-hand sides with a dense triangular solve and matrix multiplication, which
+        /// is faster than solving with each column separately.
+        ///
         /// # Panics
         /// Panics if `rhs.nrows() != self.symbolic().nrows()`.
         pub fn solve_in_place_with_conj(
@@ -2247,6 +2262,20 @@ pub mod supernodal {
             self.col_ptrs_for_values()[self.n_supernodes()].zx()
         }
 
+        /// Returns the number of bytes occupied by the index arrays of the symbolic structure.
+        #[inline]
+        pub fn byte_size(&self) -> usize {
+            let len = self.supernode_postorder.len()
+                + self.supernode_postorder_inv.len()
+                + self.descendant_count.len()
+                + self.supernode_begin.len()
+                + self.col_ptrs_for_row_indices.len()
+                + self.col_ptrs_for_values.len()
+                + self.row_indices.len()
+                + self.nnz_per_super.as_ref().map(|v| v.len()).unwrap_or(0);
+            len * core::mem::size_of::<I>()
+        }
+
         /// Returns a slice of length `self.n_supernodes()` containing the beginning index of each
         /// supernode.
         #[inline]
@@ -2979,6 +3008,265 @@ pub mod supernodal {
         move |&i| i < idx
     }
 
+    /// Estimated cost of factorizing the supernode `s`, and of the updates it contributes to its
+    /// ancestors.
+    fn supernode_cost_estimate<I: Index>(
+        symbolic: &SymbolicSupernodalCholesky<I>,
+        s: usize,
+    ) -> f64 {
+        let model = ComputationModel::OPENBLAS_I7_1185G7;
+        let ncols =
+            (symbolic.supernode_begin[s + 1].zx() - symbolic.supernode_begin[s].zx()) as f64;
+        let m = (symbolic.col_ptrs_for_row_indices[s + 1].zx()
+            - symbolic.col_ptrs_for_row_indices[s].zx()) as f64;
+
+        model.ldl_estimate(ncols)
+            + model.triangular_solve_estimate(ncols, m)
+            + model.matmul_estimate(m, m, ncols)
+            + model.assembly_estimate(m, m)
+    }
+
+    /// Assignment of the supernodes to threads for a parallel numeric factorization.
+    struct SupernodeSchedule {
+        /// Ranges of postordered supernodes forming independent subtrees of the assembly tree,
+        /// for each thread.
+        subtrees: alloc::vec::Vec<alloc::vec::Vec<core::ops::Range<usize>>>,
+        /// Postordered supernodes that are not part of any subtree. They are processed after the
+        /// subtrees, in postorder.
+        top: alloc::vec::Vec<usize>,
+    }
+
+    /// Splits the assembly tree into independent subtrees, and assigns them to `n_threads` threads
+    /// so that the estimated costs are balanced. Returns `None` if the tree can't be split.
+    fn supernode_schedule<I: Index>(
+        symbolic: &SymbolicSupernodalCholesky<I>,
+        n_threads: usize,
+    ) -> Option<SupernodeSchedule> {
+        let n_supernodes = symbolic.n_supernodes();
+        let post = &*symbolic.supernode_postorder;
+        let post_inv = &*symbolic.supernode_postorder_inv;
+        let desc_count = &*symbolic.descendant_count;
+
+        // prefix sums of the costs in postorder, so that the cost of a subtree is the difference
+        // between its endpoints
+        let mut cost_prefix = alloc::vec![0.0f64; n_supernodes + 1];
+        for (i, &s) in post.iter().enumerate() {
+            cost_prefix[i + 1] = cost_prefix[i] + supernode_cost_estimate(symbolic, s.zx());
+        }
+        let subtree = |s: usize| {
+            let s_postordered = post_inv[s].zx();
+            s_postordered - desc_count[s].zx()..s_postordered + 1
+        };
+        let cost =
+            |range: &core::ops::Range<usize>| cost_prefix[range.end] - cost_prefix[range.start];
+
+        // the roots (resp. children) of a range of postordered subtrees are found by walking it
+        // backwards, skipping over the descendants of each root
+        let push_roots = |subtrees: &mut alloc::vec::Vec<core::ops::Range<usize>>,
+                          range: core::ops::Range<usize>| {
+            let mut end = range.end;
+            while end > range.start {
+                let root = subtree(post[end - 1].zx());
+                end = root.start;
+                subtrees.push(root);
+            }
+        };
+
+        let mut subtrees = alloc::vec::Vec::new();
+        push_roots(&mut subtrees, 0..n_supernodes);
+
+        // repeatedly split the most expensive subtree, moving its root to the sequential part,
+        // until all of them are small enough to be balanced between the threads
+        let target = cost_prefix[n_supernodes] / (4 * n_threads) as f64;
+        let mut top = alloc::vec::Vec::new();
+        while let Some((idx, largest)) = subtrees
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| cost(a).total_cmp(&cost(b)))
+        {
+            if cost(largest) <= target || largest.len() == 1 {
+                break;
+            }
+            let largest = subtrees.swap_remove(idx);
+            top.push(largest.end - 1);
+            push_roots(&mut subtrees, largest.start..largest.end - 1);
+        }
+
+        if subtrees.len() < 2 {
+            return None;
+        }
+
+        // longest processing time first
+        subtrees.sort_unstable_by(|a, b| cost(b).total_cmp(&cost(a)));
+        let mut load = alloc::vec![0.0f64; n_threads];
+        let mut assigned = alloc::vec![alloc::vec::Vec::new(); n_threads];
+        for range in subtrees {
+            let tid = (0..n_threads)
+                .min_by(|&a, &b| load[a].total_cmp(&load[b]))
+                .unwrap();
+            load[tid] += cost(&range);
+            assigned[tid].push(range);
+        }
+        top.sort_unstable();
+
+        Some(SupernodeSchedule {
+            subtrees: assigned,
+            top,
+        })
+    }
+
+    /// Values of the supernodal factor that can be shared between threads that access disjoint
+    /// supernodes.
+    struct SyncValues<'a, E: Entity>(GroupFor<E, &'a [core::cell::UnsafeCell<E::Unit>]>);
+    unsafe impl<E: Entity> Sync for SyncValues<'_, E> {}
+
+    impl<'a, E: Entity> SyncValues<'a, E> {
+        fn new(values: SliceGroupMut<'a, E>) -> Self {
+            Self(E::faer_map(values.into_inner(), |slice| {
+                let len = slice.len();
+                unsafe {
+                    core::slice::from_raw_parts(
+                        slice.as_mut_ptr() as *const core::cell::UnsafeCell<E::Unit>,
+                        len,
+                    )
+                }
+            }))
+        }
+
+        /// # Safety
+        /// The values in `range` must not be mutated concurrently.
+        unsafe fn get(&self, range: core::ops::Range<usize>) -> GroupFor<E, &[E::Unit]> {
+            E::faer_map(E::faer_as_ref(&self.0), |cells| {
+                let cells = &cells[range.clone()];
+                core::slice::from_raw_parts(cells.as_ptr() as *const E::Unit, cells.len())
+            })
+        }
+
+        /// # Safety
+        /// The values in `range` must not be accessed concurrently.
+        #[allow(clippy::mut_from_ref)]
+        unsafe fn get_mut(&self, range: core::ops::Range<usize>) -> GroupFor<E, &mut [E::Unit]> {
+            E::faer_map(E::faer_as_ref(&self.0), |cells| {
+                let cells = &cells[range.clone()];
+                core::slice::from_raw_parts_mut(cells.as_ptr() as *mut E::Unit, cells.len())
+            })
+        }
+    }
+
+    /// Returns the workspace required by [`for_each_supernode`], given the workspace `inner`
+    /// required by the factorization of a single supernode with a given parallelism.
+    fn for_each_supernode_req<I: Index>(
+        n: usize,
+        parallelism: Parallelism,
+        inner: impl Fn(Parallelism) -> Result<StackReq, SizeOverflow>,
+    ) -> Result<StackReq, SizeOverflow> {
+        let global_to_local = StackReq::try_new::<I>(n)?;
+        let req = inner(parallelism)?.try_and(global_to_local)?;
+
+        let n_threads = crate::utils::thread::parallelism_degree(parallelism);
+        if n_threads <= 1 {
+            return Ok(req);
+        }
+        let thread_req = inner(Parallelism::None)?
+            .try_and(global_to_local)?
+            .try_unaligned_bytes_required()?;
+        req.try_or(StackReq::try_new::<u8>(
+            thread_req.checked_mul(n_threads).ok_or(SizeOverflow)?,
+        )?)
+    }
+
+    /// Calls `f` on every supernode such that the descendants of a supernode are processed before
+    /// it, and returns the sum of the returned values, or the smallest error.
+    ///
+    /// If `parallelism` allows more than one thread, independent subtrees of the assembly tree
+    /// are processed concurrently, each one sequentially on a single thread. The remaining
+    /// supernodes close to the roots are then processed with the full parallelism.
+    ///
+    /// `f` receives a mapping from global to local row indices that is filled with `NONE`, and
+    /// must restore it before returning successfully.
+    fn for_each_supernode<I: Index, Err: Copy + Ord + Send>(
+        symbolic: &SymbolicSupernodalCholesky<I>,
+        parallelism: Parallelism,
+        stack: &mut PodStack,
+        f: &(dyn Sync
+              + Fn(usize, &mut [I::Signed], Parallelism, &mut PodStack) -> Result<usize, Err>),
+    ) -> Result<usize, Err> {
+        let n = symbolic.nrows();
+        let n_threads = crate::utils::thread::parallelism_degree(parallelism);
+        let post = &*symbolic.supernode_postorder;
+
+        let sequential = |supernodes: &mut dyn Iterator<Item = usize>,
+                          stack: &mut PodStack|
+         -> Result<usize, Err> {
+            // mapping from global indices to local
+            let (global_to_local, mut stack) = stack.make_raw::<I::Signed>(n);
+            mem::fill_none(global_to_local);
+            let mut count = 0usize;
+            for s in supernodes {
+                count += f(s, global_to_local, parallelism, stack.rb_mut())?;
+            }
+            Ok(count)
+        };
+
+        let schedule = if n_threads > 1 {
+            supernode_schedule(symbolic, n_threads)
+        } else {
+            None
+        };
+        let Some(schedule) = schedule else {
+            return sequential(&mut (0..symbolic.n_supernodes()), stack);
+        };
+
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        let count = AtomicUsize::new(0);
+        // each thread stores the error it stopped at, if any, in its own slot
+        let mut errors = alloc::vec![None::<Err>; n_threads];
+        {
+            let errors = crate::utils::thread::Ptr(errors.as_mut_ptr());
+            // the workspace is split evenly between the threads, and each part is at least as
+            // large as the requirement for a single thread
+            let chunk = stack.len_bytes() / n_threads;
+            let (mem, _) = stack.make_raw::<u8>(chunk * n_threads);
+            let mem = crate::utils::thread::Ptr(mem.as_mut_ptr());
+
+            crate::utils::thread::for_each_raw(
+                n_threads,
+                |tid| {
+                    let mem = unsafe {
+                        core::slice::from_raw_parts_mut({ mem }.0.add(tid * chunk), chunk)
+                    };
+                    let stack = PodStack::new(mem);
+                    let (global_to_local, mut stack) = stack.make_raw::<I::Signed>(n);
+                    mem::fill_none(global_to_local);
+
+                    let mut thread_count = 0usize;
+                    for range in &schedule.subtrees[tid] {
+                        for &s in &post[range.clone()] {
+                            match f(s.zx(), global_to_local, Parallelism::None, stack.rb_mut()) {
+                                Ok(c) => thread_count += c,
+                                Err(err) => {
+                                    unsafe { *{ errors }.0.add(tid) = Some(err) };
+                                    count.fetch_add(thread_count, Ordering::Relaxed);
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                    count.fetch_add(thread_count, Ordering::Relaxed);
+                },
+                parallelism,
+            );
+        }
+
+        if let Some(err) = errors.into_iter().flatten().min() {
+            return Err(err);
+        }
+        Ok(
+            count.into_inner()
+                + sequential(&mut schedule.top.iter().map(|&s| post[s].zx()), stack)?,
+        )
+    }
+
     /// Returns the size and alignment of the workspace required to compute the numeric
     /// Cholesky LLT factorization of a matrix `A` with dimension `n`.
     pub fn factorize_supernodal_numeric_llt_req<I: Index, E: Entity>(
@@ -2995,39 +3283,41 @@ pub mod supernodal {
         let col_ptr_row = &*symbolic.col_ptrs_for_row_indices;
         let row_ind = &*symbolic.row_indices;
 
-        let mut req = StackReq::empty();
-        for s in 0..n_supernodes {
-            let s_start = symbolic.supernode_begin[s].zx();
-            let s_end = symbolic.supernode_begin[s + 1].zx();
-
-            let s_ncols = s_end - s_start;
-
-            let s_postordered = post_inv[s].zx();
-            let desc_count = desc_count[s].zx();
-            for d in &post[s_postordered - desc_count..s_postordered] {
-                let mut d_req = StackReq::empty();
-                let d = d.zx();
-
-                let d_pattern = &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()];
-                let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
-                let d_pattern_mid_len =
-                    d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
-
-                d_req = d_req.try_and(temp_mat_req::<E>(
-                    d_pattern.len() - d_pattern_start,
-                    d_pattern_mid_len,
-                )?)?;
-                req = req.try_or(d_req)?;
+        for_each_supernode_req::<I>(n, parallelism, |parallelism| {
+            let mut req = StackReq::empty();
+            for s in 0..n_supernodes {
+                let s_start = symbolic.supernode_begin[s].zx();
+                let s_end = symbolic.supernode_begin[s + 1].zx();
+
+                let s_ncols = s_end - s_start;
+
+                let s_postordered = post_inv[s].zx();
+                let desc_count = desc_count[s].zx();
+                for d in &post[s_postordered - desc_count..s_postordered] {
+                    let mut d_req = StackReq::empty();
+                    let d = d.zx();
+
+                    let d_pattern = &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()];
+                    let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
+                    let d_pattern_mid_len =
+                        d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
+
+                    d_req = d_req.try_and(temp_mat_req::<E>(
+                        d_pattern.len() - d_pattern_start,
+                        d_pattern_mid_len,
+                    )?)?;
+                    req = req.try_or(d_req)?;
+                }
+                req = req.try_or(
+                    crate::linalg::cholesky::ldlt_diagonal::compute::raw_cholesky_in_place_req::<E>(
+                        s_ncols,
+                        parallelism,
+                        Default::default(),
+                    )?,
+                )?;
             }
-            req = req.try_or(
-                crate::linalg::cholesky::ldlt_diagonal::compute::raw_cholesky_in_place_req::<E>(
-                    s_ncols,
-                    parallelism,
-                    Default::default(),
-                )?,
-            )?;
-        }
-        req.try_and(StackReq::try_new::<I>(n)?)
+            Ok(req)
+        })
     }
 
     /// Returns the size and alignment of the workspace required to compute the numeric
@@ -3046,46 +3336,48 @@ pub mod supernodal {
         let col_ptr_row = &*symbolic.col_ptrs_for_row_indices;
         let row_ind = &*symbolic.row_indices;
 
-        let mut req = StackReq::empty();
-        for s in 0..n_supernodes {
-            let s_start = symbolic.supernode_begin[s].zx();
-            let s_end = symbolic.supernode_begin[s + 1].zx();
+        for_each_supernode_req::<I>(n, parallelism, |parallelism| {
+            let mut req = StackReq::empty();
+            for s in 0..n_supernodes {
+                let s_start = symbolic.supernode_begin[s].zx();
+                let s_end = symbolic.supernode_begin[s + 1].zx();
 
-            let s_ncols = s_end - s_start;
+                let s_ncols = s_end - s_start;
 
-            let s_postordered = post_inv[s].zx();
-            let desc_count = desc_count[s].zx();
-            for d in &post[s_postordered - desc_count..s_postordered] {
-                let mut d_req = StackReq::empty();
+                let s_postordered = post_inv[s].zx();
+                let desc_count = desc_count[s].zx();
+                for d in &post[s_postordered - desc_count..s_postordered] {
+                    let mut d_req = StackReq::empty();
 
-                let d = d.zx();
-                let d_start = symbolic.supernode_begin[d].zx();
-                let d_end = symbolic.supernode_begin[d + 1].zx();
+                    let d = d.zx();
+                    let d_start = symbolic.supernode_begin[d].zx();
+                    let d_end = symbolic.supernode_begin[d + 1].zx();
 
-                let d_pattern = &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()];
+                    let d_pattern = &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()];
 
-                let d_ncols = d_end - d_start;
+                    let d_ncols = d_end - d_start;
 
-                let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
-                let d_pattern_mid_len =
-                    d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
+                    let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
+                    let d_pattern_mid_len =
+                        d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
 
-                d_req = d_req.try_and(temp_mat_req::<E>(
-                    d_pattern.len() - d_pattern_start,
-                    d_pattern_mid_len,
-                )?)?;
-                d_req = d_req.try_and(temp_mat_req::<E>(d_ncols, d_pattern_mid_len)?)?;
-                req = req.try_or(d_req)?;
+                    d_req = d_req.try_and(temp_mat_req::<E>(
+                        d_pattern.len() - d_pattern_start,
+                        d_pattern_mid_len,
+                    )?)?;
+                    d_req = d_req.try_and(temp_mat_req::<E>(d_ncols, d_pattern_mid_len)?)?;
+                    req = req.try_or(d_req)?;
+                }
+                req = req.try_or(
+                    crate::linalg::cholesky::ldlt_diagonal::compute::raw_cholesky_in_place_req::<E>(
+                        s_ncols,
+                        parallelism,
+                        Default::default(),
+                    )?,
+                )?;
             }
-            req = req.try_or(
-                crate::linalg::cholesky::ldlt_diagonal::compute::raw_cholesky_in_place_req::<E>(
-                    s_ncols,
-                    parallelism,
-                    Default::default(),
-                )?,
-            )?;
-        }
-        req.try_and(StackReq::try_new::<I>(n)?)
+            Ok(req)
+        })
     }
 
     /// Returns the size and alignment of the workspace required to compute the numeric
@@ -3169,9 +3461,7 @@ pub mod supernodal {
         parallelism: Parallelism,
         stack: &mut PodStack,
     ) -> Result<usize, CholeskyError> {
-        let n_supernodes = symbolic.n_supernodes();
         let n = symbolic.nrows();
-        let mut dynamic_regularization_count = 0usize;
         let mut L_values = SliceGroupMut::<'_, E>::new(L_values);
         L_values.fill_zero();
 
@@ -3191,177 +3481,182 @@ pub mod supernodal {
         let col_ptr_val = &*symbolic.col_ptrs_for_values;
         let row_ind = &*symbolic.row_indices;
 
-        // mapping from global indices to local
-        let (global_to_local, mut stack) = stack.make_raw::<I::Signed>(n);
-        mem::fill_none(global_to_local);
+        let L_values = SyncValues::<'_, E>::new(L_values);
 
-        for s in 0..n_supernodes {
-            let s_start = symbolic.supernode_begin[s].zx();
-            let s_end = symbolic.supernode_begin[s + 1].zx();
+        for_each_supernode(
+            symbolic,
+            parallelism,
+            stack,
+            &|s, global_to_local, parallelism, mut stack| {
+                let s_start = symbolic.supernode_begin[s].zx();
+                let s_end = symbolic.supernode_begin[s + 1].zx();
 
-            let s_pattern = &row_ind[col_ptr_row[s].zx()..col_ptr_row[s + 1].zx()];
-            let s_ncols = s_end - s_start;
-            let s_nrows = s_pattern.len() + s_ncols;
+                let s_pattern = &row_ind[col_ptr_row[s].zx()..col_ptr_row[s + 1].zx()];
+                let s_ncols = s_end - s_start;
+                let s_nrows = s_pattern.len() + s_ncols;
 
-            for (i, &row) in s_pattern.iter().enumerate() {
-                global_to_local[row.zx()] = I::Signed::truncate(i + s_ncols);
-            }
+                for (i, &row) in s_pattern.iter().enumerate() {
+                    global_to_local[row.zx()] = I::Signed::truncate(i + s_ncols);
+                }
 
-            let (head, tail) = L_values.rb_mut().split_at(col_ptr_val[s].zx());
-            let head = head.rb();
-            let mut Ls = crate::mat::from_column_major_slice_mut_generic::<'_, E, _, _>(
-                tail.subslice(0..(col_ptr_val[s + 1] - col_ptr_val[s]).zx())
-                    .into_inner(),
-                s_nrows,
-                s_ncols,
-            );
+                // SAFETY: the values of the supernode are only accessed by the current task
+                let Ls_values =
+                    unsafe { L_values.get_mut(col_ptr_val[s].zx()..col_ptr_val[s + 1].zx()) };
+                let mut Ls = crate::mat::from_column_major_slice_mut_generic::<'_, E, _, _>(
+                    Ls_values, s_nrows, s_ncols,
+                );
 
-            for j in s_start..s_end {
-                let j_shifted = j - s_start;
-                for (i, val) in zip(
-                    A_lower.row_indices_of_col(j),
-                    slice_group(A_lower.values_of_col(j)).into_ref_iter(),
-                ) {
-                    if i < j {
-                        continue;
-                    }
+                for j in s_start..s_end {
+                    let j_shifted = j - s_start;
+                    for (i, val) in zip(
+                        A_lower.row_indices_of_col(j),
+                        slice_group(A_lower.values_of_col(j)).into_ref_iter(),
+                    ) {
+                        if i < j {
+                            continue;
+                        }
 
-                    let val = val.read();
-                    let (ix, iy) = if i >= s_end {
-                        (global_to_local[i].sx(), j_shifted)
-                    } else {
-                        (i - s_start, j_shifted)
-                    };
-                    Ls.write(ix, iy, Ls.read(ix, iy).faer_add(val));
+                        let val = val.read();
+                        let (ix, iy) = if i >= s_end {
+                            (global_to_local[i].sx(), j_shifted)
+                        } else {
+                            (i - s_start, j_shifted)
+                        };
+                        Ls.write(ix, iy, Ls.read(ix, iy).faer_add(val));
+                    }
                 }
-            }
 
-            let s_postordered = post_inv[s].zx();
-            let desc_count = desc_count[s].zx();
-            for d in &post[s_postordered - desc_count..s_postordered] {
-                let d = d.zx();
-                let d_start = symbolic.supernode_begin[d].zx();
-                let d_end = symbolic.supernode_begin[d + 1].zx();
+                let s_postordered = post_inv[s].zx();
+                let desc_count = desc_count[s].zx();
+                for d in &post[s_postordered - desc_count..s_postordered] {
+                    let d = d.zx();
+                    let d_start = symbolic.supernode_begin[d].zx();
+                    let d_end = symbolic.supernode_begin[d + 1].zx();
+
+                    let d_pattern = &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()];
+                    let d_ncols = d_end - d_start;
+                    let d_nrows = d_pattern.len() + d_ncols;
+
+                    // SAFETY: the descendants of the supernode have already been processed
+                    let Ld = crate::mat::from_column_major_slice_generic::<'_, E, _, _>(
+                        unsafe { L_values.get(col_ptr_val[d].zx()..col_ptr_val[d + 1].zx()) },
+                        d_nrows,
+                        d_ncols,
+                    );
 
-                let d_pattern = &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()];
-                let d_ncols = d_end - d_start;
-                let d_nrows = d_pattern.len() + d_ncols;
+                    let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
+                    let d_pattern_mid_len =
+                        d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
+                    let d_pattern_mid = d_pattern_start + d_pattern_mid_len;
 
-                let Ld = crate::mat::from_column_major_slice_generic::<'_, E, _, _>(
-                    head.subslice(col_ptr_val[d].zx()..col_ptr_val[d + 1].zx())
-                        .into_inner(),
-                    d_nrows,
-                    d_ncols,
-                );
+                    let (_, Ld_mid_bot) = Ld.split_at_row(d_ncols);
+                    let (_, Ld_mid_bot) = Ld_mid_bot.split_at_row(d_pattern_start);
+                    let (Ld_mid, Ld_bot) = Ld_mid_bot.split_at_row(d_pattern_mid_len);
 
-                let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
-                let d_pattern_mid_len =
-                    d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
-                let d_pattern_mid = d_pattern_start + d_pattern_mid_len;
+                    let stack = stack.rb_mut();
 
-                let (_, Ld_mid_bot) = Ld.split_at_row(d_ncols);
-                let (_, Ld_mid_bot) = Ld_mid_bot.split_at_row(d_pattern_start);
-                let (Ld_mid, Ld_bot) = Ld_mid_bot.split_at_row(d_pattern_mid_len);
+                    let (tmp, _) =
+                        temp_mat_uninit::<E>(Ld_mid_bot.nrows(), d_pattern_mid_len, stack);
 
-                let stack = stack.rb_mut();
+                    let (mut tmp_top, mut tmp_bot) = tmp.split_at_row_mut(d_pattern_mid_len);
 
-                let (tmp, _) = temp_mat_uninit::<E>(Ld_mid_bot.nrows(), d_pattern_mid_len, stack);
+                    use crate::linalg::{matmul, matmul::triangular};
+                    triangular::matmul(
+                        tmp_top.rb_mut(),
+                        triangular::BlockStructure::TriangularLower,
+                        Ld_mid,
+                        triangular::BlockStructure::Rectangular,
+                        Ld_mid.rb().adjoint(),
+                        triangular::BlockStructure::Rectangular,
+                        None,
+                        E::faer_one(),
+                        parallelism,
+                    );
+                    matmul::matmul(
+                        tmp_bot.rb_mut(),
+                        Ld_bot,
+                        Ld_mid.rb().adjoint(),
+                        None,
+                        E::faer_one(),
+                        parallelism,
+                    );
+                    for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
+                        let j = j.zx();
+                        let j_s = j - s_start;
+                        for (i_idx, i) in d_pattern[d_pattern_start..d_pattern_mid][j_idx..]
+                            .iter()
+                            .enumerate()
+                        {
+                            let i_idx = i_idx + j_idx;
+
+                            let i = i.zx();
+                            let i_s = i - s_start;
+
+                            debug_assert!(i_s >= j_s);
+
+                            unsafe {
+                                Ls.write_unchecked(
+                                    i_s,
+                                    j_s,
+                                    Ls.read_unchecked(i_s, j_s)
+                                        .faer_sub(tmp_top.read_unchecked(i_idx, j_idx)),
+                                )
+                            };
+                        }
+                    }
 
-                let (mut tmp_top, mut tmp_bot) = tmp.split_at_row_mut(d_pattern_mid_len);
+                    for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
+                        let j = j.zx();
+                        let j_s = j - s_start;
+                        for (i_idx, i) in d_pattern[d_pattern_mid..].iter().enumerate() {
+                            let i = i.zx();
+                            let i_s = global_to_local[i].zx();
+                            unsafe {
+                                Ls.write_unchecked(
+                                    i_s,
+                                    j_s,
+                                    Ls.read_unchecked(i_s, j_s)
+                                        .faer_sub(tmp_bot.read_unchecked(i_idx, j_idx)),
+                                )
+                            };
+                        }
+                    }
+                }
 
-                use crate::linalg::{matmul, matmul::triangular};
-                triangular::matmul(
-                    tmp_top.rb_mut(),
-                    triangular::BlockStructure::TriangularLower,
-                    Ld_mid,
-                    triangular::BlockStructure::Rectangular,
-                    Ld_mid.rb().adjoint(),
-                    triangular::BlockStructure::Rectangular,
-                    None,
-                    E::faer_one(),
-                    parallelism,
-                );
-                matmul::matmul(
-                    tmp_bot.rb_mut(),
-                    Ld_bot,
-                    Ld_mid.rb().adjoint(),
-                    None,
-                    E::faer_one(),
-                    parallelism,
-                );
-                for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
-                    let j = j.zx();
-                    let j_s = j - s_start;
-                    for (i_idx, i) in d_pattern[d_pattern_start..d_pattern_mid][j_idx..]
-                        .iter()
-                        .enumerate()
-                    {
-                        let i_idx = i_idx + j_idx;
-
-                        let i = i.zx();
-                        let i_s = i - s_start;
-
-                        debug_assert!(i_s >= j_s);
-
-                        unsafe {
-                            Ls.write_unchecked(
-                                i_s,
-                                j_s,
-                                Ls.read_unchecked(i_s, j_s)
-                                    .faer_sub(tmp_top.read_unchecked(i_idx, j_idx)),
-                            )
-                        };
-                    }
-                }
+                let (mut Ls_top, mut Ls_bot) = Ls.rb_mut().split_at_row_mut(s_ncols);
 
-                for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
-                    let j = j.zx();
-                    let j_s = j - s_start;
-                    for (i_idx, i) in d_pattern[d_pattern_mid..].iter().enumerate() {
-                        let i = i.zx();
-                        let i_s = global_to_local[i].zx();
-                        unsafe {
-                            Ls.write_unchecked(
-                                i_s,
-                                j_s,
-                                Ls.read_unchecked(i_s, j_s)
-                                    .faer_sub(tmp_bot.read_unchecked(i_idx, j_idx)),
-                            )
-                        };
+                let params = Default::default();
+                let dynamic_regularization_count =
+                    match crate::linalg::cholesky::llt::compute::cholesky_in_place(
+                        Ls_top.rb_mut(),
+                        regularization,
+                        parallelism,
+                        stack.rb_mut(),
+                        params,
+                    ) {
+                        Ok(count) => count,
+                        Err(err) => return Err(err.non_positive_definite_minor + s_start),
                     }
-                }
-            }
-
-            let (mut Ls_top, mut Ls_bot) = Ls.rb_mut().split_at_row_mut(s_ncols);
-
-            let params = Default::default();
-            dynamic_regularization_count +=
-                match crate::linalg::cholesky::llt::compute::cholesky_in_place(
-                    Ls_top.rb_mut(),
-                    regularization,
+                    .dynamic_regularization_count;
+                zipped_rw!(Ls_top.rb_mut()).for_each_triangular_upper(
+                    crate::linalg::zip::Diag::Skip,
+                    |unzipped!(mut x)| x.write(E::faer_zero()),
+                );
+                crate::linalg::triangular_solve::solve_lower_triangular_in_place(
+                    Ls_top.rb().conjugate(),
+                    Ls_bot.rb_mut().transpose_mut(),
                     parallelism,
-                    stack.rb_mut(),
-                    params,
-                ) {
-                    Ok(count) => count,
-                    Err(err) => {
-                        return Err(CholeskyError {
-                            non_positive_definite_minor: err.non_positive_definite_minor + s_start,
-                        })
-                    }
-                }
-                .dynamic_regularization_count;
-            crate::linalg::triangular_solve::solve_lower_triangular_in_place(
-                Ls_top.rb().conjugate(),
-                Ls_bot.rb_mut().transpose_mut(),
-                parallelism,
-            );
+                );
 
-            for &row in s_pattern {
-                global_to_local[row.zx()] = none;
-            }
-        }
-        Ok(dynamic_regularization_count)
+                for &row in s_pattern {
+                    global_to_local[row.zx()] = none;
+                }
+                Ok(dynamic_regularization_count)
+            },
+        )
+        .map_err(|minor| CholeskyError {
+            non_positive_definite_minor: minor,
+        })
     }
 
     /// Computes the numeric values of the Cholesky LDLT factors of the matrix `A`, and stores them
@@ -3383,9 +3678,7 @@ pub mod supernodal {
         parallelism: Parallelism,
         stack: &mut PodStack,
     ) -> usize {
-        let n_supernodes = symbolic.n_supernodes();
         let n = symbolic.nrows();
-        let mut dynamic_regularization_count = 0usize;
         let mut L_values = SliceGroupMut::<'_, E>::new(L_values);
         L_values.fill_zero();
 
@@ -3405,209 +3698,214 @@ pub mod supernodal {
         let col_ptr_val = &*symbolic.col_ptrs_for_values;
         let row_ind = &*symbolic.row_indices;
 
-        // mapping from global indices to local
-        let (global_to_local, mut stack) = stack.make_raw::<I::Signed>(n);
-        mem::fill_none(global_to_local);
+        let L_values = SyncValues::<'_, E>::new(L_values);
 
-        for s in 0..n_supernodes {
-            let s_start = symbolic.supernode_begin[s].zx();
-            let s_end = symbolic.supernode_begin[s + 1].zx();
-            let s_pattern = if let Some(nnz_per_super) = symbolic.nnz_per_super.as_deref() {
-                &row_ind[col_ptr_row[s].zx()..][..nnz_per_super[s].zx()]
-            } else {
-                &row_ind[col_ptr_row[s].zx()..col_ptr_row[s + 1].zx()]
-            };
+        let count = for_each_supernode::<I, core::convert::Infallible>(
+            symbolic,
+            parallelism,
+            stack,
+            &|s, global_to_local, parallelism, mut stack| {
+                let s_start = symbolic.supernode_begin[s].zx();
+                let s_end = symbolic.supernode_begin[s + 1].zx();
+                let s_pattern = if let Some(nnz_per_super) = symbolic.nnz_per_super.as_deref() {
+                    &row_ind[col_ptr_row[s].zx()..][..nnz_per_super[s].zx()]
+                } else {
+                    &row_ind[col_ptr_row[s].zx()..col_ptr_row[s + 1].zx()]
+                };
 
-            let s_ncols = s_end - s_start;
-            let s_nrows = s_pattern.len() + s_ncols;
+                let s_ncols = s_end - s_start;
+                let s_nrows = s_pattern.len() + s_ncols;
 
-            for (i, &row) in s_pattern.iter().enumerate() {
-                global_to_local[row.zx()] = I::Signed::truncate(i + s_ncols);
-            }
+                for (i, &row) in s_pattern.iter().enumerate() {
+                    global_to_local[row.zx()] = I::Signed::truncate(i + s_ncols);
+                }
 
-            let (head, tail) = L_values.rb_mut().split_at(col_ptr_val[s].zx());
-            let head = head.rb();
-            let mut Ls = crate::mat::from_column_major_slice_with_stride_mut_generic::<'_, E, _, _>(
-                tail.subslice(0..(col_ptr_val[s + 1] - col_ptr_val[s]).zx())
-                    .into_inner(),
-                s_nrows,
-                s_ncols,
-                s_nrows,
-            );
+                // SAFETY: the values of the supernode are only accessed by the current task
+                let Ls_values =
+                    unsafe { L_values.get_mut(col_ptr_val[s].zx()..col_ptr_val[s + 1].zx()) };
+                let mut Ls =
+                    crate::mat::from_column_major_slice_with_stride_mut_generic::<'_, E, _, _>(
+                        Ls_values, s_nrows, s_ncols, s_nrows,
+                    );
 
-            for j in s_start..s_end {
-                let j_shifted = j - s_start;
-                for (i, val) in zip(
-                    A_lower.row_indices_of_col(j),
-                    slice_group(A_lower.values_of_col(j)).into_ref_iter(),
-                ) {
-                    if i < j {
-                        continue;
+                for j in s_start..s_end {
+                    let j_shifted = j - s_start;
+                    for (i, val) in zip(
+                        A_lower.row_indices_of_col(j),
+                        slice_group(A_lower.values_of_col(j)).into_ref_iter(),
+                    ) {
+                        if i < j {
+                            continue;
+                        }
+
+                        let val = val.read();
+                        let (ix, iy) = if i >= s_end {
+                            (global_to_local[i].sx(), j_shifted)
+                        } else {
+                            (i - s_start, j_shifted)
+                        };
+                        Ls.write(ix, iy, Ls.read(ix, iy).faer_add(val));
                     }
+                }
 
-                    let val = val.read();
-                    let (ix, iy) = if i >= s_end {
-                        (global_to_local[i].sx(), j_shifted)
+                let s_postordered = post_inv[s].zx();
+                let desc_count = desc_count[s].zx();
+                for d in &post[s_postordered - desc_count..s_postordered] {
+                    let d = d.zx();
+                    let d_start = symbolic.supernode_begin[d].zx();
+                    let d_end = symbolic.supernode_begin[d + 1].zx();
+                    let d_pattern = if let Some(nnz_per_super) = symbolic.nnz_per_super.as_deref() {
+                        &row_ind[col_ptr_row[d].zx()..][..nnz_per_super[d].zx()]
                     } else {
-                        (i - s_start, j_shifted)
+                        &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()]
                     };
-                    Ls.write(ix, iy, Ls.read(ix, iy).faer_add(val));
-                }
-            }
 
-            let s_postordered = post_inv[s].zx();
-            let desc_count = desc_count[s].zx();
-            for d in &post[s_postordered - desc_count..s_postordered] {
-                let d = d.zx();
-                let d_start = symbolic.supernode_begin[d].zx();
-                let d_end = symbolic.supernode_begin[d + 1].zx();
-                let d_pattern = if let Some(nnz_per_super) = symbolic.nnz_per_super.as_deref() {
-                    &row_ind[col_ptr_row[d].zx()..][..nnz_per_super[d].zx()]
-                } else {
-                    &row_ind[col_ptr_row[d].zx()..col_ptr_row[d + 1].zx()]
-                };
+                    let d_ncols = d_end - d_start;
+                    let d_nrows = d_pattern.len() + d_ncols;
 
-                let d_ncols = d_end - d_start;
-                let d_nrows = d_pattern.len() + d_ncols;
+                    // SAFETY: the descendants of the supernode have already been processed
+                    let Ld = crate::mat::from_column_major_slice_with_stride_generic::<'_, E, _, _>(
+                        unsafe { L_values.get(col_ptr_val[d].zx()..col_ptr_val[d + 1].zx()) },
+                        d_nrows,
+                        d_ncols,
+                        d_nrows,
+                    );
 
-                let Ld = crate::mat::from_column_major_slice_with_stride_generic::<'_, E, _, _>(
-                    head.subslice(col_ptr_val[d].zx()..col_ptr_val[d + 1].zx())
-                        .into_inner(),
-                    d_nrows,
-                    d_ncols,
-                    d_nrows,
-                );
+                    let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
+                    let d_pattern_mid_len =
+                        d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
+                    let d_pattern_mid = d_pattern_start + d_pattern_mid_len;
 
-                let d_pattern_start = d_pattern.partition_point(partition_fn(s_start));
-                let d_pattern_mid_len =
-                    d_pattern[d_pattern_start..].partition_point(partition_fn(s_end));
-                let d_pattern_mid = d_pattern_start + d_pattern_mid_len;
+                    let (Ld_top, Ld_mid_bot) = Ld.split_at_row(d_ncols);
+                    let (_, Ld_mid_bot) = Ld_mid_bot.split_at_row(d_pattern_start);
+                    let (Ld_mid, Ld_bot) = Ld_mid_bot.split_at_row(d_pattern_mid_len);
+                    let D = Ld_top.diagonal().column_vector();
 
-                let (Ld_top, Ld_mid_bot) = Ld.split_at_row(d_ncols);
-                let (_, Ld_mid_bot) = Ld_mid_bot.split_at_row(d_pattern_start);
-                let (Ld_mid, Ld_bot) = Ld_mid_bot.split_at_row(d_pattern_mid_len);
-                let D = Ld_top.diagonal().column_vector();
+                    let stack = stack.rb_mut();
 
-                let stack = stack.rb_mut();
+                    let (tmp, stack) =
+                        temp_mat_uninit::<E>(Ld_mid_bot.nrows(), d_pattern_mid_len, stack);
+                    let (tmp2, _) = temp_mat_uninit::<E>(Ld_mid.ncols(), Ld_mid.nrows(), stack);
+                    let mut Ld_mid_x_D = tmp2.transpose_mut();
 
-                let (tmp, stack) =
-                    temp_mat_uninit::<E>(Ld_mid_bot.nrows(), d_pattern_mid_len, stack);
-                let (tmp2, _) = temp_mat_uninit::<E>(Ld_mid.ncols(), Ld_mid.nrows(), stack);
-                let mut Ld_mid_x_D = tmp2.transpose_mut();
+                    for i in 0..d_pattern_mid_len {
+                        for j in 0..d_ncols {
+                            Ld_mid_x_D.write(
+                                i,
+                                j,
+                                Ld_mid.read(i, j).faer_scale_real(D.read(j).faer_real()),
+                            );
+                        }
+                    }
 
-                for i in 0..d_pattern_mid_len {
-                    for j in 0..d_ncols {
-                        Ld_mid_x_D.write(
-                            i,
-                            j,
-                            Ld_mid.read(i, j).faer_scale_real(D.read(j).faer_real()),
-                        );
+                    let (mut tmp_top, mut tmp_bot) = tmp.split_at_row_mut(d_pattern_mid_len);
+
+                    use crate::linalg::{matmul, matmul::triangular};
+                    triangular::matmul(
+                        tmp_top.rb_mut(),
+                        triangular::BlockStructure::TriangularLower,
+                        Ld_mid,
+                        triangular::BlockStructure::Rectangular,
+                        Ld_mid_x_D.rb().adjoint(),
+                        triangular::BlockStructure::Rectangular,
+                        None,
+                        E::faer_one(),
+                        parallelism,
+                    );
+                    matmul::matmul(
+                        tmp_bot.rb_mut(),
+                        Ld_bot,
+                        Ld_mid_x_D.rb().adjoint(),
+                        None,
+                        E::faer_one(),
+                        parallelism,
+                    );
+                    for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
+                        let j = j.zx();
+                        let j_s = j - s_start;
+                        for (i_idx, i) in d_pattern[d_pattern_start..d_pattern_mid][j_idx..]
+                            .iter()
+                            .enumerate()
+                        {
+                            let i_idx = i_idx + j_idx;
+
+                            let i = i.zx();
+                            let i_s = i - s_start;
+
+                            debug_assert!(i_s >= j_s);
+
+                            unsafe {
+                                Ls.write_unchecked(
+                                    i_s,
+                                    j_s,
+                                    Ls.read_unchecked(i_s, j_s)
+                                        .faer_sub(tmp_top.read_unchecked(i_idx, j_idx)),
+                                )
+                            };
+                        }
                     }
-                }
 
-                let (mut tmp_top, mut tmp_bot) = tmp.split_at_row_mut(d_pattern_mid_len);
+                    for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
+                        let j = j.zx();
+                        let j_s = j - s_start;
+                        for (i_idx, i) in d_pattern[d_pattern_mid..].iter().enumerate() {
+                            let i = i.zx();
+                            let i_s = global_to_local[i].zx();
+                            unsafe {
+                                Ls.write_unchecked(
+                                    i_s,
+                                    j_s,
+                                    Ls.read_unchecked(i_s, j_s)
+                                        .faer_sub(tmp_bot.read_unchecked(i_idx, j_idx)),
+                                )
+                            };
+                        }
+                    }
+                }
 
-                use crate::linalg::{matmul, matmul::triangular};
-                triangular::matmul(
-                    tmp_top.rb_mut(),
-                    triangular::BlockStructure::TriangularLower,
-                    Ld_mid,
-                    triangular::BlockStructure::Rectangular,
-                    Ld_mid_x_D.rb().adjoint(),
-                    triangular::BlockStructure::Rectangular,
-                    None,
-                    E::faer_one(),
-                    parallelism,
+                let (mut Ls_top, mut Ls_bot) = Ls.rb_mut().split_at_row_mut(s_ncols);
+
+                let params = Default::default();
+                let dynamic_regularization_count =
+                    crate::linalg::cholesky::ldlt_diagonal::compute::raw_cholesky_in_place(
+                        Ls_top.rb_mut(),
+                        LdltRegularization {
+                            dynamic_regularization_signs: regularization
+                                .dynamic_regularization_signs
+                                .map(|signs| &signs[s_start..s_end]),
+                            ..regularization
+                        },
+                        parallelism,
+                        stack.rb_mut(),
+                        params,
+                    )
+                    .dynamic_regularization_count;
+                zipped_rw!(Ls_top.rb_mut()).for_each_triangular_upper(
+                    crate::linalg::zip::Diag::Skip,
+                    |unzipped!(mut x)| x.write(E::faer_zero()),
                 );
-                matmul::matmul(
-                    tmp_bot.rb_mut(),
-                    Ld_bot,
-                    Ld_mid_x_D.rb().adjoint(),
-                    None,
-                    E::faer_one(),
+                crate::linalg::triangular_solve::solve_unit_lower_triangular_in_place(
+                    Ls_top.rb().conjugate(),
+                    Ls_bot.rb_mut().transpose_mut(),
                     parallelism,
                 );
-                for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
-                    let j = j.zx();
-                    let j_s = j - s_start;
-                    for (i_idx, i) in d_pattern[d_pattern_start..d_pattern_mid][j_idx..]
-                        .iter()
-                        .enumerate()
-                    {
-                        let i_idx = i_idx + j_idx;
-
-                        let i = i.zx();
-                        let i_s = i - s_start;
-
-                        debug_assert!(i_s >= j_s);
-
-                        unsafe {
-                            Ls.write_unchecked(
-                                i_s,
-                                j_s,
-                                Ls.read_unchecked(i_s, j_s)
-                                    .faer_sub(tmp_top.read_unchecked(i_idx, j_idx)),
-                            )
-                        };
+                for j in 0..s_ncols {
+                    let d = Ls_top.read(j, j).faer_real().faer_inv();
+                    for i in 0..s_pattern.len() {
+                        Ls_bot.write(i, j, Ls_bot.read(i, j).faer_scale_real(d));
                     }
                 }
 
-                for (j_idx, j) in d_pattern[d_pattern_start..d_pattern_mid].iter().enumerate() {
-                    let j = j.zx();
-                    let j_s = j - s_start;
-                    for (i_idx, i) in d_pattern[d_pattern_mid..].iter().enumerate() {
-                        let i = i.zx();
-                        let i_s = global_to_local[i].zx();
-                        unsafe {
-                            Ls.write_unchecked(
-                                i_s,
-                                j_s,
-                                Ls.read_unchecked(i_s, j_s)
-                                    .faer_sub(tmp_bot.read_unchecked(i_idx, j_idx)),
-                            )
-                        };
-                    }
+                for &row in s_pattern {
+                    global_to_local[row.zx()] = none;
                 }
-            }
-
-            let (mut Ls_top, mut Ls_bot) = Ls.rb_mut().split_at_row_mut(s_ncols);
-
-            let params = Default::default();
-            dynamic_regularization_count +=
-                crate::linalg::cholesky::ldlt_diagonal::compute::raw_cholesky_in_place(
-                    Ls_top.rb_mut(),
-                    LdltRegularization {
-                        dynamic_regularization_signs: regularization
-                            .dynamic_regularization_signs
-                            .map(|signs| &signs[s_start..s_end]),
-                        ..regularization
-                    },
-                    parallelism,
-                    stack.rb_mut(),
-                    params,
-                )
-                .dynamic_regularization_count;
-            zipped_rw!(Ls_top.rb_mut())
-                .for_each_triangular_upper(crate::linalg::zip::Diag::Skip, |unzipped!(mut x)| {
-                    x.write(E::faer_zero())
-                });
-            crate::linalg::triangular_solve::solve_unit_lower_triangular_in_place(
-                Ls_top.rb().conjugate(),
-                Ls_bot.rb_mut().transpose_mut(),
-                parallelism,
-            );
-            for j in 0..s_ncols {
-                let d = Ls_top.read(j, j).faer_real().faer_inv();
-                for i in 0..s_pattern.len() {
-                    Ls_bot.write(i, j, Ls_bot.read(i, j).faer_scale_real(d));
-                }
-            }
-
-            for &row in s_pattern {
-                global_to_local[row.zx()] = none;
-            }
+                Ok(dynamic_regularization_count)
+            },
+        );
+        match count {
+            Ok(count) => count,
+            Err(err) => match err {},
         }
-        dynamic_regularization_count
     }
 
     /// Computes the numeric values of the Cholesky Bunch-Kaufman factors of the matrix `A` with
@@ -4078,7 +4376,11 @@ pub struct SymbolicCholesky<I: Index> {
     raw: SymbolicCholeskyRaw<I>,
     perm_fwd: Option<alloc::vec::Vec<I>>,
     perm_inv: Option<alloc::vec::Vec<I>>,
+    unused_storage: (alloc::vec::Vec<I>, alloc::vec::Vec<I>),
+    etree: alloc::vec::Vec<I>,
+    col_counts: alloc::vec::Vec<I>,
     A_nnz: usize,
+    A_triangle_nnz: usize,
 }
 
 impl<I: Index> SymbolicCholesky<I> {
@@ -4103,6 +4405,16 @@ impl<I: Index> SymbolicCholesky<I> {
         &self.raw
     }
 
+    /// Returns the variant that was selected during symbolic analysis, either simplicial or
+    /// supernodal.
+    #[inline]
+    pub fn variant(&self) -> FactorizationVariant {
+        match &self.raw {
+            SymbolicCholeskyRaw::Simplicial(_) => FactorizationVariant::Simplicial,
+            SymbolicCholeskyRaw::Supernodal(_) => FactorizationVariant::Supernodal,
+        }
+    }
+
     /// Returns the permutation that was computed during symbolic analysis.
     #[inline]
     pub fn perm(&self) -> Option<PermRef<'_, I>> {
@@ -4114,6 +4426,60 @@ impl<I: Index> SymbolicCholesky<I> {
         }
     }
 
+    /// Consumes the symbolic structure and returns the storage of the fill-reducing permutation,
+    /// as `(perm_fwd, perm_inv)`, so that it can be reused with
+    /// [`factorize_symbolic_cholesky_with_perm_storage`].
+    #[inline]
+    pub fn into_perm_storage(self) -> (alloc::vec::Vec<I>, alloc::vec::Vec<I>) {
+        match (self.perm_fwd, self.perm_inv) {
+            (Some(perm_fwd), Some(perm_inv)) => (perm_fwd, perm_inv),
+            _ => self.unused_storage,
+        }
+    }
+
+    /// Returns the elimination tree of the permuted matrix.
+    ///
+    /// The `j`-th element is the index of the parent of the `j`-th column of the Cholesky factor,
+    /// or `I::truncate(usize::MAX)` if it is a root.
+    #[inline]
+    pub fn etree(&self) -> &[I] {
+        &self.etree
+    }
+
+    /// Returns the number of nonzero entries in each column of the Cholesky factor of the
+    /// permuted matrix, including the diagonal.
+    #[inline]
+    pub fn nnz_per_column(&self) -> &[I] {
+        &self.col_counts
+    }
+
+    /// Returns the number of nonzero entries in the Cholesky factor, including the diagonal.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.col_counts.iter().map(|&c| c.zx()).sum()
+    }
+
+    /// Returns the ratio of the number of nonzero entries in the Cholesky factor to the number of
+    /// nonzero entries in the analyzed triangular half of the input matrix, including the
+    /// diagonal.
+    ///
+    /// A value close to `1.0` indicates that the fill-reducing permutation introduced little
+    /// fill-in.
+    #[inline]
+    pub fn fill_ratio(&self) -> f64 {
+        self.nnz() as f64 / self.A_triangle_nnz as f64
+    }
+
+    /// Returns the number of supernodes if the factorization is supernodal, or `None` if it is
+    /// simplicial.
+    #[inline]
+    pub fn n_supernodes(&self) -> Option<usize> {
+        match &self.raw {
+            SymbolicCholeskyRaw::Simplicial(_) => None,
+            SymbolicCholeskyRaw::Supernodal(this) => Some(this.n_supernodes()),
+        }
+    }
+
     /// Returns the length of the slice needed to store the numerical values of the Cholesky
     /// decomposition.
     #[inline]
@@ -4124,6 +4490,21 @@ impl<I: Index> SymbolicCholesky<I> {
         }
     }
 
+    /// Returns the number of bytes occupied by the index arrays of the symbolic structure,
+    /// including the fill-reducing permutation.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        let raw = match &self.raw {
+            SymbolicCholeskyRaw::Simplicial(this) => this.byte_size(),
+            SymbolicCholeskyRaw::Supernodal(this) => this.byte_size(),
+        };
+        let len = self.perm_fwd.as_ref().map(|v| v.len()).unwrap_or(0)
+            + self.perm_inv.as_ref().map(|v| v.len()).unwrap_or(0)
+            + self.etree.len()
+            + self.col_counts.len();
+        raw + len * core::mem::size_of::<I>()
+    }
+
     /// Computes the required workspace size and alignment for a numerical LLT factorization.
     #[inline]
     pub fn factorize_numeric_llt_req<E: Entity>(
@@ -4797,6 +5178,150 @@ impl<'a, I: Index, E: Entity> IntranodeBunchKaufmanRef<'a, I, E> {
     }
 }
 
+/// Calls `f` on each diagonal element of the factor.
+fn for_each_factor_diagonal<I: Index, E: Entity>(
+    symbolic: &SymbolicCholesky<I>,
+    values: SliceGroup<'_, E>,
+    mut f: impl FnMut(E),
+) {
+    match symbolic.raw() {
+        SymbolicCholeskyRaw::Simplicial(symbolic) => {
+            // the diagonal element is stored first in each column
+            for &start in &symbolic.col_ptrs()[..symbolic.nrows()] {
+                f(values.read(start.zx()));
+            }
+        }
+        SymbolicCholeskyRaw::Supernodal(symbolic) => {
+            let this = supernodal::SupernodalLltRef::<'_, I, E>::new(symbolic, values.into_inner());
+            for s in 0..symbolic.n_supernodes() {
+                let diag = this.supernode(s).matrix().diagonal().column_vector();
+                for i in 0..diag.nrows() {
+                    f(diag.read(i));
+                }
+            }
+        }
+    }
+}
+
+/// Computes the diagonal of the inverse of the matrix from its Cholesky factor, using the
+/// Takahashi recurrence.
+///
+/// If `unit_diag` is `true`, the factor is stored as an $LDL^H$ decomposition with $D$ on the
+/// diagonal, and otherwise it is stored as an $LL^H$ decomposition.
+fn selected_inverse_diagonal_impl<I: Index, E: ComplexField>(
+    symbolic: &SymbolicCholesky<I>,
+    values: SliceGroup<'_, E>,
+    unit_diag: bool,
+) -> Result<Col<E>, FaerError> {
+    let n = symbolic.nrows();
+
+    // copy the strictly lower part of the unit lower triangular factor column by column, with
+    // sorted row indices, along with the diagonal factor. the number of stored values is an upper
+    // bound on the number of strictly lower entries, so the columns are pushed without reallocating
+    let mut col_ptr = alloc::vec::Vec::new();
+    let mut rows = alloc::vec::Vec::<(usize, E)>::new();
+    let mut d = alloc::vec::Vec::new();
+    col_ptr.try_reserve_exact(n + 1).map_err(nomem)?;
+    rows.try_reserve_exact(symbolic.len_values())
+        .map_err(nomem)?;
+    d.try_reserve_exact(n).map_err(nomem)?;
+    col_ptr.push(0usize);
+    let mut push_col = |diag: E, col: &mut dyn Iterator<Item = (usize, E)>| {
+        let diag = diag.faer_real();
+        let (d_j, scale) = if unit_diag {
+            (diag, E::Real::faer_one())
+        } else {
+            (diag.faer_mul(diag), diag.faer_inv())
+        };
+        d.push(d_j);
+        let start = rows.len();
+        rows.extend(col.map(|(i, l)| (i, l.faer_scale_real(scale))));
+        rows[start..].sort_unstable_by_key(|&(i, _)| i);
+        col_ptr.push(rows.len());
+    };
+    match symbolic.raw() {
+        SymbolicCholeskyRaw::Simplicial(symbolic) => {
+            let col_ptrs = symbolic.col_ptrs();
+            let row_indices = symbolic.row_indices();
+            for j in 0..n {
+                // the diagonal element is stored first in each column
+                let start = col_ptrs[j].zx();
+                let end = col_ptrs[j + 1].zx();
+                push_col(
+                    values.read(start),
+                    &mut (start + 1..end).map(|p| (row_indices[p].zx(), values.read(p))),
+                );
+            }
+        }
+        SymbolicCholeskyRaw::Supernodal(symbolic) => {
+            let this = supernodal::SupernodalLltRef::<'_, I, E>::new(symbolic, values.into_inner());
+            for s in 0..symbolic.n_supernodes() {
+                let s = this.supernode(s);
+                let Ls = s.matrix();
+                let size = Ls.ncols();
+                let start = s.start();
+                let pattern = s.pattern();
+                for c in 0..size {
+                    push_col(
+                        Ls.read(c, c),
+                        &mut (c + 1..size).map(|r| (start + r, Ls.read(r, c))).chain(
+                            pattern
+                                .iter()
+                                .enumerate()
+                                .map(|(p, i)| (i.zx(), Ls.read(size + p, c))),
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    // the pattern of the factor is closed under the recurrence, so that every entry of the
+    // inverse that is needed lies in it
+    let mut z_lower = alloc::vec::Vec::new();
+    let mut z_diag = alloc::vec::Vec::new();
+    z_lower.try_reserve_exact(rows.len()).map_err(nomem)?;
+    z_diag.try_reserve_exact(n).map_err(nomem)?;
+    z_lower.resize(rows.len(), E::faer_zero());
+    z_diag.resize(n, E::Real::faer_zero());
+    let find = |j: usize, i: usize| {
+        let col = &rows[col_ptr[j]..col_ptr[j + 1]];
+        col_ptr[j] + col.binary_search_by_key(&i, |&(i, _)| i).unwrap()
+    };
+
+    for j in (0..n).rev() {
+        let col = col_ptr[j]..col_ptr[j + 1];
+        for p in col.clone() {
+            let i = rows[p].0;
+            let mut sum = E::faer_zero();
+            for q in col.clone() {
+                let (k, l) = rows[q];
+                let z_ik = match Ord::cmp(&i, &k) {
+                    core::cmp::Ordering::Equal => E::faer_from_real(z_diag[i]),
+                    core::cmp::Ordering::Greater => z_lower[find(k, i)],
+                    core::cmp::Ordering::Less => z_lower[find(i, k)].faer_conj(),
+                };
+                sum = sum.faer_add(l.faer_mul(z_ik));
+            }
+            z_lower[p] = sum.faer_neg();
+        }
+
+        let mut sum = E::faer_zero();
+        for p in col {
+            sum = sum.faer_add(rows[p].1.faer_conj().faer_mul(z_lower[p]));
+        }
+        z_diag[j] = d[j].faer_inv().faer_sub(sum.faer_real());
+    }
+
+    Ok(match symbolic.perm() {
+        Some(perm) => {
+            let (_, inv) = perm.arrays();
+            Col::from_fn(n, |i| E::faer_from_real(z_diag[inv[i].zx()]))
+        }
+        None => Col::from_fn(n, |i| E::faer_from_real(z_diag[i])),
+    })
+}
+
 impl<'a, I: Index, E: Entity> LltRef<'a, I, E> {
     /// Creates a new Cholesky LLT factor from the symbolic part and
     /// numerical values.
@@ -4819,6 +5344,10 @@ impl<'a, I: Index, E: Entity> LltRef<'a, I, E> {
     /// Solves the equation $\text{Op}(A) x = \text{rhs}$ and stores the result in `rhs`, where
     /// $\text{Op}$ is either the identity or the conjugate, depending on the value of `conj`.
     ///
+    /// All the columns of `rhs` are solved for at once. With the supernodal variant, each supernode
+    /// is applied to the whole block of right-hand sides with dense level 3 operations, so solving
+    /// with many right-hand sides in a single call is faster than solving with each separately.
+    ///
     /// # Panics
     /// Panics if `rhs.nrows() != self.symbolic().nrows()`.
     pub fn solve_in_place_with_conj(
@@ -4882,6 +5411,52 @@ impl<'a, I: Index, E: Entity> LltRef<'a, I, E> {
             }
         }
     }
+
+    /// Returns the diagonal of the inverse of the matrix, computed from the factor without forming
+    /// the inverse.
+    ///
+    /// The entries of the inverse within the sparsity pattern of the factor are computed with the
+    /// Takahashi recurrence, whose cost is comparable to that of the factorization itself.
+    ///
+    /// # Errors
+    /// Returns [`FaerError::OutOfMemory`] if the workspace of the recurrence can't be allocated.
+    pub fn selected_inverse_diagonal(&self) -> Result<Col<E>, FaerError>
+    where
+        E: ComplexField,
+    {
+        selected_inverse_diagonal_impl(self.symbolic, self.values, false)
+    }
+
+    /// Returns the logarithm of the determinant of the matrix, computed as twice the sum of the
+    /// logarithms of the diagonal of the factor $L$.
+    pub fn log_determinant(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        let mut log_det = E::Real::faer_zero();
+        for_each_factor_diagonal(self.symbolic, self.values, |l| {
+            log_det = log_det.faer_add(crate::utils::math::ln(l.faer_real()));
+        });
+        log_det.faer_add(log_det)
+    }
+
+    /// Returns the determinant of the matrix, computed as the product of the squares of the
+    /// diagonal of the factor $L$.
+    ///
+    /// # Note
+    /// The determinant easily overflows or underflows for large matrices, in which case
+    /// [`Self::log_determinant`] should be used instead.
+    pub fn determinant(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        let mut det = E::Real::faer_one();
+        for_each_factor_diagonal(self.symbolic, self.values, |l| {
+            let l = l.faer_real();
+            det = det.faer_mul(l.faer_mul(l));
+        });
+        det
+    }
 }
 
 impl<'a, I: Index, E: Entity> LdltRef<'a, I, E> {
@@ -4906,6 +5481,10 @@ impl<'a, I: Index, E: Entity> LdltRef<'a, I, E> {
     /// Solves the equation $\text{Op}(A) x = \text{rhs}$ and stores the result in `rhs`, where
     /// $\text{Op}$ is either the identity or the conjugate, depending on the value of `conj`.
     ///
+    /// All the columns of `rhs` are solved for at once. With the supernodal variant, each supernode
+    /// is applied to the whole block of right-hand sides with dense level 3 operations, so solving
+    /// with many right-hand sides in a single call is faster than solving with each separately.
+    ///
     /// # Panics
     /// Panics if `rhs.nrows() != self.symbolic().nrows()`.
     pub fn solve_in_place_with_conj(
@@ -4969,6 +5548,51 @@ impl<'a, I: Index, E: Entity> LdltRef<'a, I, E> {
             }
         }
     }
+
+    /// Returns the diagonal of the inverse of the matrix, computed from the factor without forming
+    /// the inverse.
+    ///
+    /// The entries of the inverse within the sparsity pattern of the factor are computed with the
+    /// Takahashi recurrence, whose cost is comparable to that of the factorization itself.
+    ///
+    /// # Errors
+    /// Returns [`FaerError::OutOfMemory`] if the workspace of the recurrence can't be allocated.
+    pub fn selected_inverse_diagonal(&self) -> Result<Col<E>, FaerError>
+    where
+        E: ComplexField,
+    {
+        selected_inverse_diagonal_impl(self.symbolic, self.values, true)
+    }
+
+    /// Returns the logarithm of the absolute value of the determinant of the matrix, computed from
+    /// the diagonal of the factor $D$.
+    pub fn log_determinant(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        let mut log_det = E::Real::faer_zero();
+        for_each_factor_diagonal(self.symbolic, self.values, |d| {
+            log_det = log_det.faer_add(crate::utils::math::ln(d.faer_real().faer_abs()));
+        });
+        log_det
+    }
+
+    /// Returns the determinant of the matrix, computed as the product of the diagonal of the
+    /// factor $D$.
+    ///
+    /// # Note
+    /// The determinant easily overflows or underflows for large matrices, in which case
+    /// [`Self::log_determinant`] should be used instead.
+    pub fn determinant(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        let mut det = E::Real::faer_one();
+        for_each_factor_diagonal(self.symbolic, self.values, |d| {
+            det = det.faer_mul(d.faer_real());
+        });
+        det
+    }
 }
 
 fn postorder_depth_first_search<'n, I: Index>(
@@ -5063,12 +5687,70 @@ pub fn factorize_symbolic_cholesky<I: Index>(
     side: Side,
     ord: SymmetricOrdering<'_, I>,
     params: CholeskySymbolicParams<'_>,
+) -> Result<SymbolicCholesky<I>, FaerError> {
+    factorize_symbolic_cholesky_impl(A, side, ord, params, None, Default::default())
+}
+
+/// Computes the symbolic Cholesky factorization of the matrix `A`, storing the fill-reducing
+/// permutation in the provided buffers, or returns an error if the operation could not be
+/// completed.
+///
+/// `perm_fwd` and `perm_inv` are resized to the dimension of `A` and filled in place, so no
+/// allocation takes place for the permutation when their capacity is large enough. The buffers
+/// can be recovered with [`SymbolicCholesky::into_perm_storage`] to be reused in a subsequent
+/// factorization. If `ord` is [`SymmetricOrdering::Identity`], no permutation is stored, and the
+/// buffers are returned unchanged by [`SymbolicCholesky::into_perm_storage`].
+pub fn factorize_symbolic_cholesky_with_perm_storage<I: Index>(
+    A: SymbolicSparseColMatRef<'_, I>,
+    side: Side,
+    ord: SymmetricOrdering<'_, I>,
+    params: CholeskySymbolicParams<'_>,
+    perm_fwd: alloc::vec::Vec<I>,
+    perm_inv: alloc::vec::Vec<I>,
+) -> Result<SymbolicCholesky<I>, FaerError> {
+    factorize_symbolic_cholesky_impl(A, side, ord, params, None, (perm_fwd, perm_inv))
+}
+
+/// Computes the symbolic Cholesky factorization of the matrix `A` with the given variant, or
+/// returns an error if the operation could not be completed.
+///
+/// Unlike [`factorize_symbolic_cholesky`], the choice between the simplicial and supernodal
+/// variants is not made heuristically, and `params.supernodal_flop_ratio_threshold` is ignored.
+pub fn factorize_symbolic_cholesky_with_variant<I: Index>(
+    A: SymbolicSparseColMatRef<'_, I>,
+    side: Side,
+    ord: SymmetricOrdering<'_, I>,
+    params: CholeskySymbolicParams<'_>,
+    variant: FactorizationVariant,
+) -> Result<SymbolicCholesky<I>, FaerError> {
+    factorize_symbolic_cholesky_impl(A, side, ord, params, Some(variant), Default::default())
+}
+
+fn factorize_symbolic_cholesky_impl<I: Index>(
+    A: SymbolicSparseColMatRef<'_, I>,
+    side: Side,
+    ord: SymmetricOrdering<'_, I>,
+    params: CholeskySymbolicParams<'_>,
+    variant: Option<FactorizationVariant>,
+    perm_storage: (alloc::vec::Vec<I>, alloc::vec::Vec<I>),
 ) -> Result<SymbolicCholesky<I>, FaerError> {
     let n = A.nrows();
     let A_nnz = A.compute_nnz();
 
     assert!(A.nrows() == A.ncols());
 
+    let mut A_triangle_nnz = 0usize;
+    for j in 0..n {
+        for i in A.row_indices_of_col(j) {
+            if match side {
+                Side::Upper => i <= j,
+                Side::Lower => i >= j,
+            } {
+                A_triangle_nnz += 1;
+            }
+        }
+    }
+
     with_dim!(N, n);
     let A = A.as_shape(N, N);
 
@@ -5107,13 +5789,14 @@ pub fn factorize_symbolic_cholesky<I: Index>(
     let mut mem = dyn_stack::GlobalPodBuffer::try_new(req).map_err(nomem)?;
     let mut stack = PodStack::new(&mut mem);
 
-    let mut perm_fwd = match ord {
-        SymmetricOrdering::Identity => None,
-        _ => Some(try_zeroed(n)?),
-    };
-    let mut perm_inv = match ord {
-        SymmetricOrdering::Identity => None,
-        _ => Some(try_zeroed(n)?),
+    let (perm_fwd_storage, perm_inv_storage) = perm_storage;
+    let (mut perm_fwd, mut perm_inv, unused_storage) = match ord {
+        SymmetricOrdering::Identity => (None, None, (perm_fwd_storage, perm_inv_storage)),
+        _ => (
+            Some(try_zeroed_in(perm_fwd_storage, n)?),
+            Some(try_zeroed_in(perm_inv_storage, n)?),
+            Default::default(),
+        ),
     };
     let flops = match ord {
         SymmetricOrdering::Amd => Some(amd::order_maybe_unsorted(
@@ -5156,30 +5839,44 @@ pub fn factorize_symbolic_cholesky<I: Index>(
     let col_counts = Array::from_mut(col_counts, N);
     let etree = &*ghost_prefactorize_symbolic_cholesky::<I>(etree, col_counts, A, stack.rb_mut());
     let L_nnz = I::sum_nonnegative(col_counts.as_ref()).ok_or(FaerError::IndexOverflow)?;
-
-    let flops = match flops {
-        Some(flops) => flops,
-        None => {
-            let mut n_div = 0u128;
-            let mut n_mult_subs_ldl = 0u128;
-            for i in N.indices() {
-                let c = col_counts[i].zx();
-                n_div += c as u128;
-                n_mult_subs_ldl += (c as u128 * (c as u128 + 1)) / 2;
-            }
-            FlopCount {
-                n_div: n_div as f64,
-                n_mult_subs_ldl: n_mult_subs_ldl as f64,
-                n_mult_subs_lu: 0.0,
+    let etree_out = try_collect(
+        bytemuck::cast_slice::<I::Signed, I>(MaybeIdx::as_slice_ref(etree.as_ref()))
+            .iter()
+            .copied(),
+    )?;
+    let col_counts_out = try_collect(col_counts.as_ref().iter().copied())?;
+
+    let variant = variant.unwrap_or_else(|| {
+        let flops = match flops {
+            Some(flops) => flops,
+            None => {
+                let mut n_div = 0u128;
+                let mut n_mult_subs_ldl = 0u128;
+                for i in N.indices() {
+                    let c = col_counts[i].zx();
+                    n_div += c as u128;
+                    n_mult_subs_ldl += (c as u128 * (c as u128 + 1)) / 2;
+                }
+                FlopCount {
+                    n_div: n_div as f64,
+                    n_mult_subs_ldl: n_mult_subs_ldl as f64,
+                    n_mult_subs_lu: 0.0,
+                }
             }
+        };
+
+        let flops = flops.n_div + flops.n_mult_subs_ldl;
+        if (flops / L_nnz.zx() as f64)
+            > params.supernodal_flop_ratio_threshold.0
+                * crate::sparse::linalg::CHOLESKY_SUPERNODAL_RATIO_FACTOR
+        {
+            FactorizationVariant::Supernodal
+        } else {
+            FactorizationVariant::Simplicial
         }
-    };
+    });
 
-    let flops = flops.n_div + flops.n_mult_subs_ldl;
-    let raw = if (flops / L_nnz.zx() as f64)
-        > params.supernodal_flop_ratio_threshold.0
-            * crate::sparse::linalg::CHOLESKY_SUPERNODAL_RATIO_FACTOR
-    {
+    let raw = if variant == FactorizationVariant::Supernodal {
         SymbolicCholeskyRaw::Supernodal(supernodal::ghost_factorize_supernodal_symbolic(
             A,
             None,
@@ -5203,19 +5900,53 @@ pub fn factorize_symbolic_cholesky<I: Index>(
         raw,
         perm_fwd,
         perm_inv,
+        unused_storage,
+        etree: etree_out,
+        col_counts: col_counts_out,
         A_nnz,
+        A_triangle_nnz,
     })
 }
 
+/// Computes the elimination tree of the Cholesky factorization of the matrix `A`, without any
+/// fill-reducing permutation, or returns an error if the operation could not be completed.
+///
+/// The `j`-th element is the index of the parent of the `j`-th column of the Cholesky factor,
+/// or `I::truncate(usize::MAX)` if it is a root.
+///
+/// # Note
+/// Only the upper triangular part of `A` is analyzed.
+pub fn elimination_tree<I: Index>(
+    A: SymbolicSparseColMatRef<'_, I>,
+) -> Result<alloc::vec::Vec<I>, FaerError> {
+    let n = A.nrows();
+    assert!(A.nrows() == A.ncols());
+
+    let req = StackReq::try_new::<I>(n)
+        .and_then(|req| {
+            StackReq::try_and(
+                req,
+                simplicial::prefactorize_symbolic_cholesky_req::<I>(n, A.compute_nnz())?,
+            )
+        })
+        .map_err(nomem)?;
+    let mut mem = dyn_stack::GlobalPodBuffer::try_new(req).map_err(nomem)?;
+    let stack = PodStack::new(&mut mem);
+
+    let mut etree = try_zeroed::<I::Signed>(n)?;
+    let (col_counts, stack) = stack.make_raw::<I>(n);
+    simplicial::prefactorize_symbolic_cholesky(&mut etree, col_counts, A, stack);
+
+    try_collect(bytemuck::cast_slice::<I::Signed, I>(&etree).iter().copied())
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::{supernodal::SupernodalLdltRef, *};
     use crate::{
         assert,
-        sparse::linalg::{
-            cholesky::supernodal::{CholeskyInput, SupernodalIntranodeBunchKaufmanRef},
-            qd::Double,
-        },
+        quad::Double,
+        sparse::linalg::cholesky::supernodal::{CholeskyInput, SupernodalIntranodeBunchKaufmanRef},
         Mat,
     };
     use dyn_stack::GlobalPodBuffer;
@@ -5274,6 +6005,42 @@ pub(crate) mod tests {
             [5, 2, 7, 5, 7, 6, 8, 9, 9, 10, NONE].map(I::Signed::truncate)
         );
         assert_eq!(col_count, [3, 3, 4, 3, 3, 4, 4, 3, 3, 2, 1].map(truncate));
+
+        let expected_etree = [5, 2, 7, 5, 7, 6, 8, 9, 9, 10, NONE].map(truncate);
+        assert!(elimination_tree(A).unwrap() == expected_etree);
+
+        let symbolic = factorize_symbolic_cholesky(
+            A,
+            Side::Upper,
+            SymmetricOrdering::Identity,
+            Default::default(),
+        )
+        .unwrap();
+        assert!(symbolic.etree() == expected_etree);
+        assert!(symbolic.nnz_per_column() == col_count);
+        assert!(symbolic.nnz() == 33);
+        // 27 entries in the upper triangle of `A`
+        assert!(symbolic.fill_ratio() == 33.0 / 27.0);
+        assert!(
+            symbolic.n_supernodes().is_some()
+                == matches!(symbolic.raw(), SymbolicCholeskyRaw::Supernodal(_))
+        );
+
+        for variant in [
+            FactorizationVariant::Simplicial,
+            FactorizationVariant::Supernodal,
+        ] {
+            let symbolic = factorize_symbolic_cholesky_with_variant(
+                A,
+                Side::Upper,
+                SymmetricOrdering::Identity,
+                Default::default(),
+                variant,
+            )
+            .unwrap();
+            assert!(symbolic.variant() == variant);
+            assert!(symbolic.etree() == expected_etree);
+        }
     }
 
     include!("./data.rs");
@@ -6235,6 +7002,333 @@ pub(crate) mod tests {
         }
     }
 
+    fn test_solve_multiple_rhs<I: Index>() {
+        // 2d laplacian on a 12×12 grid
+        let m = 12;
+        let n = m * m;
+        let triplets =
+            crate::sparse::linalg::laplacian_2d_triplets::<I, f64>(m, |_| 4.0, -1.0, -1.0);
+        let A =
+            crate::sparse::SparseColMat::<I, f64>::try_new_from_triplets(n, n, &triplets).unwrap();
+
+        for variant in [
+            FactorizationVariant::Simplicial,
+            FactorizationVariant::Supernodal,
+        ] {
+            let symbolic = factorize_symbolic_cholesky_with_variant(
+                A.symbolic(),
+                Side::Upper,
+                SymmetricOrdering::Amd,
+                Default::default(),
+                variant,
+            )
+            .unwrap();
+            let mut L_values = vec![0.0f64; symbolic.len_values()];
+            symbolic
+                .factorize_numeric_llt::<f64>(
+                    &mut L_values,
+                    A.as_ref(),
+                    Side::Upper,
+                    Default::default(),
+                    Parallelism::None,
+                    PodStack::new(&mut GlobalPodBuffer::new(
+                        symbolic
+                            .factorize_numeric_llt_req::<f64>(Parallelism::None)
+                            .unwrap(),
+                    )),
+                )
+                .unwrap();
+            let llt = LltRef::<'_, I, f64>::new(&symbolic, &L_values);
+
+            let k = 8;
+            let rhs = Mat::<f64>::from_fn(n, k, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+            let mut x = rhs.clone();
+            llt.solve_in_place_with_conj(
+                Conj::No,
+                x.as_mut(),
+                Parallelism::None,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    symbolic.solve_in_place_req::<f64>(k).unwrap(),
+                )),
+            );
+
+            // solving with all the columns at once must match solving with each one separately
+            for j in 0..k {
+                let mut xj = rhs.col(j).as_2d().to_owned();
+                llt.solve_in_place_with_conj(
+                    Conj::No,
+                    xj.as_mut(),
+                    Parallelism::None,
+                    PodStack::new(&mut GlobalPodBuffer::new(
+                        symbolic.solve_in_place_req::<f64>(1).unwrap(),
+                    )),
+                );
+                assert!((xj.col(0) - x.col(j)).norm_max() < 1e-12);
+            }
+        }
+    }
+
+    fn test_determinant<I: Index>() {
+        // 2d laplacian on a 4×4 grid, with a varying diagonal
+        let m = 4;
+        let n = m * m;
+        let triplets = crate::sparse::linalg::laplacian_2d_triplets::<I, f64>(
+            m,
+            |i| 4.0 + (i % 3) as f64,
+            -1.0,
+            -1.0,
+        );
+        let A =
+            crate::sparse::SparseColMat::<I, f64>::try_new_from_triplets(n, n, &triplets).unwrap();
+        let mut A_dense = A.to_dense();
+        for j in 0..n {
+            for i in j + 1..n {
+                A_dense.write(i, j, A_dense.read(j, i));
+            }
+        }
+        let det = A_dense.determinant();
+
+        for variant in [
+            FactorizationVariant::Simplicial,
+            FactorizationVariant::Supernodal,
+        ] {
+            let symbolic = factorize_symbolic_cholesky_with_variant(
+                A.symbolic(),
+                Side::Upper,
+                SymmetricOrdering::Amd,
+                Default::default(),
+                variant,
+            )
+            .unwrap();
+            let mut mem = GlobalPodBuffer::new(
+                symbolic
+                    .factorize_numeric_ldlt_req::<f64>(false, Parallelism::None)
+                    .unwrap(),
+            );
+            let mut L_values = vec![0.0f64; symbolic.len_values()];
+
+            let llt = symbolic
+                .factorize_numeric_llt::<f64>(
+                    &mut L_values,
+                    A.as_ref(),
+                    Side::Upper,
+                    Default::default(),
+                    Parallelism::None,
+                    PodStack::new(&mut mem),
+                )
+                .unwrap();
+            assert!((llt.determinant() - det).abs() < 1e-10 * det);
+            assert!((llt.log_determinant() - det.ln()).abs() < 1e-12);
+
+            let ldlt = symbolic.factorize_numeric_ldlt::<f64>(
+                &mut L_values,
+                A.as_ref(),
+                Side::Upper,
+                Default::default(),
+                Parallelism::None,
+                PodStack::new(&mut mem),
+            );
+            assert!((ldlt.determinant() - det).abs() < 1e-10 * det);
+            assert!((ldlt.log_determinant() - det.ln()).abs() < 1e-12);
+        }
+    }
+
+    fn test_perm_storage<I: Index>() {
+        // 2d laplacian on a 4×4 grid
+        let m = 4;
+        let n = m * m;
+        let triplets =
+            crate::sparse::linalg::laplacian_2d_triplets::<I, f64>(m, |_| 4.0, -1.0, -1.0);
+        let A =
+            crate::sparse::SparseColMat::<I, f64>::try_new_from_triplets(n, n, &triplets).unwrap();
+
+        let expected = factorize_symbolic_cholesky(
+            A.symbolic(),
+            Side::Upper,
+            SymmetricOrdering::Amd,
+            Default::default(),
+        )
+        .unwrap();
+
+        let mut perm_fwd = Vec::with_capacity(2 * n);
+        let mut perm_inv = Vec::with_capacity(2 * n);
+        perm_fwd.push(I::truncate(1));
+        for _ in 0..3 {
+            let (fwd_ptr, inv_ptr) = (perm_fwd.as_ptr(), perm_inv.as_ptr());
+            let symbolic = factorize_symbolic_cholesky_with_perm_storage(
+                A.symbolic(),
+                Side::Upper,
+                SymmetricOrdering::Amd,
+                Default::default(),
+                perm_fwd,
+                perm_inv,
+            )
+            .unwrap();
+            assert!(symbolic.perm().unwrap().arrays() == expected.perm().unwrap().arrays());
+            assert!(symbolic.nnz_per_column() == expected.nnz_per_column());
+            (perm_fwd, perm_inv) = symbolic.into_perm_storage();
+            assert!(all(
+                perm_fwd.as_ptr() == fwd_ptr,
+                perm_inv.as_ptr() == inv_ptr
+            ));
+        }
+
+        // the buffers are left untouched when no permutation is needed
+        let symbolic = factorize_symbolic_cholesky_with_perm_storage(
+            A.symbolic(),
+            Side::Upper,
+            SymmetricOrdering::Identity,
+            Default::default(),
+            perm_fwd,
+            perm_inv,
+        )
+        .unwrap();
+        assert!(symbolic.perm().is_none());
+        let (perm_fwd, _) = symbolic.into_perm_storage();
+        assert!(perm_fwd.len() == n);
+    }
+
+    fn test_parallel_supernodal<I: Index>() {
+        // 2d laplacian on a 24×24 grid, and 3d laplacian on a 7×7×7 grid
+        for (m, dim) in [(24, 2), (7, 3)] {
+            let n = usize::pow(m, dim);
+            let mut triplets = Vec::new();
+            for i in 0..n {
+                triplets.push((I::truncate(i), I::truncate(i), 2.0 * dim as f64));
+                let mut stride = 1;
+                for _ in 0..dim {
+                    if (i / stride) % m + 1 < m {
+                        triplets.push((I::truncate(i), I::truncate(i + stride), -1.0));
+                    }
+                    stride *= m;
+                }
+            }
+            let A = crate::sparse::SparseColMat::<I, f64>::try_new_from_triplets(n, n, &triplets)
+                .unwrap();
+
+            let symbolic = factorize_symbolic_cholesky(
+                A.symbolic(),
+                Side::Upper,
+                SymmetricOrdering::Amd,
+                CholeskySymbolicParams {
+                    supernodal_flop_ratio_threshold: SupernodalThreshold::FORCE_SUPERNODAL,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            let mut values = Vec::new();
+            for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+                let mut llt = vec![0.0; symbolic.len_values()];
+                symbolic
+                    .factorize_numeric_llt::<f64>(
+                        &mut llt,
+                        A.as_ref(),
+                        Side::Upper,
+                        Default::default(),
+                        parallelism,
+                        PodStack::new(&mut GlobalPodBuffer::new(
+                            symbolic
+                                .factorize_numeric_llt_req::<f64>(parallelism)
+                                .unwrap(),
+                        )),
+                    )
+                    .unwrap();
+
+                let mut ldlt = vec![0.0; symbolic.len_values()];
+                symbolic.factorize_numeric_ldlt::<f64>(
+                    &mut ldlt,
+                    A.as_ref(),
+                    Side::Upper,
+                    Default::default(),
+                    parallelism,
+                    PodStack::new(&mut GlobalPodBuffer::new(
+                        symbolic
+                            .factorize_numeric_ldlt_req::<f64>(false, parallelism)
+                            .unwrap(),
+                    )),
+                );
+                values.push((llt, ldlt));
+            }
+
+            for (seq, par) in [(&values[0].0, &values[1].0), (&values[0].1, &values[1].1)] {
+                for (seq, par) in zip(seq, par) {
+                    assert!((seq - par).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    fn test_selected_inverse<I: Index>() {
+        use crate::linalg::solvers::SolverCore;
+        type E = Complex<f64>;
+
+        // 2d laplacian on a 5×5 grid, with complex off-diagonal entries
+        let m = 5;
+        let n = m * m;
+        let triplets = crate::sparse::linalg::laplacian_2d_triplets::<I, E>(
+            m,
+            |i| E::new(4.0 + (i % 3) as f64, 0.0),
+            E::new(-1.0, -0.25),
+            E::new(-1.0, 0.5),
+        );
+        let A =
+            crate::sparse::SparseColMat::<I, E>::try_new_from_triplets(n, n, &triplets).unwrap();
+        let mut A_dense = A.to_dense();
+        for j in 0..n {
+            for i in j + 1..n {
+                A_dense.write(i, j, A_dense.read(j, i).faer_conj());
+            }
+        }
+        let A_inv = A_dense.partial_piv_lu().inverse();
+
+        for variant in [
+            FactorizationVariant::Simplicial,
+            FactorizationVariant::Supernodal,
+        ] {
+            for ord in [SymmetricOrdering::Identity, SymmetricOrdering::Amd] {
+                let symbolic = factorize_symbolic_cholesky_with_variant(
+                    A.symbolic(),
+                    Side::Upper,
+                    ord,
+                    Default::default(),
+                    variant,
+                )
+                .unwrap();
+                let mut mem = GlobalPodBuffer::new(
+                    symbolic
+                        .factorize_numeric_ldlt_req::<E>(false, Parallelism::None)
+                        .unwrap(),
+                );
+                let mut L_values = Mat::<E>::zeros(symbolic.len_values(), 1);
+
+                let llt = symbolic
+                    .factorize_numeric_llt::<E>(
+                        L_values.col_as_slice_mut(0),
+                        A.as_ref(),
+                        Side::Upper,
+                        Default::default(),
+                        Parallelism::None,
+                        PodStack::new(&mut mem),
+                    )
+                    .unwrap();
+                let diag = llt.selected_inverse_diagonal().unwrap();
+                assert!((&diag - A_inv.diagonal().column_vector()).norm_max() < 1e-12);
+
+                let ldlt = symbolic.factorize_numeric_ldlt::<E>(
+                    L_values.col_as_slice_mut(0),
+                    A.as_ref(),
+                    Side::Upper,
+                    Default::default(),
+                    Parallelism::None,
+                    PodStack::new(&mut mem),
+                );
+                let diag = ldlt.selected_inverse_diagonal().unwrap();
+                assert!((&diag - A_inv.diagonal().column_vector()).norm_max() < 1e-12);
+            }
+        }
+    }
+
     fn test_solver_ldlt<I: Index>() {
         type E = Complex<Double<f64>>;
         let truncate = I::truncate;
@@ -6675,6 +7769,11 @@ pub(crate) mod tests {
     monomorphize_test!(test_simplicial, u32);
     monomorphize_test!(test_solver_llt, u32);
     monomorphize_test!(test_solver_ldlt, u32);
+    monomorphize_test!(test_solve_multiple_rhs, u32);
+    monomorphize_test!(test_determinant, u32);
+    monomorphize_test!(test_selected_inverse, u32);
+    monomorphize_test!(test_perm_storage, u32);
+    monomorphize_test!(test_parallel_supernodal, u32);
     monomorphize_test!(test_solver_intranode_bk, u32);
     monomorphize_test!(test_solver_regularization, u32);
 }
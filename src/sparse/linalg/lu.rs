@@ -8,13 +8,14 @@
 //! factors.
 
 use super::{
+    amd,
     cholesky::simplicial::EliminationTreeRef,
     colamd::Control,
     mem::{
         NONE, {self},
     },
-    nomem, try_zeroed, FaerError, Index, LuError, SupernodalThreshold, SymbolicSparseColMatRef,
-    SymbolicSupernodalParams,
+    nomem, try_zeroed, try_zeroed_in, FaerError, Index, LuError, SupernodalThreshold,
+    SymbolicSparseColMatRef, SymbolicSupernodalParams,
 };
 use crate::{
     assert,
@@ -22,7 +23,7 @@ use crate::{
     perm::PermRef,
     sparse::SparseColMatRef,
     utils::{
-        bound::{Array, Idx, MaybeIdx},
+        bound::{Array, Dim, Idx, MaybeIdx},
         slice::*,
         vec::*,
     },
@@ -184,6 +185,25 @@ pub mod supernodal {
             self.nsupernodes
         }
 
+        /// Returns the number of bytes occupied by the index arrays of the factors.
+        #[inline]
+        pub fn index_byte_size(&self) -> usize {
+            (self.supernode_ptr.len()
+                + self.l_col_ptr_for_row_ind.len()
+                + self.l_col_ptr_for_val.len()
+                + self.l_row_ind.len()
+                + self.ut_col_ptr_for_row_ind.len()
+                + self.ut_col_ptr_for_val.len()
+                + self.ut_row_ind.len())
+                * core::mem::size_of::<I>()
+        }
+
+        /// Returns the number of bytes occupied by the values of the factors.
+        #[inline]
+        pub fn value_byte_size(&self) -> usize {
+            (self.l_val.len() + self.ut_val.len()) * core::mem::size_of::<E>()
+        }
+
         /// Solves the equation $\text{Op}(A) x = \text{rhs}$ and stores the result in `rhs`, where
         /// $\text{Op}$ is either the identity or the conjugate, depending on the value of `conj`.
         ///
@@ -685,6 +705,10 @@ pub mod supernodal {
 
     /// Computes the numeric values of the LU factors of the matrix `A` as well as the row pivoting
     /// permutation, and stores them in `lu` and `row_perm`/`row_perm_inv`.
+    ///
+    /// Unlike the supernodal Cholesky factorizations, the supernodes are processed one at a time,
+    /// since the row pivots chosen in each supernode are applied to the rows of all the ones after
+    /// it. `parallelism` is only used by the dense operations within each supernode.
     pub fn factorize_supernodal_numeric_lu<I: Index, E: ComplexField>(
         row_perm: &mut [I],
         row_perm_inv: &mut [I],
@@ -1011,6 +1035,7 @@ pub mod supernodal {
                 s_size,
                 transpositions,
                 parallelism,
+                Default::default(),
             );
             for (idx, t) in transpositions.iter().enumerate() {
                 let i_t = s_row_indices[idx + t.zx()].zx();
@@ -1427,6 +1452,22 @@ pub mod simplicial {
             self.ncols
         }
 
+        /// Returns the number of bytes occupied by the index arrays of the factors.
+        #[inline]
+        pub fn index_byte_size(&self) -> usize {
+            (self.l_col_ptr.len()
+                + self.l_row_ind.len()
+                + self.u_col_ptr.len()
+                + self.u_row_ind.len())
+                * core::mem::size_of::<I>()
+        }
+
+        /// Returns the number of bytes occupied by the values of the factors.
+        #[inline]
+        pub fn value_byte_size(&self) -> usize {
+            (self.l_val.len() + self.u_val.len()) * core::mem::size_of::<E>()
+        }
+
         /// Returns the $L$ factor of the LU factorization. The row indices may or may not be
         /// sorted.
         #[inline]
@@ -1769,6 +1810,8 @@ pub mod simplicial {
             let mut l_val = lu.l_val.as_slice_mut();
             let mut u_val = lu.u_val.as_slice_mut();
 
+            // `xj` is in topological order rather than row order, so ties are broken explicitly
+            // in favor of the smallest row index
             let mut pivot_idx = n;
             let mut pivot_val = E::Real::faer_one().faer_neg();
             for i in xj {
@@ -1776,10 +1819,12 @@ pub mod simplicial {
                 let xi = x.read(i);
                 if row_perm_inv[i] == I(n) {
                     let val = xi.faer_abs();
-                    if matches!(
-                        val.partial_cmp(&pivot_val),
-                        None | Some(core::cmp::Ordering::Greater)
-                    ) {
+                    let is_better = match val.partial_cmp(&pivot_val) {
+                        None | Some(core::cmp::Ordering::Greater) => true,
+                        Some(core::cmp::Ordering::Equal) => i < pivot_idx,
+                        Some(core::cmp::Ordering::Less) => false,
+                    };
+                    if is_better {
                         pivot_idx = i;
                         pivot_val = val;
                     }
@@ -1837,11 +1882,33 @@ pub mod simplicial {
     }
 }
 
+/// Structural symmetry of the sparsity pattern of the matrix, which determines the fill reducing
+/// ordering used for the LU factorization.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PatternSymmetry {
+    /// The symmetry of the pattern is detected during the symbolic factorization. Default option.
+    #[default]
+    Detect,
+    /// The pattern is assumed to be symmetric, and the detection is skipped.
+    Symmetric,
+    /// The pattern is assumed to be unsymmetric, and the detection is skipped.
+    Unsymmetric,
+}
+
 /// Tuning parameters for the LU symbolic factorization.
 #[derive(Copy, Clone, Debug, Default)]
 pub struct LuSymbolicParams<'a> {
     /// Parameters for the fill reducing column permutation
     pub colamd_params: Control,
+    /// Parameters for the fill reducing column permutation, when the pattern of the matrix is
+    /// symmetric.
+    pub amd_params: amd::Control,
+    /// Structural symmetry of the pattern of the matrix.
+    ///
+    /// If the pattern is symmetric, the column permutation is computed with AMD on the pattern of
+    /// the matrix, which is cheaper and usually produces less fill-in than COLAMD in that case.
+    /// Otherwise, COLAMD is used.
+    pub pattern_symmetry: PatternSymmetry,
     /// Threshold for selecting the supernodal factorization.
     pub supernodal_flop_ratio_threshold: SupernodalThreshold,
     /// Supernodal factorization parameters.
@@ -1869,6 +1936,8 @@ pub struct SymbolicLu<I> {
     col_perm_fwd: alloc::vec::Vec<I>,
     col_perm_inv: alloc::vec::Vec<I>,
     A_nnz: usize,
+    LU_nnz_bound: usize,
+    symmetric_pattern: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -1903,6 +1972,34 @@ impl<I: Index, E: Entity> NumericLu<I, E> {
             row_perm_inv: alloc::vec::Vec::new(),
         }
     }
+
+    /// Returns `true` if no factorization has been computed into `self` yet.
+    #[inline]
+    pub(crate) fn is_none(&self) -> bool {
+        matches!(self.raw, NumericLuRaw::None)
+    }
+
+    /// Returns the number of bytes occupied by the index arrays of the factors, including the row
+    /// permutation.
+    #[inline]
+    pub fn index_byte_size(&self) -> usize {
+        let raw = match &self.raw {
+            NumericLuRaw::None => 0,
+            NumericLuRaw::Supernodal(this) => this.index_byte_size(),
+            NumericLuRaw::Simplicial(this) => this.index_byte_size(),
+        };
+        raw + (self.row_perm_fwd.len() + self.row_perm_inv.len()) * core::mem::size_of::<I>()
+    }
+
+    /// Returns the number of bytes occupied by the values of the factors.
+    #[inline]
+    pub fn value_byte_size(&self) -> usize {
+        match &self.raw {
+            NumericLuRaw::None => 0,
+            NumericLuRaw::Supernodal(this) => this.value_byte_size(),
+            NumericLuRaw::Simplicial(this) => this.value_byte_size(),
+        }
+    }
 }
 
 /// Sparse LU factorization wrapper.
@@ -2060,6 +2157,68 @@ impl<I: Index> SymbolicLu<I> {
         unsafe { PermRef::new_unchecked(&self.col_perm_fwd, &self.col_perm_inv, self.ncols()) }
     }
 
+    /// Returns an upper bound on the number of nonzero entries in the $L$ and $U$ factors,
+    /// counting the diagonal of both.
+    ///
+    /// The actual structure of the factors depends on the row pivoting, which is only determined
+    /// during the numerical factorization. The bound is computed from the column elimination
+    /// tree, and holds for any choice of pivots.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.LU_nnz_bound
+    }
+
+    /// Returns the ratio of [`Self::nnz`] to the number of nonzero entries in the input matrix.
+    #[inline]
+    pub fn fill_ratio(&self) -> f64 {
+        self.LU_nnz_bound as f64 / self.A_nnz as f64
+    }
+
+    /// Returns the number of supernodes if the factorization is supernodal, or `None` if it is
+    /// simplicial.
+    #[inline]
+    pub fn n_supernodes(&self) -> Option<usize> {
+        match &self.raw {
+            SymbolicLuRaw::Simplicial { .. } => None,
+            SymbolicLuRaw::Supernodal(this) => Some(this.supernode_ptr.len() - 1),
+        }
+    }
+
+    /// Returns the number of bytes occupied by the index arrays of the symbolic structure,
+    /// including the fill-reducing permutation.
+    ///
+    /// The structure of the factors themselves depends on the row pivoting, and is stored in the
+    /// numerical factorization.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        let raw = match &self.raw {
+            SymbolicLuRaw::Simplicial { .. } => 0,
+            SymbolicLuRaw::Supernodal(this) => {
+                this.supernode_ptr.len()
+                    + this.super_etree.len()
+                    + this.supernode_postorder.len()
+                    + this.supernode_postorder_inv.len()
+                    + this.descendant_count.len()
+            }
+        };
+        (raw + self.col_perm_fwd.len() + self.col_perm_inv.len()) * core::mem::size_of::<I>()
+    }
+
+    /// Returns `true` if the pattern of $A$ was detected or assumed to be symmetric, in which case
+    /// the fill-reducing column permutation was computed with AMD rather than COLAMD.
+    #[inline]
+    pub fn is_pattern_symmetric(&self) -> bool {
+        self.symmetric_pattern
+    }
+
+    /// Consumes the symbolic structure and returns the storage of the fill-reducing column
+    /// permutation, as `(col_perm_fwd, col_perm_inv)`, so that it can be reused with
+    /// [`factorize_symbolic_lu_with_perm_storage`].
+    #[inline]
+    pub fn into_perm_storage(self) -> (alloc::vec::Vec<I>, alloc::vec::Vec<I>) {
+        (self.col_perm_fwd, self.col_perm_inv)
+    }
+
     /// Computes the size and alignment of the workspace required to compute the numerical LU
     /// factorization.
     pub fn factorize_numeric_lu_req<E: Entity>(
@@ -2195,6 +2354,72 @@ impl<I: Index> SymbolicLu<I> {
 pub fn factorize_symbolic_lu<I: Index>(
     A: SymbolicSparseColMatRef<'_, I>,
     params: LuSymbolicParams<'_>,
+) -> Result<SymbolicLu<I>, FaerError> {
+    factorize_symbolic_lu_impl(A, params, Default::default())
+}
+
+/// Computes the symbolic LU factorization of the matrix `A`, storing the fill-reducing column
+/// permutation in the provided buffers, or returns an error if the operation could not be
+/// completed.
+///
+/// `col_perm_fwd` and `col_perm_inv` are resized to the number of columns of `A` and filled in
+/// place, so no allocation takes place for the permutation when their capacity is large enough.
+/// The buffers can be recovered with [`SymbolicLu::into_perm_storage`] to be reused in a
+/// subsequent factorization.
+#[track_caller]
+pub fn factorize_symbolic_lu_with_perm_storage<I: Index>(
+    A: SymbolicSparseColMatRef<'_, I>,
+    params: LuSymbolicParams<'_>,
+    col_perm_fwd: alloc::vec::Vec<I>,
+    col_perm_inv: alloc::vec::Vec<I>,
+) -> Result<SymbolicLu<I>, FaerError> {
+    factorize_symbolic_lu_impl(A, params, (col_perm_fwd, col_perm_inv))
+}
+
+/// Checks whether the sparsity pattern of the square matrix `A` is equal to the pattern of its
+/// transpose.
+fn ghost_is_pattern_symmetric<'m, 'n, I: Index>(
+    A: SymbolicSparseColMatRef<'_, I, Dim<'m>, Dim<'n>>,
+    stack: &mut PodStack,
+) -> bool {
+    let n = A.ncols().unbound();
+    let A_nnz = A.compute_nnz();
+
+    let (new_col_ptr, stack) = stack.make_raw::<I>(A.nrows().unbound() + 1);
+    let (new_row_ind, mut stack) = stack.make_raw::<I>(A_nnz);
+    let AT =
+        crate::sparse::utils::ghost_adjoint_symbolic(new_col_ptr, new_row_ind, A, stack.rb_mut())
+            .as_dyn();
+    let A = A.as_dyn();
+
+    let (mark, _) = stack.make_raw::<I::Signed>(n);
+    mem::fill_none(mark);
+
+    // the columns of `A` and `A.T` are compared as sets, which doesn't require them to be sorted,
+    // and handles duplicate entries
+    for j in 0..n {
+        let j_ = I::Signed::truncate(j);
+        for i in A.row_indices_of_col(j) {
+            mark[i] = j_;
+        }
+        if AT.row_indices_of_col(j).any(|i| mark[i] != j_) {
+            return false;
+        }
+        for i in AT.row_indices_of_col(j) {
+            mark[i] = I::Signed::truncate(NONE);
+        }
+        if A.row_indices_of_col(j).any(|i| mark[i] == j_) {
+            return false;
+        }
+    }
+    true
+}
+
+#[track_caller]
+fn factorize_symbolic_lu_impl<I: Index>(
+    A: SymbolicSparseColMatRef<'_, I>,
+    params: LuSymbolicParams<'_>,
+    perm_storage: (alloc::vec::Vec<I>, alloc::vec::Vec<I>),
 ) -> Result<SymbolicLu<I>, FaerError> {
     assert!(A.nrows() == A.ncols());
     let m = A.nrows();
@@ -2216,8 +2441,11 @@ pub fn factorize_symbolic_lu<I: Index>(
             StackReq::try_new::<I>(A_nnz)?,
         )?;
 
-        StackReq::try_or(
+        StackReq::try_any_of([
+            // symmetry detection
+            StackReq::try_all_of([AT_req, StackReq::try_or(n_req, m_req)?])?,
             crate::sparse::linalg::colamd::order_req::<I>(m, n, A_nnz)?,
+            amd::order_maybe_unsorted_req::<I>(n, A_nnz)?,
             StackReq::try_all_of([
                 n_req,
                 n_req,
@@ -2231,24 +2459,41 @@ pub fn factorize_symbolic_lu<I: Index>(
                     supernodal::factorize_supernodal_symbolic_lu_req::<I>(m, n)?,
                 ])?,
             ])?,
-        )
+        ])
     };
 
     let req = req().map_err(nomem)?;
     let mut mem = dyn_stack::GlobalPodBuffer::try_new(req).map_err(nomem)?;
     let mut stack = PodStack::new(&mut mem);
 
-    let mut col_perm_fwd = try_zeroed::<I>(n)?;
-    let mut col_perm_inv = try_zeroed::<I>(n)?;
+    let (col_perm_fwd, col_perm_inv) = perm_storage;
+    let mut col_perm_fwd = try_zeroed_in::<I>(col_perm_fwd, n)?;
+    let mut col_perm_inv = try_zeroed_in::<I>(col_perm_inv, n)?;
     let mut min_row = try_zeroed::<I>(m)?;
 
-    crate::sparse::linalg::colamd::order(
-        &mut col_perm_fwd,
-        &mut col_perm_inv,
-        A.as_dyn(),
-        params.colamd_params,
-        stack.rb_mut(),
-    )?;
+    let symmetric_pattern = match params.pattern_symmetry {
+        PatternSymmetry::Detect => ghost_is_pattern_symmetric(A, stack.rb_mut()),
+        PatternSymmetry::Symmetric => true,
+        PatternSymmetry::Unsymmetric => false,
+    };
+
+    if symmetric_pattern {
+        amd::order_maybe_unsorted(
+            &mut col_perm_fwd,
+            &mut col_perm_inv,
+            A.as_dyn(),
+            params.amd_params,
+            stack.rb_mut(),
+        )?;
+    } else {
+        crate::sparse::linalg::colamd::order(
+            &mut col_perm_fwd,
+            &mut col_perm_inv,
+            A.as_dyn(),
+            params.colamd_params,
+            stack.rb_mut(),
+        )?;
+    }
 
     let col_perm = PermRef::new_checked(&col_perm_fwd, &col_perm_inv, n).as_shape(N);
 
@@ -2286,35 +2531,42 @@ pub fn factorize_symbolic_lu<I: Index>(
     );
     let min_col = min_row;
 
-    let mut threshold = params.supernodal_flop_ratio_threshold;
-    if threshold != SupernodalThreshold::FORCE_SIMPLICIAL
-        && threshold != SupernodalThreshold::FORCE_SUPERNODAL
-    {
-        mem::fill_zero(h_col_counts);
-        for i in 0..m {
-            let min_col = min_col[i];
-            if min_col.to_signed() < I::Signed::truncate(0) {
-                continue;
-            }
-            h_col_counts[min_col.zx()] += I::truncate(1);
+    mem::fill_zero(h_col_counts);
+    for i in 0..m {
+        let min_col = min_col[i];
+        if min_col.to_signed() < I::Signed::truncate(0) {
+            continue;
         }
-        for j in 0..n {
-            let parent = etree[j];
-            if parent < I::Signed::truncate(0) {
-                continue;
-            }
-            h_col_counts[parent.zx()] += h_col_counts[j] - I::truncate(1);
+        h_col_counts[min_col.zx()] += I::truncate(1);
+    }
+    for j in 0..n {
+        let parent = etree[j];
+        if parent < I::Signed::truncate(0) {
+            continue;
         }
+        h_col_counts[parent.zx()] += h_col_counts[j] - I::truncate(1);
+    }
 
-        let mut nnz = 0.0f64;
-        let mut flops = 0.0f64;
-        for j in 0..n {
-            let hj = h_col_counts[j].zx() as f64;
-            let rj = col_counts[j].zx() as f64;
-            flops += hj + hj * rj;
-            nnz += hj + rj;
-        }
+    let mut LU_nnz_bound = 0usize;
+    let mut nnz = 0.0f64;
+    let mut flops = 0.0f64;
+    for j in 0..n {
+        let hj = h_col_counts[j].zx();
+        let rj = col_counts[j].zx();
+        LU_nnz_bound = LU_nnz_bound
+            .checked_add(hj + rj)
+            .ok_or(FaerError::IndexOverflow)?;
+
+        let hj = hj as f64;
+        let rj = rj as f64;
+        flops += hj + hj * rj;
+        nnz += hj + rj;
+    }
 
+    let mut threshold = params.supernodal_flop_ratio_threshold;
+    if threshold != SupernodalThreshold::FORCE_SIMPLICIAL
+        && threshold != SupernodalThreshold::FORCE_SUPERNODAL
+    {
         if flops / nnz > threshold.0 * crate::sparse::linalg::LU_SUPERNODAL_RATIO_FACTOR {
             threshold = SupernodalThreshold::FORCE_SUPERNODAL;
         } else {
@@ -2337,6 +2589,8 @@ pub fn factorize_symbolic_lu<I: Index>(
             col_perm_fwd,
             col_perm_inv,
             A_nnz,
+            LU_nnz_bound,
+            symmetric_pattern,
         })
     } else {
         Ok(SymbolicLu {
@@ -2344,6 +2598,8 @@ pub fn factorize_symbolic_lu<I: Index>(
             col_perm_fwd,
             col_perm_inv,
             A_nnz,
+            LU_nnz_bound,
+            symmetric_pattern,
         })
     }
 }
@@ -2364,12 +2620,12 @@ mod tests {
                         factorize_supernodal_numeric_lu, factorize_supernodal_numeric_lu_req,
                         SupernodalLu,
                     },
-                    LuSymbolicParams, NumericLu,
+                    LuSymbolicParams, NumericLu, NumericLuRaw, PatternSymmetry, SymbolicLuRaw,
                 },
                 qr::col_etree,
                 SupernodalThreshold, SymbolicSparseColMatRef,
             },
-            SparseColMatRef,
+            SparseColMat, SparseColMatRef,
         },
         utils::slice::*,
         Conj, Index, Mat, Parallelism,
@@ -2381,7 +2637,7 @@ mod tests {
     use rand::{rngs::StdRng, Rng, SeedableRng};
     use reborrow::*;
 
-    use super::factorize_symbolic_lu;
+    use super::{factorize_symbolic_lu, factorize_symbolic_lu_with_perm_storage};
 
     fn sparse_to_dense<I: Index, E: ComplexField>(sparse: SparseColMatRef<'_, I, E>) -> Mat<E> {
         let m = sparse.nrows();
@@ -2720,6 +2976,147 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_simplicial_lu_pivot_ties() {
+        // [[0, 0, 1], [1, 1, 1], [-1, 1, 2]]
+        let col_ptr = [0usize, 2, 4, 7];
+        let row_ind = [1usize, 2, 1, 2, 0, 1, 2];
+        let val = [1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 2.0];
+        let n = 3;
+        let A = SparseColMatRef::<'_, usize, f64>::new(
+            SymbolicSparseColMatRef::new_checked(n, n, &col_ptr, None, &row_ind),
+            &val,
+        );
+
+        let mut row_perm = vec![0usize; n];
+        let mut row_perm_inv = vec![0usize; n];
+        let col_perm = [0usize, 1, 2];
+        let col_perm = PermRef::<'_, usize>::new_checked(&col_perm, &col_perm, n);
+
+        let mut lu = SimplicialLu::<usize, f64>::new();
+        factorize_simplicial_numeric_lu(
+            &mut row_perm,
+            &mut row_perm_inv,
+            &mut lu,
+            A,
+            col_perm,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                factorize_simplicial_numeric_lu_req::<usize, f64>(n, n).unwrap(),
+            )),
+        )
+        .unwrap();
+
+        // rows 1 and 2 tie in the first column, and the smallest index wins
+        assert!(row_perm == [1, 2, 0]);
+    }
+
+    #[test]
+    fn test_perm_storage() {
+        let (m, n, col_ptr, row_ind, _) =
+            load_mtx::<usize>(MtxData::from_file("test_data/YAO.mtx").unwrap());
+        let A = SymbolicSparseColMatRef::new_checked(m, n, &col_ptr, None, &row_ind);
+
+        let expected = factorize_symbolic_lu(A, Default::default()).unwrap();
+
+        let mut col_perm_fwd = alloc::vec::Vec::with_capacity(n);
+        let mut col_perm_inv = alloc::vec::Vec::with_capacity(n);
+        for _ in 0..2 {
+            let (fwd_ptr, inv_ptr) = (col_perm_fwd.as_ptr(), col_perm_inv.as_ptr());
+            let symbolic = factorize_symbolic_lu_with_perm_storage(
+                A,
+                Default::default(),
+                col_perm_fwd,
+                col_perm_inv,
+            )
+            .unwrap();
+            assert!(symbolic.col_perm().arrays() == expected.col_perm().arrays());
+            (col_perm_fwd, col_perm_inv) = symbolic.into_perm_storage();
+            assert!(all(
+                col_perm_fwd.as_ptr() == fwd_ptr,
+                col_perm_inv.as_ptr() == inv_ptr
+            ));
+        }
+    }
+
+    #[test]
+    fn test_pattern_symmetry() {
+        type E = f64;
+
+        // structurally symmetric jacobian of a 2d convection-diffusion problem on a 12×12 grid
+        let mut rng = StdRng::seed_from_u64(0);
+        let m = 12;
+        let n = m * m;
+        let mut triplets = alloc::vec::Vec::new();
+        for x in 0..m {
+            for y in 0..m {
+                let i = x * m + y;
+                triplets.push((i, i, 4.0 + rng.gen::<f64>()));
+                for (j, ok) in [(i + m, x + 1 < m), (i + 1, y + 1 < m)] {
+                    if ok {
+                        triplets.push((i, j, -1.0 + rng.gen::<f64>()));
+                        triplets.push((j, i, -1.0 - rng.gen::<f64>()));
+                    }
+                }
+            }
+        }
+        let A = SparseColMat::<usize, E>::try_new_from_triplets(n, n, &triplets).unwrap();
+        triplets.push((0, n - 1, 1.0));
+        let B = SparseColMat::<usize, E>::try_new_from_triplets(n, n, &triplets).unwrap();
+
+        let rhs = Mat::<E>::from_fn(n, 2, |_, _| rng.gen());
+
+        for (A, symmetric) in [(A.as_ref(), true), (B.as_ref(), false)] {
+            for (pattern_symmetry, expected) in [
+                (PatternSymmetry::Detect, symmetric),
+                (PatternSymmetry::Symmetric, true),
+                (PatternSymmetry::Unsymmetric, false),
+            ] {
+                for supernodal_flop_ratio_threshold in [
+                    SupernodalThreshold::FORCE_SUPERNODAL,
+                    SupernodalThreshold::FORCE_SIMPLICIAL,
+                ] {
+                    let symbolic = factorize_symbolic_lu(
+                        A.symbolic(),
+                        LuSymbolicParams {
+                            pattern_symmetry,
+                            supernodal_flop_ratio_threshold,
+                            ..Default::default()
+                        },
+                    )
+                    .unwrap();
+                    assert!(symbolic.is_pattern_symmetric() == expected);
+
+                    let mut numeric = NumericLu::<usize, E>::new();
+                    let lu = symbolic
+                        .factorize_numeric_lu(
+                            &mut numeric,
+                            A,
+                            Parallelism::None,
+                            PodStack::new(&mut GlobalPodBuffer::new(
+                                symbolic
+                                    .factorize_numeric_lu_req::<E>(Parallelism::None)
+                                    .unwrap(),
+                            )),
+                        )
+                        .unwrap();
+
+                    let mut x = rhs.clone();
+                    lu.solve_in_place_with_conj(
+                        crate::Conj::No,
+                        x.as_mut(),
+                        Parallelism::None,
+                        PodStack::new(&mut GlobalPodBuffer::new(
+                            symbolic
+                                .solve_in_place_req::<E>(rhs.ncols(), Parallelism::None)
+                                .unwrap(),
+                        )),
+                    );
+                    assert!((A * &x - &rhs).norm_max() <= 1e-10);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_solver_lu_simplicial() {
         type E = crate::complex_native::c64;
@@ -2765,6 +3162,12 @@ mod tests {
                 )
                 .unwrap();
 
+            assert!(
+                symbolic.n_supernodes().is_some()
+                    == matches!(symbolic.raw, SymbolicLuRaw::Supernodal(_))
+            );
+            assert!(symbolic.fill_ratio() >= 1.0);
+
             {
                 let mut x = rhs.clone();
                 lu.solve_in_place_with_conj(
@@ -2830,6 +3233,12 @@ mod tests {
                 let linsolve_diff = A.adjoint() * &x - &rhs;
                 assert!(linsolve_diff.norm_max() <= 1e-10);
             }
+
+            if let NumericLuRaw::Simplicial(numeric) = &numeric.raw {
+                let actual_nnz = numeric.l_factor_unsorted().compute_nnz()
+                    + numeric.u_factor_unsorted().compute_nnz();
+                assert!(actual_nnz <= symbolic.nnz());
+            }
         }
     }
 }
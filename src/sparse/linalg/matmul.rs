@@ -430,6 +430,126 @@ pub fn dense_sparse_matmul<
     )
 }
 
+impl<I: Index, E: ComplexField> SparseColMatRef<'_, I, E> {
+    /// Returns the product of `self` and the column vector `x`.
+    ///
+    /// # Panics
+    /// Panics if `x.nrows() != self.ncols()`.
+    #[track_caller]
+    pub fn mul_vec<RhsE: Conjugate<Canonical = E>>(&self, x: ColRef<'_, RhsE>) -> Col<E> {
+        let mut acc = Col::zeros(self.nrows());
+        self.mul_vec_acc(acc.as_mut(), x, None, E::faer_one());
+        acc
+    }
+
+    /// Computes the product of `self` and the column vector `x`, and stores the result in `acc`.
+    ///
+    /// The columns of `self` are traversed once, and `beta * x[j] * self[:, j]` is added to the
+    /// accumulator for each column `j`. Following the convention of
+    /// [`sparse_dense_matmul`], the accumulator is first set to zero if `alpha` is `None`, or
+    /// scaled by `alpha` otherwise, so that:
+    /// - `acc := beta * self * x` if `alpha` is `None`,
+    /// - `acc := alpha * acc + beta * self * x` otherwise.
+    ///
+    /// # Panics
+    /// Panics if `x.nrows() != self.ncols()` or `acc.nrows() != self.nrows()`.
+    #[track_caller]
+    pub fn mul_vec_acc<RhsE: Conjugate<Canonical = E>>(
+        &self,
+        acc: ColMut<'_, E>,
+        x: ColRef<'_, RhsE>,
+        alpha: Option<E>,
+        beta: E,
+    ) {
+        sparse_dense_matmul(acc, *self, x, alpha, beta, Parallelism::None)
+    }
+
+    /// Returns the product of the transpose of `self` and the column vector `x`.
+    ///
+    /// Since `self` is stored by columns, each entry of the result is computed as the dot product
+    /// of a column of `self` with `x`, without forming the transpose.
+    ///
+    /// # Panics
+    /// Panics if `x.nrows() != self.nrows()`.
+    #[track_caller]
+    pub fn mul_vec_transpose<RhsE: Conjugate<Canonical = E>>(&self, x: ColRef<'_, RhsE>) -> Col<E> {
+        let mut acc = Col::zeros(self.ncols());
+        dense_sparse_matmul(
+            acc.as_mut().transpose_mut(),
+            x.transpose(),
+            *self,
+            None,
+            E::faer_one(),
+            Parallelism::None,
+        );
+        acc
+    }
+}
+
+impl<I: Index, E: ComplexField> SparseColMatMut<'_, I, E> {
+    /// Returns the product of `self` and the column vector `x`.
+    ///
+    /// See [`SparseColMatRef::mul_vec`].
+    #[track_caller]
+    pub fn mul_vec<RhsE: Conjugate<Canonical = E>>(&self, x: ColRef<'_, RhsE>) -> Col<E> {
+        self.rb().mul_vec(x)
+    }
+
+    /// Computes the product of `self` and the column vector `x`, and stores the result in `acc`.
+    ///
+    /// See [`SparseColMatRef::mul_vec_acc`].
+    #[track_caller]
+    pub fn mul_vec_acc<RhsE: Conjugate<Canonical = E>>(
+        &self,
+        acc: ColMut<'_, E>,
+        x: ColRef<'_, RhsE>,
+        alpha: Option<E>,
+        beta: E,
+    ) {
+        self.rb().mul_vec_acc(acc, x, alpha, beta)
+    }
+
+    /// Returns the product of the transpose of `self` and the column vector `x`.
+    ///
+    /// See [`SparseColMatRef::mul_vec_transpose`].
+    #[track_caller]
+    pub fn mul_vec_transpose<RhsE: Conjugate<Canonical = E>>(&self, x: ColRef<'_, RhsE>) -> Col<E> {
+        self.rb().mul_vec_transpose(x)
+    }
+}
+
+impl<I: Index, E: ComplexField> SparseColMat<I, E> {
+    /// Returns the product of `self` and the column vector `x`.
+    ///
+    /// See [`SparseColMatRef::mul_vec`].
+    #[track_caller]
+    pub fn mul_vec<RhsE: Conjugate<Canonical = E>>(&self, x: ColRef<'_, RhsE>) -> Col<E> {
+        self.as_ref().mul_vec(x)
+    }
+
+    /// Computes the product of `self` and the column vector `x`, and stores the result in `acc`.
+    ///
+    /// See [`SparseColMatRef::mul_vec_acc`].
+    #[track_caller]
+    pub fn mul_vec_acc<RhsE: Conjugate<Canonical = E>>(
+        &self,
+        acc: ColMut<'_, E>,
+        x: ColRef<'_, RhsE>,
+        alpha: Option<E>,
+        beta: E,
+    ) {
+        self.as_ref().mul_vec_acc(acc, x, alpha, beta)
+    }
+
+    /// Returns the product of the transpose of `self` and the column vector `x`.
+    ///
+    /// See [`SparseColMatRef::mul_vec_transpose`].
+    #[track_caller]
+    pub fn mul_vec_transpose<RhsE: Conjugate<Canonical = E>>(&self, x: ColRef<'_, RhsE>) -> Col<E> {
+        self.as_ref().mul_vec_transpose(x)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -489,4 +609,42 @@ mod tests {
 
         assert!(c.to_dense() == crate::scale(2.00) * a.to_dense() * b.to_dense());
     }
+
+    #[test]
+    fn test_sp_mul_vec() {
+        use crate::complex_native::c64;
+
+        let a = SparseColMat::<usize, c64>::try_new_from_triplets(
+            5,
+            4,
+            &[
+                (0, 0, c64::new(1.0, 1.0)),
+                (3, 0, c64::new(3.0, 0.0)),
+                (1, 1, c64::new(5.0, -2.0)),
+                (4, 1, c64::new(6.0, 0.0)),
+                (2, 2, c64::new(8.0, 1.0)),
+                (0, 3, c64::new(9.0, 0.0)),
+                (4, 3, c64::new(12.0, -3.0)),
+            ],
+        )
+        .unwrap();
+        let dense = a.to_dense();
+        let x = Col::from_fn(4, |i| c64::new(i as f64 + 1.0, -(i as f64)));
+        let y = Col::from_fn(5, |i| c64::new(2.0 * i as f64, 1.0));
+
+        assert!(a.mul_vec(x.as_ref()) == &dense * &x);
+        assert!(a.as_ref().mul_vec(x.as_ref().conjugate()) == &dense * x.conjugate());
+        assert!(a.mul_vec_transpose(y.as_ref()) == dense.transpose() * &y);
+
+        let alpha = c64::new(0.5, 0.0);
+        let beta = c64::new(2.0, -1.0);
+        let mut acc = y.clone();
+        a.mul_vec_acc(acc.as_mut(), x.as_ref(), Some(alpha), beta);
+        let expected = &y * crate::scale(alpha) + (&dense * &x) * crate::scale(beta);
+        assert!((&acc - &expected).norm_max() < 1e-12);
+
+        let mut acc = y.clone();
+        a.mul_vec_acc(acc.as_mut(), x.as_ref(), None, beta);
+        assert!((&acc - (&dense * &x) * crate::scale(beta)).norm_max() < 1e-12);
+    }
 }
@@ -420,6 +420,17 @@ pub mod supernodal {
         pub fn len_tau_values(&self) -> usize {
             self.col_ptrs_for_tau_values()[self.n_supernodes()].zx()
         }
+
+        /// Returns the number of bytes occupied by the index arrays of the symbolic structure.
+        #[inline]
+        pub fn byte_size(&self) -> usize {
+            (self.col_ptrs_for_row_indices.len()
+                + self.col_ptrs_for_tau_values.len()
+                + self.col_ptrs_for_values.len()
+                + self.super_etree.len()
+                + self.max_blocksize.len())
+                * core::mem::size_of::<I>()
+        }
     }
 
     /// Symbolic structure of the QR decomposition,
@@ -447,6 +458,17 @@ pub mod supernodal {
             &self.H
         }
 
+        /// Returns the number of bytes occupied by the index arrays of the symbolic structure.
+        #[inline]
+        pub fn byte_size(&self) -> usize {
+            let len = self.min_col.len()
+                + self.min_col_perm.len()
+                + self.index_to_super.len()
+                + self.child_head.len()
+                + self.child_next.len();
+            self.L.byte_size() + self.H.byte_size() + len * core::mem::size_of::<I>()
+        }
+
         /// Computes the size and alignment of the workspace required to solve the linear system $A
         /// x = \text{rhs}$ in the sense of least squares.
         pub fn solve_in_place_req<E: Entity>(
@@ -1504,6 +1526,13 @@ pub mod simplicial {
         pub fn len_r(&self) -> usize {
             self.l_nnz
         }
+
+        /// Returns the number of bytes occupied by the index arrays of the symbolic structure.
+        #[inline]
+        pub fn byte_size(&self) -> usize {
+            (self.postorder.len() + self.postorder_inv.len() + self.desc_count.len())
+                * core::mem::size_of::<I>()
+        }
     }
 
     /// QR factors containing both the symbolic and numeric representations.
@@ -2179,6 +2208,17 @@ impl<I: Index> SymbolicQr<I> {
         unsafe { PermRef::new_unchecked(&self.col_perm_fwd, &self.col_perm_inv, self.ncols()) }
     }
 
+    /// Returns the number of bytes occupied by the index arrays of the symbolic structure,
+    /// including the fill-reducing permutation.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        let raw = match &self.raw {
+            SymbolicQrRaw::Simplicial(this) => this.byte_size(),
+            SymbolicQrRaw::Supernodal(this) => this.byte_size(),
+        };
+        raw + (self.col_perm_fwd.len() + self.col_perm_inv.len()) * core::mem::size_of::<I>()
+    }
+
     /// Returns the length of the slice needed to store the symbolic indices of the QR
     /// decomposition.
     #[inline]
@@ -1,8 +1,13 @@
 use super::*;
 use crate::{
-    col::ColBatchMut,
+    assert,
+    col::{ColBatchMut, ColRef},
     linalg::solvers::{SpSolverCore, SpSolverLstsqCore},
+    unzipped,
+    utils::slice::SliceGroup,
+    zipped_rw, Col,
 };
+use core::marker::PhantomData;
 
 /// Reference-counted sparse symbolic Cholesky factorization.
 #[derive(Debug)]
@@ -69,7 +74,12 @@ impl<I: Index> Clone for SymbolicLu<I> {
 impl<I: Index> SymbolicCholesky<I> {
     /// Returns the symbolic Cholesky factorization of the input matrix.
     ///
-    /// Only the provided side is accessed.
+    /// The symbolic factorization only depends on the sparsity pattern, and can be shared by the
+    /// numerical factorizations of all the matrices with that pattern, see
+    /// [`Cholesky::try_new_with_symbolic`] and [`Cholesky::refactorize`].
+    ///
+    /// Only the provided side is accessed. The entries of the other side may be omitted from the
+    /// matrix, see [`Side`].
     #[track_caller]
     pub fn try_new(mat: SymbolicSparseColMatRef<'_, I>, side: Side) -> Result<Self, FaerError> {
         Ok(Self {
@@ -81,9 +91,19 @@ impl<I: Index> SymbolicCholesky<I> {
             )?),
         })
     }
+
+    /// Returns the number of bytes occupied by the symbolic factorization.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.inner.byte_size()
+    }
 }
 impl<I: Index> SymbolicQr<I> {
     /// Returns the symbolic QR factorization of the input matrix.
+    ///
+    /// The symbolic factorization only depends on the sparsity pattern, and can be shared by the
+    /// numerical factorizations of all the matrices with that pattern, see
+    /// [`Qr::try_new_with_symbolic`] and [`Qr::refactorize`].
     #[track_caller]
     pub fn try_new(mat: SymbolicSparseColMatRef<'_, I>) -> Result<Self, FaerError> {
         Ok(Self {
@@ -93,9 +113,19 @@ impl<I: Index> SymbolicQr<I> {
             )?),
         })
     }
+
+    /// Returns the number of bytes occupied by the symbolic factorization.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.inner.byte_size()
+    }
 }
 impl<I: Index> SymbolicLu<I> {
     /// Returns the symbolic LU factorization of the input matrix.
+    ///
+    /// The symbolic factorization only depends on the sparsity pattern, and can be shared by the
+    /// numerical factorizations of all the matrices with that pattern, see
+    /// [`Lu::try_new_with_symbolic`] and [`Lu::refactorize`].
     #[track_caller]
     pub fn try_new(mat: SymbolicSparseColMatRef<'_, I>) -> Result<Self, FaerError> {
         Ok(Self {
@@ -105,13 +135,20 @@ impl<I: Index> SymbolicLu<I> {
             )?),
         })
     }
+
+    /// Returns the number of bytes occupied by the symbolic factorization.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.inner.byte_size()
+    }
 }
 
 impl<I: Index, E: ComplexField> Cholesky<I, E> {
     /// Returns the Cholesky factorization of the input matrix with the same sparsity pattern as the
     /// original one used to construct the symbolic factorization.
     ///
-    /// Only the provided side is accessed.
+    /// Only the provided side is accessed. The entries of the other side may be omitted from the
+    /// matrix, or contain arbitrary values, see [`Side`].
     #[track_caller]
     pub fn try_new_with_symbolic(
         symbolic: SymbolicCholesky<I>,
@@ -124,21 +161,150 @@ impl<I: Index, E: ComplexField> Cholesky<I, E> {
             .try_reserve_exact(len_values)
             .map_err(|_| FaerError::OutOfMemory)?;
         values.resize(len_values, E::faer_zero().faer_into_units());
+        let mut this = Self { symbolic, values };
+        this.refactorize(mat, side)?;
+        Ok(this)
+    }
+
+    /// Recomputes the Cholesky factorization in place for a matrix with the same sparsity pattern
+    /// as the one used to construct the symbolic factorization, but with different values.
+    ///
+    /// The symbolic analysis and the storage of the factor are reused, so that repeatedly
+    /// factorizing matrices with a fixed pattern (e.g., the Jacobian in a Newton iteration) only
+    /// pays for the numerical factorization.
+    ///
+    /// Only the provided side is accessed.
+    ///
+    /// # Errors
+    /// If the workspace can't be allocated, the previous factor is left untouched.
+    /// If the matrix is not numerically positive definite, the previous factor has already been
+    /// partially overwritten, so its values are set to NaN and every solve performed with it
+    /// returns NaN, until a later call to this function succeeds.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{
+    ///     prelude::*,
+    ///     sparse::{linalg::solvers::Cholesky, SparseColMat},
+    ///     Side,
+    /// };
+    ///
+    /// let triplets = |shift: f64| {
+    ///     [
+    ///         (0, 0, 4.0 + shift),
+    ///         (1, 0, 1.0),
+    ///         (0, 1, 1.0),
+    ///         (1, 1, 3.0 + shift),
+    ///         (2, 1, 1.0),
+    ///         (1, 2, 1.0),
+    ///         (2, 2, 2.0 + shift),
+    ///     ]
+    /// };
+    /// let A = SparseColMat::<usize, f64>::try_new_from_triplets(3, 3, &triplets(0.0)).unwrap();
+    /// let mut llt = A.sp_cholesky(Side::Lower).unwrap();
+    ///
+    /// for shift in [1.0, 2.0, 3.0] {
+    ///     // same sparsity pattern, new values
+    ///     let A = SparseColMat::<usize, f64>::try_new_from_triplets(3, 3, &triplets(shift))
+    ///         .unwrap();
+    ///     llt.refactorize(A.as_ref(), Side::Lower).unwrap();
+    ///
+    ///     let b = faer::col![1.0, 2.0, 3.0];
+    ///     let x = llt.solve(&b);
+    ///     assert!((A.as_ref() * x.as_ref() - &b).norm_max() < 1e-12);
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn refactorize(
+        &mut self,
+        mat: SparseColMatRef<'_, I, E>,
+        side: Side,
+    ) -> Result<(), CholeskyError> {
+        let symbolic = &self.symbolic;
         let parallelism = get_global_parallelism();
-        symbolic.inner.factorize_numeric_llt::<E>(
-            values.as_slice_mut().into_inner(),
-            mat,
-            side,
-            Default::default(),
-            parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
-                symbolic
-                    .inner
-                    .factorize_numeric_llt_req::<E>(parallelism)
+        let result = symbolic
+            .inner
+            .factorize_numeric_llt::<E>(
+                self.values.as_slice_mut().into_inner(),
+                mat,
+                side,
+                Default::default(),
+                parallelism,
+                PodStack::new(
+                    &mut GlobalPodBuffer::try_new(
+                        symbolic
+                            .inner
+                            .factorize_numeric_llt_req::<E>(parallelism)
+                            .map_err(|_| FaerError::OutOfMemory)?,
+                    )
                     .map_err(|_| FaerError::OutOfMemory)?,
-            )),
-        )?;
-        Ok(Self { symbolic, values })
+                ),
+            )
+            .map(drop);
+        if result.is_err() {
+            // the factor is partially overwritten at this point, so poison it instead of letting it
+            // silently produce wrong solutions
+            let len = self.values.len();
+            self.values.clear();
+            self.values.resize(len, E::faer_nan().faer_into_units());
+        }
+        result?;
+        Ok(())
+    }
+
+    /// Returns the number of bytes occupied by the index arrays of the factorization.
+    ///
+    /// The symbolic factorization may be shared with other factorizations.
+    #[inline]
+    pub fn index_byte_size(&self) -> usize {
+        self.symbolic.byte_size()
+    }
+
+    /// Returns the number of bytes occupied by the values of the factor.
+    #[inline]
+    pub fn value_byte_size(&self) -> usize {
+        self.values.len() * core::mem::size_of::<E>()
+    }
+
+    /// Returns the number of bytes occupied by the index arrays and the values of the
+    /// factorization.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.index_byte_size() + self.value_byte_size()
+    }
+
+    /// Returns the logarithm of the determinant of the matrix.
+    ///
+    /// This is computed from the diagonal of the Cholesky factor, and doesn't overflow or underflow
+    /// even when the determinant itself does.
+    pub fn log_determinant(&self) -> E::Real {
+        super::cholesky::LltRef::<'_, I, E>::new(
+            &self.symbolic.inner,
+            self.values.as_slice().into_inner(),
+        )
+        .log_determinant()
+    }
+
+    /// Returns the diagonal of the inverse of the matrix, computed from the Cholesky factor without
+    /// forming the inverse.
+    ///
+    /// # Errors
+    /// Returns [`FaerError::OutOfMemory`] if the workspace of the computation can't be allocated.
+    pub fn selected_inverse_diagonal(&self) -> Result<Col<E>, FaerError> {
+        super::cholesky::LltRef::<'_, I, E>::new(
+            &self.symbolic.inner,
+            self.values.as_slice().into_inner(),
+        )
+        .selected_inverse_diagonal()
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(&self) -> E::Real {
+        super::cholesky::LltRef::<'_, I, E>::new(
+            &self.symbolic.inner,
+            self.values.as_slice().into_inner(),
+        )
+        .determinant()
     }
 }
 
@@ -162,24 +328,60 @@ impl<I: Index, E: ComplexField> Qr<I, E> {
             .map_err(|_| FaerError::OutOfMemory)?;
         values.resize(len_values, E::faer_zero().faer_into_units());
         indices.resize(len_indices, I::truncate(0));
+        let mut this = Self {
+            symbolic,
+            indices,
+            values,
+        };
+        this.refactorize(mat)?;
+        Ok(this)
+    }
+
+    /// Recomputes the QR factorization in place for a matrix with the same sparsity pattern as the
+    /// one used to construct the symbolic factorization, but with different values.
+    ///
+    /// The symbolic analysis and the storage of the factors are reused.
+    #[track_caller]
+    pub fn refactorize(&mut self, mat: SparseColMatRef<'_, I, E>) -> Result<(), FaerError> {
+        let symbolic = &self.symbolic;
         let parallelism = get_global_parallelism();
         symbolic.inner.factorize_numeric_qr::<E>(
-            &mut indices,
-            values.as_slice_mut().into_inner(),
+            &mut self.indices,
+            self.values.as_slice_mut().into_inner(),
             mat,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
-                symbolic
-                    .inner
-                    .factorize_numeric_qr_req::<E>(parallelism)
-                    .map_err(|_| FaerError::OutOfMemory)?,
-            )),
+            PodStack::new(
+                &mut GlobalPodBuffer::try_new(
+                    symbolic
+                        .inner
+                        .factorize_numeric_qr_req::<E>(parallelism)
+                        .map_err(|_| FaerError::OutOfMemory)?,
+                )
+                .map_err(|_| FaerError::OutOfMemory)?,
+            ),
         );
-        Ok(Self {
-            symbolic,
-            indices,
-            values,
-        })
+        Ok(())
+    }
+
+    /// Returns the number of bytes occupied by the index arrays of the factorization.
+    ///
+    /// The symbolic factorization may be shared with other factorizations.
+    #[inline]
+    pub fn index_byte_size(&self) -> usize {
+        self.symbolic.byte_size() + self.indices.len() * core::mem::size_of::<I>()
+    }
+
+    /// Returns the number of bytes occupied by the values of the factors.
+    #[inline]
+    pub fn value_byte_size(&self) -> usize {
+        self.values.len() * core::mem::size_of::<E>()
+    }
+
+    /// Returns the number of bytes occupied by the index arrays and the values of the
+    /// factorization.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.index_byte_size() + self.value_byte_size()
     }
 }
 
@@ -191,20 +393,82 @@ impl<I: Index, E: ComplexField> Lu<I, E> {
         symbolic: SymbolicLu<I>,
         mat: SparseColMatRef<'_, I, E>,
     ) -> Result<Self, super::LuError> {
-        let mut numeric = super::lu::NumericLu::new();
+        let mut this = Self {
+            symbolic,
+            numeric: super::lu::NumericLu::new(),
+        };
+        this.refactorize(mat)?;
+        Ok(this)
+    }
+
+    /// Recomputes the LU factorization in place for a matrix with the same sparsity pattern as the
+    /// one used to construct the symbolic factorization, but with different values.
+    ///
+    /// The symbolic analysis, including the fill-reducing column permutation, is reused, and the
+    /// storage of the factors is reused whenever it is large enough.
+    ///
+    /// # Errors
+    /// If the workspace can't be allocated, the previous factorization is left untouched.
+    /// Otherwise, if the factorization fails, the previous factors have already been partially
+    /// overwritten, so they are discarded, and solving with `self` panics until a later call to
+    /// this function succeeds.
+    #[track_caller]
+    pub fn refactorize(&mut self, mat: SparseColMatRef<'_, I, E>) -> Result<(), super::LuError> {
+        let symbolic = &self.symbolic;
         let parallelism = get_global_parallelism();
-        symbolic.inner.factorize_numeric_lu::<E>(
-            &mut numeric,
-            mat,
-            parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
-                symbolic
-                    .inner
-                    .factorize_numeric_lu_req::<E>(parallelism)
+        let result = symbolic
+            .inner
+            .factorize_numeric_lu::<E>(
+                &mut self.numeric,
+                mat,
+                parallelism,
+                PodStack::new(
+                    &mut GlobalPodBuffer::try_new(
+                        symbolic
+                            .inner
+                            .factorize_numeric_lu_req::<E>(parallelism)
+                            .map_err(|_| FaerError::OutOfMemory)?,
+                    )
                     .map_err(|_| FaerError::OutOfMemory)?,
-            )),
-        )?;
-        Ok(Self { symbolic, numeric })
+                ),
+            )
+            .map(drop);
+        if result.is_err() {
+            // the structure of the partially overwritten factors may not match their values, so
+            // they can't be reused for solving
+            self.numeric = super::lu::NumericLu::new();
+        }
+        result
+    }
+
+    #[track_caller]
+    fn lu_ref(&self) -> super::lu::LuRef<'_, I, E> {
+        if self.numeric.is_none() {
+            panic!("the LU factorization is invalid, since the last call to `refactorize` failed");
+        }
+        unsafe { super::lu::LuRef::<'_, I, E>::new_unchecked(&self.symbolic.inner, &self.numeric) }
+    }
+
+    /// Returns the number of bytes occupied by the index arrays of the factorization, including
+    /// the structure of the factors, which depends on the row pivoting.
+    ///
+    /// The symbolic factorization may be shared with other factorizations.
+    #[inline]
+    pub fn index_byte_size(&self) -> usize {
+        self.symbolic.byte_size() + self.numeric.index_byte_size()
+    }
+
+    /// Returns the number of bytes occupied by the values of the factors.
+    #[inline]
+    pub fn value_byte_size(&self) -> usize {
+        self.numeric.value_byte_size()
+    }
+
+    /// Returns the number of bytes occupied by the index arrays and the values of the
+    /// factorization.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.index_byte_size() + self.value_byte_size()
     }
 }
 
@@ -326,36 +590,34 @@ impl<I: Index, E: ComplexField> SpSolverCore<E> for Lu<I, E> {
     fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
         let parallelism = get_global_parallelism();
         let rhs_ncols = rhs.ncols();
-        unsafe { super::lu::LuRef::<'_, I, E>::new_unchecked(&self.symbolic.inner, &self.numeric) }
-            .solve_in_place_with_conj(
-                conj,
-                rhs,
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    self.symbolic
-                        .inner
-                        .solve_in_place_req::<E>(rhs_ncols, parallelism)
-                        .unwrap(),
-                )),
-            );
+        self.lu_ref().solve_in_place_with_conj(
+            conj,
+            rhs,
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                self.symbolic
+                    .inner
+                    .solve_in_place_req::<E>(rhs_ncols, parallelism)
+                    .unwrap(),
+            )),
+        );
     }
 
     #[track_caller]
     fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
         let parallelism = get_global_parallelism();
         let rhs_ncols = rhs.ncols();
-        unsafe { super::lu::LuRef::<'_, I, E>::new_unchecked(&self.symbolic.inner, &self.numeric) }
-            .solve_transpose_in_place_with_conj(
-                conj,
-                rhs,
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    self.symbolic
-                        .inner
-                        .solve_in_place_req::<E>(rhs_ncols, parallelism)
-                        .unwrap(),
-                )),
-            );
+        self.lu_ref().solve_transpose_in_place_with_conj(
+            conj,
+            rhs,
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                self.symbolic
+                    .inner
+                    .solve_in_place_req::<E>(rhs_ncols, parallelism)
+                    .unwrap(),
+            )),
+        );
     }
 }
 
@@ -783,3 +1045,492 @@ impl<I: Index, E: ComplexField> SparseRowMat<I, E> {
         self.as_ref().sp_qr()
     }
 }
+
+/// Parameters of the stationary iterative methods [`jacobi`], [`gauss_seidel`] and [`sor`].
+#[derive(Copy, Clone, Debug)]
+pub struct StationaryParams<E: ComplexField> {
+    /// Absolute tolerance for convergence testing.
+    pub abs_tolerance: E::Real,
+    /// Relative tolerance for convergence testing, with respect to the norm of the right-hand
+    /// side.
+    pub rel_tolerance: E::Real,
+    /// Maximum number of iterations.
+    pub max_iters: usize,
+
+    #[doc(hidden)]
+    pub __private: PhantomData<()>,
+}
+
+impl<E: ComplexField> Default for StationaryParams<E> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            abs_tolerance: E::Real::faer_zero(),
+            rel_tolerance: E::Real::faer_epsilon().faer_mul(E::Real::faer_from_f64(128.0)),
+            max_iters: 1000,
+            __private: PhantomData,
+        }
+    }
+}
+
+/// Result of a stationary iterative method.
+#[derive(Clone, Debug)]
+pub struct StationaryResult<E: ComplexField> {
+    /// Final iterate.
+    pub x: Col<E>,
+    /// Norm of the residual `b - A * x` for the initial guess, followed by its norm after each
+    /// iteration.
+    pub residuals: alloc::vec::Vec<E::Real>,
+    /// Whether the residual norm reached the requested tolerance before the maximum number of
+    /// iterations was exhausted.
+    pub converged: bool,
+}
+
+/// Stationary iterative method error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StationaryError {
+    /// The diagonal element at the given index is zero, or is not stored in the matrix.
+    ZeroDiagonal(usize),
+}
+
+impl core::fmt::Display for StationaryError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl core::error::Error for StationaryError {}
+
+/// Solves `A * x = b` with the Jacobi method, starting from `initial_guess`.
+///
+/// Each iteration computes `x += D^-1 * (b - A * x)`, where `D` is the diagonal of `A`. The method
+/// converges for strictly diagonally dominant matrices.
+///
+/// # Panics
+/// Panics if `A` is not square, or if `b` or `initial_guess` don't have the same number of rows
+/// as `A`.
+#[track_caller]
+pub fn jacobi<I: Index, E: ComplexField>(
+    a: SparseColMatRef<'_, I, E>,
+    b: ColRef<'_, E>,
+    initial_guess: ColRef<'_, E>,
+    params: StationaryParams<E>,
+) -> Result<StationaryResult<E>, StationaryError> {
+    stationary(a, b, initial_guess, None, params)
+}
+
+/// Solves `A * x = b` with the Gauss-Seidel method, starting from `initial_guess`.
+///
+/// Each iteration computes `x += (D + L)^-1 * (b - A * x)`, where `D` and `L` are respectively
+/// the diagonal and the strictly lower triangular part of `A`. The method converges for strictly
+/// diagonally dominant and for symmetric positive definite matrices.
+///
+/// # Panics
+/// Panics if `A` is not square, or if `b` or `initial_guess` don't have the same number of rows
+/// as `A`.
+#[track_caller]
+pub fn gauss_seidel<I: Index, E: ComplexField>(
+    a: SparseColMatRef<'_, I, E>,
+    b: ColRef<'_, E>,
+    initial_guess: ColRef<'_, E>,
+    params: StationaryParams<E>,
+) -> Result<StationaryResult<E>, StationaryError> {
+    stationary(a, b, initial_guess, Some(E::Real::faer_one()), params)
+}
+
+/// Solves `A * x = b` with the successive over-relaxation method, starting from
+/// `initial_guess`.
+///
+/// Each iteration computes `x += omega * (D + omega * L)^-1 * (b - A * x)`, where `D` and `L` are
+/// respectively the diagonal and the strictly lower triangular part of `A`. Taking `omega = 1`
+/// gives the Gauss-Seidel method. For symmetric positive definite matrices, the method converges
+/// if and only if `0 < omega < 2`.
+///
+/// # Panics
+/// Panics if `A` is not square, or if `b` or `initial_guess` don't have the same number of rows
+/// as `A`.
+#[track_caller]
+pub fn sor<I: Index, E: ComplexField>(
+    a: SparseColMatRef<'_, I, E>,
+    b: ColRef<'_, E>,
+    initial_guess: ColRef<'_, E>,
+    omega: E::Real,
+    params: StationaryParams<E>,
+) -> Result<StationaryResult<E>, StationaryError> {
+    stationary(a, b, initial_guess, Some(omega), params)
+}
+
+/// Jacobi iterations if `omega` is `None`, SOR iterations otherwise.
+#[track_caller]
+fn stationary<I: Index, E: ComplexField>(
+    a: SparseColMatRef<'_, I, E>,
+    b: ColRef<'_, E>,
+    initial_guess: ColRef<'_, E>,
+    omega: Option<E::Real>,
+    params: StationaryParams<E>,
+) -> Result<StationaryResult<E>, StationaryError> {
+    assert!(all(
+        a.nrows() == a.ncols(),
+        b.nrows() == a.nrows(),
+        initial_guess.nrows() == a.nrows(),
+    ));
+    let n = a.nrows();
+
+    // duplicate entries are summed
+    let mut diag = Col::<E>::zeros(n);
+    for j in 0..n {
+        for (i, aij) in core::iter::zip(
+            a.row_indices_of_col(j),
+            SliceGroup::<'_, E>::new(a.values_of_col(j)).into_ref_iter(),
+        ) {
+            if i == j {
+                diag.write(j, diag.read(j).faer_add(aij.read()));
+            }
+        }
+    }
+    for j in 0..n {
+        if diag.read(j) == E::faer_zero() {
+            return Err(StationaryError::ZeroDiagonal(j));
+        }
+    }
+    let diag_inv = Col::<E>::from_fn(n, |j| diag.read(j).faer_inv());
+
+    let rel_threshold = params.rel_tolerance.faer_mul(b.norm_l2());
+    let threshold = if params.abs_tolerance > rel_threshold {
+        params.abs_tolerance
+    } else {
+        rel_threshold
+    };
+
+    let mut x = initial_guess.to_owned();
+    let mut r = Col::<E>::zeros(n);
+    let mut residuals = alloc::vec::Vec::new();
+    let mut converged = false;
+
+    for iter in 0.. {
+        r.copy_from(b);
+        crate::sparse::linalg::matmul::sparse_dense_matmul(
+            &mut r,
+            a,
+            &x,
+            Some(E::faer_one()),
+            E::faer_one().faer_neg(),
+            Parallelism::None,
+        );
+        let residual = r.norm_l2();
+        residuals.push(residual);
+        if residual <= threshold {
+            converged = true;
+            break;
+        }
+        if iter == params.max_iters {
+            break;
+        }
+
+        match omega {
+            None => zipped_rw!(&mut x, &r, &diag_inv).for_each(|unzipped!(mut x, r, d)| {
+                x.write(x.read().faer_add(r.read().faer_mul(d.read())))
+            }),
+            Some(omega) => {
+                // forward substitution with `D + omega * L`, overwriting the residual
+                for j in 0..n {
+                    let yj = r.read(j).faer_mul(diag_inv.read(j));
+                    let yj = yj.faer_scale_real(omega);
+                    x.write(j, x.read(j).faer_add(yj));
+                    for (i, aij) in core::iter::zip(
+                        a.row_indices_of_col(j),
+                        SliceGroup::<'_, E>::new(a.values_of_col(j)).into_ref_iter(),
+                    ) {
+                        if i > j {
+                            r.write(i, r.read(i).faer_sub(aij.read().faer_mul(yj)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(StationaryResult {
+        x,
+        residuals,
+        converged,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert,
+        linalg::solvers::{SpSolver, SpSolverLstsq},
+    };
+
+    #[test]
+    fn test_stationary() {
+        let n = 30;
+        let mut triplets = alloc::vec::Vec::new();
+        for i in 0..n {
+            triplets.push((i, i, 4.0));
+            if i + 1 < n {
+                triplets.push((i + 1, i, -1.0));
+                triplets.push((i, i + 1, -2.0));
+            }
+        }
+        // duplicate diagonal entries are summed
+        triplets.push((0, 0, 1.0));
+        let A = SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets).unwrap();
+        let b = Col::<f64>::from_fn(n, |i| (i % 3) as f64 + 1.0);
+        let x0 = Col::<f64>::from_fn(n, |i| i as f64);
+        let x_exact = A.sp_lu().unwrap().solve(&b);
+
+        let params = StationaryParams {
+            rel_tolerance: 1e-12,
+            ..Default::default()
+        };
+        let jac = jacobi(A.as_ref(), b.as_ref(), x0.as_ref(), params).unwrap();
+        let gs = gauss_seidel(A.as_ref(), b.as_ref(), x0.as_ref(), params).unwrap();
+        let over = sor(A.as_ref(), b.as_ref(), x0.as_ref(), 1.1, params).unwrap();
+
+        let r0 = (&b - A.as_ref() * &x0).norm_l2();
+        for res in [&jac, &gs, &over] {
+            assert!(res.converged);
+            assert!(res.residuals[0] == r0);
+            assert!(*res.residuals.last().unwrap() <= 1e-12 * b.norm_l2());
+            assert!((&res.x - &x_exact).norm_max() < 1e-10);
+        }
+        assert!(gs.residuals.len() < jac.residuals.len());
+
+        let params = StationaryParams {
+            max_iters: 3,
+            ..Default::default()
+        };
+        let res = jacobi(A.as_ref(), b.as_ref(), x0.as_ref(), params).unwrap();
+        assert!(!res.converged);
+        assert!(res.residuals.len() == 4);
+    }
+
+    #[test]
+    fn test_stationary_zero_diagonal() {
+        let triplets = [(0, 0, 1.0), (1, 0, 1.0), (0, 1, 1.0), (2, 2, 1.0)];
+        let A = SparseColMat::<usize, f64>::try_new_from_triplets(3, 3, &triplets).unwrap();
+        let b = Col::<f64>::ones(3);
+        let x0 = Col::<f64>::zeros(3);
+        let params = StationaryParams::default();
+
+        assert!(
+            jacobi(A.as_ref(), b.as_ref(), x0.as_ref(), params).unwrap_err()
+                == StationaryError::ZeroDiagonal(1)
+        );
+        assert!(
+            gauss_seidel(A.as_ref(), b.as_ref(), x0.as_ref(), params).unwrap_err()
+                == StationaryError::ZeroDiagonal(1)
+        );
+    }
+
+    #[test]
+    fn test_refactorize() {
+        let n = 20;
+        let matrix = |shift: f64| {
+            let mut triplets = alloc::vec::Vec::new();
+            for i in 0..n {
+                triplets.push((i, i, 4.0 + shift * (i as f64)));
+                if i + 1 < n {
+                    triplets.push((i + 1, i, -1.0));
+                    triplets.push((i, i + 1, -1.0));
+                }
+            }
+            SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets).unwrap()
+        };
+        let b = Col::<f64>::from_fn(n, |i| (i % 3) as f64 + 1.0);
+
+        let A = matrix(0.0);
+        let mut llt = A.sp_cholesky(Side::Lower).unwrap();
+        let mut lu = A.sp_lu().unwrap();
+        let mut qr = A.sp_qr().unwrap();
+
+        for shift in [0.5, 1.0, 2.0] {
+            let A = matrix(shift);
+            llt.refactorize(A.as_ref(), Side::Lower).unwrap();
+            lu.refactorize(A.as_ref()).unwrap();
+            qr.refactorize(A.as_ref()).unwrap();
+
+            for x in [llt.solve(&b), lu.solve(&b), qr.solve_lstsq(&b)] {
+                assert!((A.as_ref() * x.as_ref() - &b).norm_max() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_refactorize_error() {
+        let n = 20;
+        let matrix = |shift: f64| {
+            let mut triplets = alloc::vec::Vec::new();
+            for i in 0..n {
+                triplets.push((i, i, 4.0 + shift * (i as f64)));
+                if i + 1 < n {
+                    triplets.push((i + 1, i, -1.0));
+                    triplets.push((i, i + 1, -1.0));
+                }
+            }
+            SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets).unwrap()
+        };
+        let b = Col::<f64>::from_fn(n, |i| (i % 3) as f64 + 1.0);
+
+        let mut llt = matrix(0.0).sp_cholesky(Side::Lower).unwrap();
+
+        // the diagonal becomes negative halfway through the factorization
+        assert!(matches!(
+            llt.refactorize(matrix(-0.5).as_ref(), Side::Lower),
+            Err(CholeskyError::NotPositiveDefinite)
+        ));
+        let x = llt.solve(&b);
+        assert!((0..n).all(|i| x[i].is_nan()));
+
+        // a successful refactorization makes the factor usable again
+        let A = matrix(1.0);
+        llt.refactorize(A.as_ref(), Side::Lower).unwrap();
+        assert!((A.as_ref() * llt.solve(&b).as_ref() - &b).norm_max() < 1e-12);
+
+        let mut lu = matrix(0.0).sp_lu().unwrap();
+        assert!(matches!(
+            lu.refactorize(singular_matrix(n).as_ref()),
+            Err(LuError::SymbolicSingular(_))
+        ));
+        lu.refactorize(A.as_ref()).unwrap();
+        assert!((A.as_ref() * lu.solve(&b).as_ref() - &b).norm_max() < 1e-12);
+    }
+
+    // same dimensions as the matrices above, but the middle column is empty, so that no pivot can
+    // be found for it
+    fn singular_matrix(n: usize) -> SparseColMat<usize, f64> {
+        let triplets = (0..n)
+            .filter(|&i| i != n / 2)
+            .map(|i| (i, i, 1.0))
+            .collect::<alloc::vec::Vec<_>>();
+        SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets).unwrap()
+    }
+
+    #[test]
+    #[should_panic = "the last call to `refactorize` failed"]
+    fn test_lu_solve_after_refactorize_error() {
+        let n = 20;
+        let A = singular_matrix(n);
+        let triplets = (0..n).map(|i| (i, i, 1.0)).collect::<alloc::vec::Vec<_>>();
+        let mut lu = SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets)
+            .unwrap()
+            .sp_lu()
+            .unwrap();
+
+        assert!(lu.refactorize(A.as_ref()).is_err());
+        lu.solve(&Col::<f64>::zeros(n));
+    }
+
+    #[test]
+    fn test_cholesky_ignores_other_side() {
+        // 2d laplacian, large enough for the supernodal factorization to be selected
+        let m = 20;
+        let n = m * m;
+        let mut triplets =
+            crate::sparse::linalg::laplacian_2d_triplets::<usize, f64>(m, |_| 4.5, -1.0, -1.0);
+        for k in 0..triplets.len() {
+            let (i, j, v) = triplets[k];
+            if i != j {
+                triplets.push((j, i, v));
+            }
+        }
+        let A = SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets).unwrap();
+        let b = Col::<f64>::from_fn(n, |i| (i % 5) as f64 - 2.0);
+
+        for side in [Side::Lower, Side::Upper] {
+            // same pattern, but the entries outside of `side` are NaN
+            let poisoned = triplets
+                .iter()
+                .map(|&(i, j, v)| match side {
+                    Side::Lower if i < j => (i, j, f64::NAN),
+                    Side::Upper if i > j => (i, j, f64::NAN),
+                    _ => (i, j, v),
+                })
+                .collect::<alloc::vec::Vec<_>>();
+            let half = SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &poisoned).unwrap();
+
+            let x = half.sp_cholesky(side).unwrap().solve(&b);
+            assert!((A.as_ref() * x.as_ref() - &b).norm_max() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_determinant() {
+        let A = SparseColMat::<usize, f64>::try_new_from_triplets(
+            3,
+            3,
+            &[
+                (0, 0, 4.0),
+                (1, 0, 1.0),
+                (0, 1, 1.0),
+                (1, 1, 3.0),
+                (2, 2, 2.0),
+            ],
+        )
+        .unwrap();
+        let llt = A.sp_cholesky(Side::Lower).unwrap();
+        assert!((llt.determinant() - 22.0).abs() < 1e-12);
+        assert!((llt.log_determinant() - 22.0f64.ln()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_selected_inverse_diagonal() {
+        let A = SparseColMat::<usize, f64>::try_new_from_triplets(
+            3,
+            3,
+            &[
+                (0, 0, 4.0),
+                (1, 0, 1.0),
+                (0, 1, 1.0),
+                (1, 1, 3.0),
+                (2, 2, 2.0),
+            ],
+        )
+        .unwrap();
+        let llt = A.sp_cholesky(Side::Lower).unwrap();
+        let diag = llt.selected_inverse_diagonal().unwrap();
+        let expected = crate::col![3.0 / 11.0, 4.0 / 11.0, 0.5];
+        assert!((&diag - &expected).norm_max() < 1e-14);
+    }
+
+    #[test]
+    fn test_byte_size() {
+        let triplets = [
+            (0, 0, 4.0),
+            (1, 0, 1.0),
+            (0, 1, 1.0),
+            (1, 1, 3.0),
+            (2, 2, 2.0),
+        ];
+        let A = SparseColMat::<u32, f64>::try_new_from_triplets(3, 3, &triplets).unwrap();
+        assert!(A.index_byte_size() == (4 + 5) * 4);
+        assert!(A.value_byte_size() == 5 * 8);
+        assert!(A.byte_size() == A.index_byte_size() + A.value_byte_size());
+        let A = A.to_row_major().unwrap();
+        assert!(A.byte_size() == (4 + 5) * 4 + 5 * 8);
+
+        let triplets = triplets.map(|(i, j, v)| (i as u64, j as u64, v));
+        let A = SparseColMat::<u64, f64>::try_new_from_triplets(3, 3, &triplets).unwrap();
+        assert!(A.index_byte_size() == (4 + 5) * 8);
+
+        let llt = A.sp_cholesky(Side::Lower).unwrap();
+        assert!(llt.value_byte_size() >= 4 * 8);
+        assert!(llt.index_byte_size() > 0);
+        assert!(llt.byte_size() == llt.index_byte_size() + llt.value_byte_size());
+
+        let lu = A.sp_lu().unwrap();
+        assert!(lu.value_byte_size() >= 5 * 8);
+        assert!(lu.byte_size() == lu.index_byte_size() + lu.value_byte_size());
+
+        let qr = A.sp_qr().unwrap();
+        let symbolic = SymbolicQr::try_new(A.symbolic()).unwrap();
+        assert!(qr.value_byte_size() == symbolic.inner.len_values() * 8);
+        assert!(qr.index_byte_size() == symbolic.byte_size() + symbolic.inner.len_indices() * 8);
+        assert!(qr.byte_size() == qr.index_byte_size() + qr.value_byte_size());
+    }
+}
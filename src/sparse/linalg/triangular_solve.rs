@@ -1,8 +1,10 @@
 use crate::{
     assert,
-    sparse::SparseColMatRef,
-    utils::{bound, slice::*},
-    Conj, Index, MatMut, Parallelism,
+    sparse::{
+        FaerError, SparseColMat, SparseColMatRef, SymbolicSparseColMat, SymbolicSparseColMatRef,
+    },
+    utils::{bound, slice::*, vec::VecGroup},
+    Conj, Index, MatMut, Parallelism, SignedIndex,
 };
 use core::iter;
 use faer_entity::ComplexField;
@@ -1789,3 +1791,209 @@ pub fn solve_unit_upper_triangular_transpose_in_place<I: Index, E: ComplexField>
         }
     }
 }
+
+/// Computes the set of rows reachable from the nonzero rows of the right-hand side in the graph
+/// of `l`, which is the nonzero pattern of the solution of `l * x = b`.
+///
+/// The reachable rows are stored in `xi[top..]` in topological order, and `top` is returned.
+fn reach<I: Index>(
+    l: SymbolicSparseColMatRef<'_, I>,
+    bi: &[I],
+    marked: &mut [usize],
+    mark: usize,
+    xi: &mut [I],
+    stack: &mut [usize],
+) -> usize {
+    let li = l.row_indices();
+    let mut top = xi.len();
+
+    for &b in bi {
+        if marked[b.zx()] == mark {
+            continue;
+        }
+
+        // `xi[..head_len]` is the current dfs path, and `stack` holds the position of the next
+        // child to visit for each node of the path
+        let mut head_len = 1usize;
+        xi[0] = b;
+        'dfs: while head_len > 0 {
+            let j = xi[head_len - 1].zx();
+            let range = l.col_range(j);
+            if marked[j] != mark {
+                marked[j] = mark;
+                stack[head_len - 1] = range.start;
+            }
+
+            let start = stack[head_len - 1];
+            for (ptr, i) in iter::zip(start..range.end, &li[start..range.end]) {
+                let i = i.zx();
+                if marked[i] == mark {
+                    continue;
+                }
+                stack[head_len - 1] = ptr + 1;
+                xi[head_len] = I::truncate(i);
+                head_len += 1;
+                continue 'dfs;
+            }
+
+            head_len -= 1;
+            top -= 1;
+            xi[top] = I::truncate(j);
+        }
+    }
+
+    top
+}
+
+/// Assuming `l` is a lower triangular matrix, solves the equation `Op(l) * X = rhs`, where `Op` is
+/// either the conjugate or the identity depending on the value of `conj`, and the right-hand side
+/// is sparse. Returns the solution as a sparse matrix.
+///
+/// Each column of the solution is computed by only visiting the columns of `l` that are reachable
+/// from the nonzero rows of the corresponding column of `rhs`, so that the cost of the solve is
+/// proportional to the number of floating point operations, rather than to the dimension of `l`.
+///
+/// The nonzero pattern of the solution is the structural one: it contains the entries that are
+/// reachable from the nonzero pattern of `rhs`, even if their value is zero due to numerical
+/// cancellation.
+///
+/// # Note
+/// The matrix indices need not be sorted, but
+/// the diagonal element is assumed to be the first stored element in each column.
+/// The row indices of each column of the output are sorted.
+#[track_caller]
+pub fn solve_sparse_rhs<I: Index, E: ComplexField>(
+    l: SparseColMatRef<'_, I, E>,
+    conj: Conj,
+    rhs: SparseColMatRef<'_, I, E>,
+) -> Result<SparseColMat<I, E>, FaerError> {
+    assert!(all(l.nrows() == l.ncols(), rhs.nrows() == l.nrows()));
+
+    let n = l.nrows();
+    let k = rhs.ncols();
+    let slice_group = SliceGroup::<'_, E>::new;
+
+    let mut marked = crate::sparse::try_zeroed::<usize>(n)?;
+    let mut stack = crate::sparse::try_zeroed::<usize>(n)?;
+    let mut xi = crate::sparse::try_zeroed::<I>(n)?;
+    let mut x = VecGroup::<E>::new();
+    x.try_reserve_exact(n).map_err(|_| FaerError::OutOfMemory)?;
+    x.resize(n, E::faer_zero().faer_into_units());
+    let mut x = x.as_slice_mut();
+
+    let mut col_ptrs = crate::sparse::try_zeroed::<I>(k + 1)?;
+    let mut row_ind = alloc::vec::Vec::new();
+    let mut values = VecGroup::<E>::new();
+
+    for j in 0..k {
+        let bi = rhs.row_indices_of_col_raw(j);
+        let top = reach(l.symbolic(), bi, &mut marked, j + 1, &mut xi, &mut stack);
+        let xi = &mut xi[top..];
+
+        for (i, b) in iter::zip(bi, slice_group(rhs.values_of_col(j)).into_ref_iter()) {
+            let i = i.zx();
+            x.write(i, x.read(i).faer_add(b.read()));
+        }
+
+        for &c in &*xi {
+            let c = c.zx();
+            let row_ind = l.row_indices_of_col_raw(c);
+            let lx = slice_group(l.values_of_col(c));
+            let len = row_ind.len();
+
+            let d = lx.read(0).faer_inv();
+            let d = if conj == Conj::Yes { d.faer_conj() } else { d };
+            let xc = x.read(c).faer_mul(d);
+            x.write(c, xc);
+
+            for (i, lic) in iter::zip(&row_ind[1..], lx.subslice(1..len).into_ref_iter()) {
+                let i = i.zx();
+                let lic = lic.read();
+                let lic = if conj == Conj::Yes {
+                    lic.faer_conj()
+                } else {
+                    lic
+                };
+                x.write(i, x.read(i).faer_sub(lic.faer_mul(xc)));
+            }
+        }
+
+        xi.sort_unstable();
+        row_ind
+            .try_reserve(xi.len())
+            .map_err(|_| FaerError::OutOfMemory)?;
+        values
+            .try_reserve(xi.len())
+            .map_err(|_| FaerError::OutOfMemory)?;
+        for &i in &*xi {
+            row_ind.push(i);
+            values.push(x.read(i.zx()).faer_into_units());
+            x.write(i.zx(), E::faer_zero());
+        }
+
+        col_ptrs[j + 1] = col_ptrs[j] + I::truncate(xi.len());
+        if col_ptrs[j + 1] > I::from_signed(I::Signed::MAX) {
+            return Err(FaerError::IndexOverflow);
+        }
+    }
+
+    unsafe {
+        Ok(SparseColMat::new(
+            SymbolicSparseColMat::new_unchecked(n, k, col_ptrs, None, row_ind),
+            values.into_inner(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Mat};
+
+    #[test]
+    fn test_solve_sparse_rhs() {
+        // l is block diagonal with blocks {0, 2, 4} and {1, 3, 5}
+        let l = SparseColMat::<usize, c64>::try_new_from_triplets(
+            6,
+            6,
+            &[
+                (0, 0, c64::new(2.0, 1.0)),
+                (2, 0, c64::new(1.0, 0.0)),
+                (1, 1, c64::new(1.0, 0.0)),
+                (3, 1, c64::new(-1.0, 2.0)),
+                (5, 1, c64::new(0.5, 0.0)),
+                (2, 2, c64::new(3.0, 0.0)),
+                (4, 2, c64::new(1.0, -1.0)),
+                (3, 3, c64::new(1.0, 1.0)),
+                (5, 3, c64::new(2.0, 0.0)),
+                (4, 4, c64::new(-2.0, 0.0)),
+                (5, 5, c64::new(4.0, 0.0)),
+            ],
+        )
+        .unwrap();
+        let rhs = SparseColMat::<usize, c64>::try_new_from_triplets(
+            6,
+            4,
+            &[
+                (2, 0, c64::new(1.0, 1.0)),
+                (0, 1, c64::new(1.0, 0.0)),
+                (3, 1, c64::new(0.0, 1.0)),
+                (5, 2, c64::new(2.0, 0.0)),
+            ],
+        )
+        .unwrap();
+
+        for conj in [Conj::No, Conj::Yes] {
+            let x = solve_sparse_rhs(l.as_ref(), conj, rhs.as_ref()).unwrap();
+
+            assert!(x.row_indices_of_col_raw(0) == [2, 4]);
+            assert!(x.row_indices_of_col_raw(1) == [0, 2, 3, 4, 5]);
+            assert!(x.row_indices_of_col_raw(2) == [5]);
+            assert!(x.row_indices_of_col_raw(3).is_empty());
+
+            let mut expected: Mat<c64> = rhs.to_dense();
+            solve_lower_triangular_in_place(l.as_ref(), conj, expected.as_mut(), Parallelism::None);
+            assert!((x.to_dense() - expected).norm_max() < 1e-14);
+        }
+    }
+}
@@ -188,6 +188,67 @@ fn try_collect<I: IntoIterator>(iter: I) -> Result<alloc::vec::Vec<I::Item>, Fae
     Ok(v)
 }
 
+/// Callback generic over the index type, used with [`with_auto_index`].
+pub trait WithIndex {
+    /// Type of the value returned by the callback.
+    type Output;
+
+    /// Calls the callback with the index type `I`.
+    fn call<I: Index>(self) -> Self::Output;
+}
+
+/// Calls `f` with `u32` indices if a sparse matrix with the given dimensions and number of
+/// non-zeros can be represented with them, and with `u64` indices otherwise (`usize` indices on
+/// targets where `u64` is not an index type).
+///
+/// Using the smaller index type halves the memory occupied by the index arrays of the matrix and
+/// its factorizations, so this avoids hard-coding the index type for problems whose size is only
+/// known at runtime.
+///
+/// # Example
+/// ```
+/// use faer::{
+///     sparse::{with_auto_index, SparseColMat, WithIndex},
+///     Index,
+/// };
+///
+/// struct IndexBytes<'a>(&'a [(usize, usize, f64)]);
+/// impl WithIndex for IndexBytes<'_> {
+///     type Output = usize;
+///
+///     fn call<I: Index>(self) -> usize {
+///         let triplets: Vec<_> = self
+///             .0
+///             .iter()
+///             .map(|&(i, j, v)| (I::truncate(i), I::truncate(j), v))
+///             .collect();
+///         let A = SparseColMat::<I, f64>::try_new_from_triplets(2, 2, &triplets).unwrap();
+///         A.index_byte_size()
+///     }
+/// }
+///
+/// let triplets = [(0, 0, 1.0), (1, 1, 2.0)];
+/// // u32 indices are selected: 3 column pointers and 2 row indices
+/// assert_eq!(with_auto_index(2, 2, 2, IndexBytes(&triplets)), 5 * 4);
+/// ```
+#[inline]
+pub fn with_auto_index<F: WithIndex>(nrows: usize, ncols: usize, nnz: usize, f: F) -> F::Output {
+    // dimensions and column pointers must fit in the signed index type
+    let max = i32::MAX as usize;
+    if nrows <= max && ncols <= max && nnz <= max {
+        f.call::<u32>()
+    } else {
+        #[cfg(target_pointer_width = "64")]
+        {
+            f.call::<u64>()
+        }
+        #[cfg(not(target_pointer_width = "64"))]
+        {
+            f.call::<usize>()
+        }
+    }
+}
+
 /// The order values should be read in, when constructing/filling from indices and values.
 ///
 /// Allows separately creating the symbolic structure and filling the numerical values.
@@ -221,6 +282,8 @@ pub mod ops;
 pub use csc::*;
 pub use csr::*;
 
+pub use linalg::cholesky::elimination_tree;
+
 /// Useful sparse matrix primitives.
 pub mod utils {
     use super::*;
@@ -1561,4 +1624,22 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_with_auto_index() {
+        struct IndexSize;
+        impl WithIndex for IndexSize {
+            type Output = usize;
+            fn call<I: Index>(self) -> usize {
+                core::mem::size_of::<I>()
+            }
+        }
+
+        let max = i32::MAX as usize;
+        assert!(with_auto_index(0, 0, 0, IndexSize) == 4);
+        assert!(with_auto_index(max, max, max, IndexSize) == 4);
+        assert!(with_auto_index(max + 1, 1, 1, IndexSize) == 8);
+        assert!(with_auto_index(1, max + 1, 1, IndexSize) == 8);
+        assert!(with_auto_index(1, 1, max + 1, IndexSize) == 8);
+    }
 }
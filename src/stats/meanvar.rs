@@ -26,7 +26,7 @@ pub enum NanHandling {
 }
 
 #[inline(always)]
-fn from_usize<E: RealField>(n: usize) -> E {
+pub(super) fn from_usize<E: RealField>(n: usize) -> E {
     E::faer_from_f64(n as u32 as f64)
         .faer_add(E::faer_from_f64((n as u64 - (n as u32 as u64)) as f64))
 }
@@ -1,9 +1,13 @@
-use crate::{Col, ComplexField, Mat, Row};
+use crate::{Col, ComplexField, Mat, MatMut, Row};
+#[cfg(all(feature = "cholesky", feature = "evd"))]
+use crate::{ColRef, MatRef, RealField};
 use rand::distributions::Distribution;
 use rand_distr::{Standard, StandardNormal};
 
 mod meanvar;
+mod moments;
 pub use meanvar::{col_mean, col_varm, row_mean, row_varm, NanHandling};
+pub use moments::OnlineMoments;
 
 /// The normal distribution, `N(mean, std_dev**2)`.
 pub struct Normal<E: ComplexField> {
@@ -247,3 +251,300 @@ where
         Row::from_fn(self.ncols, |_| Standard.sample(rng))
     }
 }
+
+/// Perturbs each element of `a` in place with noise proportional to its magnitude, such that
+/// $a_{ij}$ is replaced by $a_{ij} + \delta |a_{ij}| z_{ij}$, where $\delta$ is
+/// `relative_magnitude` and $z_{ij}$ is drawn from the standard normal distribution.
+///
+/// Zero elements are left unchanged, so the sparsity pattern of `a` is preserved. The elements
+/// are visited in column-major order, so that the perturbation only depends on the state of
+/// `rng`, and seeding it makes the result reproducible.
+///
+/// # Example
+/// ```
+/// use faer::{mat, stats::perturb};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let a = mat![[1.0, 2.0], [0.0, 4.0f64]];
+/// let mut b = a.clone();
+/// perturb(b.as_mut(), 1e-8, &mut StdRng::seed_from_u64(0));
+/// let mut c = a.clone();
+/// perturb(c.as_mut(), 1e-8, &mut StdRng::seed_from_u64(0));
+///
+/// assert!(b == c);
+/// assert!(b.read(1, 0) == 0.0);
+/// assert!((&b - &a).norm_max() < 1e-6);
+/// ```
+pub fn perturb<E: ComplexField, R: rand::Rng + ?Sized>(
+    a: MatMut<'_, E>,
+    relative_magnitude: E::Real,
+    rng: &mut R,
+) where
+    StandardNormal: Distribution<E>,
+{
+    let mut a = a;
+    for j in 0..a.ncols() {
+        for i in 0..a.nrows() {
+            let a_ij = a.read(i, j);
+            let z: E = StandardNormal.sample(rng);
+            a.write(
+                i,
+                j,
+                a_ij.faer_add(z.faer_scale_real(relative_magnitude.faer_mul(a_ij.faer_abs()))),
+            );
+        }
+    }
+}
+
+/// Perturbs `a` in place with a random rank one matrix, such that $A$ is replaced by
+/// $A + \delta \|A\|_F u v^H$, where $\delta$ is `relative_magnitude`, and $u$ and $v$ are
+/// drawn uniformly from the unit sphere. The perturbation therefore has a Frobenius norm (and
+/// spectral norm) equal to $\delta \|A\|_F$.
+///
+/// Returns the vectors $u$ and $v$. Like [`perturb`], the result only depends on the state of
+/// `rng`.
+pub fn perturb_rank_one<E: ComplexField, R: rand::Rng + ?Sized>(
+    a: MatMut<'_, E>,
+    relative_magnitude: E::Real,
+    rng: &mut R,
+) -> (Col<E>, Col<E>)
+where
+    StandardNormal: Distribution<E>,
+{
+    let unit = |n: usize, rng: &mut R| {
+        let x: Col<E> = StandardNormalCol { nrows: n }.sample(rng);
+        let norm = x.norm_l2();
+        if norm > E::Real::faer_zero() {
+            x * crate::scale(E::faer_from_real(norm.faer_inv()))
+        } else {
+            x
+        }
+    };
+    let u = unit(a.nrows(), rng);
+    let v = unit(a.ncols(), rng);
+
+    let scale = relative_magnitude.faer_mul(a.norm_l2());
+    crate::linalg::matmul::matmul(
+        a,
+        u.as_ref().as_2d(),
+        v.adjoint().as_2d(),
+        Some(E::faer_one()),
+        E::faer_from_real(scale),
+        crate::Parallelism::None,
+    );
+    (u, v)
+}
+
+/// Error returned by [`sample_mvn`] when the covariance matrix is invalid.
+#[cfg(all(feature = "cholesky", feature = "evd"))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MvnError {
+    /// The covariance matrix is not numerically self-adjoint.
+    NotSelfAdjoint,
+    /// The covariance matrix has a negative eigenvalue that is not attributable to rounding
+    /// errors.
+    Indefinite,
+}
+
+#[cfg(all(feature = "cholesky", feature = "evd"))]
+impl core::fmt::Display for MvnError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(all(feature = "cholesky", feature = "evd"))]
+impl core::error::Error for MvnError {}
+
+/// Draws `n_samples` samples from the multivariate normal distribution `N(mean, cov)`, and returns
+/// them as the columns of a matrix.
+///
+/// The covariance is factorized as $\Sigma = LL^H$, and each sample is computed as $\mu + Lz$,
+/// where $z$ is drawn from the standard normal distribution. The Cholesky decomposition is used if
+/// the covariance is positive definite. Otherwise, the factor $L = US^{1/2}$ is computed from the
+/// eigendecomposition $\Sigma = USU^H$, with eigenvalues that are negative due to rounding errors
+/// replaced by zero. This allows sampling from degenerate distributions whose covariance is only
+/// positive semidefinite.
+///
+/// # Errors
+/// Returns [`MvnError::NotSelfAdjoint`] if the covariance is not self-adjoint, and
+/// [`MvnError::Indefinite`] if it has a negative eigenvalue larger in magnitude than
+/// `dim * epsilon` times its largest eigenvalue. Both checks are relative to the scale of the
+/// covariance.
+///
+/// # Panics
+/// Panics if `cov` is not a square matrix, or if its dimension differs from that of `mean`.
+#[cfg(all(feature = "cholesky", feature = "evd"))]
+#[track_caller]
+pub fn sample_mvn<E: ComplexField, R: rand::Rng + ?Sized>(
+    mean: ColRef<'_, E>,
+    cov: MatRef<'_, E>,
+    n_samples: usize,
+    rng: &mut R,
+) -> Result<Mat<E>, MvnError>
+where
+    StandardNormal: Distribution<E>,
+{
+    crate::assert!(all(cov.nrows() == cov.ncols(), cov.nrows() == mean.nrows()));
+    let n = cov.nrows();
+    let tol = E::Real::faer_epsilon()
+        .faer_mul(E::Real::faer_from_f64(Ord::max(n, 1) as f64))
+        .faer_mul(cov.norm_max());
+
+    for j in 0..n {
+        for i in j..n {
+            let diff = cov.read(i, j).faer_sub(cov.read(j, i).faer_conj());
+            // also rejects nan values
+            if !matches!(
+                diff.faer_abs().partial_cmp(&tol),
+                Some(core::cmp::Ordering::Less | core::cmp::Ordering::Equal)
+            ) {
+                return Err(MvnError::NotSelfAdjoint);
+            }
+        }
+    }
+
+    let factor = match cov.cholesky(crate::Side::Lower) {
+        Ok(llt) => llt.compute_l(),
+        Err(_) => {
+            let (s, mut u) = cov
+                .selfadjoint_eigendecomposition(crate::Side::Lower)
+                .into_real_parts();
+            let s_max = if n == 0 {
+                E::Real::faer_zero()
+            } else {
+                s.read(n - 1)
+            };
+            let tol = E::Real::faer_epsilon()
+                .faer_mul(E::Real::faer_from_f64(n as f64))
+                .faer_mul(s_max.faer_abs());
+            for j in 0..n {
+                let s = s.read(j);
+                if s < tol.faer_neg() {
+                    return Err(MvnError::Indefinite);
+                }
+                let scale = if s > E::Real::faer_zero() {
+                    s.faer_sqrt()
+                } else {
+                    E::Real::faer_zero()
+                };
+                crate::zipped_rw!(u.as_mut().col_mut(j)).for_each(|crate::unzipped!(mut u)| {
+                    u.write(u.read().faer_scale_real(scale));
+                });
+            }
+            u
+        }
+    };
+
+    let z: Mat<E> = StandardNormalMat {
+        nrows: n,
+        ncols: n_samples,
+    }
+    .sample(rng);
+    let mut samples = Mat::from_fn(n, n_samples, |i, _| mean.read(i));
+    crate::linalg::matmul::matmul(
+        samples.as_mut(),
+        factor.as_ref(),
+        z.as_ref(),
+        Some(E::faer_one()),
+        E::faer_one(),
+        crate::get_global_parallelism(),
+    );
+    Ok(samples)
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "cholesky", feature = "evd"))]
+mod tests {
+    use super::*;
+    use crate::assert;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn sample_cov(samples: MatRef<'_, f64>, mean: ColRef<'_, f64>) -> Mat<f64> {
+        let centered = Mat::from_fn(samples.nrows(), samples.ncols(), |i, j| {
+            samples.read(i, j) - mean.read(i)
+        });
+        &centered * centered.transpose() * (1.0 / samples.ncols() as f64)
+    }
+
+    #[test]
+    fn test_sample_mvn() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let mean = crate::col![1.0, -2.0, 3.0f64];
+        let n = 200_000;
+
+        // positive definite
+        let cov = mat![[4.0, 1.0, 0.5], [1.0, 2.0, -0.3], [0.5, -0.3, 1.0f64]];
+        let samples = sample_mvn(mean.as_ref(), cov.as_ref(), n, rng).unwrap();
+        assert!(all(samples.nrows() == 3, samples.ncols() == n));
+        let sample_mean = Col::from_fn(3, |i| samples.row(i).sum() / n as f64);
+        assert!((&sample_mean - &mean).norm_max() < 0.02);
+        assert!((sample_cov(samples.as_ref(), mean.as_ref()) - &cov).norm_max() < 0.05);
+
+        // rank one, only positive semidefinite
+        let v = crate::col![1.0, 2.0, -1.0f64];
+        let cov = &v * v.transpose();
+        let samples = sample_mvn(mean.as_ref(), cov.as_ref(), n, rng).unwrap();
+        assert!((sample_cov(samples.as_ref(), mean.as_ref()) - &cov).norm_max() < 0.1);
+        // every sample lies on the line spanned by `v`
+        for j in 0..100 {
+            let d = samples.col(j) - &mean;
+            let t = d.read(0);
+            assert!((d - &v * crate::scale(t)).norm_max() < 1e-10);
+        }
+
+        let cov = mat![[1.0, 2.0], [0.0, 1.0f64]];
+        let mean = crate::col![0.0, 0.0f64];
+        assert!(sample_mvn(mean.as_ref(), cov.as_ref(), 1, rng) == Err(MvnError::NotSelfAdjoint));
+        let cov = mat![[1.0, 2.0], [2.0, 1.0f64]];
+        assert!(sample_mvn(mean.as_ref(), cov.as_ref(), 1, rng) == Err(MvnError::Indefinite));
+
+        let empty = Mat::<f64>::zeros(0, 0);
+        let samples = sample_mvn(Col::zeros(0).as_ref(), empty.as_ref(), 3, rng).unwrap();
+        assert!((samples.nrows(), samples.ncols()) == (0, 3));
+    }
+
+    #[test]
+    fn test_perturb() {
+        let a = Mat::from_fn(20, 10, |i, j| {
+            if (i + j) % 3 == 0 {
+                0.0
+            } else {
+                (i * j) as f64 + 1.0
+            }
+        });
+
+        let mut b = a.clone();
+        perturb(b.as_mut(), 1e-3, &mut StdRng::seed_from_u64(1));
+        let mut c = a.clone();
+        perturb(c.as_mut(), 1e-3, &mut StdRng::seed_from_u64(1));
+        let mut d = a.clone();
+        perturb(d.as_mut(), 1e-3, &mut StdRng::seed_from_u64(2));
+        assert!(b == c);
+        assert!(b != d);
+
+        for j in 0..10 {
+            for i in 0..20 {
+                let a = a.read(i, j);
+                let b = b.read(i, j);
+                if a == 0.0 {
+                    assert!(b == 0.0);
+                } else {
+                    assert!(b != a);
+                    // ten standard deviations
+                    assert!((b - a).abs() <= 1e-2 * a.abs());
+                }
+            }
+        }
+
+        let mut b = a.clone();
+        let (u, v) = perturb_rank_one(b.as_mut(), 1e-3, &mut StdRng::seed_from_u64(1));
+        assert!((u.norm_l2() - 1.0).abs() < 1e-12);
+        assert!((v.norm_l2() - 1.0).abs() < 1e-12);
+        let delta = &b - &a;
+        let expected = &u * v.transpose() * crate::scale(1e-3 * a.norm_l2());
+        assert!((&delta - &expected).norm_max() < 1e-12);
+        assert!((delta.norm_l2() - 1e-3 * a.norm_l2()).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,244 @@
+use super::meanvar::from_usize;
+use crate::{linalg::matmul::matmul_scale_real, Col, ColRef, ComplexField, Mat, Parallelism};
+use equator::assert;
+
+/// Streaming accumulator for the mean and covariance of a sequence of observations, each of which
+/// is a column with `nfeatures` entries.
+///
+/// The observations are never stored, so the memory usage only depends on the number of features.
+/// Updates use Welford's algorithm, which avoids the cancellation issues of accumulating raw sums
+/// of squares, and two accumulators over disjoint sets of observations can be combined with
+/// [`OnlineMoments::merge`], e.g. for a parallel reduction.
+///
+/// # Example
+/// ```
+/// use faer::{col, stats::OnlineMoments};
+///
+/// let mut moments = OnlineMoments::<f64>::new(2);
+/// moments.update(col![1.0, 2.0].as_ref());
+/// moments.update(col![3.0, 6.0].as_ref());
+///
+/// let (mean, cov) = moments.finalize();
+/// assert_eq!(mean, col![2.0, 4.0]);
+/// assert_eq!(cov, faer::mat![[2.0, 4.0], [4.0, 8.0]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct OnlineMoments<E: ComplexField> {
+    count: usize,
+    mean: Col<E>,
+    // sum of the outer products of the deviations from the mean
+    m2: Mat<E>,
+    // scratch space for the deviation, to avoid allocating on every update
+    delta: Col<E>,
+}
+
+impl<E: ComplexField> OnlineMoments<E> {
+    /// Returns an empty accumulator for observations with `nfeatures` entries.
+    pub fn new(nfeatures: usize) -> Self {
+        Self {
+            count: 0,
+            mean: Col::zeros(nfeatures),
+            m2: Mat::zeros(nfeatures, nfeatures),
+            delta: Col::zeros(nfeatures),
+        }
+    }
+
+    /// Returns the number of entries in each observation.
+    #[inline]
+    pub fn nfeatures(&self) -> usize {
+        self.mean.nrows()
+    }
+
+    /// Returns the number of observations accumulated so far.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the observations accumulated so far, or zeros if there are none.
+    #[inline]
+    pub fn mean(&self) -> ColRef<'_, E> {
+        self.mean.as_ref()
+    }
+
+    /// Adds the observation `x` to the accumulator.
+    ///
+    /// # Panics
+    /// Panics if `x.nrows() != self.nfeatures()`.
+    #[track_caller]
+    pub fn update(&mut self, x: ColRef<'_, E>) {
+        assert!(x.nrows() == self.nfeatures());
+
+        self.count += 1;
+        let n = from_usize::<E::Real>(self.count);
+        let inv_n = n.faer_inv();
+
+        zipped_rw!(self.delta.as_mut(), self.mean.as_mut(), x).for_each(
+            |unzipped!(mut delta, mut mean, x)| {
+                let d = x.read().faer_sub(mean.read());
+                delta.write(d);
+                mean.write(mean.read().faer_add(d.faer_scale_real(inv_n)));
+            },
+        );
+
+        // m2 += (n - 1) / n * delta * delta^H
+        matmul_scale_real(
+            self.m2.as_mut(),
+            self.delta.as_ref().as_2d(),
+            self.delta.as_ref().adjoint().as_2d(),
+            Some(E::Real::faer_one()),
+            n.faer_sub(E::Real::faer_one()).faer_mul(inv_n),
+            Parallelism::None,
+        );
+    }
+
+    /// Adds all the observations accumulated in `other` to `self`.
+    ///
+    /// # Panics
+    /// Panics if `other.nfeatures() != self.nfeatures()`.
+    #[track_caller]
+    pub fn merge(&mut self, other: &Self) {
+        assert!(other.nfeatures() == self.nfeatures());
+
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            self.count = other.count;
+            self.mean.copy_from(&other.mean);
+            self.m2.copy_from(&other.m2);
+            return;
+        }
+
+        let na = from_usize::<E::Real>(self.count);
+        let nb = from_usize::<E::Real>(other.count);
+        self.count += other.count;
+        let n = from_usize::<E::Real>(self.count);
+        let inv_n = n.faer_inv();
+        let wb = nb.faer_mul(inv_n);
+
+        zipped_rw!(self.delta.as_mut(), self.mean.as_mut(), other.mean.as_ref()).for_each(
+            |unzipped!(mut delta, mut mean, other)| {
+                let d = other.read().faer_sub(mean.read());
+                delta.write(d);
+                mean.write(mean.read().faer_add(d.faer_scale_real(wb)));
+            },
+        );
+
+        // m2 = m2_a + m2_b + na * nb / n * delta * delta^H
+        zipped_rw!(self.m2.as_mut(), other.m2.as_ref())
+            .for_each(|unzipped!(mut dst, src)| dst.write(dst.read().faer_add(src.read())));
+        matmul_scale_real(
+            self.m2.as_mut(),
+            self.delta.as_ref().as_2d(),
+            self.delta.as_ref().adjoint().as_2d(),
+            Some(E::Real::faer_one()),
+            na.faer_mul(wb),
+            Parallelism::None,
+        );
+    }
+
+    /// Returns the mean and the sample covariance of the accumulated observations.
+    ///
+    /// The covariance is normalized by `count - 1`. If no observations were accumulated, both
+    /// results are filled with NaN, and if a single observation was accumulated, the covariance is
+    /// zero.
+    pub fn finalize(self) -> (Col<E>, Mat<E>) {
+        let Self {
+            count,
+            mut mean,
+            mut m2,
+            ..
+        } = self;
+
+        match count {
+            0 => {
+                mean.fill(E::faer_nan());
+                m2.fill(E::faer_nan());
+            }
+            1 => m2.fill_zero(),
+            _ => {
+                let scale = from_usize::<E::Real>(count - 1).faer_inv();
+                zipped_rw!(m2.as_mut())
+                    .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(scale)));
+            }
+        }
+
+        (mean, m2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{complex_native::c64, stats::StandardNormalMat};
+    use equator::assert;
+    use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+    fn reference<E: ComplexField>(data: &Mat<E>) -> (Col<E>, Mat<E>) {
+        let n = data.ncols();
+        let mean = Col::from_fn(data.nrows(), |i| {
+            let mut acc = E::faer_zero();
+            for j in 0..n {
+                acc = acc.faer_add(data.read(i, j));
+            }
+            acc.faer_scale_real(from_usize::<E::Real>(n).faer_inv())
+        });
+        let centered = Mat::from_fn(data.nrows(), n, |i, j| {
+            data.read(i, j).faer_sub(mean.read(i))
+        });
+        let cov = (&centered * centered.adjoint())
+            * crate::scale(E::faer_from_f64((n - 1) as f64).faer_inv());
+        (mean, cov)
+    }
+
+    #[test]
+    fn test_online_moments() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let p = 5;
+        let n = 200;
+        let mut data: Mat<c64> = StandardNormalMat { nrows: p, ncols: n }.sample(rng);
+        // large offset, which naive sums of squares handle poorly
+        for j in 0..n {
+            for i in 0..p {
+                data.write(i, j, data.read(i, j) + c64::new(1e8, -1e8));
+            }
+        }
+        let (mean_ref, cov_ref) = reference(&data);
+
+        let mut full = OnlineMoments::new(p);
+        let mut left = OnlineMoments::new(p);
+        let mut right = OnlineMoments::new(p);
+        for j in 0..n {
+            full.update(data.col(j));
+            if j < 73 {
+                left.update(data.col(j));
+            } else {
+                right.update(data.col(j));
+            }
+        }
+        assert!(full.count() == n);
+        left.merge(&right);
+        left.merge(&OnlineMoments::new(p));
+        assert!(left.count() == n);
+
+        for moments in [full, left] {
+            let (mean, cov) = moments.finalize();
+            assert!((&mean - &mean_ref).norm_max() < 1e-7);
+            assert!((&cov - &cov_ref).norm_max() < 1e-6);
+            assert!((&cov - cov.adjoint()).norm_max() < 1e-12);
+        }
+
+        let mut empty = OnlineMoments::<f64>::new(2);
+        let (mean, cov) = empty.clone().finalize();
+        assert!(all(mean.has_nan(), cov.has_nan()));
+        empty.merge(&{
+            let mut one = OnlineMoments::new(2);
+            one.update(crate::col![1.0, 2.0].as_ref());
+            one
+        });
+        let (mean, cov) = empty.finalize();
+        assert!(mean == crate::col![1.0, 2.0]);
+        assert!(cov == Mat::<f64>::zeros(2, 2));
+    }
+}
@@ -0,0 +1,265 @@
+//! Elementary functions over [`RealField`] types, which the trait itself does not provide.
+//!
+//! `f32` and `f64` are forwarded to `libm`, other types use series expansions that are evaluated
+//! until the terms fall below the type's epsilon.
+
+use crate::RealField;
+
+#[inline(always)]
+fn from_f64<E: RealField>(value: f64) -> E {
+    E::faer_from_f64(value)
+}
+
+/// Returns the exponential of `x`.
+#[inline]
+pub(crate) fn exp<E: RealField>(x: E) -> E {
+    if coe::is_same::<E, f64>() {
+        coe::coerce_static::<f64, E>(libm::exp(coe::coerce_static::<E, f64>(x)))
+    } else if coe::is_same::<E, f32>() {
+        coe::coerce_static::<f32, E>(libm::expf(coe::coerce_static::<E, f32>(x)))
+    } else {
+        exp_generic(x)
+    }
+}
+
+/// Returns the natural logarithm of `x`.
+#[inline]
+pub(crate) fn ln<E: RealField>(x: E) -> E {
+    if coe::is_same::<E, f64>() {
+        coe::coerce_static::<f64, E>(libm::log(coe::coerce_static::<E, f64>(x)))
+    } else if coe::is_same::<E, f32>() {
+        coe::coerce_static::<f32, E>(libm::logf(coe::coerce_static::<E, f32>(x)))
+    } else {
+        ln_generic(x)
+    }
+}
+
+/// Returns the arcsine of `x`, or NaN if `x` is outside `[-1, 1]`.
+#[inline]
+pub(crate) fn asin<E: RealField>(x: E) -> E {
+    if coe::is_same::<E, f64>() {
+        coe::coerce_static::<f64, E>(libm::asin(coe::coerce_static::<E, f64>(x)))
+    } else if coe::is_same::<E, f32>() {
+        coe::coerce_static::<f32, E>(libm::asinf(coe::coerce_static::<E, f32>(x)))
+    } else {
+        asin_generic(x)
+    }
+}
+
+/// Returns the arccosine of `x`, or NaN if `x` is outside `[-1, 1]`.
+#[inline]
+pub(crate) fn acos<E: RealField>(x: E) -> E {
+    if coe::is_same::<E, f64>() {
+        coe::coerce_static::<f64, E>(libm::acos(coe::coerce_static::<E, f64>(x)))
+    } else if coe::is_same::<E, f32>() {
+        coe::coerce_static::<f32, E>(libm::acosf(coe::coerce_static::<E, f32>(x)))
+    } else {
+        acos_generic(x)
+    }
+}
+
+fn exp_generic<E: RealField>(x: E) -> E {
+    let zero = E::faer_zero();
+    let one = E::faer_one();
+    let half = from_f64::<E>(0.5);
+
+    if x.faer_is_nan() {
+        return x;
+    }
+    if !x.faer_is_finite() {
+        return if x > zero { x } else { zero };
+    }
+
+    // exp(x) = exp(x / 2^k)^(2^k), with |x / 2^k| <= 1/2
+    let mut r = x;
+    let mut squarings = 0usize;
+    while r.faer_abs() > half {
+        r = r.faer_mul(half);
+        squarings += 1;
+    }
+
+    let eps = E::faer_epsilon();
+    let mut term = one;
+    let mut sum = one;
+    let mut i = 1.0;
+    loop {
+        term = term.faer_mul(r).faer_div(from_f64(i));
+        sum = sum.faer_add(term);
+        if term.faer_abs() <= eps.faer_mul(sum.faer_abs()) {
+            break;
+        }
+        i += 1.0;
+    }
+
+    for _ in 0..squarings {
+        sum = sum.faer_mul(sum);
+    }
+    sum
+}
+
+/// Returns `atanh(s)` for `|s| <= 1/3`.
+fn atanh_small<E: RealField>(s: E) -> E {
+    let eps = E::faer_epsilon();
+    let s2 = s.faer_mul(s);
+    let mut power = s;
+    let mut sum = s;
+    let mut i = 3.0;
+    loop {
+        power = power.faer_mul(s2);
+        let term = power.faer_div(from_f64(i));
+        sum = sum.faer_add(term);
+        if term.faer_abs() <= eps.faer_mul(sum.faer_abs()) {
+            break;
+        }
+        i += 2.0;
+    }
+    sum
+}
+
+fn ln_generic<E: RealField>(x: E) -> E {
+    let zero = E::faer_zero();
+    let one = E::faer_one();
+    let two = from_f64::<E>(2.0);
+    let half = from_f64::<E>(0.5);
+
+    if x.faer_is_nan() || x < zero {
+        return from_f64(f64::NAN);
+    }
+    if x == zero {
+        return from_f64(f64::NEG_INFINITY);
+    }
+    if !x.faer_is_finite() {
+        return x;
+    }
+
+    // x = m * 2^k, with 1/sqrt(2) <= m <= sqrt(2)
+    let big = from_f64::<E>(18446744073709551616.0);
+    let small = big.faer_inv();
+    let sqrt2 = two.faer_sqrt();
+    let mut m = x;
+    let mut k = 0i64;
+    while m > big {
+        m = m.faer_mul(small);
+        k += 64;
+    }
+    while m < small {
+        m = m.faer_mul(big);
+        k -= 64;
+    }
+    while m > sqrt2 {
+        m = m.faer_mul(half);
+        k += 1;
+    }
+    while m.faer_mul(sqrt2) < one {
+        m = m.faer_mul(two);
+        k -= 1;
+    }
+
+    // ln(m) = 2 atanh((m - 1) / (m + 1)), ln(2) = 2 atanh(1/3)
+    let ln_m = two.faer_mul(atanh_small(m.faer_sub(one).faer_div(m.faer_add(one))));
+    let ln_2 = two.faer_mul(atanh_small(from_f64::<E>(3.0).faer_inv()));
+    from_f64::<E>(k as f64).faer_mul(ln_2).faer_add(ln_m)
+}
+
+fn atan_generic<E: RealField>(y: E) -> E {
+    let zero = E::faer_zero();
+    let one = E::faer_one();
+    let two = from_f64::<E>(2.0);
+
+    if y.faer_is_nan() {
+        return y;
+    }
+    if y < zero {
+        return atan_generic(y.faer_neg()).faer_neg();
+    }
+    if y > one {
+        // atan(y) = pi/2 - atan(1/y)
+        let half_pi = two.faer_mul(atan_generic(one));
+        return half_pi.faer_sub(atan_generic(y.faer_inv()));
+    }
+
+    // atan(y) = 2 atan(y / (1 + sqrt(1 + y^2))), applied until y <= 1/8
+    let eighth = from_f64::<E>(0.125);
+    let mut r = y;
+    let mut scale = one;
+    while r > eighth {
+        r = r.faer_div(one.faer_add(one.faer_add(r.faer_mul(r)).faer_sqrt()));
+        scale = scale.faer_mul(two);
+    }
+
+    let eps = E::faer_epsilon();
+    let r2 = r.faer_mul(r);
+    let mut power = r;
+    let mut sum = r;
+    let mut i = 3.0;
+    loop {
+        power = power.faer_mul(r2).faer_neg();
+        let term = power.faer_div(from_f64(i));
+        sum = sum.faer_add(term);
+        if term.faer_abs() <= eps.faer_mul(sum.faer_abs()) {
+            break;
+        }
+        i += 2.0;
+    }
+    scale.faer_mul(sum)
+}
+
+fn asin_generic<E: RealField>(x: E) -> E {
+    let one = E::faer_one();
+    if x.faer_is_nan() || x.faer_abs() > one {
+        return from_f64(f64::NAN);
+    }
+    // asin(x) = atan(x / sqrt((1 - x)(1 + x)))
+    atan_generic(x.faer_div(one.faer_sub(x).faer_mul(one.faer_add(x)).faer_sqrt()))
+}
+
+fn acos_generic<E: RealField>(x: E) -> E {
+    let one = E::faer_one();
+    if x.faer_is_nan() || x.faer_abs() > one {
+        return from_f64(f64::NAN);
+    }
+    // acos(x) = 2 atan(sqrt((1 - x) / (1 + x)))
+    from_f64::<E>(2.0).faer_mul(atan_generic(
+        one.faer_sub(x).faer_div(one.faer_add(x)).faer_sqrt(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use equator::assert;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-12 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn test_exp_ln_generic() {
+        for x in [
+            -40.0, -3.5, -1.0, -0.25, 0.0, 1e-10, 0.5, 1.0, 2.0, 10.0, 100.0,
+        ] {
+            assert!(close(exp_generic(x), libm::exp(x)));
+        }
+        for x in [1e-300, 1e-10, 0.3, 0.75, 1.0, 1.5, 2.0, 3.0, 1e10, 1e300] {
+            assert!(close(ln_generic(x), libm::log(x)));
+        }
+
+        assert!(exp_generic(f64::NEG_INFINITY) == 0.0);
+        assert!(exp_generic(f64::INFINITY) == f64::INFINITY);
+        assert!(exp_generic(1000.0) == f64::INFINITY);
+        assert!(ln_generic(0.0) == f64::NEG_INFINITY);
+        assert!(ln_generic(-1.0f64).is_nan());
+        assert!(ln_generic(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_asin_acos_generic() {
+        for x in [-1.0, -0.9, -0.5, -1e-10, 0.0, 0.1, 0.5, 0.75, 0.99, 1.0] {
+            assert!(close(asin_generic(x), libm::asin(x)));
+            assert!(close(acos_generic(x), libm::acos(x)));
+        }
+        assert!(asin_generic(1.5f64).is_nan());
+        assert!(acos_generic(-1.5f64).is_nan());
+        assert!(acos_generic(f64::NAN).is_nan());
+    }
+}
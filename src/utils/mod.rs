@@ -16,6 +16,29 @@ pub(crate) unsafe fn unchecked_add(a: isize, b: isize) -> isize {
     sum
 }
 
+/// Panics if the offset of some element of a view with the given dimensions and strides is not
+/// representable as an `isize`, in which case computing its address would silently wrap around.
+#[track_caller]
+#[inline]
+pub(crate) fn assert_strided_offsets_fit(
+    nrows: usize,
+    ncols: usize,
+    row_stride: isize,
+    col_stride: isize,
+) {
+    let max_offset = if nrows == 0 || ncols == 0 {
+        Some(0)
+    } else {
+        usize::checked_mul(nrows - 1, row_stride.unsigned_abs())
+            .zip(usize::checked_mul(ncols - 1, col_stride.unsigned_abs()))
+            .and_then(|(row, col)| row.checked_add(col))
+    };
+    crate::assert!(
+        max_offset.is_some_and(|offset| offset <= isize::MAX as usize),
+        "the element offsets of a view with dimensions ({nrows}, {ncols}) and strides ({row_stride}, {col_stride}) overflow `isize`",
+    );
+}
+
 #[doc(hidden)]
 pub(crate) trait DivCeil: Sized {
     fn msrv_div_ceil(self, rhs: Self) -> Self;
@@ -45,6 +68,8 @@ impl DivCeil for usize {
     }
 }
 
+pub(crate) mod math;
+
 /// Simd operations for a specific type satisfying [`ComplexField`](crate::ComplexField).
 pub mod simd;
 /// Slice types for [entities](crate::Entity).
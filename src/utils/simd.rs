@@ -459,6 +459,104 @@ impl<E: RealField, S: pulp::Simd> SimdFor<E, S> {
         E::faer_simd_index_add(self.simd, a, b)
     }
 }
+/// Lane-wise kernel applied by [`MatRef::map_simd`](crate::MatRef::map_simd),
+/// [`ColRef::map_simd`](crate::ColRef::map_simd) and [`RowRef::map_simd`](crate::RowRef::map_simd).
+///
+/// The kernel is dispatched through [`pulp`], the same way the crate's own vectorized routines
+/// are, so [`SimdMap::apply`] is instantiated once for every instruction set supported by
+/// `E::Simd`, and the best one available at runtime is selected. Implementations must respect the
+/// [`pulp::Simd`] contract:
+/// - only the token `simd.simd` (or [`SimdFor`] wrappers created from it) may be used to perform
+///   vector operations, since the target features it enables are only guaranteed to be available
+///   inside of the call,
+/// - the output lanes must only depend on the input lanes at the same positions, since the values
+///   are not processed in any particular grouping,
+/// - elements that don't fill a whole register are padded with zeros before being passed to the
+///   kernel, and the corresponding output lanes are discarded, so the kernel must not panic or
+///   produce side effects depending on these values.
+pub trait SimdMap<E: ComplexField> {
+    /// Applies the kernel to each lane of `values`.
+    fn apply<S: pulp::Simd>(
+        &mut self,
+        simd: SimdFor<E, S>,
+        values: SimdGroupFor<E, S>,
+    ) -> SimdGroupFor<E, S>;
+}
+
+/// Writes `f` applied to the elements of `src` to `dst`, which must be contiguous.
+#[track_caller]
+pub(crate) fn map_simd_col<E: ComplexField>(
+    dst: crate::ColMut<'_, E>,
+    src: crate::ColRef<'_, E>,
+    f: &mut impl SimdMap<E>,
+) {
+    struct Impl<'a, E: ComplexField, F> {
+        dst: SliceGroupMut<'a, E>,
+        src: Option<SliceGroup<'a, E>>,
+        f: &'a mut F,
+    }
+
+    impl<E: ComplexField, F: SimdMap<E>> pulp::WithSimd for Impl<'_, E, F> {
+        type Output = ();
+
+        #[inline(always)]
+        fn with_simd<S: pulp::Simd>(self, simd: S) -> Self::Output {
+            let Self { mut dst, src, f } = self;
+            let simd = SimdFor::<E, S>::new(simd);
+            let zero = simd.splat(E::faer_zero());
+
+            #[inline(always)]
+            fn process<E: ComplexField, S: pulp::Simd>(
+                simd: SimdFor<E, S>,
+                f: &mut impl SimdMap<E>,
+                mut dst: impl Write<Output = SimdGroupFor<E, S>>,
+                src: impl Read<Output = SimdGroupFor<E, S>>,
+                zero: SimdGroupFor<E, S>,
+            ) {
+                dst.write(f.apply(simd, src.read_or(zero)))
+            }
+
+            let offset = simd.align_offset(dst.rb());
+            let (mut dst_head, dst_body, mut dst_tail) =
+                simd.as_aligned_simd_mut(dst.rb_mut(), offset);
+            match src {
+                Some(src) => {
+                    let (src_head, src_body, src_tail) = simd.as_aligned_simd(src, offset);
+                    process(simd, f, dst_head, src_head, zero);
+                    for (dst, src) in dst_body.into_mut_iter().zip(src_body.into_ref_iter()) {
+                        process(simd, f, dst, src, zero);
+                    }
+                    process(simd, f, dst_tail, src_tail, zero);
+                }
+                None => {
+                    let head = dst_head.read_or(zero);
+                    dst_head.write(f.apply(simd, head));
+                    for mut dst in dst_body.into_mut_iter() {
+                        let values = dst.read_or(zero);
+                        dst.write(f.apply(simd, values));
+                    }
+                    let tail = dst_tail.read_or(zero);
+                    dst_tail.write(f.apply(simd, tail));
+                }
+            }
+        }
+    }
+
+    let mut dst = dst;
+    crate::assert!(all(dst.nrows() == src.nrows(), dst.row_stride() == 1));
+
+    // strided sources are copied to the output first, then mapped in place
+    let src = match src.try_as_slice() {
+        Some(src) => Some(SliceGroup::new(src)),
+        None => {
+            dst.copy_from(src);
+            None
+        }
+    };
+    let dst = SliceGroupMut::new(dst.try_as_slice_mut().unwrap());
+    E::Simd::default().dispatch(Impl { dst, src, f });
+}
+
 impl<E: Entity, S: pulp::Simd> Read for Prefix<'_, E, S> {
     type Output = SimdGroupFor<E, S>;
     #[inline(always)]